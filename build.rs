@@ -0,0 +1,139 @@
+//! Bakes strategy data for the embedded wordbank into the binary when the `precomputed`
+//! feature is on: the best starting words, and the best second guess after each feedback the
+//! top starting word can get. Without the feature it writes an empty file, so the crate can
+//! always include it.
+//!
+//! The scoring here mirrors `solver::expected_pool_size` on raw bytes, since a build script
+//! can't use the crate it builds. `precomputed`'s tests check the two agree.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::Path;
+
+/// Source list of the embedded wordbank, which `wordbank.wbk` is packed from
+const WORDBANK: &str = "src/resources/wordbank.txt";
+
+/// Starting words baked in, enough for the default and a few more
+const STARTING_WORDS: usize = 10;
+
+const WORD_LENGTH: usize = 5;
+
+type Word = [u8; WORD_LENGTH];
+
+/// Feedback digits per position: 2 green, 1 yellow, 0 gray
+fn feedback(guess: &Word, solution: &Word) -> Word {
+    let mut digits = [0; WORD_LENGTH];
+    let mut used = [false; WORD_LENGTH];
+    for i in 0..WORD_LENGTH {
+        if guess[i] == solution[i] {
+            digits[i] = 2;
+            used[i] = true;
+        }
+    }
+    for i in 0..WORD_LENGTH {
+        if digits[i] == 2 {
+            continue;
+        }
+        if let Some(j) = (0..WORD_LENGTH).find(|&j| !used[j] && solution[j] == guess[i]) {
+            digits[i] = 1;
+            used[j] = true;
+        }
+    }
+    digits
+}
+
+fn code(digits: &Word) -> usize {
+    digits
+        .iter()
+        .rev()
+        .fold(0, |code, &d| code * 3 + usize::from(d))
+}
+
+/// Sum of squared bucket sizes of `guess` over `candidates`, stopping once it reaches `bound`
+fn sum_of_squares(guess: &Word, candidates: &[Word], bound: usize) -> usize {
+    let mut counts = [0usize; 243];
+    let mut sum = 0;
+    for solution in candidates {
+        let count = &mut counts[code(&feedback(guess, solution))];
+        sum += 2 * *count + 1;
+        *count += 1;
+        if sum >= bound {
+            break;
+        }
+    }
+    sum
+}
+
+/// The `count` guesses with the smallest sums over `candidates`, ties going to the earlier
+/// word, as `(sum, index)` pairs best first
+fn best_guesses(words: &[Word], candidates: &[Word], count: usize) -> Vec<(usize, usize)> {
+    let mut best: Vec<(usize, usize)> = Vec::with_capacity(count + 1);
+    for (i, guess) in words.iter().enumerate() {
+        // Words come in order, so a later word only gets in by scoring strictly lower
+        let bound = if best.len() == count {
+            best[count - 1].0
+        } else {
+            usize::MAX
+        };
+        let sum = sum_of_squares(guess, candidates, bound);
+        if sum < bound {
+            let at = best.partition_point(|&(s, _)| s <= sum);
+            best.insert(at, (sum, i));
+            best.truncate(count);
+        }
+    }
+    best
+}
+
+fn compute(source: &str) -> String {
+    let words: Vec<Word> = source
+        .lines()
+        .map(str::trim)
+        .filter(|w| w.len() == WORD_LENGTH && w.bytes().all(|b| b.is_ascii_alphabetic()))
+        .map(|w| {
+            let mut word = [0; WORD_LENGTH];
+            word.copy_from_slice(w.to_ascii_uppercase().as_bytes());
+            word
+        })
+        .collect();
+    let text = |word: &Word| String::from_utf8_lossy(word).into_owned();
+
+    let mut out = format!("# Generated by build.rs from {WORDBANK}\n");
+    let starting = best_guesses(&words, &words, STARTING_WORDS);
+    for &(sum, i) in &starting {
+        out += &format!("start {} {sum}\n", text(&words[i]));
+    }
+    let Some(&(_, opener)) = starting.first() else {
+        return out;
+    };
+    let mut buckets: BTreeMap<Word, Vec<Word>> = BTreeMap::new();
+    for solution in &words {
+        buckets
+            .entry(feedback(&words[opener], solution))
+            .or_default()
+            .push(*solution);
+    }
+    // A bucket of one is solved, so needs no second guess
+    for (digits, bucket) in buckets.iter().filter(|(_, bucket)| bucket.len() > 1) {
+        let pattern: String = digits
+            .iter()
+            .map(|&d| ['X', 'Y', 'G'][usize::from(d)])
+            .collect();
+        let (_, guess) = best_guesses(&words, bucket, 1)[0];
+        out += &format!("second {pattern} {}\n", text(&words[guess]));
+    }
+    out
+}
+
+fn main() {
+    println!("cargo::rerun-if-changed=build.rs");
+    println!("cargo::rerun-if-changed={WORDBANK}");
+    let data = if env::var_os("CARGO_FEATURE_PRECOMPUTED").is_some() {
+        compute(&fs::read_to_string(WORDBANK).expect("the embedded wordbank's source list"))
+    } else {
+        String::new()
+    };
+    let out = Path::new(&env::var("OUT_DIR").expect("set by cargo")).join("precomputed.txt");
+    fs::write(out, data).expect("OUT_DIR is writable");
+}
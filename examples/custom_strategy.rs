@@ -0,0 +1,78 @@
+//! Plays every word of a wordbank with a guess picker of your own, and compares it with the
+//! built-in recommender. The picker here guesses the candidate made of the letters most common
+//! among the remaining candidates, which only looks at letters and never at the feedback
+//! patterns a guess would split the pool into.
+//!
+//! ```bash
+//! cargo run --release --example custom_strategy
+//! ```
+
+use wordle_solver::game::Game;
+use wordle_solver::solver::{SolverConfig, get_feedback, letter_counts};
+use wordle_solver::wordbank::sample_wordbank;
+use wordle_solver::words::{Word, WordBank};
+
+/// The candidate whose distinct letters appear in the most candidates
+fn common_letters_guess(candidates: &[String]) -> Option<String> {
+    let counts = letter_counts(candidates);
+    let score = |word: &String| {
+        let mut letters: Vec<u8> = word.bytes().collect();
+        letters.sort_unstable();
+        letters.dedup();
+        letters
+            .iter()
+            .map(|&b| counts[usize::from(b - b'A')])
+            .sum::<usize>()
+    };
+    candidates
+        .iter()
+        .max_by(|a, b| score(a).cmp(&score(b)).then_with(|| b.cmp(a)))
+        .cloned()
+}
+
+/// Guesses it took to play `answer`, picking each guess with `pick`
+fn play(wordbank: &WordBank, answer: &str, pick: impl Fn(&mut Game) -> Option<Word>) -> usize {
+    let config = SolverConfig {
+        use_cache: false,
+        ..SolverConfig::default()
+    };
+    let mut game = Game::new(wordbank, config);
+    while !game.is_over() {
+        let Some(guess) = pick(&mut game) else {
+            break;
+        };
+        game.guess(&guess, &get_feedback(&guess, answer));
+    }
+    // The game ends once only the answer is left, which may still need playing
+    let played = game
+        .history()
+        .last()
+        .is_some_and(|(guess, _)| guess == answer);
+    game.history().len() + usize::from(!played)
+}
+
+fn main() {
+    let wordbank = sample_wordbank();
+    let total = |pick: &dyn Fn(&mut Game) -> Option<Word>| -> usize {
+        wordbank
+            .iter()
+            .map(|answer| play(&wordbank, answer, pick))
+            .sum()
+    };
+
+    let recommended = total(&|game| game.recommendation().map(|rec| rec.guess));
+    let common_letters =
+        total(&|game| common_letters_guess(game.candidates()).and_then(|guess| Word::new(&guess)));
+
+    #[allow(clippy::cast_precision_loss)] // a handful of games
+    let average = |guesses: usize| guesses as f64 / wordbank.len() as f64;
+    println!("{} games", wordbank.len());
+    println!(
+        "recommender:    {:.2} guesses on average",
+        average(recommended)
+    );
+    println!(
+        "common letters: {:.2} guesses on average",
+        average(common_letters)
+    );
+}
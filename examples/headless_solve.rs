@@ -0,0 +1,57 @@
+//! Solves one game with the headless `Game` facade, following every recommendation.
+//!
+//! ```bash
+//! cargo run --example headless_solve -- quota
+//! ```
+
+use wordle_solver::game::{Game, Outcome};
+use wordle_solver::share::feedback_square;
+use wordle_solver::solver::{SolverConfig, get_feedback};
+use wordle_solver::wordbank::sample_wordbank;
+
+fn main() {
+    let wordbank = sample_wordbank();
+    let answer = std::env::args()
+        .nth(1)
+        .map_or_else(|| "CHAMP".to_string(), |word| word.to_uppercase());
+    if !wordbank.contains(&answer) {
+        eprintln!("{answer} isn't in the sample wordbank");
+        std::process::exit(1);
+    }
+
+    let config = SolverConfig {
+        use_cache: false,
+        ..SolverConfig::default()
+    };
+    let mut game = Game::new(&wordbank, config);
+    while !game.is_over() {
+        let Some(recommendation) = game.recommendation() else {
+            break;
+        };
+        let feedback = get_feedback(&recommendation.guess, &answer);
+        let squares: String = feedback.iter().map(|&f| feedback_square(f)).collect();
+        let result = game.guess(&recommendation.guess, &feedback);
+        println!(
+            "{} {squares} ({} candidates left)",
+            recommendation.guess,
+            result.diff.survived.len()
+        );
+    }
+
+    match game.outcome() {
+        Outcome::Solved(word) => {
+            // The game ends once only the answer is left, which may still need playing
+            let mut guesses = game.history().len();
+            if game
+                .history()
+                .last()
+                .is_none_or(|(guess, _)| *guess != word)
+            {
+                println!("{word} {}", "🟩".repeat(5));
+                guesses += 1;
+            }
+            println!("Solved: {word} in {guesses} guesses");
+        }
+        outcome => println!("Stopped at {outcome:?}"),
+    }
+}
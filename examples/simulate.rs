@@ -0,0 +1,169 @@
+//! Solves every word of a wordbank as the answer with each strategy, following the
+//! recommendations, and summarises how many guesses each game took. Pass an error rate to
+//! have the simulated player mis-mark that share of tiles, and see how often each strategy
+//! still gets there.
+//!
+//! Each strategy's chart is followed by a calibration report: the chances of solving by each
+//! guess that the solver shows during a game, next to how often the simulated games actually
+//! were. Calibration error is the weighted gap between the two; near 0, the shown chances can
+//! be trusted.
+//!
+//! Run in a terminal, a live dashboard shows each strategy's progress, running average and
+//! worst answer so far while the games are played, and the report follows once they're done.
+//!
+//! ```bash
+//! cargo run --release --example simulate            # a player who never slips
+//! cargo run --release --example simulate -- 0.05    # one tile in twenty marked wrong
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+#[cfg(feature = "tui")]
+use std::io::IsTerminal;
+use wordle_solver::noise::{NoiseModel, SimulatedEnd, SimulationProgress, simulate_game_observed};
+use wordle_solver::odds::{Calibration, WIN_ODDS_LIMIT, WinOdds};
+use wordle_solver::rng::Rng;
+use wordle_solver::solver::{SolverConfig, Strategy};
+#[cfg(feature = "tui")]
+use wordle_solver::tui::{SimulationDashboard, install_panic_hook};
+use wordle_solver::wordbank::sample_wordbank;
+use wordle_solver::words::WordArena;
+
+/// Games played per answer when marking is noisy, to average out the luck
+const NOISY_TRIALS: usize = 25;
+
+fn main() {
+    let error_rate: f64 = match std::env::args().nth(1).map(|arg| arg.parse()) {
+        None => 0.0,
+        Some(Ok(rate)) if (0.0..=1.0).contains(&rate) => rate,
+        Some(_) => {
+            eprintln!("The error rate must be a number from 0 to 1");
+            std::process::exit(1);
+        }
+    };
+    let noise = NoiseModel::new(error_rate);
+    let trials = if error_rate > 0.0 { NOISY_TRIALS } else { 1 };
+    let wordbank = sample_wordbank();
+    let arena = WordArena::new(&wordbank);
+    let games = wordbank.len() * trials;
+    let title = format!(
+        "{games} games per strategy, {:.1}% of tiles mis-marked",
+        error_rate * 100.0
+    );
+    println!("{title}");
+
+    let strategies = [Strategy::ExpectedSize, Strategy::CandidatesOnly];
+    let mut progress: Vec<SimulationProgress> = strategies
+        .iter()
+        .map(|strategy| SimulationProgress::new(&strategy.to_string(), games))
+        .collect();
+    // Shown on the dashboard's screen while the games run, so printed once it's closed
+    let mut report = String::new();
+    #[cfg(feature = "tui")]
+    let mut dashboard = std::io::stdout()
+        .is_terminal()
+        .then(|| {
+            install_panic_hook();
+            SimulationDashboard::new().ok()
+        })
+        .flatten();
+
+    for (row, strategy) in strategies.into_iter().enumerate() {
+        let config = SolverConfig {
+            strategy,
+            use_cache: false,
+            ..SolverConfig::default()
+        };
+        let mut rng = Rng::new(1);
+        let mut guesses_taken: BTreeMap<usize, usize> = BTreeMap::new();
+        let (mut out_of_guesses, mut unsolvable) = (0, 0);
+        let mut calibration = Calibration::default();
+        for answer in &wordbank {
+            for _ in 0..trials {
+                // The odds the solver would show before each guess
+                let mut predictions = Vec::new();
+                let end =
+                    simulate_game_observed(&wordbank, answer, &config, noise, &mut rng, |game| {
+                        let candidates = game.candidates();
+                        if (1..=WIN_ODDS_LIMIT).contains(&candidates.len()) {
+                            predictions.push(WinOdds::compute(
+                                &wordbank,
+                                candidates,
+                                game.history().len(),
+                                strategy,
+                                arena.as_ref(),
+                            ));
+                        }
+                    });
+                let solved_on = match end {
+                    SimulatedEnd::Solved(guesses) => {
+                        *guesses_taken.entry(guesses).or_default() += 1;
+                        Some(guesses)
+                    }
+                    SimulatedEnd::OutOfGuesses => {
+                        out_of_guesses += 1;
+                        None
+                    }
+                    SimulatedEnd::Unsolvable(_) => {
+                        unsolvable += 1;
+                        None
+                    }
+                };
+                for odds in &predictions {
+                    calibration.record(odds, solved_on);
+                }
+                progress[row].record(answer, end);
+                #[cfg(feature = "tui")]
+                if let Some(dashboard) = &mut dashboard {
+                    let done = progress[row].played == games;
+                    let _ = dashboard.draw(&title, &progress, done);
+                }
+            }
+        }
+
+        #[allow(clippy::cast_precision_loss)] // a handful of games
+        let share = |count: usize| count as f64 * 100.0 / games as f64;
+        let solved: usize = guesses_taken.values().sum();
+        #[allow(clippy::cast_precision_loss)]
+        let average = guesses_taken
+            .iter()
+            .map(|(&guesses, &games)| guesses * games)
+            .sum::<usize>() as f64
+            / solved.max(1) as f64;
+        writeln!(report).unwrap();
+        writeln!(
+            report,
+            "{strategy}: {:.1}% solved in {average:.2} guesses on average, \
+             {:.1}% out of guesses, {:.1}% unsolvable",
+            share(solved),
+            share(out_of_guesses),
+            share(unsolvable)
+        )
+        .unwrap();
+        for (guesses, games) in &guesses_taken {
+            writeln!(report, "{guesses}: {}", "#".repeat(games.div_ceil(trials))).unwrap();
+        }
+
+        writeln!(
+            report,
+            "calibration over {} predictions: error {:.3}",
+            calibration.len(),
+            calibration.error()
+        )
+        .unwrap();
+        writeln!(report, "  predicted  observed  predictions").unwrap();
+        for bin in calibration.bins() {
+            writeln!(
+                report,
+                "  {:>8.1}%  {:>7.1}%  {:>11}",
+                bin.predicted * 100.0,
+                bin.observed * 100.0,
+                bin.count
+            )
+            .unwrap();
+        }
+    }
+    #[cfg(feature = "tui")]
+    drop(dashboard);
+    print!("{report}");
+}
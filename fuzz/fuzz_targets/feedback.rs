@@ -0,0 +1,19 @@
+//! Feedback typed at the prompt (`GYXXG`, emoji squares, `greens: 1,4`) and pasted share
+//! grids.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use wordle_solver::cli::parse_feedback;
+use wordle_solver::share::parse_share_grid;
+use wordle_solver::words::WORD_LENGTH;
+
+fuzz_target!(|input: &str| {
+    if let Some(feedback) = parse_feedback(input) {
+        assert_eq!(feedback.len(), WORD_LENGTH);
+    }
+    if let Ok(rows) = parse_share_grid(input) {
+        assert!(!rows.is_empty());
+        assert!(rows.iter().all(|row| row.len() == WORD_LENGTH));
+    }
+});
@@ -0,0 +1,31 @@
+//! Filtering candidates with arbitrary guesses, feedback and words, of any length.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use wordle_solver::Feedback;
+use wordle_solver::solver::{filter_candidates, get_feedback, is_consistent};
+
+fuzz_target!(|input: (String, Vec<u8>, Vec<String>)| {
+    let (guess, codes, candidates) = input;
+    let feedback: Vec<Feedback> = codes
+        .iter()
+        .map(|code| match code % 3 {
+            0 => Feedback::NoMatch,
+            1 => Feedback::PartialMatch,
+            _ => Feedback::Match,
+        })
+        .collect();
+
+    let filtered = filter_candidates(&candidates, &guess, &feedback);
+    assert!(filtered.iter().all(|word| candidates.contains(word)));
+
+    // Each candidate is always consistent with the feedback it would give itself
+    for word in &candidates {
+        let own = get_feedback(&guess, word);
+        assert_eq!(own.len(), guess.chars().count());
+        if word.chars().count() == guess.chars().count() {
+            assert!(is_consistent(word, &guess, &own));
+        }
+    }
+});
@@ -0,0 +1,29 @@
+//! Wordbank files, both plain text with a header and packed `.wbk` data.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use wordle_solver::wordbank::{decode_wordbank, encode_wordbank, parse_wordbank};
+use wordle_solver::words::{WORD_LENGTH, WordArena};
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(words) = decode_wordbank(data) {
+        assert!(words.iter().all(|word| word.len() == WORD_LENGTH));
+        let packed = encode_wordbank(&words).expect("decoded words re-encode");
+        assert_eq!(decode_wordbank(&packed).as_ref(), Ok(&words));
+    }
+    if let Ok((_, words)) = std::str::from_utf8(data)
+        .map_err(|e| e.to_string())
+        .and_then(parse_wordbank)
+    {
+        assert!(
+            words
+                .iter()
+                .all(|word| word.len() == WORD_LENGTH
+                    && word.bytes().all(|b| b.is_ascii_uppercase()))
+        );
+        if words.len() <= usize::from(u16::MAX) + 1 {
+            assert!(WordArena::new(&words).is_some());
+        }
+    }
+});
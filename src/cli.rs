@@ -1,8 +1,29 @@
-use crate::game_state::{GameInterface, Recommendation, StartingWordsInfo, UserAction};
-use crate::solver::Feedback;
-use clap::{Parser, ValueEnum};
-use std::io::BufRead;
-use std::path::PathBuf;
+use crate::cluster::{Splitter, parse_pattern};
+use crate::elimination::WhyNot;
+use crate::export::write_candidates;
+use crate::external::ExternalScorer;
+use crate::game_state::{
+    CandidateDiff, Correction, GameDisplay, GameEvent, GameInterface, GuessWarning, HINT_LEVELS,
+    Hint, InputResult, Recommendation, SessionOptions, StartingWordsInfo, Tagging, UserAction,
+};
+use crate::i18n::Message;
+use crate::information::{GuessInformation, information_chart};
+use crate::odds::WinOdds;
+use crate::play::TimeoutAction;
+use crate::progress::Budget;
+#[cfg(feature = "scripting")]
+use crate::script::ScriptScorer;
+use crate::setup::Setup;
+use crate::share::{ShareAnalysis, feedback_square};
+use crate::solver::{Feedback, MAX_LOOKAHEAD, Profile, SolverConfig, Strategy, expected_pool_size};
+use crate::stats::Achievement;
+use crate::wordbank::{WordbankStats, load_wordbank_from_file};
+use crate::words::{WORD_LENGTH, Word};
+use clap::{Parser, Subcommand, ValueEnum};
+use std::collections::BTreeSet;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 /// UI mode for the application
 #[derive(Clone, Debug, ValueEnum, Default)]
@@ -14,17 +35,388 @@ pub enum UiMode {
     Cli,
 }
 
+/// Subcommands that run instead of the interactive solver
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Inspect the loaded wordbank
+    Wordbank {
+        #[command(subcommand)]
+        action: WordbankCommand,
+    },
+    /// Two players take turns guessing the same hidden word in the TUI
+    Versus,
+    /// Race the solver to a hidden word in the TUI; its guesses show after you make yours
+    Race,
+    /// Print your current and longest daily-play streak on one line, e.g. for a shell prompt
+    Streak,
+    /// Paste a Wordle share grid on stdin to see which guesses fit it and how well they played
+    ImportShare {
+        /// The answer of the shared game
+        answer: String,
+    },
+    /// Show today's official puzzle number (fetched with the `online` feature)
+    Puzzle,
+    /// Check the caches, config, wordbank and terminal, and suggest fixes for any problems
+    Doctor,
+    /// Print a code for the solver flags given, which `--setup` runs with exactly the same way
+    Setup,
+}
+
+#[derive(Subcommand, Clone, Debug, PartialEq, Eq)]
+pub enum WordbankCommand {
+    /// Letter frequencies, vowel share, repeated letters, and common letters by position
+    Stats,
+    /// Pack the wordbank into a compact .wbk file that `--input` can load
+    Compile {
+        /// Where to write the packed wordbank
+        output: PathBuf,
+    },
+    /// List the words added, removed, or changed in case between two word lists
+    Diff {
+        /// The older list
+        old: PathBuf,
+        /// The newer list
+        new: PathBuf,
+    },
+}
+
+/// How much the CLI prints each round
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Verbosity {
+    /// Only the recommended guess, one word per line, for piping into other tools
+    Quiet,
+    /// The recommendation, candidates and messages
+    #[default]
+    Normal,
+    /// Also scores for the candidates shown, what each position can still be, and timing
+    Verbose,
+}
+
+/// What the CLI interface prints, shared by everything that writes CLI output
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OutputOptions {
+    pub verbosity: Verbosity,
+    /// Print `[time]` output for each step
+    pub timing: bool,
+    /// Describe rounds in words and skip the progress bar
+    pub accessible: bool,
+}
+
+impl OutputOptions {
+    #[must_use]
+    pub fn quiet(&self) -> bool {
+        self.verbosity == Verbosity::Quiet
+    }
+
+    #[must_use]
+    pub fn verbose(&self) -> bool {
+        self.verbosity == Verbosity::Verbose
+    }
+
+    /// Whether `[time]` output is shown, as it is with `--time` or `--verbose`
+    #[must_use]
+    pub fn show_timing(&self) -> bool {
+        self.timing || self.verbose()
+    }
+}
+
 /// Wordle Solver CLI options
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 pub struct Cli {
-    /// Path to a newline-delimited wordbank file
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Wordbank to load: a newline-delimited file, a directory of them, or an http(s) URL
     #[arg(short = 'i', long = "input")]
     pub wordbank_path: Option<String>,
 
     /// User interface mode
     #[arg(long = "ui", default_value = "tui")]
     pub ui_mode: UiMode,
+
+    /// Solver preset: fast, balanced, or exhaustive
+    #[arg(long = "profile", default_value = "balanced")]
+    pub profile: Profile,
+
+    /// Run with the solver flags of a code from the `setup` command instead of a profile
+    #[arg(long = "setup", value_name = "CODE", conflicts_with = "profile")]
+    pub setup: Option<Setup>,
+
+    /// Guess pool to score: expected-size (whole wordbank) or candidates-only
+    #[arg(long = "strategy")]
+    pub strategy: Option<Strategy>,
+
+    /// Score guesses against a sample of N candidates when more remain (faster, approximate)
+    #[arg(long = "sample", value_name = "N")]
+    pub sample_size: Option<usize>,
+
+    /// Guesses to look ahead when ranking (0 = greedy, 1 = re-rank the best guesses by the
+    /// following guess too)
+    #[arg(long = "lookahead", value_name = "DEPTH", value_parser = parse_lookahead)]
+    pub lookahead: Option<usize>,
+
+    /// How many of the best starting words to show and cache
+    #[arg(long = "starting-words", value_name = "N", value_parser = parse_starting_words)]
+    pub starting_words: Option<usize>,
+
+    /// Don't read or write the starting-words cache
+    #[arg(long = "no-cache")]
+    pub no_cache: bool,
+
+    /// Compute the starting words afresh instead of reading them from the cache, then cache
+    /// them
+    #[arg(long = "recompute", conflicts_with = "no_cache")]
+    pub recompute: bool,
+
+    /// Keep memory down on constrained machines: score guesses from scratch until the pool is
+    /// small, and report roughly how much memory the game takes
+    #[arg(long = "low-memory")]
+    pub low_memory: bool,
+
+    /// Command to score guesses with instead of expected pool size, e.g. "python3 scorer.py";
+    /// it reads JSON on stdin and prints JSON scores
+    #[arg(long = "external-scorer", value_name = "CMD", value_parser = parse_external_scorer)]
+    pub external_scorer: Option<ExternalScorer>,
+
+    /// Rhai script defining score(guess, patterns) to rank guesses with instead of expected
+    /// pool size
+    #[cfg(feature = "scripting")]
+    #[arg(long = "script", value_name = "PATH", value_parser = parse_script)]
+    pub script: Option<ScriptScorer>,
+
+    /// Tag each game's first guess as forced, leaving a fixed opener out of the post-game score
+    #[arg(long = "fixed-opener")]
+    pub fixed_opener: bool,
+
+    /// Seed for anything random (e.g. sampled scoring), so runs can be reproduced
+    #[arg(long = "seed", value_name = "N")]
+    pub seed: Option<u64>,
+
+    /// Letters to keep out of recommendations where a comparable guess does without them,
+    /// e.g. "S,Y"
+    #[arg(long = "avoid", value_name = "LETTERS", value_parser = parse_avoid)]
+    pub avoid: Option<String>,
+
+    /// Don't show starting words or recommendations; ask for hints instead
+    #[arg(long = "hints")]
+    pub hints: bool,
+
+    /// Describe rounds in words instead of drawing tiles or progress bars, for screen readers
+    #[arg(long = "accessible")]
+    pub accessible: bool,
+
+    /// Mark tiles with ✓ (green), • (yellow) and ✗ (gray) so they read without colour
+    #[arg(long = "accessible-tiles")]
+    pub accessible_tiles: bool,
+
+    /// Interface language: en (default), de, a name with a .toml file in ~/.wordle_locales, or
+    /// the path of a .toml translation. Overrides `language` in ~/.wordle_config
+    #[arg(long = "lang", value_name = "LANG")]
+    pub language: Option<String>,
+
+    /// Resume a game from its rounds so far, e.g. "CRANE:XYGXX,SLATE:GYXXX"
+    #[arg(long = "state", value_name = "ROUNDS")]
+    pub state: Option<String>,
+
+    /// Resume a saved game, such as the one Ctrl+C leaves in ~/.wordle_saved_game. A save
+    /// made for another wordbank or that doesn't add up is refused.
+    #[arg(long = "resume", value_name = "PATH", conflicts_with = "state")]
+    pub resume: Option<PathBuf>,
+
+    /// Print how long loading, caching, and each recommendation take
+    #[arg(long = "time")]
+    pub time: bool,
+
+    /// Print only the recommended guess each round (CLI only)
+    #[arg(short = 'q', long = "quiet", conflicts_with = "verbose")]
+    pub quiet: bool,
+
+    /// Also print candidate scores, what each position can still be, and timing (CLI only)
+    #[arg(short = 'v', long = "verbose")]
+    pub verbose: bool,
+
+    /// Write tracing spans and events for rounds, scoring and TUI state changes to this file
+    #[arg(long = "trace-output", value_name = "PATH")]
+    pub trace_output: Option<PathBuf>,
+
+    /// Record the TUI session's keys and states to this JSON file, e.g. for a bug report
+    #[arg(long = "record", value_name = "PATH")]
+    pub record: Option<PathBuf>,
+
+    /// Play back a session recorded with --record in the TUI, then hand the keyboard back
+    #[arg(long = "replay", value_name = "PATH")]
+    pub replay: Option<PathBuf>,
+
+    /// How many times faster than recorded to play back --replay
+    #[arg(long = "replay-speed", value_name = "N", default_value_t = 1.0, value_parser = parse_replay_speed)]
+    pub replay_speed: f64,
+
+    /// Start a new game after this many seconds without input (TUI only)
+    #[arg(long = "idle-timeout", value_name = "SECS")]
+    pub idle_timeout: Option<u64>,
+
+    /// Give up with an error after this many invalid inputs in a row, e.g. from a script
+    #[arg(long = "max-invalid", value_name = "N")]
+    pub max_invalid: Option<usize>,
+
+    /// Stop each recommendation after this many milliseconds with the best guess so far
+    #[arg(long = "budget-ms", value_name = "MS")]
+    pub budget_ms: Option<u64>,
+
+    /// Stop each recommendation after scoring this many guesses with the best so far
+    #[arg(long = "budget-evals", value_name = "N")]
+    pub budget_evals: Option<usize>,
+
+    /// Seconds allowed for each guess in versus and race modes
+    #[arg(long = "guess-timer", value_name = "SECS")]
+    pub guess_timer: Option<u64>,
+
+    /// What happens when the guess timer runs out: forfeit or recommend
+    #[arg(long = "on-timeout", default_value = "forfeit")]
+    pub on_timeout: TimeoutAction,
+}
+
+impl Cli {
+    /// Solver configuration from the chosen profile or `--setup` code, with any explicit
+    /// flags applied on top
+    #[must_use]
+    pub fn solver_config(&self) -> SolverConfig {
+        let mut config = self
+            .setup
+            .as_ref()
+            .map_or_else(|| self.profile.config(), |setup| setup.config.clone());
+        if let Some(strategy) = self.strategy {
+            config.strategy = strategy;
+        }
+        if self.sample_size.is_some() {
+            config.sample_size = self.sample_size;
+        }
+        if let Some(lookahead) = self.lookahead {
+            config.lookahead = lookahead;
+        }
+        if let Some(count) = self.starting_words {
+            config.starting_words = count;
+        }
+        if self.no_cache {
+            config.use_cache = false;
+        }
+        config.fixed_opener = self.fixed_opener;
+        if self.external_scorer.is_some() {
+            config.external_scorer.clone_from(&self.external_scorer);
+        }
+        #[cfg(feature = "scripting")]
+        if self.script.is_some() {
+            config.script.clone_from(&self.script);
+        }
+        if self.seed.is_some() {
+            config.seed = self.seed;
+        }
+        if self.hints {
+            config.auto_recommend = false;
+        }
+        if let Some(avoid) = &self.avoid {
+            config.avoid = avoid.chars().collect();
+        }
+        if self.budget_ms.is_some() || self.budget_evals.is_some() {
+            config.budget = Budget {
+                max_time: self.budget_ms.map(Duration::from_millis),
+                max_evaluations: self.budget_evals,
+            };
+        }
+        config
+    }
+
+    /// Session options from the `--setup` code, if any, and the session flags. The stats
+    /// file and the rounds to resume from are left for the caller to fill in.
+    #[must_use]
+    pub fn session_options(&self) -> SessionOptions {
+        let mut session = self
+            .setup
+            .as_ref()
+            .map(|setup| setup.session.clone())
+            .unwrap_or_default();
+        session.recompute |= self.recompute;
+        session.low_memory |= self.low_memory;
+        session.idle_timeout = self.idle_timeout.map(Duration::from_secs);
+        session.max_invalid_inputs = self.max_invalid;
+        session
+    }
+
+    /// CLI output settings from `--quiet`, `--verbose`, `--time` and `--accessible`
+    #[must_use]
+    pub fn output_options(&self) -> OutputOptions {
+        let verbosity = if self.quiet {
+            Verbosity::Quiet
+        } else if self.verbose {
+            Verbosity::Verbose
+        } else {
+            Verbosity::Normal
+        };
+        OutputOptions {
+            verbosity,
+            timing: self.time,
+            accessible: self.accessible,
+        }
+    }
+
+    /// Rounds to resume from given with `--state`, empty without it
+    ///
+    /// # Errors
+    /// Returns an error if `--state` isn't a list of `GUESS:FEEDBACK` rounds.
+    pub fn initial_rounds(&self) -> Result<Vec<(String, Vec<Feedback>)>, String> {
+        match &self.state {
+            None => Ok(Vec::new()),
+            Some(state) => parse_rounds(state).ok_or_else(|| {
+                format!("invalid --state '{state}' (expected rounds like CRANE:XYGXX,SLATE:GYXXX)")
+            }),
+        }
+    }
+}
+
+/// Parses a `--starting-words` count, which must be at least one
+fn parse_starting_words(s: &str) -> Result<usize, String> {
+    match s.parse() {
+        Ok(0) => Err("must be at least 1".to_string()),
+        Ok(count) => Ok(count),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Parses a `--lookahead` depth, which the search supports up to [`MAX_LOOKAHEAD`]
+fn parse_lookahead(s: &str) -> Result<usize, String> {
+    match s.parse() {
+        Ok(depth) if depth > MAX_LOOKAHEAD => Err(format!("must be at most {MAX_LOOKAHEAD}")),
+        Ok(depth) => Ok(depth),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Parses a `--replay-speed`, which must be a positive number
+fn parse_replay_speed(s: &str) -> Result<f64, String> {
+    s.parse::<f64>()
+        .ok()
+        .filter(|speed| speed.is_finite() && *speed > 0.0)
+        .ok_or_else(|| "expected a positive number, e.g. 2 for double speed".to_string())
+}
+
+/// Parses an `--external-scorer` command, which must name a program
+fn parse_external_scorer(s: &str) -> Result<ExternalScorer, String> {
+    ExternalScorer::new(s)
+        .ok_or_else(|| "expected a command, e.g. \"python3 scorer.py\"".to_string())
+}
+
+/// Loads and compiles the `--script` file
+#[cfg(feature = "scripting")]
+fn parse_script(s: &str) -> Result<ScriptScorer, String> {
+    ScriptScorer::load(Path::new(s))
+}
+
+/// Parses the `--avoid` letters into the distinct uppercase letters
+fn parse_avoid(s: &str) -> Result<String, String> {
+    parse_letters(s)
+        .map(|letters| letters.into_iter().collect())
+        .ok_or_else(|| "expected letters, e.g. S,Y".to_string())
 }
 
 #[must_use]
@@ -34,29 +426,142 @@ pub fn parse_cli() -> Cli {
 
 // UI Input/Output functions
 
+/// Number of words per column on each page of the candidate diff view
+pub const DIFF_PAGE_SIZE: usize = 20;
+
 pub enum GuessInput {
     Valid(String),
     Invalid,
     Exit,
     NewGame,
+    /// Show a page (zero-based) of the last round's candidate diff
+    Diff(usize),
+    /// Guess whatever the solver last suggested
+    UseRecommendation,
+    /// Ask for the next hint
+    Hint,
+    /// Rounds entered as `GUESS=FEEDBACK`
+    Rounds(Vec<(String, Vec<Feedback>)>),
+    /// Letters to find guesses for, entered as `probe AEIOU`
+    Probe(Vec<char>),
+    /// Letters for recommendations to avoid, entered as `avoid S,Y` (`avoid -` for none)
+    Avoid(Vec<char>),
+    /// Write the current candidates with their scores to this file
+    Export(PathBuf),
+    /// Keep only the candidates on the word list in this file
+    Restrict(PathBuf),
+    /// A round (counting from 1) and the feedback it should have had, entered as
+    /// `correct 2 GYXXG`
+    Correct(usize, Vec<Feedback>),
+    /// A word to explain the elimination of, entered as `why-not CRANE`
+    WhyNot(String),
+    /// A pattern of candidates to find the best splitting guess for, entered as `split _ATCH`
+    Split(String),
+    /// A round (counting from 1) and whether its guess was forced, entered as `tag 1 forced`
+    /// or `tag 1 free`
+    Tag(usize, bool),
 }
 
-fn is_valid_word(word: &str) -> bool {
-    word.len() == 5 && word.chars().all(|c| c.is_ascii_alphabetic())
+/// Parses a list of letters, optionally separated by commas or spaces, into distinct
+/// uppercase letters in the order given.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::cli::parse_letters;
+///
+/// assert_eq!(parse_letters("s, y"), Some(vec!['S', 'Y']));
+/// assert_eq!(parse_letters("AEIOUA"), Some(vec!['A', 'E', 'I', 'O', 'U']));
+/// assert_eq!(parse_letters("S1"), None);
+/// assert_eq!(parse_letters(" "), None);
+/// ```
+#[must_use]
+pub fn parse_letters(input: &str) -> Option<Vec<char>> {
+    let mut letters: Vec<char> = Vec::new();
+    for c in input.chars().filter(|&c| c != ',' && !c.is_whitespace()) {
+        if !c.is_ascii_alphabetic() {
+            return None;
+        }
+        let c = c.to_ascii_uppercase();
+        if !letters.contains(&c) {
+            letters.push(c);
+        }
+    }
+    (!letters.is_empty()).then_some(letters)
 }
 
-fn is_valid_feedback(feedback: &str) -> bool {
-    if feedback.is_empty() {
-        return false;
+/// Parses feedback for one guess in any of the forms [`Feedback::from_char`] takes: letters
+/// (`GYXXG` or `GYBBG`, in either case), digits (`21002`), or pasted share grid squares.
+/// Spaces and emoji variation selectors are ignored. It can also be given as lists of
+/// positions, e.g. `greens: 1,5 yellows: 2`, with every position not listed gray.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::cli::parse_feedback;
+/// use wordle_solver::Feedback;
+///
+/// let expected = vec![
+///     Feedback::Match,
+///     Feedback::PartialMatch,
+///     Feedback::NoMatch,
+///     Feedback::NoMatch,
+///     Feedback::Match,
+/// ];
+/// assert_eq!(parse_feedback("gyxxg"), Some(expected.clone()));
+/// assert_eq!(parse_feedback("GYBBG"), Some(expected.clone()));
+/// assert_eq!(parse_feedback("21002"), Some(expected.clone()));
+/// assert_eq!(parse_feedback("🟩🟨⬛⬜🟩"), Some(expected.clone()));
+/// assert_eq!(parse_feedback("greens: 1,5  yellows: 2"), Some(expected));
+/// assert_eq!(parse_feedback("GYXX"), None);
+/// ```
+#[must_use]
+pub fn parse_feedback(input: &str) -> Option<Vec<Feedback>> {
+    let lower = input.to_lowercase();
+    if lower.contains("green") || lower.contains("yellow") {
+        return parse_positions(&lower);
+    }
+    let feedback: Vec<Feedback> = input
+        .chars()
+        .filter(|&c| !c.is_whitespace() && c != '\u{fe0f}')
+        .map(|c| Feedback::from_char(c.to_ascii_uppercase()))
+        .collect::<Option<_>>()?;
+    (feedback.len() == WORD_LENGTH).then_some(feedback)
+}
+
+/// Parses lowercase `greens: 1,4 yellows: 2` feedback. Positions count from 1, a list may be
+/// empty, and no position can be both green and yellow.
+fn parse_positions(input: &str) -> Option<Vec<Feedback>> {
+    let mut feedback = vec![Feedback::NoMatch; WORD_LENGTH];
+    let mut colour = None;
+    for token in input
+        .split(|c: char| c == ':' || c == '=' || c == ',' || c.is_whitespace())
+        .filter(|token| !token.is_empty())
+    {
+        match token {
+            "green" | "greens" => colour = Some(Feedback::Match),
+            "yellow" | "yellows" => colour = Some(Feedback::PartialMatch),
+            _ => {
+                let position: usize = token.parse().ok()?;
+                let slot = feedback.get_mut(position.checked_sub(1)?)?;
+                if *slot != Feedback::NoMatch {
+                    return None;
+                }
+                *slot = colour?;
+            }
+        }
     }
-    let upper = feedback.to_uppercase();
-    upper.len() == 5 && upper.chars().all(|c| c == 'G' || c == 'Y' || c == 'X')
+    Some(feedback)
 }
 
-pub fn display_starting_words(words: &[String], used_cache: bool, cache_path: Option<&PathBuf>) {
+pub fn display_starting_words(
+    words: &[(String, f64)],
+    used_cache: bool,
+    cache_path: Option<&PathBuf>,
+) {
     println!("Optimal starting words:");
-    for (i, word) in words.iter().enumerate() {
-        println!("{}. {}", i + 1, word);
+    for (i, (word, score)) in words.iter().enumerate() {
+        println!("{}. {word} (expected pool size {score:.2})", i + 1);
     }
 
     if let Some(path) = cache_path {
@@ -68,134 +573,1012 @@ pub fn display_starting_words(words: &[String], used_cache: bool, cache_path: Op
     }
 
     if !words.is_empty() {
-        println!("Suggested starting word: {}", words[0]);
+        println!("Suggested starting word: {}", words[0].0);
     }
 }
 
-/// Read a guess from the user
-///
-/// # Panics
-/// Panics if reading from the input stream fails
-pub fn read_guess<R: BufRead>(reader: &mut R) -> GuessInput {
-    println!("\nEnter your guess (5 letters, or 'exit' to quit, or 'next' to start a new game):");
+/// Reads a line of input, or `None` once the input has ended or can't be read (which is
+/// reported)
+fn read_input<R: BufRead>(reader: &mut R) -> Option<String> {
     let mut input = String::new();
-    reader.read_line(&mut input).unwrap();
-    let input = input.trim().to_uppercase();
+    match reader.read_line(&mut input) {
+        Ok(0) => None,
+        Ok(_) => Some(input),
+        Err(e) => {
+            eprintln!("{}", Message::ErrorReadInput.fill(&[("error", &e)]));
+            None
+        }
+    }
+}
+
+/// Read a guess from the user. The end of the input (or a failure to read it) exits.
+pub fn read_guess<R: BufRead>(reader: &mut R) -> GuessInput {
+    println!("\n{}", Message::PromptGuess.text());
+    read_guess_unprompted(reader)
+}
+
+/// [`read_guess`] without the prompt, for quiet output
+fn read_guess_unprompted<R: BufRead>(reader: &mut R) -> GuessInput {
+    let Some(input) = read_input(reader) else {
+        return GuessInput::Exit;
+    };
+    let raw = input.trim();
+    let input = raw.to_uppercase();
 
     match input.as_str() {
         "EXIT" => GuessInput::Exit,
         "NEXT" => GuessInput::NewGame,
-        _ if is_valid_word(&input) => GuessInput::Valid(input),
+        "DIFF" => GuessInput::Diff(0),
+        "USE" => GuessInput::UseRecommendation,
+        "HINT" => GuessInput::Hint,
+        // Before rounds, since position-list feedback has colons
+        _ if input.starts_with("CORRECT ") => parse_correction(raw).map_or_else(
+            || {
+                println!("{}", Message::ErrorInvalidCorrect.text());
+                GuessInput::Invalid
+            },
+            |(round, feedback)| GuessInput::Correct(round, feedback),
+        ),
+        // Before rounds, so a path with a drive letter isn't taken for one
+        _ if input.starts_with("EXPORT-CANDIDATES") => {
+            match raw.get("export-candidates".len()..).map(str::trim) {
+                Some(path) if !path.is_empty() => GuessInput::Export(PathBuf::from(path)),
+                _ => {
+                    println!("{}", Message::ErrorInvalidExport.text());
+                    GuessInput::Invalid
+                }
+            }
+        }
+        _ if input.starts_with("RESTRICT") && !Word::is_valid(&input) => {
+            match raw.get("restrict".len()..).map(str::trim) {
+                Some(path) if !path.is_empty() => GuessInput::Restrict(PathBuf::from(path)),
+                _ => {
+                    println!("{}", Message::ErrorInvalidRestrict.text());
+                    GuessInput::Invalid
+                }
+            }
+        }
+        _ if input.contains(['=', ':']) => parse_rounds(&input).map_or_else(
+            || {
+                println!("{}", Message::ErrorInvalidRounds.text());
+                GuessInput::Invalid
+            },
+            GuessInput::Rounds,
+        ),
+        _ if input.starts_with("PROBE") && !Word::is_valid(&input) => parse_letters(&input[5..])
+            .map_or_else(
+                || {
+                    println!("{}", Message::ErrorInvalidProbe.text());
+                    GuessInput::Invalid
+                },
+                GuessInput::Probe,
+            ),
+        _ if input.starts_with("AVOID") && !Word::is_valid(&input) => match input[5..].trim() {
+            "-" => GuessInput::Avoid(Vec::new()),
+            letters => parse_letters(letters).map_or_else(
+                || {
+                    println!("{}", Message::ErrorInvalidAvoid.text());
+                    GuessInput::Invalid
+                },
+                GuessInput::Avoid,
+            ),
+        },
+        _ if input.starts_with("WHY-NOT") => parse_why_not(&input).map_or_else(
+            || {
+                println!("{}", Message::ErrorInvalidWhyNot.text());
+                GuessInput::Invalid
+            },
+            GuessInput::WhyNot,
+        ),
+        _ if input.starts_with("TAG ") => parse_tag(&input).map_or_else(
+            || {
+                println!("{}", Message::ErrorInvalidTag.text());
+                GuessInput::Invalid
+            },
+            |(round, forced)| GuessInput::Tag(round, forced),
+        ),
+        _ if input.starts_with("SPLIT") && !Word::is_valid(&input) => parse_pattern(&input[5..])
+            .map_or_else(
+                || {
+                    println!("{}", Message::ErrorInvalidSplit.text());
+                    GuessInput::Invalid
+                },
+                GuessInput::Split,
+            ),
+        _ if Word::is_valid(&input) => GuessInput::Valid(input),
+        _ if input.starts_with("DIFF ") => match input[5..].trim().parse::<usize>() {
+            Ok(page) if page > 0 => GuessInput::Diff(page - 1),
+            _ => {
+                println!("{}", Message::ErrorInvalidPage.text());
+                GuessInput::Invalid
+            }
+        },
         _ => {
-            println!("Invalid guess. Please enter 5 letters.");
+            println!("{}", Message::ErrorInvalidGuess.text());
             GuessInput::Invalid
         }
     }
 }
 
-/// Read feedback from the user
+/// Parses a game so far entered in one go as `GUESS=FEEDBACK` (or `GUESS:FEEDBACK`) rounds,
+/// separated by spaces, commas, or new lines.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::cli::parse_rounds;
+///
+/// let rounds = parse_rounds("crane=xygxx, SLATE=GGXXY").unwrap();
+/// assert_eq!(rounds.len(), 2);
+/// assert_eq!(rounds[1].0, "SLATE");
+/// assert!(parse_rounds("CRANE=XYG").is_none());
+/// ```
+#[must_use]
+pub fn parse_rounds(input: &str) -> Option<Vec<(String, Vec<Feedback>)>> {
+    let rounds: Option<Vec<_>> = input
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|round| !round.is_empty())
+        .map(|round| {
+            let (guess, feedback) = round.split_once(['=', ':'])?;
+            let guess = guess.to_uppercase();
+            if !Word::is_valid(&guess) {
+                return None;
+            }
+            Some((guess, parse_feedback(feedback)?))
+        })
+        .collect();
+    rounds.filter(|rounds| !rounds.is_empty())
+}
+
+/// Parses a correction entered as `correct <round> <feedback>`, with rounds counting from 1
+/// and the feedback in any form [`parse_feedback`] takes.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::cli::parse_correction;
+///
+/// let (round, feedback) = parse_correction("correct 2 GYXXG").unwrap();
+/// assert_eq!(round, 2);
+/// assert_eq!(feedback.len(), 5);
+/// assert!(parse_correction("CORRECT 1 greens: 1,4").is_some());
+/// assert!(parse_correction("correct GYXXG").is_none());
+/// ```
+#[must_use]
+pub fn parse_correction(input: &str) -> Option<(usize, Vec<Feedback>)> {
+    let input = input.trim();
+    let rest = input
+        .get(.."correct".len())
+        .filter(|command| command.eq_ignore_ascii_case("correct"))
+        .and_then(|_| input.get("correct".len()..))?;
+    let (round, feedback) = rest.trim_start().split_once(char::is_whitespace)?;
+    Some((round.parse().ok()?, parse_feedback(feedback)?))
+}
+
+/// Parses a request entered as `why-not <word>` into the uppercase word.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::cli::parse_why_not;
+///
+/// assert_eq!(parse_why_not("why-not crane"), Some("CRANE".to_string()));
+/// assert_eq!(parse_why_not("WHY-NOT  SLATE "), Some("SLATE".to_string()));
+/// assert!(parse_why_not("why-not CRANES").is_none());
+/// assert!(parse_why_not("why not CRANE").is_none());
+/// ```
+#[must_use]
+pub fn parse_why_not(input: &str) -> Option<String> {
+    let input = input.trim();
+    let word = input
+        .get(.."why-not".len())
+        .filter(|command| command.eq_ignore_ascii_case("why-not"))
+        .and_then(|_| input.get("why-not".len()..))?;
+    let word = word.trim().to_uppercase();
+    Word::is_valid(&word).then_some(word)
+}
+
+/// Parses a tag entered as `tag <round> forced` or `tag <round> free`, with rounds counting
+/// from 1, into the round and whether it was forced.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::cli::parse_tag;
+///
+/// assert_eq!(parse_tag("tag 1 forced"), Some((1, true)));
+/// assert_eq!(parse_tag("TAG 3 Free"), Some((3, false)));
+/// assert!(parse_tag("tag forced").is_none());
+/// assert!(parse_tag("tag 1 lucky").is_none());
+/// ```
+#[must_use]
+pub fn parse_tag(input: &str) -> Option<(usize, bool)> {
+    let mut parts = input.split_whitespace();
+    if !parts.next()?.eq_ignore_ascii_case("tag") {
+        return None;
+    }
+    let round = parts.next()?.parse().ok()?;
+    let forced = match parts.next()?.to_ascii_lowercase().as_str() {
+        "forced" => true,
+        "free" => false,
+        _ => return None,
+    };
+    parts.next().is_none().then_some((round, forced))
+}
+
+/// Warn about a dominated guess and ask whether to use it anyway.
+///
+/// A bare ENTER (or `y`) accepts the guess; anything else rejects it.
 ///
 /// # Panics
 /// Panics if reading from the input stream fails
-pub fn read_feedback<R: BufRead>(reader: &mut R) -> Option<Vec<Feedback>> {
-    println!("Enter feedback (G=green, Y=yellow, X=gray, e.g. GYXXG):");
-    let mut input = String::new();
-    reader.read_line(&mut input).unwrap();
-    let input = input.trim().to_uppercase();
+pub fn confirm_dominated_guess<R: BufRead>(reader: &mut R, warning: &GuessWarning) -> bool {
+    println!("{}", dominated_warning(warning));
+    println!(
+        "{}",
+        Message::PromptDominated.fill(&[("guess", &warning.guess)])
+    );
+    read_input(reader)
+        .is_some_and(|input| matches!(input.trim().to_uppercase().as_str(), "" | "Y" | "YES"))
+}
 
-    if is_valid_feedback(&input) {
-        let feedback: Option<Vec<Feedback>> = input.chars().map(Feedback::from_char).collect();
+/// The warning shown for a dominated guess, in the interface language
+#[must_use]
+pub fn dominated_warning(warning: &GuessWarning) -> String {
+    Message::GameDominated.fill(&[
+        ("guess", &warning.guess),
+        ("better", &warning.better_guess),
+        ("better_score", &format!("{:.2}", warning.better_score)),
+        ("score", &format!("{:.2}", warning.score)),
+    ])
+}
 
-        if feedback.is_none() {
-            println!("Invalid feedback. Please enter 5 characters using G, Y, or X.");
-        }
-        feedback
-    } else {
-        println!("Invalid feedback. Please enter 5 characters using G, Y, or X.");
-        None
+/// Read feedback from the user
+pub fn read_feedback<R: BufRead>(reader: &mut R) -> InputResult<Vec<Feedback>> {
+    println!("{}", Message::PromptFeedback.text());
+    read_feedback_unprompted(reader)
+}
+
+/// [`read_feedback`] without the prompt, for quiet output
+fn read_feedback_unprompted<R: BufRead>(reader: &mut R) -> InputResult<Vec<Feedback>> {
+    let Some(input) = read_input(reader) else {
+        return InputResult::Closed;
+    };
+    let feedback = parse_feedback(&input);
+    if feedback.is_none() {
+        println!("{}", Message::ErrorInvalidFeedback.text());
     }
+    feedback.into()
 }
 
+/// Candidates listed under the count
+const CANDIDATES_SHOWN: usize = 5;
+
 pub fn display_candidates(candidates: &[String]) {
-    println!("Possible candidates ({})", candidates.len());
-    for word in candidates.iter().take(5) {
+    println!(
+        "{}",
+        Message::GameCandidates.fill(&[("count", &candidates.len())])
+    );
+    for word in candidates.iter().take(CANDIDATES_SHOWN) {
         println!("{word}");
     }
 }
 
-pub fn display_recommendation(guess: &str, score: f64, is_candidate: bool) {
+/// [`display_candidates`] with each word's expected pool size if guessed, and a
+/// [`constraint_summary`]
+pub fn display_scored_candidates(candidates: &[String]) {
+    println!(
+        "{}",
+        Message::GameCandidates.fill(&[("count", &candidates.len())])
+    );
+    for word in candidates.iter().take(CANDIDATES_SHOWN) {
+        println!(
+            "{word} (expected pool size {:.2})",
+            expected_pool_size(word, candidates)
+        );
+    }
+    if !candidates.is_empty() {
+        println!("{}", constraint_summary(candidates));
+    }
+}
+
+/// What the candidates leave possible: the letters each position can still hold, as a
+/// regex-like pattern, and the letters every candidate has. A position with a choice shows
+/// the set of letters it can hold, or `[^...]` the letters it can't when that's shorter.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::cli::constraint_summary;
+///
+/// let candidates: Vec<String> = ["BATCH", "CATCH", "HATCH"]
+///     .iter()
+///     .map(|s| s.to_string())
+///     .collect();
+/// assert_eq!(
+///     constraint_summary(&candidates),
+///     "Letters still possible: [BCH]ATCH; in every candidate: A, C, H, T"
+/// );
+/// ```
+#[must_use]
+pub fn constraint_summary(candidates: &[String]) -> String {
+    let pattern: String = (0..WORD_LENGTH)
+        .map(|position| {
+            let possible: BTreeSet<char> = candidates
+                .iter()
+                .filter_map(|word| word.chars().nth(position))
+                .collect();
+            let excluded: String = ('A'..='Z').filter(|c| !possible.contains(c)).collect();
+            let possible: String = possible.into_iter().collect();
+            match (possible.len(), excluded.len()) {
+                (1, _) => possible,
+                (_, 0) => ".".to_string(),
+                (shown, hidden) if hidden < shown => format!("[^{excluded}]"),
+                _ => format!("[{possible}]"),
+            }
+        })
+        .collect();
+    let required: Vec<String> = ('A'..='Z')
+        .filter(|&letter| candidates.iter().all(|word| word.contains(letter)))
+        .map(String::from)
+        .collect();
+    let required = if required.is_empty() {
+        "-".to_string()
+    } else {
+        required.join(", ")
+    };
+    Message::GameConstraints.fill(&[("pattern", &pattern), ("letters", &required)])
+}
+
+/// Writes `candidates` with their scores to `path` and says how it went
+pub fn export_candidates(path: &Path, candidates: &[String]) {
+    if candidates.is_empty() {
+        println!("{}", Message::ErrorNothingToExport.text());
+        return;
+    }
+    match write_candidates(path, candidates) {
+        Ok(()) => println!(
+            "{}",
+            Message::GameExported.fill(&[("count", &candidates.len()), ("path", &path.display())])
+        ),
+        Err(e) => println!("{}", Message::ErrorExport.fill(&[("error", &e)])),
+    }
+}
+
+pub fn display_candidate_diff(diff: Option<&CandidateDiff>, page: usize) {
+    let Some(diff) = diff else {
+        println!("{}", Message::ErrorNoDiff.text());
+        return;
+    };
+
+    let pages = diff.page_count(DIFF_PAGE_SIZE);
+    let page = page.min(pages - 1);
+    let feedback: String = diff.feedback.iter().map(|f| f.as_char()).collect();
+    println!(
+        "Last round: {} = {} | eliminated {}, survived {} (page {}/{})",
+        diff.guess,
+        feedback,
+        diff.eliminated.len(),
+        diff.survived.len(),
+        page + 1,
+        pages
+    );
+
+    let (eliminated, survived) = diff.page(page, DIFF_PAGE_SIZE);
+    println!("{:<12}Survived", "Eliminated");
+    for i in 0..eliminated.len().max(survived.len()) {
+        let left = eliminated.get(i).map_or("", String::as_str);
+        let right = survived.get(i).map_or("", String::as_str);
+        println!("{left:<12}{right}");
+    }
+
+    if page + 1 < pages {
+        println!("(Enter 'diff {}' for the next page.)", page + 2);
+    }
+}
+
+pub fn display_recommendation(
+    guess: &str,
+    score: f64,
+    is_candidate: bool,
+    confidence_interval: Option<(f64, f64)>,
+) {
     let category = if is_candidate {
-        "solution candidate"
+        Message::GameSolutionCandidate
+    } else {
+        Message::GameInformationGathering
+    };
+    let score = match confidence_interval {
+        Some((lower, upper)) => format!("~{score:.2}, 95% CI {lower:.2}-{upper:.2}"),
+        None => format!("{score:.2}"),
+    };
+    println!(
+        "{}",
+        Message::GameRecommended.fill(&[
+            ("guess", &guess),
+            ("score", &score),
+            ("category", &category.text()),
+        ])
+    );
+}
+
+/// Confirmation that recommendations now avoid `letters`, or no letters when it's empty
+#[must_use]
+pub fn avoid_message(letters: &[char]) -> String {
+    if letters.is_empty() {
+        return Message::GameAvoidNone.text().to_string();
+    }
+    let letters: Vec<String> = letters.iter().map(char::to_string).collect();
+    Message::GameAvoid.fill(&[("letters", &letters.join(", "))])
+}
+
+/// How many candidates a list of `listed` words kept, or that it wasn't applied when none
+#[must_use]
+pub fn restrict_message(listed: usize, remaining: usize) -> String {
+    let message = if remaining == 0 {
+        Message::GameRestrictNone
     } else {
-        "information-gathering"
+        Message::GameRestrict
     };
-    println!("Recommended guess: {guess} (expected pool size {score:.2}) [{category}]");
+    message.fill(&[("listed", &listed), ("remaining", &remaining)])
+}
+
+/// The guess that best tells apart a cluster, or that there's nothing to split
+#[must_use]
+pub fn splitter_message(splitter: &Splitter) -> String {
+    match &splitter.guess {
+        Some((guess, groups)) => Message::GameSplitter.fill(&[
+            ("count", &splitter.cluster.len()),
+            ("pattern", &splitter.pattern),
+            ("words", &splitter.cluster.join(", ")),
+            ("guess", guess),
+            ("groups", groups),
+        ]),
+        None => Message::GameSplitterNone.fill(&[("pattern", &splitter.pattern)]),
+    }
+}
+
+/// Warning that the candidates are a trap family, naming the guess that splits them
+#[must_use]
+pub fn trap_message(splitter: &Splitter, guesses_left: usize) -> String {
+    let (guess, groups) = splitter.guess.clone().unwrap_or_default();
+    Message::GameTrap.fill(&[
+        ("count", &splitter.cluster.len()),
+        ("pattern", &splitter.pattern),
+        ("left", &guesses_left),
+        ("guess", &guess),
+        ("groups", &groups),
+    ])
+}
+
+/// Confirmation of a corrected round, or why it couldn't be corrected
+#[must_use]
+pub fn correction_message(correction: Correction<'_>) -> String {
+    match correction {
+        Correction::Applied { round, feedback } => {
+            let feedback: String = feedback.iter().map(|fb| fb.as_char()).collect();
+            Message::GameCorrected.fill(&[("round", &round), ("feedback", &feedback)])
+        }
+        Correction::NoSuchRound { round, rounds } => {
+            Message::ErrorNoSuchRound.fill(&[("round", &round), ("rounds", &rounds)])
+        }
+        Correction::Solved => Message::ErrorCorrectSolved.text().to_string(),
+    }
+}
+
+/// Confirmation of a round's new tag, or why it couldn't be tagged
+#[must_use]
+pub fn tagging_message(tagging: Tagging<'_>) -> String {
+    match tagging {
+        Tagging::Applied {
+            round,
+            guess,
+            forced,
+        } => {
+            let message = if forced {
+                Message::GameTaggedForced
+            } else {
+                Message::GameTaggedFree
+            };
+            message.fill(&[("round", &round), ("guess", &guess)])
+        }
+        Tagging::NoSuchRound { round, rounds } => {
+            Message::ErrorNoSuchTagRound.fill(&[("round", &round), ("rounds", &rounds)])
+        }
+    }
+}
+
+/// Format a duration in milliseconds with two decimals
+#[must_use]
+pub fn format_duration(elapsed: Duration) -> String {
+    format!("{:.2} ms", elapsed.as_secs_f64() * 1000.0)
+}
+
+pub fn display_timing(label: &str, elapsed: Duration) {
+    println!("[time] {label}: {}", format_duration(elapsed));
+}
+
+/// Describe the memory a game takes, in mebibytes with one decimal.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::cli::memory_message;
+///
+/// assert_eq!(
+///     memory_message(3 * 1024 * 1024, 1024 * 1024 / 2),
+///     "[memory] 3.0 MiB, scorer 0.5 MiB"
+/// );
+/// ```
+#[allow(clippy::cast_precision_loss)] // far below 2^52 bytes
+#[must_use]
+pub fn memory_message(total: usize, scorer: usize) -> String {
+    let mib = |bytes: usize| bytes as f64 / (1024.0 * 1024.0);
+    format!(
+        "[memory] {:.1} MiB, scorer {:.1} MiB",
+        mib(total),
+        mib(scorer)
+    )
+}
+
+/// Cells in the CLI progress bar
+const PROGRESS_BAR_WIDTH: usize = 20;
+
+/// Render a one-line progress bar with the best guess so far.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::cli::progress_bar;
+///
+/// assert_eq!(
+///     progress_bar(50, 100, Some(("CRANE", 3.214))),
+///     "[##########..........]  50% best CRANE 3.21"
+/// );
+/// ```
+#[must_use]
+pub fn progress_bar(current: usize, total: usize, best: Option<(&str, f64)>) -> String {
+    let filled = (current * PROGRESS_BAR_WIDTH)
+        .checked_div(total)
+        .unwrap_or(PROGRESS_BAR_WIDTH);
+    let percent = (current * 100).checked_div(total).unwrap_or(100);
+    let mut line = format!(
+        "[{}{}] {percent:>3}%",
+        "#".repeat(filled),
+        ".".repeat(PROGRESS_BAR_WIDTH - filled)
+    );
+    if let Some((word, score)) = best {
+        line.push_str(&format!(" best {word} {score:.2}"));
+    }
+    line
+}
+
+/// Redraw the progress bar in place, ending the line once `current` reaches `total`.
+///
+/// Does nothing when stdout isn't a terminal, where the redrawn frames would pile up.
+pub fn display_progress(current: usize, total: usize, best: Option<(&str, f64)>) {
+    if !io::stdout().is_terminal() {
+        return;
+    }
+    print!("\r{}", progress_bar(current, total, best));
+    if current >= total {
+        println!();
+    }
+    io::stdout().flush().ok();
+}
+
+/// A round spelled out letter by letter for screen readers.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::cli::describe_round;
+/// use wordle_solver::get_feedback;
+///
+/// assert_eq!(
+///     describe_round("CRANE", &get_feedback("CRANE", "REACT")),
+///     "C yellow, R yellow, A green, N gray, E yellow"
+/// );
+/// ```
+#[must_use]
+pub fn describe_round(guess: &str, feedback: &[Feedback]) -> String {
+    let letters: Vec<String> = guess
+        .chars()
+        .zip(feedback)
+        .map(|(letter, fb)| format!("{letter} {}", fb.color_name()))
+        .collect();
+    letters.join(", ")
+}
+
+pub fn display_wordbank_stats(stats: &WordbankStats) {
+    for line in stats.report() {
+        println!("{line}");
+    }
+}
+
+/// Number of possible guesses listed for each row of an imported share grid
+const SHARE_EXAMPLES: usize = 5;
+
+pub fn display_share_analysis(analysis: &ShareAnalysis) {
+    println!(
+        "{}: {} guess sequences fit this grid",
+        analysis.answer,
+        analysis.sequence_count()
+    );
+    for (i, round) in analysis.rounds.iter().enumerate() {
+        let squares: String = round.feedback.iter().map(|&f| feedback_square(f)).collect();
+        if round.possible_guesses.len() == 1 && round.likely_guess == analysis.answer {
+            println!("{}. {squares}  {}", i + 1, analysis.answer);
+            continue;
+        }
+        let examples: Vec<&str> = round
+            .possible_guesses
+            .iter()
+            .take(SHARE_EXAMPLES)
+            .map(String::as_str)
+            .collect();
+        let more = if round.possible_guesses.len() > SHARE_EXAMPLES {
+            ", ..."
+        } else {
+            ""
+        };
+        println!(
+            "{}. {squares}  {} possible ({}{more})",
+            i + 1,
+            round.possible_guesses.len(),
+            examples.join(", ")
+        );
+        println!(
+            "   at best {} ({:.2}) vs solver {} ({:.2}) with {} left: {:.0}%",
+            round.likely_guess,
+            round.score,
+            round.best_guess,
+            round.best_score,
+            round.pool_size,
+            round.efficiency() * 100.0
+        );
+    }
+    println!(
+        "Play efficiency: {:.0}% of optimal",
+        analysis.efficiency() * 100.0
+    );
 }
 
 pub fn display_exit_message() {
-    println!("Exiting.");
+    println!("{}", Message::GameExiting.text());
 }
 
 pub fn display_new_game_message(word_count: usize) {
-    println!("New game started. Loaded {word_count} words.");
+    println!("{}", Message::GameNewGame.fill(&[("count", &word_count)]));
 }
 
 pub fn display_computing_message() {
-    println!("Computing optimal guess, please wait...");
+    println!("{}", Message::GameComputing.text());
 }
 
 pub fn display_no_candidates_message() {
-    println!("No candidates remain. Check your inputs.");
+    println!("{}", Message::ErrorNoCandidates.text());
 }
 
 pub fn display_solution_found(solution: &str) {
-    println!("Solution found: {solution}");
+    println!(
+        "{}",
+        Message::GameSolutionFound.fill(&[("solution", &solution)])
+    );
 }
 
 /// CLI implementation of the `GameInterface` trait
 /// This struct wraps a `BufRead` reader and implements the game interface for CLI interaction
 pub struct CliInterface<R: BufRead> {
     reader: R,
+    last_diff: Option<CandidateDiff>,
+    /// Candidates after the last round, for `export-candidates`
+    candidates: Vec<String>,
+    /// Word played by the `use` command: the top starting word, then each recommendation
+    suggestion: Option<String>,
+    output: OutputOptions,
+    /// Percentage last drawn by the progress bar, so it only redraws when it moves
+    progress_percent: Option<usize>,
 }
 
 impl<R: BufRead> CliInterface<R> {
     pub fn new(reader: R) -> Self {
-        Self { reader }
+        Self {
+            reader,
+            last_diff: None,
+            candidates: Vec::new(),
+            suggestion: None,
+            output: OutputOptions::default(),
+            progress_percent: None,
+        }
+    }
+
+    /// Set how much is printed, along with timing and accessible output
+    #[must_use]
+    pub const fn with_output(mut self, output: OutputOptions) -> Self {
+        self.output = output;
+        self
+    }
+
+    /// Enable or disable `[time]` output for each step
+    #[must_use]
+    pub const fn with_timing(mut self, show_timing: bool) -> Self {
+        self.output.timing = show_timing;
+        self
+    }
+
+    /// Enable or disable screen-reader friendly output
+    #[must_use]
+    pub const fn with_accessible(mut self, accessible: bool) -> Self {
+        self.output.accessible = accessible;
+        self
     }
 }
 
 impl<R: BufRead> GameInterface for CliInterface<R> {
-    fn display_starting_words(&mut self, info: &StartingWordsInfo) {
-        display_starting_words(&info.words, info.used_cache, info.cache_path.as_ref());
+    fn handle_event(&mut self, event: GameEvent<'_>) {
+        event.dispatch(self);
     }
 
     fn read_guess(&mut self) -> Option<UserAction> {
-        match read_guess(&mut self.reader) {
-            GuessInput::Valid(guess) => Some(UserAction::Guess(guess)),
-            GuessInput::Exit => Some(UserAction::Exit),
-            GuessInput::NewGame => Some(UserAction::NewGame),
-            GuessInput::Invalid => None,
+        loop {
+            let input = if self.output.quiet() {
+                read_guess_unprompted(&mut self.reader)
+            } else {
+                read_guess(&mut self.reader)
+            };
+            return match input {
+                GuessInput::Valid(guess) => Some(UserAction::Guess(guess)),
+                GuessInput::Exit => Some(UserAction::Exit),
+                GuessInput::NewGame => Some(UserAction::NewGame),
+                // Views answered here, so the guess is asked for again without counting as
+                // invalid input
+                GuessInput::Diff(page) => {
+                    display_candidate_diff(self.last_diff.as_ref(), page);
+                    continue;
+                }
+                GuessInput::Export(path) => {
+                    export_candidates(&path, &self.candidates);
+                    continue;
+                }
+                GuessInput::Restrict(path) => match load_wordbank_from_file(&path) {
+                    Ok(words) => Some(UserAction::Restrict(words.into_vec())),
+                    Err(e) => {
+                        println!("{}", Message::ErrorRestrict.fill(&[("error", &e)]));
+                        None
+                    }
+                },
+                GuessInput::UseRecommendation => {
+                    if self.suggestion.is_none() {
+                        println!("{}", Message::ErrorNoSuggestion.text());
+                    }
+                    self.suggestion.clone().map(UserAction::Guess)
+                }
+                GuessInput::Hint => Some(UserAction::Hint),
+                GuessInput::Rounds(rounds) => Some(UserAction::Rounds(rounds)),
+                GuessInput::Probe(letters) => Some(UserAction::Probe(letters)),
+                GuessInput::Avoid(letters) => Some(UserAction::Avoid(letters)),
+                GuessInput::Correct(round, feedback) => {
+                    Some(UserAction::Correct { round, feedback })
+                }
+                GuessInput::WhyNot(word) => Some(UserAction::WhyNot(word)),
+                GuessInput::Split(pattern) => Some(UserAction::Split(pattern)),
+                GuessInput::Tag(round, forced) => Some(UserAction::Tag { round, forced }),
+                GuessInput::Invalid => None,
+            };
+        }
+    }
+
+    fn confirm_dominated_guess(&mut self, warning: &GuessWarning) -> bool {
+        confirm_dominated_guess(&mut self.reader, warning)
+    }
+
+    fn read_feedback(&mut self) -> InputResult<Vec<Feedback>> {
+        if self.output.quiet() {
+            read_feedback_unprompted(&mut self.reader)
+        } else {
+            read_feedback(&mut self.reader)
         }
     }
+}
 
-    fn read_feedback(&mut self) -> Option<Vec<Feedback>> {
-        read_feedback(&mut self.reader)
+impl<R: BufRead> GameDisplay for CliInterface<R> {
+    fn display_starting_words(&mut self, info: &StartingWordsInfo) {
+        self.suggestion = info.words.first().map(|(word, _)| word.clone());
+        if self.output.quiet() {
+            // The top starting word is the first round's recommendation
+            if let Some(word) = &self.suggestion {
+                println!("{word}");
+            }
+            return;
+        }
+        display_starting_words(&info.words, info.used_cache, info.cache_path.as_ref());
     }
 
     fn display_candidates(&mut self, candidates: &[String]) {
-        display_candidates(candidates);
+        self.candidates = candidates.to_vec();
+        match self.output.verbosity {
+            Verbosity::Quiet => {}
+            Verbosity::Normal => display_candidates(candidates),
+            Verbosity::Verbose => display_scored_candidates(candidates),
+        }
+    }
+
+    fn display_rounds(&mut self, rounds: &[(String, Vec<Feedback>)]) {
+        if self.output.quiet() {
+            return;
+        }
+        for (i, (guess, feedback)) in rounds.iter().enumerate() {
+            if self.output.accessible {
+                println!(
+                    "Round {}: {guess}, {}",
+                    i + 1,
+                    describe_round(guess, feedback)
+                );
+            } else {
+                let feedback: String = feedback.iter().map(|f| f.as_char()).collect();
+                println!(
+                    "{}",
+                    Message::GameRound.fill(&[
+                        ("number", &(i + 1)),
+                        ("guess", guess),
+                        ("feedback", &feedback),
+                    ])
+                );
+            }
+        }
+    }
+
+    fn display_candidate_diff(&mut self, diff: &CandidateDiff) {
+        if self.output.accessible && !self.output.quiet() {
+            println!(
+                "{}: {}. {} candidates remain.",
+                diff.guess,
+                describe_round(&diff.guess, &diff.feedback),
+                diff.survived.len()
+            );
+        }
+        self.last_diff = Some(diff.clone());
     }
 
     fn display_recommendation(&mut self, recommendation: &Recommendation) {
+        self.suggestion = Some(recommendation.guess.to_string());
+        if self.output.quiet() {
+            println!("{}", recommendation.guess);
+            return;
+        }
         display_recommendation(
             &recommendation.guess,
             recommendation.score,
             recommendation.is_candidate,
+            recommendation.confidence_interval,
         );
+        if recommendation.truncated {
+            println!("{}", Message::GameTruncated.text());
+        }
+    }
+
+    fn display_hint(&mut self, hint: &Hint) {
+        let level = hint.level();
+        let levels = HINT_LEVELS;
+        match hint {
+            Hint::ProbeLetter(letter) => println!(
+                "{}.",
+                Message::HintProbeLetter.fill(&[
+                    ("level", &level),
+                    ("levels", &levels),
+                    ("letter", letter)
+                ])
+            ),
+            Hint::FirstLetter(letter) => println!(
+                "{}.",
+                Message::HintFirstLetter.fill(&[
+                    ("level", &level),
+                    ("levels", &levels),
+                    ("letter", letter)
+                ])
+            ),
+            Hint::Full(recommendation) => {
+                println!(
+                    "{}.",
+                    Message::HintFull.fill(&[
+                        ("level", &level),
+                        ("levels", &levels),
+                        ("guess", &recommendation.guess)
+                    ])
+                );
+                self.display_recommendation(recommendation);
+            }
+        }
+    }
+
+    fn display_probe(&mut self, letters: &[char], guesses: &[(String, f64)]) {
+        let letters: String = letters.iter().collect();
+        if guesses.is_empty() {
+            println!("{}", Message::GameProbeNone.fill(&[("letters", &letters)]));
+            return;
+        }
+        println!("{}", Message::GameProbe.fill(&[("letters", &letters)]));
+        for (i, (guess, score)) in guesses.iter().enumerate() {
+            let tested: String = letters.chars().filter(|&c| guess.contains(c)).collect();
+            println!(
+                "{}",
+                Message::GameProbeGuess.fill(&[
+                    ("number", &(i + 1)),
+                    ("guess", guess),
+                    ("tested", &tested),
+                    ("score", &format!("{score:.2}")),
+                ])
+            );
+        }
+    }
+
+    fn display_win_odds(&mut self, odds: &WinOdds) {
+        if !self.output.quiet() {
+            println!("{}", odds.summary());
+        }
+    }
+
+    fn display_avoid(&mut self, letters: &[char]) {
+        println!("{}", avoid_message(letters));
+    }
+
+    fn display_restrict(&mut self, listed: usize, remaining: usize) {
+        println!("{}", restrict_message(listed, remaining));
+    }
+
+    fn display_correction(&mut self, correction: Correction<'_>) {
+        println!("{}", correction_message(correction));
+    }
+
+    fn display_tagging(&mut self, tagging: Tagging<'_>) {
+        println!("{}", tagging_message(tagging));
+    }
+
+    fn display_splitter(&mut self, splitter: &Splitter) {
+        if !self.output.quiet() {
+            println!("{}", splitter_message(splitter));
+        }
+    }
+
+    fn display_trap(&mut self, splitter: &Splitter, guesses_left: usize) {
+        if !self.output.quiet() {
+            println!("{}", trap_message(splitter, guesses_left));
+        }
+    }
+
+    fn display_why_not(&mut self, word: &str, explanation: &WhyNot) {
+        println!("{}", explanation.describe(word));
     }
 
     fn display_computing_message(&mut self) {
-        display_computing_message();
+        if !self.output.quiet() {
+            display_computing_message();
+        }
+    }
+
+    fn display_timing(&mut self, label: &str, elapsed: Duration) {
+        if self.output.show_timing() {
+            display_timing(label, elapsed);
+        }
+    }
+
+    fn display_memory_usage(&mut self, total: usize, scorer: usize) {
+        if !self.output.quiet() {
+            println!("{}", memory_message(total, scorer));
+        }
+    }
+
+    fn display_progress(&mut self, current: usize, total: usize, best: Option<(&str, f64)>) {
+        // A redrawn bar reads as noise to a screen reader, and to whatever reads quiet output
+        if self.output.accessible || self.output.quiet() {
+            return;
+        }
+        let percent = (current * 100).checked_div(total).unwrap_or(100);
+        if self.progress_percent != Some(percent) || current >= total {
+            display_progress(current, total, best);
+        }
+        self.progress_percent = (current < total).then_some(percent);
     }
 
     fn display_no_candidates_message(&mut self) {
@@ -203,15 +1586,47 @@ impl<R: BufRead> GameInterface for CliInterface<R> {
     }
 
     fn display_solution_found(&mut self, solution: &str) {
-        println!("Solution found: {solution}");
+        if self.output.quiet() {
+            // The solution is the last round's recommendation
+            println!("{solution}");
+        } else {
+            display_solution_found(solution);
+        }
+    }
+
+    fn display_achievements(&mut self, unlocked: &[Achievement]) {
+        if self.output.quiet() {
+            return;
+        }
+        for achievement in unlocked {
+            println!(
+                "{}",
+                Message::GameAchievement.fill(&[("achievement", achievement)])
+            );
+        }
+    }
+
+    fn display_information(&mut self, info: &[GuessInformation]) {
+        if self.output.quiet() {
+            return;
+        }
+        for line in information_chart(info) {
+            println!("{line}");
+        }
     }
 
     fn display_exit_message(&mut self) {
-        display_exit_message();
+        if !self.output.quiet() {
+            display_exit_message();
+        }
     }
 
     fn display_new_game_message(&mut self, word_count: usize) {
-        println!("New game started. Loaded {word_count} words.");
+        self.last_diff = None;
+        self.candidates.clear();
+        if !self.output.quiet() {
+            display_new_game_message(word_count);
+        }
     }
 }
 
@@ -219,14 +1634,81 @@ impl<R: BufRead> GameInterface for CliInterface<R> {
 mod tests {
     use super::*;
     use crate::solver::Feedback;
+    use crate::test_util::word;
+    use crate::words::WordBank;
     use std::io::Cursor;
 
+    fn test_cli() -> Cli {
+        Cli::parse_from(["wordle-solver"])
+    }
+
+    #[test]
+    fn test_solver_config_from_profile() {
+        let cli = Cli::parse_from(["wordle-solver", "--profile", "fast"]);
+        assert_eq!(cli.solver_config(), Profile::Fast.config());
+
+        let cli = test_cli();
+        assert_eq!(cli.solver_config(), SolverConfig::default());
+    }
+
+    #[test]
+    fn test_solver_config_flags_override_profile() {
+        let cli = Cli::parse_from([
+            "wordle-solver",
+            "--profile",
+            "exhaustive",
+            "--strategy",
+            "candidates-only",
+            "--sample",
+            "100",
+            "--lookahead",
+            "0",
+        ]);
+        let config = cli.solver_config();
+        assert_eq!(config.strategy, Strategy::CandidatesOnly);
+        assert_eq!(config.sample_size, Some(100));
+        assert_eq!(config.lookahead, 0);
+        // Not overridden, so still from the profile
+        assert!(config.use_cache);
+        assert_eq!(config.budget, Budget::UNLIMITED);
+    }
+
+    #[test]
+    fn test_session_options_low_memory() {
+        assert!(
+            !Cli::parse_from(["wordle-solver"])
+                .session_options()
+                .low_memory
+        );
+        let cli = Cli::parse_from(["wordle-solver", "--low-memory"]);
+        assert!(cli.session_options().low_memory);
+    }
+
+    #[test]
+    fn test_session_options_recompute() {
+        assert!(
+            !Cli::parse_from(["wordle-solver"])
+                .session_options()
+                .recompute
+        );
+        let cli = Cli::parse_from(["wordle-solver", "--recompute"]);
+        assert!(cli.session_options().recompute);
+        // Nothing would be cached to refresh
+        assert!(Cli::try_parse_from(["wordle-solver", "--recompute", "--no-cache"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_cli_rejects_unknown_profile() {
+        assert!(Cli::try_parse_from(["wordle-solver", "--profile", "slow"]).is_err());
+    }
+
     #[test]
     fn test_parse_cli_no_args() {
         // Test parsing with no custom wordbank
         let cli = Cli {
             wordbank_path: None,
             ui_mode: UiMode::Tui,
+            ..test_cli()
         };
         assert_eq!(cli.wordbank_path, None);
     }
@@ -237,6 +1719,7 @@ mod tests {
         let cli = Cli {
             wordbank_path: Some("custom_wordbank.txt".to_string()),
             ui_mode: UiMode::Tui,
+            ..test_cli()
         };
         assert_eq!(cli.wordbank_path, Some("custom_wordbank.txt".to_string()));
     }
@@ -247,6 +1730,7 @@ mod tests {
         let cli = Cli {
             wordbank_path: Some("/path/to/words.txt".to_string()),
             ui_mode: UiMode::Cli,
+            ..test_cli()
         };
 
         match cli.wordbank_path {
@@ -255,31 +1739,259 @@ mod tests {
         }
     }
 
-    // Tests for validation functions
     #[test]
-    fn test_is_valid_word() {
-        assert!(is_valid_word("CRANE"));
-        assert!(is_valid_word("crane"));
-        assert!(is_valid_word("AbCdE"));
-        assert!(!is_valid_word("CRAN")); // Too short
-        assert!(!is_valid_word("CRANES")); // Too long
-        assert!(!is_valid_word("CRAN3")); // Contains digit
-        assert!(!is_valid_word("CRAN ")); // Contains space
-        assert!(!is_valid_word("")); // Empty
+    fn test_parse_cli_wordbank_stats() {
+        assert!(test_cli().command.is_none());
+
+        let cli = Cli::parse_from(["wordle-solver", "-i", "words.txt", "wordbank", "stats"]);
+        assert!(matches!(
+            cli.command,
+            Some(Command::Wordbank {
+                action: WordbankCommand::Stats
+            })
+        ));
+        assert_eq!(cli.wordbank_path.as_deref(), Some("words.txt"));
+    }
+
+    #[test]
+    fn test_parse_cli_wordbank_compile() {
+        let cli = Cli::parse_from(["wordle-solver", "wordbank", "compile", "out.wbk"]);
+        assert!(matches!(
+            cli.command,
+            Some(Command::Wordbank {
+                action: WordbankCommand::Compile { output }
+            }) if output == std::path::Path::new("out.wbk")
+        ));
+
+        let cli = Cli::parse_from(["wordle-solver", "wordbank", "diff", "old.txt", "new.txt"]);
+        assert!(matches!(
+            cli.command,
+            Some(Command::Wordbank {
+                action: WordbankCommand::Diff { old, new }
+            }) if old == std::path::Path::new("old.txt") && new == std::path::Path::new("new.txt")
+        ));
+    }
+
+    #[test]
+    fn test_parse_cli_versus() {
+        let cli = Cli::parse_from(["wordle-solver", "--seed", "3", "versus"]);
+        assert!(matches!(cli.command, Some(Command::Versus)));
+        assert_eq!(cli.seed, Some(3));
+        let cli = Cli::parse_from(["wordle-solver", "race"]);
+        assert!(matches!(cli.command, Some(Command::Race)));
+        assert_eq!(cli.guess_timer, None);
+        assert_eq!(cli.on_timeout, TimeoutAction::Forfeit);
+        let cli = Cli::parse_from(["wordle-solver", "streak"]);
+        assert!(matches!(cli.command, Some(Command::Streak)));
+        let cli = Cli::parse_from(["wordle-solver", "import-share", "crate"]);
+        assert!(matches!(cli.command, Some(Command::ImportShare { answer }) if answer == "crate"));
+        let cli = Cli::parse_from(["wordle-solver", "puzzle"]);
+        assert!(matches!(cli.command, Some(Command::Puzzle)));
+        let cli = Cli::parse_from(["wordle-solver", "doctor"]);
+        assert!(matches!(cli.command, Some(Command::Doctor)));
+    }
+
+    #[test]
+    fn test_state_flag() {
+        assert_eq!(test_cli().initial_rounds(), Ok(Vec::new()));
+        let cli = Cli::parse_from(["wordle-solver", "--state", "CRANE:XYGXX,slate:gyxxx"]);
+        let rounds = cli.initial_rounds().unwrap();
+        assert_eq!(rounds.len(), 2);
+        assert_eq!(rounds[1].0, "SLATE");
+        let cli = Cli::parse_from(["wordle-solver", "--state", "CRANE"]);
+        assert!(cli.initial_rounds().is_err());
+
+        let cli = Cli::parse_from(["wordle-solver", "--resume", "game.txt"]);
+        assert_eq!(cli.resume, Some(PathBuf::from("game.txt")));
+        let both = [
+            "wordle-solver",
+            "--resume",
+            "game.txt",
+            "--state",
+            "CRANE:XYGXX",
+        ];
+        assert!(Cli::try_parse_from(both).is_err());
+
+        let cli = Cli::parse_from([
+            "wordle-solver",
+            "--guess-timer",
+            "30",
+            "--on-timeout",
+            "recommend",
+            "race",
+        ]);
+        assert_eq!(cli.guess_timer, Some(30));
+        assert_eq!(cli.on_timeout, TimeoutAction::Recommend);
     }
 
     #[test]
-    fn test_is_valid_feedback() {
-        assert!(is_valid_feedback("GGGGG"));
-        assert!(is_valid_feedback("XXYGG"));
-        assert!(is_valid_feedback("YYYXX"));
-        assert!(is_valid_feedback("gygxg")); // lowercase should pass (case-insensitive)
-        assert!(is_valid_feedback("GyGxG")); // mixed case should pass
-        assert!(!is_valid_feedback("GGGG")); // Too short
-        assert!(!is_valid_feedback("GGGGGG")); // Too long
-        assert!(!is_valid_feedback("GGGGA")); // Invalid character
-        assert!(!is_valid_feedback("12345")); // Numbers
-        assert!(!is_valid_feedback("")); // Empty
+    fn test_accessible_flag() {
+        assert!(!test_cli().accessible);
+        assert!(Cli::parse_from(["wordle-solver", "--accessible"]).accessible);
+        assert!(!test_cli().accessible_tiles);
+        assert!(Cli::parse_from(["wordle-solver", "--accessible-tiles"]).accessible_tiles);
+    }
+
+    #[test]
+    fn test_verbosity_flags() {
+        assert_eq!(test_cli().output_options(), OutputOptions::default());
+        let quiet = Cli::parse_from(["wordle-solver", "-q", "--time"]).output_options();
+        assert!(quiet.quiet() && quiet.show_timing());
+        let verbose = Cli::parse_from(["wordle-solver", "--verbose"]).output_options();
+        assert_eq!(verbose.verbosity, Verbosity::Verbose);
+        // Verbose output includes timing without --time
+        assert!(verbose.show_timing() && !verbose.timing);
+        assert!(Cli::try_parse_from(["wordle-solver", "-q", "-v"]).is_err());
+
+        let candidates = vec!["CRANE".to_string(), "CRATE".to_string()];
+        assert_eq!(
+            constraint_summary(&candidates),
+            "Letters still possible: CRA[NT]E; in every candidate: A, C, E, R"
+        );
+    }
+
+    #[test]
+    fn test_avoid_flag() {
+        assert!(test_cli().solver_config().avoid.is_empty());
+        let cli = Cli::parse_from(["wordle-solver", "--avoid", "s,y"]);
+        assert_eq!(cli.solver_config().avoid, ['S', 'Y']);
+        assert!(Cli::try_parse_from(["wordle-solver", "--avoid", "5"]).is_err());
+    }
+
+    #[test]
+    fn test_lang_flag() {
+        assert_eq!(test_cli().language, None);
+        let cli = Cli::parse_from(["wordle-solver", "--lang", "de"]);
+        assert_eq!(cli.language.as_deref(), Some("de"));
+    }
+
+    #[test]
+    fn test_starting_words_flag() {
+        assert_eq!(test_cli().solver_config().starting_words, 5);
+        let cli = Cli::parse_from(["wordle-solver", "--starting-words", "10"]);
+        assert_eq!(cli.solver_config().starting_words, 10);
+        assert!(Cli::try_parse_from(["wordle-solver", "--starting-words", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_lookahead_flag_rejects_unsupported_depth() {
+        let cli = Cli::parse_from(["wordle-solver", "--lookahead", "1"]);
+        assert_eq!(cli.solver_config().lookahead, 1);
+        assert!(Cli::try_parse_from(["wordle-solver", "--lookahead", "2"]).is_err());
+    }
+
+    #[test]
+    fn test_setup_flag() {
+        let cli = Cli::parse_from(["wordle-solver", "--profile", "fast", "--seed", "9"]);
+        let code = Setup::new(&cli.solver_config(), &cli.session_options(), &[]).encode();
+        let cli = Cli::parse_from(["wordle-solver", "--setup", &code]);
+        assert_eq!(
+            cli.solver_config(),
+            SolverConfig {
+                seed: Some(9),
+                ..Profile::Fast.config()
+            }
+        );
+        // Flags still apply on top
+        let cli = Cli::parse_from(["wordle-solver", "--setup", &code, "--lookahead", "1"]);
+        assert_eq!(cli.solver_config().lookahead, 1);
+        assert!(Cli::try_parse_from(["wordle-solver", "--setup", "nonsense!"]).is_err());
+        assert!(
+            Cli::try_parse_from(["wordle-solver", "--setup", &code, "--profile", "fast"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_replay_speed_flag() {
+        let cli = Cli::parse_from(["wordle-solver", "--replay", "session.json"]);
+        assert_eq!(cli.replay_speed, 1.0);
+        let cli = Cli::parse_from([
+            "wordle-solver",
+            "--replay",
+            "s.json",
+            "--replay-speed",
+            "2.5",
+        ]);
+        assert_eq!(cli.replay_speed, 2.5);
+        for speed in ["0", "-1", "fast", "inf"] {
+            assert!(Cli::try_parse_from(["wordle-solver", "--replay-speed", speed]).is_err());
+        }
+    }
+
+    #[test]
+    fn test_budget_flags() {
+        assert_eq!(test_cli().solver_config().budget, Budget::UNLIMITED);
+        let cli = Cli::parse_from([
+            "wordle-solver",
+            "--budget-ms",
+            "250",
+            "--budget-evals",
+            "500",
+        ]);
+        assert_eq!(
+            cli.solver_config().budget,
+            Budget {
+                max_time: Some(Duration::from_millis(250)),
+                max_evaluations: Some(500),
+            }
+        );
+    }
+
+    #[test]
+    fn test_idle_timeout_flag() {
+        assert_eq!(test_cli().session_options().idle_timeout, None);
+        let cli = Cli::parse_from(["wordle-solver", "--idle-timeout", "300"]);
+        assert_eq!(
+            cli.session_options().idle_timeout,
+            Some(Duration::from_secs(300))
+        );
+    }
+
+    #[test]
+    fn test_solver_config_seed() {
+        assert_eq!(test_cli().solver_config().seed, None);
+        let cli = Cli::parse_from(["wordle-solver", "--sample", "100", "--seed", "42"]);
+        assert_eq!(cli.solver_config().seed, Some(42));
+    }
+
+    #[test]
+    fn test_max_invalid_flag() {
+        assert_eq!(test_cli().session_options().max_invalid_inputs, None);
+        let cli = Cli::parse_from(["wordle-solver", "--max-invalid", "5"]);
+        assert_eq!(cli.session_options().max_invalid_inputs, Some(5));
+    }
+
+    #[test]
+    fn test_parse_cli_time_flag() {
+        assert!(!test_cli().time);
+        assert!(Cli::parse_from(["wordle-solver", "--time"]).time);
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_micros(1500)), "1.50 ms");
+        assert_eq!(format_duration(Duration::ZERO), "0.00 ms");
+    }
+
+    #[test]
+    fn test_progress_bar() {
+        assert_eq!(progress_bar(0, 10, None), "[....................]   0%");
+        assert_eq!(progress_bar(10, 10, None), "[####################] 100%");
+        assert_eq!(progress_bar(0, 0, None), "[####################] 100%");
+    }
+
+    #[test]
+    fn test_parse_feedback() {
+        assert!(parse_feedback("GGGGG").is_some());
+        assert!(parse_feedback("XXYGG").is_some());
+        assert!(parse_feedback("YYYXX").is_some());
+        assert!(parse_feedback("gygxg").is_some()); // lowercase should pass (case-insensitive)
+        assert!(parse_feedback("GyGxG").is_some()); // mixed case should pass
+        assert!(parse_feedback("GGGG").is_none()); // Too short
+        assert!(parse_feedback("GGGGGG").is_none()); // Too long
+        assert!(parse_feedback("GGGGA").is_none()); // Invalid character
+        assert!(parse_feedback("12345").is_none()); // Only 0, 1 and 2 are feedback digits
+        assert!(parse_feedback("").is_none()); // Empty
     }
 
     // Tests for read_guess function
@@ -293,6 +2005,84 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_read_guess_probe() {
+        let mut reader = Cursor::new("probe ae iou\n");
+        assert!(
+            matches!(read_guess(&mut reader), GuessInput::Probe(l) if l == ['A', 'E', 'I', 'O', 'U'])
+        );
+        // A bare PROBE is a guess like any other word
+        assert!(matches!(
+            read_guess(&mut Cursor::new("probe\n")),
+            GuessInput::Valid(_)
+        ));
+        assert!(matches!(
+            read_guess(&mut Cursor::new("probe 42\n")),
+            GuessInput::Invalid
+        ));
+    }
+
+    #[test]
+    fn test_read_guess_split() {
+        assert!(matches!(
+            read_guess(&mut Cursor::new("split _atch\n")),
+            GuessInput::Split(pattern) if pattern == "_ATCH"
+        ));
+        // A bare SPLIT is a guess like any other word
+        assert!(matches!(
+            read_guess(&mut Cursor::new("split\n")),
+            GuessInput::Valid(_)
+        ));
+        assert!(matches!(
+            read_guess(&mut Cursor::new("split BATCH\n")),
+            GuessInput::Invalid
+        ));
+    }
+
+    #[test]
+    fn test_read_guess_why_not() {
+        assert!(matches!(
+            read_guess(&mut Cursor::new("why-not slate\n")),
+            GuessInput::WhyNot(word) if word == "SLATE"
+        ));
+        assert!(matches!(
+            read_guess(&mut Cursor::new("why-not\n")),
+            GuessInput::Invalid
+        ));
+    }
+
+    #[test]
+    fn test_read_guess_tag() {
+        let mut reader = Cursor::new("tag 1 forced\ntag 2 free\ntag one forced\n");
+        assert!(matches!(read_guess(&mut reader), GuessInput::Tag(1, true)));
+        assert!(matches!(read_guess(&mut reader), GuessInput::Tag(2, false)));
+        assert!(matches!(read_guess(&mut reader), GuessInput::Invalid));
+        assert_eq!(
+            tagging_message(Tagging::Applied {
+                round: 1,
+                guess: "SLATE",
+                forced: true
+            }),
+            "Round 1 (SLATE) tagged forced; it's left out of the post-game score."
+        );
+    }
+
+    #[test]
+    fn test_read_guess_avoid() {
+        let mut reader = Cursor::new("avoid s,y\n");
+        assert!(matches!(read_guess(&mut reader), GuessInput::Avoid(l) if l == ['S', 'Y']));
+        let mut reader = Cursor::new("avoid -\n");
+        assert!(matches!(read_guess(&mut reader), GuessInput::Avoid(l) if l.is_empty()));
+        assert!(matches!(
+            read_guess(&mut Cursor::new("avoid\n")),
+            GuessInput::Valid(_)
+        ));
+        assert!(matches!(
+            read_guess(&mut Cursor::new("avoid s;y\n")),
+            GuessInput::Invalid
+        ));
+    }
+
     #[test]
     fn test_read_guess_lowercase_converted() {
         let input = "crane\n";
@@ -363,12 +2153,181 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_read_guess_diff() {
+        let mut reader = Cursor::new("diff\n");
+        assert!(matches!(read_guess(&mut reader), GuessInput::Diff(0)));
+
+        let mut reader = Cursor::new("diff 3\n");
+        assert!(matches!(read_guess(&mut reader), GuessInput::Diff(2)));
+    }
+
+    #[test]
+    fn test_read_guess_diff_invalid_page() {
+        let mut reader = Cursor::new("diff 0\n");
+        assert!(matches!(read_guess(&mut reader), GuessInput::Invalid));
+
+        let mut reader = Cursor::new("diff abc\n");
+        assert!(matches!(read_guess(&mut reader), GuessInput::Invalid));
+    }
+
+    #[test]
+    fn test_read_guess_use() {
+        let mut reader = Cursor::new("use\n");
+        assert!(matches!(
+            read_guess(&mut reader),
+            GuessInput::UseRecommendation
+        ));
+    }
+
+    #[test]
+    fn test_read_guess_hint() {
+        let mut reader = Cursor::new("hint\n");
+        assert!(matches!(read_guess(&mut reader), GuessInput::Hint));
+    }
+
+    #[test]
+    fn test_solver_config_hints() {
+        assert!(test_cli().solver_config().auto_recommend);
+        let cli = Cli::parse_from(["wordle-solver", "--hints"]);
+        assert!(!cli.solver_config().auto_recommend);
+    }
+
+    #[test]
+    fn test_use_plays_latest_suggestion() {
+        let mut interface = CliInterface::new(Cursor::new("use\nuse\nuse\n"));
+        assert!(interface.read_guess().is_none());
+
+        interface.display_starting_words(&StartingWordsInfo {
+            words: vec![("SLATE".to_string(), 70.1), ("CRANE".to_string(), 78.7)],
+            used_cache: false,
+            cache_path: None,
+        });
+        assert!(matches!(interface.read_guess(), Some(UserAction::Guess(g)) if g == "SLATE"));
+
+        interface.display_recommendation(&Recommendation {
+            guess: word("TRACE"),
+            score: 1.5,
+            is_candidate: true,
+            confidence_interval: None,
+            truncated: false,
+        });
+        assert!(matches!(interface.read_guess(), Some(UserAction::Guess(g)) if g == "TRACE"));
+    }
+
+    fn sample_warning() -> GuessWarning {
+        GuessWarning {
+            guess: "ZZZZZ".to_string(),
+            score: 3.0,
+            better_guess: "CRANE".to_string(),
+            better_score: 1.0,
+        }
+    }
+
+    #[test]
+    fn test_dominated_warning() {
+        let text = dominated_warning(&sample_warning());
+        assert!(text.contains("ZZZZZ can't be the answer, and CRANE"));
+        assert!(text.contains("(expected pool size 1.00 vs 3.00)"));
+    }
+
+    #[test]
+    fn test_confirm_dominated_guess_enter_accepts() {
+        let mut reader = Cursor::new("\n");
+        assert!(confirm_dominated_guess(&mut reader, &sample_warning()));
+
+        let mut reader = Cursor::new("y\n");
+        assert!(confirm_dominated_guess(&mut reader, &sample_warning()));
+    }
+
+    #[test]
+    fn test_confirm_dominated_guess_other_input_rejects() {
+        let mut reader = Cursor::new("n\n");
+        assert!(!confirm_dominated_guess(&mut reader, &sample_warning()));
+    }
+
+    #[test]
+    fn test_read_guess_export_candidates() {
+        let mut reader = Cursor::new("export-candidates Out/Pool.tsv\nEXPORT-CANDIDATES\n");
+        assert!(
+            matches!(read_guess(&mut reader), GuessInput::Export(p) if p == Path::new("Out/Pool.tsv"))
+        );
+        assert!(matches!(read_guess(&mut reader), GuessInput::Invalid));
+
+        // Written from the candidates last shown, then the guess is asked for again
+        let path = std::env::temp_dir().join("test_cli_export_candidates.tsv");
+        let input = format!("export-candidates {}\nCRANE\n", path.display());
+        let mut interface = CliInterface::new(Cursor::new(input));
+        interface.display_candidates(&["CRANE".to_string(), "TRACE".to_string()]);
+        assert!(matches!(interface.read_guess(), Some(UserAction::Guess(g)) if g == "CRANE"));
+        let written = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        assert_eq!(written.lines().count(), 3);
+    }
+
+    #[test]
+    fn test_read_guess_restrict() {
+        let mut reader = Cursor::new("restrict lists/Themed.txt\nrestrict\n");
+        assert!(
+            matches!(read_guess(&mut reader), GuessInput::Restrict(p) if p == Path::new("lists/Themed.txt"))
+        );
+        assert!(matches!(read_guess(&mut reader), GuessInput::Invalid));
+
+        let path = std::env::temp_dir().join("test_cli_restrict.txt");
+        std::fs::write(&path, "grace\nbrace\n").unwrap();
+        let input = format!(
+            "restrict {}\nrestrict /nonexistent/list.txt\n",
+            path.display()
+        );
+        let mut interface = CliInterface::new(Cursor::new(input));
+        let action = interface.read_guess();
+        std::fs::remove_file(&path).ok();
+        assert!(matches!(action, Some(UserAction::Restrict(words)) if words == ["GRACE", "BRACE"]));
+        assert!(interface.read_guess().is_none());
+    }
+
+    #[test]
+    fn test_read_guess_correct() {
+        let mut reader = Cursor::new("correct 2 gyxxg\ncorrect 1 greens: 1,5\ncorrect two GYXXG\n");
+        assert!(matches!(
+            read_guess(&mut reader),
+            GuessInput::Correct(2, feedback) if feedback == parse_feedback("GYXXG").unwrap()
+        ));
+        assert!(matches!(
+            read_guess(&mut reader),
+            GuessInput::Correct(1, feedback) if feedback == parse_feedback("GXXXG").unwrap()
+        ));
+        assert!(matches!(read_guess(&mut reader), GuessInput::Invalid));
+
+        assert_eq!(
+            correction_message(Correction::NoSuchRound {
+                round: 3,
+                rounds: 1
+            }),
+            "There's no round 3 to correct; 1 played so far."
+        );
+    }
+
+    #[test]
+    fn test_end_of_input_ends_the_session() {
+        let mut empty = Cursor::new("");
+        assert!(matches!(read_guess(&mut empty), GuessInput::Exit));
+        assert_eq!(read_feedback(&mut empty), InputResult::Closed);
+        assert!(!confirm_dominated_guess(&mut empty, &sample_warning()));
+
+        // Piped input that stops partway through a round used to spin or panic
+        let wordbank = WordBank::from_valid(["CRANE", "SLATE", "TRACE"]);
+        let mut interface = CliInterface::new(Cursor::new("CRANE\nGGG\n"));
+        let result = crate::game_state::game_loop(&wordbank, &mut interface);
+        assert_eq!(result.rounds, 0);
+    }
+
     // Tests for read_feedback function
     #[test]
     fn test_read_feedback_valid_all_green() {
         let input = "GGGGG\n";
         let mut reader = Cursor::new(input);
-        let result = read_feedback(&mut reader);
+        let result = read_feedback(&mut reader).ready();
         assert!(result.is_some());
         let feedback = result.unwrap();
         assert_eq!(feedback.len(), 5);
@@ -379,7 +2338,7 @@ mod tests {
     fn test_read_feedback_valid_mixed() {
         let input = "GYXXG\n";
         let mut reader = Cursor::new(input);
-        let result = read_feedback(&mut reader);
+        let result = read_feedback(&mut reader).ready();
         assert!(result.is_some());
         let feedback = result.unwrap();
         assert_eq!(feedback.len(), 5);
@@ -390,12 +2349,60 @@ mod tests {
         assert!(matches!(feedback[4], Feedback::Match));
     }
 
+    #[test]
+    fn test_read_feedback_alternate_formats() {
+        let expected = read_feedback(&mut Cursor::new("GYXXG\n")).ready().unwrap();
+        for input in [
+            "gybbg\n",
+            "21002\n",
+            "2 1 0 0 2\n",
+            "🟩🟨⬛⬜🟩\n",
+            "🟧🟦⬛\u{fe0f}⬛🟧\n",
+        ] {
+            assert_eq!(
+                read_feedback(&mut Cursor::new(input)).ready(),
+                Some(expected.clone()),
+                "{input}"
+            );
+        }
+        assert!(read_feedback(&mut Cursor::new("21003\n")).ready().is_none());
+        assert!(
+            read_feedback(&mut Cursor::new("🟩🟨⬛⬜\n"))
+                .ready()
+                .is_none()
+        );
+        let rounds = parse_rounds("CRANE=21002 SLATE:🟩🟨⬛⬜🟩").unwrap();
+        assert_eq!(rounds[0].1, expected);
+        assert_eq!(rounds[1].1, expected);
+    }
+
+    #[test]
+    fn test_read_feedback_positions() {
+        use Feedback::{Match, NoMatch, PartialMatch};
+
+        let read = |input: &str| read_feedback(&mut Cursor::new(input)).ready();
+        assert_eq!(
+            read("greens: 1,4  yellows: 2\n"),
+            Some(vec![Match, PartialMatch, NoMatch, Match, NoMatch])
+        );
+        assert_eq!(
+            read("Yellow 5 Green 3\n"),
+            Some(vec![NoMatch, NoMatch, Match, NoMatch, PartialMatch])
+        );
+        assert_eq!(read("greens:\n"), Some(vec![NoMatch; 5]));
+        assert!(read("greens: 1,6\n").is_none()); // Out of range
+        assert!(read("greens: 0\n").is_none());
+        assert!(read("greens: 1 yellows: 1\n").is_none()); // Both colours
+        assert!(read("1,4 greens\n").is_none()); // Position before any colour
+        assert!(read("greens: one\n").is_none());
+    }
+
     #[test]
     fn test_read_feedback_invalid_too_short() {
         let input = "GGG\n";
         let mut reader = Cursor::new(input);
         let result = read_feedback(&mut reader);
-        assert!(result.is_none());
+        assert_eq!(result, InputResult::Invalid);
     }
 
     #[test]
@@ -403,7 +2410,7 @@ mod tests {
         let input = "GGGGGG\n";
         let mut reader = Cursor::new(input);
         let result = read_feedback(&mut reader);
-        assert!(result.is_none());
+        assert_eq!(result, InputResult::Invalid);
     }
 
     #[test]
@@ -411,14 +2418,14 @@ mod tests {
         let input = "GGGGA\n";
         let mut reader = Cursor::new(input);
         let result = read_feedback(&mut reader);
-        assert!(result.is_none());
+        assert_eq!(result, InputResult::Invalid);
     }
 
     #[test]
     fn test_read_feedback_lowercase_converted() {
         let input = "gygxg\n";
         let mut reader = Cursor::new(input);
-        let result = read_feedback(&mut reader);
+        let result = read_feedback(&mut reader).ready();
         // After uppercase conversion, this should work
         assert!(result.is_some());
         let feedback = result.unwrap();
@@ -0,0 +1,48 @@
+//! System clipboard access.
+//!
+//! Only available with the `clipboard` feature; without it both calls return an error that
+//! says how to turn it on, so callers can show it like any other failure.
+
+#[cfg(not(feature = "clipboard"))]
+const DISABLED: &str =
+    "clipboard support needs the `clipboard` feature (cargo build --features clipboard)";
+
+/// Puts `text` on the clipboard
+///
+/// # Errors
+/// Returns an error if the clipboard can't be opened or written.
+#[cfg(feature = "clipboard")]
+pub fn copy(text: &str) -> Result<(), String> {
+    arboard::Clipboard::new()
+        .and_then(|mut clipboard| clipboard.set_text(text))
+        .map_err(|e| e.to_string())
+}
+
+/// Reads text from the clipboard
+///
+/// # Errors
+/// Returns an error if the clipboard can't be opened or holds no text.
+#[cfg(feature = "clipboard")]
+pub fn paste() -> Result<String, String> {
+    arboard::Clipboard::new()
+        .and_then(|mut clipboard| clipboard.get_text())
+        .map_err(|e| e.to_string())
+}
+
+/// Puts `text` on the clipboard
+///
+/// # Errors
+/// Always fails; clipboard access needs the `clipboard` feature.
+#[cfg(not(feature = "clipboard"))]
+pub fn copy(_text: &str) -> Result<(), String> {
+    Err(DISABLED.to_string())
+}
+
+/// Reads text from the clipboard
+///
+/// # Errors
+/// Always fails; clipboard access needs the `clipboard` feature.
+#[cfg(not(feature = "clipboard"))]
+pub fn paste() -> Result<String, String> {
+    Err(DISABLED.to_string())
+}
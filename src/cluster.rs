@@ -0,0 +1,213 @@
+//! Clusters of candidates that differ in a single letter, and the guess that splits them.
+//!
+//! Words like BATCH, CATCH, HATCH, LATCH, MATCH and PATCH are the classic endgame trap:
+//! guessing them one at a time can take a guess each. A word from outside the cluster that
+//! tests several of the differing letters at once, such as CLAMP, tells most of them apart
+//! in one turn.
+
+use crate::solver::{PATTERN_COUNT, best_finishing_guess, feedback_code};
+use crate::words::WORD_LENGTH;
+use std::collections::HashMap;
+
+/// Fewest candidates sharing all but one letter that make a trap worth pointing out
+pub const TRAP_SIZE: usize = 4;
+
+/// Character standing for any letter in a cluster pattern
+pub const WILDCARD: char = '_';
+
+/// The best guess for telling a cluster of candidates apart
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Splitter {
+    /// The cluster's pattern, with [`WILDCARD`] for the letters that differ
+    pub pattern: String,
+    /// The candidates matching the pattern
+    pub cluster: Vec<String>,
+    /// The guess, with the number of groups its feedback splits the cluster into, or `None`
+    /// when fewer than two candidates match
+    pub guess: Option<(String, usize)>,
+}
+
+/// Parses a cluster pattern: five letters or wildcards (`_`, `.`, `?` or `*`), with at
+/// least one wildcard.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::cluster::parse_pattern;
+///
+/// assert_eq!(parse_pattern("_atch"), Some("_ATCH".to_string()));
+/// assert_eq!(parse_pattern("S.A?E"), Some("S_A_E".to_string()));
+/// assert!(parse_pattern("BATCH").is_none());
+/// assert!(parse_pattern("_ATCHY").is_none());
+/// ```
+#[must_use]
+pub fn parse_pattern(input: &str) -> Option<String> {
+    let pattern: String = input
+        .trim()
+        .chars()
+        .map(|c| match c {
+            '_' | '.' | '?' | '*' => Some(WILDCARD),
+            c if c.is_ascii_alphabetic() => Some(c.to_ascii_uppercase()),
+            _ => None,
+        })
+        .collect::<Option<_>>()?;
+    (pattern.len() == WORD_LENGTH && pattern.contains(WILDCARD)).then_some(pattern)
+}
+
+/// Whether `word` fits `pattern`
+#[must_use]
+pub fn matches_pattern(word: &str, pattern: &str) -> bool {
+    word.len() == pattern.len()
+        && word
+            .chars()
+            .zip(pattern.chars())
+            .all(|(letter, p)| p == WILDCARD || p == letter)
+}
+
+/// The pattern of the largest group of at least [`TRAP_SIZE`] candidates that differ only in
+/// one position, or `None` if there's no such group. Ties go to the earliest position, then
+/// alphabetically.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::cluster::find_trap;
+///
+/// let words = |list: &[&str]| -> Vec<String> { list.iter().map(|s| s.to_string()).collect() };
+/// let candidates = words(&["BATCH", "CATCH", "HATCH", "MATCH", "PITCH"]);
+/// assert_eq!(find_trap(&candidates), Some("_ATCH".to_string()));
+/// assert_eq!(find_trap(&candidates[1..]), None);
+/// ```
+#[must_use]
+pub fn find_trap(candidates: &[String]) -> Option<String> {
+    (0..WORD_LENGTH)
+        .flat_map(|position| {
+            let mut groups: HashMap<String, usize> = HashMap::new();
+            for word in candidates {
+                let pattern: String = word
+                    .chars()
+                    .enumerate()
+                    .map(|(i, letter)| if i == position { WILDCARD } else { letter })
+                    .collect();
+                *groups.entry(pattern).or_default() += 1;
+            }
+            groups
+                .into_iter()
+                .map(move |(pattern, size)| (size, position, pattern))
+        })
+        .filter(|&(size, _, _)| size >= TRAP_SIZE)
+        .min_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)).then(a.2.cmp(&b.2)))
+        .map(|(_, _, pattern)| pattern)
+}
+
+/// Whether guessing the words of `cluster` one at a time could run out of guesses. Each
+/// leaves the others looking alike, so it can take a guess per word.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::cluster::is_trap;
+///
+/// let family: Vec<String> = ["BATCH", "CATCH", "HATCH", "LATCH", "MATCH"]
+///     .iter()
+///     .map(|s| s.to_string())
+///     .collect();
+/// assert!(is_trap(&family, 4));
+/// assert!(!is_trap(&family, 5));
+/// ```
+#[must_use]
+pub fn is_trap(cluster: &[String], guesses_left: usize) -> bool {
+    cluster.len() > guesses_left
+}
+
+/// Number of groups the feedback of `guess` splits `cluster` into
+#[must_use]
+pub fn groups(guess: &str, cluster: &[String]) -> usize {
+    let mut seen = [false; PATTERN_COUNT];
+    for word in cluster {
+        seen[usize::from(feedback_code(guess.as_bytes(), word.as_bytes()))] = true;
+    }
+    seen.iter().filter(|&&seen| seen).count()
+}
+
+/// Finds the guess from `wordbank` that splits the candidates matching `pattern` into the
+/// most groups, preferring one of them and then the smaller expected pool size.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::cluster::best_splitter;
+///
+/// let words = |list: &[&str]| -> Vec<String> { list.iter().map(|s| s.to_string()).collect() };
+/// let candidates = words(&["BATCH", "CATCH", "HATCH", "LATCH", "MATCH", "PATCH"]);
+/// let mut wordbank = candidates.clone();
+/// wordbank.push("CLAMP".to_string());
+///
+/// let splitter = best_splitter(&wordbank, &candidates, "_ATCH");
+/// assert_eq!(splitter.cluster.len(), 6);
+/// // CLAMP tests C, L, M and P at once, leaving only BATCH and HATCH together
+/// assert_eq!(splitter.guess, Some(("CLAMP".to_string(), 5)));
+/// ```
+#[must_use]
+pub fn best_splitter(wordbank: &[String], candidates: &[String], pattern: &str) -> Splitter {
+    let cluster: Vec<String> = candidates
+        .iter()
+        .filter(|word| matches_pattern(word, pattern))
+        .cloned()
+        .collect();
+    let guess = if cluster.len() < 2 {
+        None
+    } else {
+        best_finishing_guess(wordbank, &cluster)
+            .map(|(guess, _, _)| (guess.clone(), groups(guess, &cluster)))
+    };
+    Splitter {
+        pattern: pattern.to_string(),
+        cluster,
+        guess,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::words;
+    use crate::wordbank::sample_wordbank;
+
+    #[test]
+    fn test_find_trap_prefers_the_largest_group() {
+        let candidates = words(&[
+            "LIGHT", "MIGHT", "NIGHT", "SIGHT", "FIGHT", "TIGHT", "BATCH",
+        ]);
+        assert_eq!(find_trap(&candidates), Some("_IGHT".to_string()));
+        // Groups of four tie: the earlier position wins, then the earlier pattern
+        let candidates = words(&["STARE", "STARK", "STARS", "START", "LIGHT", "MIGHT"]);
+        let mut candidates = [candidates, words(&["NIGHT", "SIGHT"])].concat();
+        assert_eq!(find_trap(&candidates), Some("_IGHT".to_string()));
+        candidates.extend(words(&["BATCH", "CATCH", "HATCH", "MATCH"]));
+        assert_eq!(find_trap(&candidates), Some("_ATCH".to_string()));
+        assert_eq!(find_trap(&[]), None);
+    }
+
+    #[test]
+    fn test_best_splitter_beats_guessing_the_cluster() {
+        let wordbank = sample_wordbank();
+        let cluster = words(&["BATCH", "CATCH", "HATCH", "LATCH", "MATCH", "PATCH"]);
+        let mut candidates = cluster.clone();
+        candidates.push("CRANE".to_string());
+
+        let splitter = best_splitter(&wordbank, &candidates, "_ATCH");
+        assert_eq!(splitter.cluster, cluster);
+        let (guess, split) = splitter.guess.expect("six words to split");
+        assert_eq!(split, groups(&guess, &cluster));
+        // Any word of the cluster leaves the other five together
+        assert!(
+            split > groups("BATCH", &cluster),
+            "{guess} splits into {split}"
+        );
+
+        let none = best_splitter(&wordbank, &candidates, "_RANE");
+        assert_eq!(none.cluster, ["CRANE"]);
+        assert_eq!(none.guess, None);
+    }
+}
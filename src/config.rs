@@ -0,0 +1,390 @@
+//! User preferences persisted between runs.
+//!
+//! Settings live in `~/.wordle_config` as `key = value` lines. Unknown keys and bad values
+//! are ignored so an old or hand-edited file never stops the solver from starting.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Order of the words in the TUI candidate pane
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CandidateSort {
+    /// A to Z (default)
+    #[default]
+    Alphabetical,
+    /// Best expected pool size first
+    Score,
+    /// Words made of the most common remaining letters first
+    Frequency,
+}
+
+impl CandidateSort {
+    /// The next order in the cycle alphabetical → score → frequency
+    #[must_use]
+    pub const fn next(self) -> Self {
+        match self {
+            Self::Alphabetical => Self::Score,
+            Self::Score => Self::Frequency,
+            Self::Frequency => Self::Alphabetical,
+        }
+    }
+}
+
+impl FromStr for CandidateSort {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "alphabetical" => Ok(Self::Alphabetical),
+            "score" => Ok(Self::Score),
+            "frequency" => Ok(Self::Frequency),
+            _ => Err(format!(
+                "unknown sort '{s}' (expected alphabetical, score, or frequency)"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for CandidateSort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Alphabetical => "alphabetical",
+            Self::Score => "score",
+            Self::Frequency => "frequency",
+        })
+    }
+}
+
+/// Physical keyboard layout the on-screen keyboard is drawn in
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum KeyboardLayout {
+    #[default]
+    Qwerty,
+    /// German and Central European, with Y and Z swapped
+    Qwertz,
+    /// French and Belgian
+    Azerty,
+    Dvorak,
+}
+
+impl KeyboardLayout {
+    /// The letter keys of each row, top first, with how far the row is indented in half-key
+    /// widths. Punctuation keys are left out, so rows that start with them are indented more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wordle_solver::config::KeyboardLayout;
+    ///
+    /// assert_eq!(KeyboardLayout::Qwertz.rows()[0], (0, "QWERTZUIOP"));
+    /// assert_eq!(KeyboardLayout::Azerty.rows()[2], (2, "WXCVBN"));
+    /// ```
+    #[must_use]
+    pub const fn rows(self) -> [(usize, &'static str); 3] {
+        match self {
+            Self::Qwerty => [(0, "QWERTYUIOP"), (1, "ASDFGHJKL"), (2, "ZXCVBNM")],
+            Self::Qwertz => [(0, "QWERTZUIOP"), (1, "ASDFGHJKL"), (2, "YXCVBNM")],
+            Self::Azerty => [(0, "AZERTYUIOP"), (1, "QSDFGHJKLM"), (2, "WXCVBN")],
+            // The top row starts after ' , . and the bottom row after ;
+            Self::Dvorak => [(6, "PYFGCRL"), (1, "AOEUIDHTNS"), (4, "QJKXBMWVZ")],
+        }
+    }
+}
+
+impl FromStr for KeyboardLayout {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "qwerty" => Ok(Self::Qwerty),
+            "qwertz" => Ok(Self::Qwertz),
+            "azerty" => Ok(Self::Azerty),
+            "dvorak" => Ok(Self::Dvorak),
+            _ => Err(format!(
+                "unknown keyboard layout '{s}' (expected qwerty, qwertz, azerty, or dvorak)"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for KeyboardLayout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Qwerty => "qwerty",
+            Self::Qwertz => "qwertz",
+            Self::Azerty => "azerty",
+            Self::Dvorak => "dvorak",
+        })
+    }
+}
+
+/// Moments in the TUI that can sound an audible cue
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Cue {
+    /// A key or guess was rejected
+    Invalid,
+    /// Feedback for a guess was confirmed
+    Feedback,
+    /// The answer was found
+    Solve,
+}
+
+impl FromStr for Cue {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "invalid" => Ok(Self::Invalid),
+            "feedback" => Ok(Self::Feedback),
+            "solve" => Ok(Self::Solve),
+            _ => Err(format!(
+                "unknown cue '{s}' (expected invalid, feedback, or solve)"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Cue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Invalid => "invalid",
+            Self::Feedback => "feedback",
+            Self::Solve => "solve",
+        })
+    }
+}
+
+/// Saved user preferences
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Config {
+    pub candidate_sort: CandidateSort,
+    /// Show each candidate's probability and score next to it
+    pub annotate_candidates: bool,
+    /// Moments that ring the terminal bell (none by default)
+    pub cues: Vec<Cue>,
+    /// Program run with the cue's name as its argument whenever a cue sounds, e.g. to play a
+    /// sound file
+    pub cue_command: Option<String>,
+    pub keyboard_layout: KeyboardLayout,
+    /// Interface language, as given to [`load_catalog`](crate::i18n::load_catalog)
+    pub language: Option<String>,
+}
+
+impl Config {
+    /// Parses `key = value` lines, skipping blanks, `#` comments, and anything unrecognised.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wordle_solver::config::{CandidateSort, Config};
+    ///
+    /// let config = Config::parse("candidate_sort = score\nannotate_candidates = true");
+    /// assert_eq!(config.candidate_sort, CandidateSort::Score);
+    /// assert!(config.annotate_candidates);
+    /// assert!(config.cues.is_empty());
+    /// ```
+    #[must_use]
+    pub fn parse(data: &str) -> Self {
+        Self::parse_checked(data).0
+    }
+
+    /// Parses like [`Self::parse`], also describing each line that had to be skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wordle_solver::config::Config;
+    ///
+    /// let (config, problems) = Config::parse_checked("annotate_candidates = true\ncolor = blue");
+    /// assert!(config.annotate_candidates);
+    /// assert_eq!(problems, ["line 2: unknown setting 'color'"]);
+    /// ```
+    #[must_use]
+    pub fn parse_checked(data: &str) -> (Self, Vec<String>) {
+        let mut config = Self::default();
+        let mut problems = Vec::new();
+        for (i, line) in data.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Err(e) = config.apply(line) {
+                problems.push(format!("line {}: {e}", i + 1));
+            }
+        }
+        (config, problems)
+    }
+
+    /// Applies one `key = value` line, leaving the setting alone if the value is bad
+    fn apply(&mut self, line: &str) -> Result<(), String> {
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| format!("'{line}' is not a `key = value` setting"))?;
+        let value = value.trim();
+        match key.trim() {
+            "candidate_sort" => self.candidate_sort = value.parse()?,
+            "annotate_candidates" => {
+                self.annotate_candidates = value.parse().map_err(|_| {
+                    format!("annotate_candidates must be true or false, not '{value}'")
+                })?;
+            }
+            "keyboard_layout" => self.keyboard_layout = value.parse()?,
+            "cues" => {
+                let (cues, unknown): (Vec<_>, Vec<_>) = value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|cue| !cue.is_empty())
+                    .map(|cue| cue.parse::<Cue>().map_err(|_| cue))
+                    .partition(Result::is_ok);
+                // Known cues still apply alongside unknown ones
+                self.cues = cues.into_iter().filter_map(Result::ok).collect();
+                if let Some(Err(cue)) = unknown.first() {
+                    return Err(format!("unknown cue '{cue}'"));
+                }
+            }
+            "cue_command" => {
+                if !value.is_empty() {
+                    self.cue_command = Some(value.to_string());
+                }
+            }
+            "language" => {
+                if !value.is_empty() {
+                    self.language = Some(value.to_string());
+                }
+            }
+            key => return Err(format!("unknown setting '{key}'")),
+        }
+        Ok(())
+    }
+
+    /// Reads the config at `path`, falling back to defaults if it is missing or unreadable
+    #[must_use]
+    pub fn load(path: &Path) -> Self {
+        fs::read_to_string(path)
+            .map(|data| Self::parse(&data))
+            .unwrap_or_default()
+    }
+
+    /// # Errors
+    /// Returns an error if the file cannot be written.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+}
+
+impl fmt::Display for Config {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "candidate_sort = {}", self.candidate_sort)?;
+        writeln!(f, "annotate_candidates = {}", self.annotate_candidates)?;
+        writeln!(f, "keyboard_layout = {}", self.keyboard_layout)?;
+        let cues: Vec<String> = self.cues.iter().map(Cue::to_string).collect();
+        writeln!(f, "cues = {}", cues.join(", "))?;
+        if let Some(command) = &self.cue_command {
+            writeln!(f, "cue_command = {command}")?;
+        }
+        if let Some(language) = &self.language {
+            writeln!(f, "language = {language}")?;
+        }
+        Ok(())
+    }
+}
+
+#[must_use]
+pub fn get_wordle_config_path() -> Option<PathBuf> {
+    dirs::home_dir().map(|mut path| {
+        path.push(".wordle_config");
+        path
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_defaults_for_empty_input() {
+        assert_eq!(Config::parse(""), Config::default());
+    }
+
+    #[test]
+    fn test_parse_ignores_comments_and_bad_values() {
+        let data = "# saved by wordle-solver\ncandidate_sort = sideways\nannotate_candidates = maybe\ncolor = blue\nnonsense";
+        assert_eq!(Config::parse(data), Config::default());
+    }
+
+    #[test]
+    fn test_parse_checked_reports_skipped_lines() {
+        let data = "# saved by wordle-solver\ncandidate_sort = sideways\ncues = solve, beep\ncues =\nnonsense";
+        let (config, problems) = Config::parse_checked(data);
+        assert_eq!(config, Config::parse(data));
+        assert_eq!(problems.len(), 3);
+        assert!(problems[0].starts_with("line 2:"));
+        assert_eq!(problems[1], "line 3: unknown cue 'beep'");
+        assert!(problems[2].starts_with("line 5:"));
+    }
+
+    #[test]
+    fn test_parse_cues_skips_unknown_names() {
+        let config = Config::parse("cues = solve, beep ,INVALID\ncue_command =");
+        assert_eq!(config.cues, [Cue::Solve, Cue::Invalid]);
+        assert_eq!(config.cue_command, None);
+    }
+
+    #[test]
+    fn test_candidate_sort_cycle() {
+        let sort = CandidateSort::default();
+        assert_eq!(sort, CandidateSort::Alphabetical);
+        assert_eq!(sort.next().next().next(), sort);
+        for sort in [sort, sort.next(), sort.next().next()] {
+            assert_eq!(sort.to_string().parse::<CandidateSort>(), Ok(sort));
+        }
+    }
+
+    #[test]
+    fn test_keyboard_layouts_cover_the_alphabet() {
+        for layout in [
+            KeyboardLayout::Qwerty,
+            KeyboardLayout::Qwertz,
+            KeyboardLayout::Azerty,
+            KeyboardLayout::Dvorak,
+        ] {
+            let mut letters: Vec<char> = layout
+                .rows()
+                .iter()
+                .flat_map(|(_, row)| row.chars())
+                .collect();
+            letters.sort_unstable();
+            assert_eq!(letters, ('A'..='Z').collect::<Vec<_>>(), "{layout}");
+            assert_eq!(layout.to_string().parse::<KeyboardLayout>(), Ok(layout));
+        }
+    }
+
+    #[test]
+    fn test_save_then_load_roundtrip() {
+        let file_path = std::env::temp_dir().join("test_wordle_config.txt");
+        let config = Config {
+            candidate_sort: CandidateSort::Frequency,
+            annotate_candidates: true,
+            cues: vec![Cue::Invalid, Cue::Solve],
+            cue_command: Some("paplay /usr/share/sounds/bell.oga".to_string()),
+            language: Some("de".to_string()),
+            keyboard_layout: KeyboardLayout::Dvorak,
+        };
+
+        config.save(&file_path).unwrap();
+        assert_eq!(Config::load(&file_path), config);
+
+        std::fs::remove_file(&file_path).unwrap();
+    }
+
+    #[test]
+    fn test_load_missing_file_uses_defaults() {
+        assert_eq!(
+            Config::load(Path::new("nonexistent_wordle_config.txt")),
+            Config::default()
+        );
+    }
+}
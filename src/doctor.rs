@@ -0,0 +1,386 @@
+//! Environment health checks behind `wordle-solver doctor`.
+//!
+//! Each check looks at one thing a bug report tends to hinge on (the wordbank, the caches, the
+//! config file, the terminal) and says how to fix anything wrong with it, so most problems can
+//! be sorted out without a round trip and the rest come with a useful report.
+
+use crate::config::Config;
+use crate::sources::WordSource;
+use crate::tui::MIN_TERMINAL_SIZE;
+use crate::wordbank::{
+    get_wordle_start_path, read_starting_checkpoint, read_starting_words, starting_checkpoint_path,
+    wordbank_fingerprint,
+};
+use crate::words::WordBank;
+use std::collections::HashSet;
+use std::fmt;
+use std::io::{self, IsTerminal};
+use std::path::Path;
+
+/// How a check went
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Status {
+    Ok,
+    /// Works, but not as well as it could
+    Warning,
+    /// Stops the solver from working
+    Error,
+}
+
+/// Result of one check, with a suggested fix unless it passed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Check {
+    pub name: &'static str,
+    pub status: Status,
+    pub detail: String,
+    pub fix: Option<String>,
+}
+
+impl Check {
+    fn ok(name: &'static str, detail: String) -> Self {
+        Self {
+            name,
+            status: Status::Ok,
+            detail,
+            fix: None,
+        }
+    }
+
+    fn warning(name: &'static str, detail: String, fix: String) -> Self {
+        Self {
+            name,
+            status: Status::Warning,
+            detail,
+            fix: Some(fix),
+        }
+    }
+
+    fn error(name: &'static str, detail: String, fix: String) -> Self {
+        Self {
+            name,
+            status: Status::Error,
+            detail,
+            fix: Some(fix),
+        }
+    }
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self.status {
+            Status::Ok => "ok",
+            Status::Warning => "warn",
+            Status::Error => "FAIL",
+        };
+        write!(f, "[{label:>4}] {}: {}", self.name, self.detail)?;
+        if let Some(fix) = &self.fix {
+            write!(f, "\n       fix: {fix}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Runs every check against the wordbank from `source` and the files in the home directory
+#[must_use]
+pub fn run_checks(source: &dyn WordSource) -> Vec<Check> {
+    let words = source.words();
+    let wordbank = words.as_deref().unwrap_or_default();
+    let start_path = get_wordle_start_path();
+    let config_path = crate::config::get_wordle_config_path();
+    let stdout = io::stdout();
+    vec![
+        check_wordbank(&source.describe(), &words),
+        check_starting_cache(start_path.as_deref(), wordbank),
+        check_stale_files(start_path.as_deref(), wordbank),
+        check_config(config_path.as_deref()),
+        check_terminal(
+            stdout.is_terminal(),
+            crossterm::terminal::size().ok(),
+            std::env::var("TERM").ok().as_deref(),
+        ),
+    ]
+}
+
+/// Whether the wordbank loaded, and its size and fingerprint for comparing installs
+#[must_use]
+pub fn check_wordbank(description: &str, words: &io::Result<WordBank>) -> Check {
+    const NAME: &str = "wordbank";
+    let words = match words {
+        Ok(words) => words,
+        Err(e) => {
+            return Check::error(
+                NAME,
+                format!("couldn't load {description}: {e}"),
+                "check the path or URL given to --input".to_string(),
+            );
+        }
+    };
+    if words.is_empty() {
+        return Check::error(
+            NAME,
+            format!("{description} has no valid 5-letter words"),
+            "put one 5-letter word on each line".to_string(),
+        );
+    }
+    let distinct: HashSet<&String> = words.iter().collect();
+    let duplicates = words.len() - distinct.len();
+    if duplicates > 0 {
+        return Check::warning(
+            NAME,
+            format!(
+                "{description}: {} words, {duplicates} duplicated",
+                words.len()
+            ),
+            "remove the duplicate lines, which skew candidate counts".to_string(),
+        );
+    }
+    Check::ok(
+        NAME,
+        format!(
+            "{description}: {} words, fingerprint {:016x}",
+            words.len(),
+            wordbank_fingerprint(words)
+        ),
+    )
+}
+
+/// Whether the starting-word cache at `path` can be read and was made for `wordbank`
+#[must_use]
+pub fn check_starting_cache(path: Option<&Path>, wordbank: &[String]) -> Check {
+    const NAME: &str = "starting-word cache";
+    let Some(path) = path else {
+        return Check::warning(
+            NAME,
+            "no home directory, so starting words are recomputed every run".to_string(),
+            "set HOME (or USERPROFILE on Windows)".to_string(),
+        );
+    };
+    if !path.exists() {
+        return Check::ok(
+            NAME,
+            format!(
+                "{} not written yet; the next run computes it",
+                path.display()
+            ),
+        );
+    }
+    match read_starting_words(path, 1) {
+        None => Check::warning(
+            NAME,
+            format!("{} is unreadable or from an older version", path.display()),
+            format!("delete {} and it will be recomputed", path.display()),
+        ),
+        Some(words) if !wordbank.contains(&words[0].0) => Check::warning(
+            NAME,
+            format!(
+                "{} was computed for a different wordbank ({} isn't in this one)",
+                path.display(),
+                words[0].0
+            ),
+            format!(
+                "delete {} or run with --no-cache when using this wordbank",
+                path.display()
+            ),
+        ),
+        Some(words) => Check::ok(
+            NAME,
+            format!("{} opens with {}", path.display(), words[0].0),
+        ),
+    }
+}
+
+/// Whether files left by earlier runs are still of use
+#[must_use]
+pub fn check_stale_files(start_path: Option<&Path>, wordbank: &[String]) -> Check {
+    const NAME: &str = "stale files";
+    let Some(checkpoint) = start_path.map(starting_checkpoint_path) else {
+        return Check::ok(NAME, "none".to_string());
+    };
+    if !checkpoint.exists() {
+        return Check::ok(NAME, "none".to_string());
+    }
+    let scored = read_starting_checkpoint(&checkpoint, wordbank).len();
+    if scored == 0 {
+        Check::warning(
+            NAME,
+            format!(
+                "{} is left from an interrupted run over another wordbank",
+                checkpoint.display()
+            ),
+            format!("delete {}", checkpoint.display()),
+        )
+    } else {
+        Check::ok(
+            NAME,
+            format!(
+                "{} will resume the starting words after {scored} of {} words",
+                checkpoint.display(),
+                wordbank.len()
+            ),
+        )
+    }
+}
+
+/// Whether the config file at `path` can be read and every line in it is understood
+#[must_use]
+pub fn check_config(path: Option<&Path>) -> Check {
+    const NAME: &str = "config";
+    let Some(path) = path else {
+        return Check::warning(
+            NAME,
+            "no home directory, so preferences can't be saved".to_string(),
+            "set HOME (or USERPROFILE on Windows)".to_string(),
+        );
+    };
+    if !path.exists() {
+        return Check::ok(
+            NAME,
+            format!("{} not written yet; defaults apply", path.display()),
+        );
+    }
+    let data = match std::fs::read_to_string(path) {
+        Ok(data) => data,
+        Err(e) => {
+            return Check::error(
+                NAME,
+                format!("couldn't read {}: {e}", path.display()),
+                "check the file's permissions, or delete it to go back to defaults".to_string(),
+            );
+        }
+    };
+    let (_, problems) = Config::parse_checked(&data);
+    if problems.is_empty() {
+        Check::ok(NAME, format!("{} is valid", path.display()))
+    } else {
+        Check::warning(
+            NAME,
+            format!(
+                "{} has ignored lines: {}",
+                path.display(),
+                problems.join("; ")
+            ),
+            format!("fix those lines or delete {}", path.display()),
+        )
+    }
+}
+
+/// Whether the TUI can run in this terminal and fits in it
+#[must_use]
+pub fn check_terminal(is_terminal: bool, size: Option<(u16, u16)>, term: Option<&str>) -> Check {
+    const NAME: &str = "terminal";
+    if !is_terminal {
+        return Check::warning(
+            NAME,
+            "output isn't a terminal, so the TUI can't start".to_string(),
+            "run from an interactive terminal, or use --ui cli".to_string(),
+        );
+    }
+    if term == Some("dumb") {
+        return Check::warning(
+            NAME,
+            "TERM=dumb doesn't support the TUI's colours and cursor movement".to_string(),
+            "use --ui cli, or a terminal that sets TERM".to_string(),
+        );
+    }
+    let (min_columns, min_rows) = MIN_TERMINAL_SIZE;
+    match size {
+        Some((columns, rows)) if columns < min_columns || rows < min_rows => Check::warning(
+            NAME,
+            format!("{columns}x{rows} is smaller than the TUI's {min_columns}x{min_rows}"),
+            "enlarge the window, or use --ui cli".to_string(),
+        ),
+        Some((columns, rows)) => Check::ok(
+            NAME,
+            format!("{columns}x{rows}, TERM={}", term.unwrap_or("unset")),
+        ),
+        None => Check::warning(
+            NAME,
+            "couldn't read the terminal size".to_string(),
+            "use --ui cli if the TUI doesn't draw properly".to_string(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{bank, words};
+    use crate::wordbank::{write_starting_checkpoint, write_starting_words};
+
+    #[test]
+    fn test_check_wordbank() {
+        let wordbank = Ok(bank(&["CRANE", "SLATE"]));
+        assert_eq!(check_wordbank("bank", &wordbank).status, Status::Ok);
+        let duplicated = Ok(bank(&["CRANE", "CRANE"]));
+        assert_eq!(check_wordbank("bank", &duplicated).status, Status::Warning);
+        assert_eq!(
+            check_wordbank("bank", &Ok(WordBank::default())).status,
+            Status::Error
+        );
+        let missing = Err(io::Error::new(io::ErrorKind::NotFound, "no such file"));
+        let check = check_wordbank("bank", &missing);
+        assert_eq!(check.status, Status::Error);
+        assert!(check.to_string().contains("fix: check the path"));
+    }
+
+    #[test]
+    fn test_check_starting_cache_and_stale_checkpoint() {
+        let bank = words(&["CRANE", "SLATE"]);
+        let path = std::env::temp_dir().join("test_doctor_start");
+        assert_eq!(check_starting_cache(Some(&path), &bank).status, Status::Ok);
+
+        std::fs::write(&path, "CRANE\nSLATE\n").unwrap();
+        assert_eq!(
+            check_starting_cache(Some(&path), &bank).status,
+            Status::Warning
+        );
+        write_starting_words(&path, &[("TRACE".to_string(), 1.0)]);
+        assert_eq!(
+            check_starting_cache(Some(&path), &bank).status,
+            Status::Warning
+        );
+        write_starting_words(&path, &[("SLATE".to_string(), 1.0)]);
+        assert_eq!(check_starting_cache(Some(&path), &bank).status, Status::Ok);
+
+        let checkpoint = starting_checkpoint_path(&path);
+        write_starting_checkpoint(&checkpoint, &bank, &[("CRANE".to_string(), 1.0)]);
+        assert_eq!(check_stale_files(Some(&path), &bank).status, Status::Ok);
+        assert_eq!(
+            check_stale_files(Some(&path), &bank[..1]).status,
+            Status::Warning
+        );
+
+        std::fs::remove_file(&path).unwrap();
+        std::fs::remove_file(&checkpoint).unwrap();
+    }
+
+    #[test]
+    fn test_check_config() {
+        let path = std::env::temp_dir().join("test_doctor_config");
+        std::fs::write(&path, "annotate_candidates = true\n").unwrap();
+        assert_eq!(check_config(Some(&path)).status, Status::Ok);
+        std::fs::write(&path, "annotate_candidates = maybe\n").unwrap();
+        let check = check_config(Some(&path));
+        assert_eq!(check.status, Status::Warning);
+        assert!(check.detail.contains("line 1"));
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(check_config(Some(&path)).status, Status::Ok);
+    }
+
+    #[test]
+    fn test_check_terminal() {
+        assert_eq!(check_terminal(false, None, None).status, Status::Warning);
+        assert_eq!(
+            check_terminal(true, Some((120, 40)), Some("dumb")).status,
+            Status::Warning
+        );
+        assert_eq!(
+            check_terminal(true, Some((60, 20)), None).status,
+            Status::Warning
+        );
+        assert_eq!(
+            check_terminal(true, Some((120, 40)), Some("xterm")).status,
+            Status::Ok
+        );
+    }
+}
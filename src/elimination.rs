@@ -0,0 +1,265 @@
+//! Why a word is, or is no longer, a candidate.
+//!
+//! [`why_not`] finds the first round whose feedback the word contradicts, and within it the
+//! first tile that would have come out differently had the word been the answer. That tile
+//! and its colour are all it takes to rule the word out, so it makes the simplest reason.
+
+use crate::i18n::Message;
+use crate::solver::{Feedback, PATTERN_COUNT, feedback_code, get_feedback};
+
+/// Which reading of a tile a word contradicts
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rule {
+    /// A green tile puts its letter in that position
+    GreenHere,
+    /// A yellow tile keeps its letter out of that position
+    YellowNotHere,
+    /// A yellow tile means the word has at least as many of the letter as the guess marked
+    YellowInWord,
+    /// A gray tile keeps its letter out of that position
+    GrayNotHere,
+    /// A gray tile means the word has no more of the letter than the guess marked
+    GrayTooMany,
+}
+
+/// The tile that ruled a word out
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Elimination {
+    /// Round the tile is in, counting from 1
+    pub round: usize,
+    pub guess: String,
+    pub feedback: Vec<Feedback>,
+    /// Position of the tile, counting from 0
+    pub position: usize,
+    pub rule: Rule,
+}
+
+/// What [`why_not`] found out about a word
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WhyNot {
+    /// The word isn't in the wordbank, so it was never a candidate
+    NotInWordbank,
+    /// Still a candidate, ranked by expected pool size if guessed (1 is the best of `count`)
+    Candidate { rank: usize, count: usize },
+    /// A round's feedback rules it out
+    Eliminated(Elimination),
+    /// It fits every round, but a `restrict` list left it out
+    Restricted,
+}
+
+/// Explains why `word` is or isn't among `candidates`, given the rounds played.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::elimination::{Rule, WhyNot, why_not};
+/// use wordle_solver::get_feedback;
+///
+/// let wordbank: Vec<String> = ["SLATE", "SHARE", "CRANE"].iter().map(|s| s.to_string()).collect();
+/// let history = vec![("SLATE".to_string(), get_feedback("SLATE", "SHARE"))];
+/// let candidates = vec!["SHARE".to_string()];
+///
+/// // SLATE's S came back green, and CRANE doesn't start with S
+/// let WhyNot::Eliminated(elimination) = why_not("CRANE", &wordbank, &history, &candidates) else {
+///     panic!("CRANE should be ruled out");
+/// };
+/// assert_eq!((elimination.round, elimination.position), (1, 0));
+/// assert_eq!(elimination.rule, Rule::GreenHere);
+/// assert_eq!(
+///     why_not("SHARE", &wordbank, &history, &candidates),
+///     WhyNot::Candidate { rank: 1, count: 1 }
+/// );
+/// ```
+#[must_use]
+pub fn why_not(
+    word: &str,
+    wordbank: &[String],
+    history: &[(String, Vec<Feedback>)],
+    candidates: &[String],
+) -> WhyNot {
+    if candidates.iter().any(|candidate| candidate == word) {
+        return WhyNot::Candidate {
+            rank: rank(word, candidates),
+            count: candidates.len(),
+        };
+    }
+    if !wordbank.iter().any(|known| known == word) {
+        return WhyNot::NotInWordbank;
+    }
+    for (i, (guess, feedback)) in history.iter().enumerate() {
+        let would_be = get_feedback(guess, word);
+        let Some(position) = (0..feedback.len()).find(|&p| would_be.get(p) != feedback.get(p))
+        else {
+            continue;
+        };
+        let letter = guess.as_bytes()[position];
+        let here = word.as_bytes().get(position) == Some(&letter);
+        let rule = match (feedback[position], here) {
+            (Feedback::Match, _) => Rule::GreenHere,
+            (Feedback::PartialMatch, true) => Rule::YellowNotHere,
+            (Feedback::PartialMatch, false) => Rule::YellowInWord,
+            (Feedback::NoMatch, true) => Rule::GrayNotHere,
+            (Feedback::NoMatch, false) => Rule::GrayTooMany,
+        };
+        return WhyNot::Eliminated(Elimination {
+            round: i + 1,
+            guess: guess.clone(),
+            feedback: feedback.clone(),
+            position,
+            rule,
+        });
+    }
+    WhyNot::Restricted
+}
+
+/// Where `word` ranks among `candidates` by expected pool size if guessed, best first and
+/// then alphabetically, as in the exported candidate table
+fn rank(word: &str, candidates: &[String]) -> usize {
+    // Sum of squared bucket sizes, which orders guesses the same way as expected pool size
+    let score = |guess: &str| {
+        let mut buckets = [0usize; PATTERN_COUNT];
+        for solution in candidates {
+            buckets[usize::from(feedback_code(guess.as_bytes(), solution.as_bytes()))] += 1;
+        }
+        buckets.iter().map(|&n| n * n).sum::<usize>()
+    };
+    let own = score(word);
+    1 + candidates
+        .iter()
+        .filter(|other| {
+            let theirs = score(other);
+            theirs < own || (theirs == own && other.as_str() < word)
+        })
+        .count()
+}
+
+impl WhyNot {
+    /// The explanation as a sentence about `word`
+    #[must_use]
+    pub fn describe(&self, word: &str) -> String {
+        match self {
+            Self::NotInWordbank => Message::GameWhyNotWordbank.fill(&[("word", &word)]),
+            Self::Candidate { rank, count } => Message::GameWhyNotCandidate.fill(&[
+                ("word", &word),
+                ("rank", rank),
+                ("count", count),
+            ]),
+            Self::Restricted => Message::GameWhyNotRestricted.fill(&[("word", &word)]),
+            Self::Eliminated(elimination) => {
+                let letter = char::from(elimination.guess.as_bytes()[elimination.position]);
+                // Tiles of the letter the guess marked green or yellow
+                let marked = elimination
+                    .guess
+                    .chars()
+                    .zip(&elimination.feedback)
+                    .filter(|&(c, &fb)| c == letter && fb != Feedback::NoMatch)
+                    .count();
+                let has = word.chars().filter(|&c| c == letter).count();
+                let actual = word.chars().nth(elimination.position).unwrap_or('?');
+                let position = elimination.position + 1;
+                let reason = match elimination.rule {
+                    Rule::GreenHere => Message::GameWhyNotGreenHere.fill(&[
+                        ("letter", &letter),
+                        ("position", &position),
+                        ("word", &word),
+                        ("actual", &actual),
+                    ]),
+                    Rule::YellowNotHere => Message::GameWhyNotYellowNotHere.fill(&[
+                        ("letter", &letter),
+                        ("position", &position),
+                        ("word", &word),
+                    ]),
+                    Rule::YellowInWord => Message::GameWhyNotYellowInWord.fill(&[
+                        ("letter", &letter),
+                        ("count", &marked),
+                        ("word", &word),
+                        ("has", &has),
+                    ]),
+                    Rule::GrayNotHere => Message::GameWhyNotGrayNotHere.fill(&[
+                        ("letter", &letter),
+                        ("position", &position),
+                        ("word", &word),
+                    ]),
+                    Rule::GrayTooMany => Message::GameWhyNotGrayTooMany.fill(&[
+                        ("letter", &letter),
+                        ("position", &position),
+                        ("count", &marked),
+                        ("word", &word),
+                        ("has", &has),
+                    ]),
+                };
+                let feedback: String = elimination.feedback.iter().map(|f| f.as_char()).collect();
+                Message::GameWhyNotEliminated.fill(&[
+                    ("word", &word),
+                    ("round", &elimination.round),
+                    ("guess", &elimination.guess),
+                    ("feedback", &feedback),
+                    ("reason", &reason),
+                ])
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::words;
+
+    fn rule_for(word: &str, guess: &str, answer: &str) -> Rule {
+        let wordbank = words(&[word, guess, answer]);
+        let history = vec![(guess.to_string(), get_feedback(guess, answer))];
+        match why_not(word, &wordbank, &history, &[answer.to_string()]) {
+            WhyNot::Eliminated(elimination) => elimination.rule,
+            other => panic!("{word} should be ruled out, not {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_why_not_names_the_rule() {
+        // SLATE's S is green for SHARE, and CRANE starts with C
+        assert_eq!(rule_for("CRANE", "SLATE", "SHARE"), Rule::GreenHere);
+        // CABIN's A is yellow for TRACE, and BASIC has its A right there
+        assert_eq!(rule_for("BASIC", "CABIN", "TRACE"), Rule::YellowNotHere);
+        // EVERY's first E is yellow for TRACE, and PLANT has no E
+        assert_eq!(rule_for("PLANT", "EVERY", "TRACE"), Rule::YellowInWord);
+        // TOXIC's T is gray for CRANE, and TRICE starts with T
+        assert_eq!(rule_for("TRICE", "TOXIC", "CRANE"), Rule::GrayNotHere);
+        // EERIE has one E green for TRACE and the others gray, but THERE has two
+        assert_eq!(rule_for("THERE", "EERIE", "TRACE"), Rule::GrayTooMany);
+    }
+
+    #[test]
+    fn test_why_not_other_outcomes() {
+        let wordbank = words(&["BATCH", "CATCH", "HATCH", "CLIMB"]);
+        let candidates = words(&["BATCH", "CATCH", "HATCH"]);
+        let history = vec![("CLIMB".to_string(), get_feedback("CLIMB", "HATCH"))];
+        assert_eq!(
+            why_not("ZEBRA", &wordbank, &history, &candidates),
+            WhyNot::NotInWordbank
+        );
+        // Each of the three leaves the other two together, so they tie and rank alphabetically
+        assert_eq!(
+            why_not("CATCH", &wordbank, &history, &candidates),
+            WhyNot::Candidate { rank: 2, count: 3 }
+        );
+        // HATCH fits CLIMB's feedback, so only a restrict list can have left it out
+        assert_eq!(
+            why_not("HATCH", &wordbank, &history, &candidates[..2]),
+            WhyNot::Restricted
+        );
+
+        let explanation = why_not("CLIMB", &wordbank, &history, &candidates);
+        assert!(matches!(
+            &explanation,
+            WhyNot::Eliminated(Elimination {
+                round: 1,
+                position: 0,
+                rule: Rule::YellowNotHere,
+                ..
+            })
+        ));
+        let described = explanation.describe("CLIMB");
+        assert!(described.contains("CLIMB = YXXXX"), "{described}");
+    }
+}
@@ -0,0 +1,87 @@
+//! Process exit codes, so shell scripts wrapping the solver can branch on how it ended.
+//!
+//! Commands other than the game itself exit with [`SUCCESS`], or [`FAILURE`] when something
+//! goes wrong that none of the other codes describe. Ctrl+C exits with 130, as shells
+//! expect.
+
+use crate::game::Outcome;
+use crate::game_state::GameResult;
+
+/// The game was solved, or a command succeeded
+pub const SUCCESS: i32 = 0;
+/// Something else went wrong, e.g. the terminal couldn't be set up or a file couldn't be
+/// written
+pub const FAILURE: i32 = 1;
+/// The input couldn't be used: bad arguments (clap exits with 2 for these too), an invalid
+/// `--state` or share grid, or too many invalid inputs in a row
+pub const INPUT_ERROR: i32 = 2;
+/// The session ended with more than one candidate left
+pub const UNSOLVED: i32 = 3;
+/// The feedback ruled out every word in the wordbank
+pub const NO_CANDIDATES: i32 = 4;
+/// The wordbank couldn't be loaded or has no words to play with
+pub const WORDBANK_ERROR: i32 = 5;
+
+/// Exit code for a session that ended with `result`, going by its last game.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::exit::{UNSOLVED, game_exit_code};
+/// use wordle_solver::game::Outcome;
+/// use wordle_solver::game_state::GameResult;
+///
+/// let result = GameResult {
+///     outcome: Outcome::InProgress,
+///     rounds: 1,
+///     candidates: vec!["CRANE".to_string(), "TRACE".to_string()],
+///     history: Vec::new(),
+///     aborted: false,
+/// };
+/// assert_eq!(game_exit_code(&result), UNSOLVED);
+/// ```
+#[must_use]
+pub fn game_exit_code(result: &GameResult) -> i32 {
+    if result.aborted {
+        return INPUT_ERROR;
+    }
+    match result.outcome {
+        Outcome::Solved(_) => SUCCESS,
+        Outcome::InProgress => UNSOLVED,
+        Outcome::NoSolution => NO_CANDIDATES,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(outcome: Outcome, aborted: bool) -> GameResult {
+        GameResult {
+            outcome,
+            rounds: 0,
+            candidates: Vec::new(),
+            history: Vec::new(),
+            aborted,
+        }
+    }
+
+    #[test]
+    fn test_game_exit_codes_are_distinct() {
+        let solved = result(Outcome::Solved("CRANE".to_string()), false);
+        assert_eq!(game_exit_code(&solved), SUCCESS);
+        assert_eq!(
+            game_exit_code(&result(Outcome::NoSolution, false)),
+            NO_CANDIDATES
+        );
+        // Giving up on invalid input says so, whatever state the game was in
+        assert_eq!(
+            game_exit_code(&result(Outcome::InProgress, true)),
+            INPUT_ERROR
+        );
+        assert_eq!(
+            game_exit_code(&result(Outcome::InProgress, false)),
+            UNSOLVED
+        );
+    }
+}
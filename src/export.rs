@@ -0,0 +1,72 @@
+//! Writing the remaining candidates to a file, to look through or grep outside the solver
+//! when there are too many to show.
+
+use crate::solver::expected_pool_size;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// File the TUI exports candidates to, in the current directory
+pub const DEFAULT_EXPORT_PATH: &str = "wordle-candidates.tsv";
+
+/// The candidates as tab-separated lines under a header, each with its expected pool size
+/// if guessed, best first and then alphabetically.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::export::candidate_table;
+///
+/// let candidates: Vec<String> = ["BATCH", "CATCH", "HATCH", "CLIMB"]
+///     .iter()
+///     .map(|s| s.to_string())
+///     .collect();
+/// let table = candidate_table(&candidates);
+/// assert!(table.starts_with("word\texpected_pool_size\nCLIMB\t1.00\nBATCH\t1.50\n"));
+/// ```
+#[must_use]
+pub fn candidate_table(candidates: &[String]) -> String {
+    let mut scored: Vec<(&String, f64)> = candidates
+        .iter()
+        .map(|word| (word, expected_pool_size(word, candidates)))
+        .collect();
+    scored.sort_by(|a, b| a.1.total_cmp(&b.1).then_with(|| a.0.cmp(b.0)));
+
+    let mut table = String::from("word\texpected_pool_size\n");
+    for (word, score) in scored {
+        table.push_str(&format!("{word}\t{score:.2}\n"));
+    }
+    table
+}
+
+/// Writes [`candidate_table`] to `path`, replacing anything already there.
+///
+/// # Errors
+/// Returns an error if the file can't be written.
+pub fn write_candidates(path: &Path, candidates: &[String]) -> io::Result<()> {
+    fs::write(path, candidate_table(candidates))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_candidates() {
+        let candidates: Vec<String> = ["HATCH", "CATCH", "BATCH"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let path = std::env::temp_dir().join("test_wordle_candidates.tsv");
+        write_candidates(&path, &candidates).unwrap();
+        let written = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).ok();
+
+        // Each guess only tells apart itself, so they tie and go alphabetically
+        assert_eq!(
+            written,
+            "word\texpected_pool_size\nBATCH\t1.67\nCATCH\t1.67\nHATCH\t1.67\n"
+        );
+        assert!(write_candidates(Path::new("/nonexistent/dir/out.tsv"), &candidates).is_err());
+    }
+}
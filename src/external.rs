@@ -0,0 +1,292 @@
+//! Guess scores from an external command, for trying out scoring functions without changing
+//! the crate.
+//!
+//! For each recommendation the command is run once. It reads a JSON object with the guesses
+//! to score and the candidates left on stdin:
+//!
+//! ```json
+//! {"guesses": ["CRANE", "SLATE"], "candidates": ["GRACE", "TRACE", "BRACE"]}
+//! ```
+//!
+//! and prints one score per guess, in the same order, where lower is better as with expected
+//! pool size:
+//!
+//! ```json
+//! {"scores": [1.67, 3.0]}
+//! ```
+//!
+//! The command is split on whitespace into a program and its arguments, with no shell
+//! quoting, so `python3 scorer.py` works but a quoted path with spaces doesn't.
+//!
+//! The request is written and the output read on their own threads, so a command that starts
+//! printing before it has read all its input can't leave both sides waiting on a full pipe. A
+//! command still running when the time budget runs out or the search is cancelled is killed.
+
+use crate::progress::CancelToken;
+use std::io::{self, Read, Write};
+use std::process::{Command, Stdio};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// How often a running command is checked for having finished, run out of time or been
+/// cancelled
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// A command that scores guesses
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExternalScorer {
+    program: String,
+    args: Vec<String>,
+}
+
+impl ExternalScorer {
+    /// The scorer run by `command`, or `None` if it names no program
+    #[must_use]
+    pub fn new(command: &str) -> Option<Self> {
+        let mut parts = command.split_whitespace().map(str::to_string);
+        Some(Self {
+            program: parts.next()?,
+            args: parts.collect(),
+        })
+    }
+
+    /// Runs the command on `guesses` and `candidates` and reads back a score per guess. The
+    /// command is killed if it's still running after `max_time` or once `cancel` is set.
+    ///
+    /// # Errors
+    /// Returns an error if the command can't be run, fails, is stopped, or doesn't print one
+    /// finite score per guess.
+    pub fn scores(
+        &self,
+        guesses: &[String],
+        candidates: &[String],
+        max_time: Option<Duration>,
+        cancel: &CancelToken,
+    ) -> Result<Vec<f64>, String> {
+        let started = Instant::now();
+        let mut child = Command::new(&self.program)
+            .args(&self.args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("couldn't run '{}': {e}", self.program))?;
+        let request = request_json(guesses, candidates);
+        // Moved to the thread so stdin closes once written, and the command sees the end of
+        // its input
+        let stdin = child.stdin.take();
+        let writer = thread::spawn(move || match stdin {
+            Some(mut stdin) => stdin.write_all(request.as_bytes()),
+            None => Ok(()),
+        });
+        let stdout = read_pipe(child.stdout.take());
+        let stderr = read_pipe(child.stderr.take());
+
+        let status = loop {
+            if let Some(status) = child
+                .try_wait()
+                .map_err(|e| format!("couldn't wait for '{}': {e}", self.program))?
+            {
+                break status;
+            }
+            let stopped = if cancel.is_cancelled() {
+                Some("cancelled")
+            } else if max_time.is_some_and(|max| started.elapsed() >= max) {
+                Some("out of time")
+            } else {
+                None
+            };
+            if let Some(reason) = stopped {
+                // Killing it closes the pipes, which lets the threads finish on their own
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(format!("'{}' was stopped: {reason}", self.program));
+            }
+            thread::sleep(POLL_INTERVAL);
+        };
+
+        let stdout = stdout.join().unwrap_or_default();
+        let stderr = stderr.join().unwrap_or_default();
+        if !status.success() {
+            return Err(format!(
+                "'{}' failed ({status}): {}",
+                self.program,
+                String::from_utf8_lossy(&stderr).trim()
+            ));
+        }
+        // A command that has its answer without reading everything may close stdin early
+        match writer.join() {
+            Ok(Err(e)) if e.kind() != io::ErrorKind::BrokenPipe => {
+                return Err(format!("couldn't write to '{}': {e}", self.program));
+            }
+            _ => {}
+        }
+        parse_scores(&String::from_utf8_lossy(&stdout), guesses.len())
+    }
+}
+
+/// Reads all of `pipe` on its own thread
+fn read_pipe<R: Read + Send + 'static>(pipe: Option<R>) -> JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut output = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut output);
+        }
+        output
+    })
+}
+
+/// The JSON object sent to the command.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::external::request_json;
+///
+/// let guesses = vec!["CRANE".to_string()];
+/// let candidates = vec!["GRACE".to_string(), "TRACE".to_string()];
+/// assert_eq!(
+///     request_json(&guesses, &candidates),
+///     r#"{"guesses": ["CRANE"], "candidates": ["GRACE", "TRACE"]}"#
+/// );
+/// ```
+#[must_use]
+pub fn request_json(guesses: &[String], candidates: &[String]) -> String {
+    let list = |words: &[String]| {
+        words
+            .iter()
+            .map(|word| format!("\"{}\"", word.replace('\\', "\\\\").replace('"', "\\\"")))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    format!(
+        "{{\"guesses\": [{}], \"candidates\": [{}]}}",
+        list(guesses),
+        list(candidates)
+    )
+}
+
+/// Reads the `scores` array of the command's output, which must hold `count` finite numbers
+///
+/// # Errors
+/// Returns an error if there's no `scores` array, or it has anything else in it.
+pub fn parse_scores(json: &str, count: usize) -> Result<Vec<f64>, String> {
+    let rest = json
+        .find("\"scores\"")
+        .map(|at| &json[at + "\"scores\"".len()..])
+        .ok_or_else(|| "no \"scores\" in the output".to_string())?;
+    let list = rest
+        .trim_start()
+        .strip_prefix(':')
+        .map(str::trim_start)
+        .and_then(|rest| rest.strip_prefix('['))
+        .and_then(|rest| rest.split_once(']'))
+        .map(|(list, _)| list.trim())
+        .ok_or_else(|| "\"scores\" isn't an array".to_string())?;
+    let scores = if list.is_empty() {
+        Vec::new()
+    } else {
+        list.split(',')
+            .map(|score| {
+                score
+                    .trim()
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|score| score.is_finite())
+                    .ok_or_else(|| format!("'{}' isn't a score", score.trim()))
+            })
+            .collect::<Result<Vec<_>, _>>()?
+    };
+    if scores.len() == count {
+        Ok(scores)
+    } else {
+        Err(format!(
+            "expected {count} scores, one per guess, got {}",
+            scores.len()
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_scores() {
+        assert_eq!(
+            parse_scores("{\"scores\": [1.5, 2, 0.25]}\n", 3),
+            Ok(vec![1.5, 2.0, 0.25])
+        );
+        assert_eq!(
+            parse_scores("{\"model\": \"x\", \"scores\":[]}", 0),
+            Ok(vec![])
+        );
+        assert!(parse_scores("{\"scores\": [1.5]}", 2).is_err());
+        assert!(parse_scores("{\"scores\": [1.5, NaN]}", 2).is_err());
+        assert!(parse_scores("{\"scores\": 1.5}", 1).is_err());
+        assert!(parse_scores("[1.5]", 1).is_err());
+    }
+
+    #[test]
+    fn test_scorer_command() {
+        assert_eq!(ExternalScorer::new("  "), None);
+        let scorer = ExternalScorer::new("python3 -u scorer.py").unwrap();
+        assert_eq!(scorer.program, "python3");
+        assert_eq!(scorer.args, ["-u", "scorer.py"]);
+
+        let missing = ExternalScorer::new("wordle-solver-no-such-scorer").unwrap();
+        let words = vec!["CRANE".to_string()];
+        assert!(
+            missing
+                .scores(&words, &words, None, &CancelToken::new())
+                .is_err()
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_scorer_printing_before_reading_its_input() {
+        use std::os::unix::fs::PermissionsExt;
+
+        // Fills the stdout pipe before reading a request too big for the stdin pipe
+        let path =
+            std::env::temp_dir().join(format!("wordle-chatty-scorer-{}.sh", std::process::id()));
+        std::fs::write(
+            &path,
+            "#!/bin/sh\nhead -c 200000 /dev/zero\ncat > /dev/null\necho '{\"scores\": [1]}'\n",
+        )
+        .unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let scorer = ExternalScorer::new(path.to_str().unwrap()).unwrap();
+        let guesses = vec!["CRANE".to_string()];
+        let candidates = vec!["SLATE".to_string(); 20_000];
+        let scores = scorer.scores(&guesses, &candidates, None, &CancelToken::new());
+        let _ = std::fs::remove_file(&path);
+        // Only the scores object is read, so the padding before it is ignored
+        assert_eq!(scores, Ok(vec![1.0]));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_scorer_killed_when_out_of_time_or_cancelled() {
+        let scorer = ExternalScorer::new("sleep 10").unwrap();
+        let words = vec!["CRANE".to_string()];
+        let started = Instant::now();
+        let timed_out = scorer.scores(
+            &words,
+            &words,
+            Some(Duration::from_millis(50)),
+            &CancelToken::new(),
+        );
+        assert_eq!(
+            timed_out,
+            Err("'sleep' was stopped: out of time".to_string())
+        );
+
+        let cancel = CancelToken::new();
+        cancel.cancel();
+        let cancelled = scorer.scores(&words, &words, None, &cancel);
+        assert_eq!(cancelled, Err("'sleep' was stopped: cancelled".to_string()));
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+}
@@ -0,0 +1,448 @@
+//! A headless game for library embedders.
+//!
+//! [`Game`] tracks the candidates, history and recommendation of one game without going
+//! through a [`GameInterface`](crate::game_state::GameInterface), so a bot or a GUI can drive
+//! the solver with plain method calls.
+
+use crate::game_state::{
+    CandidateDiff, Monitor, Recommendation, SearchCache, opening_recommendation, partition_round,
+    recommend, remaining_guesses, words_footprint,
+};
+use crate::progress::{CancelToken, NoProgress};
+use crate::rng::Rng;
+use crate::solver::{Feedback, PoolScorer, SolverConfig};
+use crate::wordbank::{get_wordle_start_path, read_cached_starting_words, wordbank_fingerprint};
+use crate::words::{CandidateSet, Word, WordArena, WordBank};
+use std::path::PathBuf;
+
+/// Where a game interrupted with Ctrl+C is saved, for `--resume`
+#[must_use]
+pub fn get_wordle_save_path() -> Option<PathBuf> {
+    dirs::home_dir().map(|mut path| {
+        path.push(".wordle_saved_game");
+        path
+    })
+}
+
+/// Where a game stands after a round
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    /// More than one candidate is left
+    InProgress,
+    /// Only this word is left
+    Solved(String),
+    /// The feedback ruled out every word
+    NoSolution,
+}
+
+impl Outcome {
+    /// Where a game with these candidates left stands
+    #[must_use]
+    pub fn of(candidates: &[String]) -> Self {
+        match candidates {
+            [] => Self::NoSolution,
+            [solution] => Self::Solved(solution.clone()),
+            _ => Self::InProgress,
+        }
+    }
+}
+
+/// What one call to [`Game::guess`] changed
+#[derive(Clone, Debug)]
+pub struct RoundResult {
+    pub diff: CandidateDiff,
+    pub outcome: Outcome,
+}
+
+/// One game of Wordle, driven by method calls.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::game::{Game, Outcome};
+/// use wordle_solver::solver::SolverConfig;
+/// use wordle_solver::words::{Word, WordBank};
+/// use wordle_solver::get_feedback;
+///
+/// let wordbank = WordBank::from_valid(["CRANE", "SLATE", "TRACE"]);
+/// let config = SolverConfig { use_cache: false, ..SolverConfig::default() };
+/// let mut game = Game::new(&wordbank, config);
+///
+/// let crane = Word::new("crane").unwrap();
+/// let result = game.guess(&crane, &get_feedback("CRANE", "TRACE"));
+/// assert_eq!(result.outcome, Outcome::Solved("TRACE".to_string()));
+/// assert!(game.is_over());
+/// assert_eq!(game.history().len(), 1);
+/// ```
+pub struct Game {
+    wordbank: WordBank,
+    config: SolverConfig,
+    candidates: Vec<String>,
+    history: Vec<(String, Vec<Feedback>)>,
+    arena: Option<WordArena>,
+    /// The candidates as interned ids, kept in step with `candidates`
+    alive: Option<CandidateSet>,
+    /// Scorer and warm start carried over between recommendations
+    search: SearchCache,
+    rng: Option<Rng>,
+    /// Recommendation for the current candidates, once computed
+    current: Option<Recommendation>,
+    /// Bytes the game may take before it stops building the incremental scorer
+    memory_limit: Option<usize>,
+    /// Compute the first recommendation even when the cache has a starting word
+    recompute: bool,
+}
+
+impl Game {
+    /// A new game over `wordbank`, recommending guesses as `config` says
+    #[must_use]
+    pub fn new(wordbank: &WordBank, config: SolverConfig) -> Self {
+        let arena = WordArena::from_wordbank(wordbank);
+        Self {
+            wordbank: wordbank.clone(),
+            candidates: wordbank.to_vec(),
+            history: Vec::new(),
+            alive: arena.as_ref().map(|arena| CandidateSet::full(arena.len())),
+            arena,
+            search: SearchCache {
+                remaining_guesses: remaining_guesses(0),
+                ..SearchCache::default()
+            },
+            rng: config.seed.map(Rng::new),
+            current: None,
+            memory_limit: None,
+            recompute: false,
+            config,
+        }
+    }
+
+    /// Computes the first recommendation instead of taking the top cached starting word
+    #[must_use]
+    pub const fn recomputing(mut self) -> Self {
+        self.recompute = true;
+        self
+    }
+
+    /// Caps the game at roughly `bytes`. Exact recommendations normally build a scorer whose
+    /// size grows with the wordbank times the candidate pool; over the cap, each
+    /// recommendation is computed from scratch instead, which is slower but takes no memory.
+    #[must_use]
+    pub const fn with_memory_limit(mut self, bytes: usize) -> Self {
+        self.memory_limit = Some(bytes);
+        self
+    }
+
+    /// Approximate bytes the game takes, including its wordbank and scorer
+    #[must_use]
+    pub fn memory_footprint(&self) -> usize {
+        let history: usize = self
+            .history
+            .iter()
+            .map(|(guess, feedback)| {
+                words_footprint(std::slice::from_ref(guess)) + feedback.capacity()
+            })
+            .sum();
+        words_footprint(&self.wordbank)
+            + words_footprint(&self.candidates)
+            + history
+            + self.arena.as_ref().map_or(0, WordArena::memory_footprint)
+            + self.search.memory_footprint()
+    }
+
+    /// Plays `word` and narrows the candidates by the `feedback` it got
+    pub fn guess(&mut self, word: &Word, feedback: &[Feedback]) -> RoundResult {
+        let guess = word.to_string();
+        let (survived, eliminated) = partition_round(
+            &self.candidates,
+            self.arena.as_ref().zip(self.alive.as_mut()),
+            self.search.scorer.as_mut(),
+            &guess,
+            feedback,
+        );
+        self.candidates.clone_from(&survived);
+        self.history.push((guess.clone(), feedback.to_vec()));
+        self.search.remaining_guesses = remaining_guesses(self.history.len());
+        self.current = None;
+        RoundResult {
+            diff: CandidateDiff {
+                guess,
+                feedback: feedback.to_vec(),
+                eliminated,
+                survived,
+            },
+            outcome: self.outcome(),
+        }
+    }
+
+    /// The best next guess, or `None` once no candidates are left.
+    ///
+    /// Before the first guess this is the top cached starting word when the cache is enabled
+    /// and has one and no letters are avoided; otherwise it is computed, which can take a
+    /// while on a full wordbank.
+    pub fn recommendation(&mut self) -> Option<Recommendation> {
+        if self.candidates.is_empty() {
+            return None;
+        }
+        if self.current.is_none() {
+            let opener = self
+                .config
+                .use_cache
+                .then(get_wordle_start_path)
+                .flatten()
+                .filter(|_| {
+                    self.history.is_empty() && self.config.avoid.is_empty() && !self.recompute
+                })
+                .and_then(|path| {
+                    read_cached_starting_words(&path, &self.wordbank, self.config.strategy, 1)
+                })
+                .and_then(|words| words.into_iter().next())
+                .map(|(word, _)| word);
+            // Only build a scorer that fits in what's left of the memory limit
+            let scorer_fits = self.search.scorer.is_some()
+                || self.arena.as_ref().is_some_and(|arena| {
+                    let size = PoolScorer::estimated_size(arena.len(), self.candidates.len());
+                    self.memory_limit
+                        .is_none_or(|limit| self.memory_footprint() + size <= limit)
+                });
+            self.current = match opener {
+                Some(word) => Some(opening_recommendation(&word, &self.candidates)),
+                None => recommend(
+                    &self.wordbank,
+                    &self.candidates,
+                    &self.config,
+                    self.arena.as_ref().filter(|_| scorer_fits),
+                    &mut self.search,
+                    self.rng.as_mut(),
+                    Monitor {
+                        progress: &mut NoProgress,
+                        cancel: &CancelToken::new(),
+                    },
+                ),
+            };
+        }
+        self.current.clone()
+    }
+
+    /// Words that could still be the answer
+    #[must_use]
+    pub fn candidates(&self) -> &[String] {
+        &self.candidates
+    }
+
+    /// Every guess so far with its feedback, oldest first
+    #[must_use]
+    pub fn history(&self) -> &[(String, Vec<Feedback>)] {
+        &self.history
+    }
+
+    /// Where the game stands
+    #[must_use]
+    pub fn outcome(&self) -> Outcome {
+        Outcome::of(&self.candidates)
+    }
+
+    /// Whether the game is solved or has no solution left
+    #[must_use]
+    pub fn is_over(&self) -> bool {
+        self.outcome() != Outcome::InProgress
+    }
+
+    /// The game as text for [`Game::resume`]: a fingerprint of the wordbank, one line per
+    /// round, and the number and a fingerprint of the candidates left.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wordle_solver::game::Game;
+    /// use wordle_solver::solver::SolverConfig;
+    /// use wordle_solver::words::{Word, WordBank};
+    /// use wordle_solver::get_feedback;
+    ///
+    /// let wordbank = WordBank::from_valid(["CRANE", "SLATE", "PLATE"]);
+    /// let config = SolverConfig { use_cache: false, ..SolverConfig::default() };
+    /// let mut game = Game::new(&wordbank, config.clone());
+    /// game.guess(&Word::new("CRANE").unwrap(), &get_feedback("CRANE", "PLATE"));
+    ///
+    /// let resumed = Game::resume(&wordbank, config, &game.save()).unwrap();
+    /// assert_eq!(resumed.candidates(), game.candidates());
+    /// ```
+    #[must_use]
+    pub fn save(&self) -> String {
+        let mut saved = format!("wordbank {:016x}\n", wordbank_fingerprint(&self.wordbank));
+        for (guess, feedback) in &self.history {
+            let feedback: String = feedback.iter().map(|f| f.as_char()).collect();
+            saved.push_str(&format!("{guess} {feedback}\n"));
+        }
+        saved.push_str(&format!(
+            "candidates {} {:016x}\n",
+            self.candidates.len(),
+            wordbank_fingerprint(&self.candidates)
+        ));
+        saved
+    }
+
+    /// Resumes a game saved by [`Game::save`] over the same `wordbank`. The saved rounds are
+    /// played again and the candidates they leave are checked against the saved ones, so a
+    /// save that was edited, cut short or made for another wordbank is refused instead of
+    /// resumed with the wrong candidates.
+    ///
+    /// # Errors
+    /// Returns a message saying what doesn't match if the save can't be read, was made for a
+    /// different wordbank, or its rounds don't lead to the saved candidates.
+    pub fn resume(wordbank: &WordBank, config: SolverConfig, saved: &str) -> Result<Self, String> {
+        let mut lines = saved.lines();
+        let header = format!("wordbank {:016x}", wordbank_fingerprint(wordbank));
+        if lines.next() != Some(header.as_str()) {
+            return Err("The saved game was made for a different wordbank".to_string());
+        }
+        let mut game = Self::new(wordbank, config);
+        for line in lines.by_ref() {
+            if let Some(candidates) = line.strip_prefix("candidates ") {
+                let expected = format!(
+                    "{} {:016x}",
+                    game.candidates.len(),
+                    wordbank_fingerprint(&game.candidates)
+                );
+                if candidates != expected {
+                    return Err(format!(
+                        "The saved rounds leave {} candidates that don't match the saved ones",
+                        game.candidates.len()
+                    ));
+                }
+                return match lines.next() {
+                    None => Ok(game),
+                    Some(line) => Err(format!("Unexpected line after the candidates: {line}")),
+                };
+            }
+            let round = line.split_once(' ').and_then(|(guess, feedback)| {
+                let guess = Word::new(guess)?;
+                let feedback: Vec<Feedback> = feedback
+                    .chars()
+                    .map(Feedback::from_char)
+                    .collect::<Option<_>>()?;
+                (feedback.len() == guess.len()).then_some((guess, feedback))
+            });
+            let Some((guess, feedback)) = round else {
+                return Err(format!("Invalid round in the saved game: {line}"));
+            };
+            game.guess(&guess, &feedback);
+        }
+        Err("The saved game is missing its candidates".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::progress::Budget;
+    use crate::solver::get_feedback;
+    use crate::test_util::{bank, word};
+
+    fn game(words: &[&str]) -> Game {
+        let config = SolverConfig {
+            use_cache: false,
+            ..SolverConfig::default()
+        };
+        Game::new(&bank(words), config)
+    }
+
+    #[test]
+    fn test_guess_narrows_candidates_and_records_history() {
+        let mut game = game(&["CRANE", "SLATE", "PLATE", "ELATE"]);
+        let result = game.guess(&word("crane"), &get_feedback("CRANE", "PLATE"));
+
+        assert_eq!(result.outcome, Outcome::InProgress);
+        assert_eq!(result.diff.eliminated, ["CRANE"]);
+        assert_eq!(game.candidates(), ["SLATE", "PLATE", "ELATE"]);
+        assert_eq!(game.history()[0].0, "CRANE");
+        assert!(!game.is_over());
+    }
+
+    #[test]
+    fn test_recommendation_follows_candidates() {
+        let mut game = game(&["CRANE", "SLATE", "PLATE", "CRATE"]);
+        assert!(game.recommendation().is_some());
+
+        game.guess(&word("CRANE"), &get_feedback("CRANE", "SLATE"));
+        let recommendation = game.recommendation().unwrap();
+        assert!(
+            game.candidates()
+                .contains(&recommendation.guess.to_string())
+                || !recommendation.is_candidate
+        );
+
+        game.guess(&word("ZZZZZ"), &[Feedback::Match; 5]);
+        assert_eq!(game.outcome(), Outcome::NoSolution);
+        assert!(game.recommendation().is_none());
+    }
+
+    #[test]
+    fn test_budget_truncates_recommendation() {
+        let wordbank = WordBank::from_valid(
+            ('A'..='J').flat_map(|a| ('A'..='J').map(move |b| format!("{a}{b}XYZ"))),
+        );
+        let config = SolverConfig {
+            use_cache: false,
+            budget: Budget {
+                max_evaluations: Some(1),
+                ..Budget::UNLIMITED
+            },
+            ..SolverConfig::default()
+        };
+        let recommendation = Game::new(&wordbank, config).recommendation().unwrap();
+        assert!(recommendation.truncated);
+        assert!(wordbank.contains(&recommendation.guess.to_string()));
+
+        let full = game(&["CRANE", "SLATE", "PLATE", "CRATE"])
+            .recommendation()
+            .unwrap();
+        assert!(!full.truncated);
+    }
+
+    #[test]
+    fn test_resume_checks_the_saved_game() {
+        let mut saved_game = game(&["CRANE", "SLATE", "PLATE", "ELATE"]);
+        saved_game.guess(&word("CRANE"), &get_feedback("CRANE", "PLATE"));
+        let saved = saved_game.save();
+        assert!(saved.starts_with("wordbank "));
+        assert!(saved.contains("\nCRANE XXGXG\ncandidates 3 "));
+
+        let wordbank = saved_game.wordbank.clone();
+        let config = || SolverConfig {
+            use_cache: false,
+            ..SolverConfig::default()
+        };
+        let Ok(resumed) = Game::resume(&wordbank, config(), &saved) else {
+            panic!("the save should resume");
+        };
+        assert_eq!(resumed.history(), saved_game.history());
+        assert_eq!(resumed.candidates(), saved_game.candidates());
+
+        // Another wordbank, an edited round, a torn save and trailing junk are all refused
+        let other = WordBank::new(wordbank[..3].to_vec()).unwrap();
+        assert!(
+            Game::resume(&other, config(), &saved)
+                .err()
+                .unwrap()
+                .contains("wordbank")
+        );
+        let edited = saved.replace("CRANE XXGXG", "CRANE XXGXX");
+        assert!(
+            Game::resume(&wordbank, config(), &edited)
+                .err()
+                .unwrap()
+                .contains("match")
+        );
+        let torn = &saved[..saved.find("candidates").unwrap()];
+        assert!(
+            Game::resume(&wordbank, config(), torn)
+                .err()
+                .unwrap()
+                .contains("missing")
+        );
+        let bad_round = saved.replace("CRANE XXGXG", "CRANE XXG");
+        assert!(Game::resume(&wordbank, config(), &bad_round).is_err());
+        let junk = format!("{saved}extra");
+        assert!(Game::resume(&wordbank, config(), &junk).is_err());
+    }
+}
@@ -1,8 +1,39 @@
+use crate::cluster::{Splitter, best_splitter, find_trap, groups, is_trap};
+use crate::elimination::{WhyNot, why_not};
+use crate::game::Outcome;
+use crate::information::{GuessInformation, information_per_guess};
+use crate::odds::{WIN_ODDS_LIMIT, WinOdds};
+use crate::play::MAX_GUESSES;
+use crate::precomputed;
+use crate::progress::{Budget, BudgetedProgress, CancelToken, ProgressSink};
+use crate::rng::Rng;
 use crate::solver::{
-    Feedback, best_information_guess, compute_best_starting_words, filter_candidates,
+    Feedback, LOW_MEMORY_LIMIT, PoolScorer, SolverConfig, Strategy, WarmStart,
+    best_avoiding_guess_with_progress, best_finishing_guess, best_guess_for_sample,
+    best_information_guess, best_information_guess_warm, best_lookahead_guess_with_progress,
+    best_sampled_guess, estimate_pool_size, expected_pool_size, find_dominating_guess,
+    partition_candidates, partition_ids, probe_guesses, probe_letter, resume_scored_starting_words,
+    sample_candidates,
 };
-use crate::wordbank::{get_wordle_start_path, read_starting_words, write_starting_words};
-use std::path::PathBuf;
+use crate::stats::{Achievement, GameRecord, Stats};
+use crate::wordbank::{
+    get_wordle_start_path, read_cached_starting_words, read_starting_checkpoint,
+    starting_checkpoint_path, write_cached_starting_words, write_starting_checkpoint,
+};
+use crate::words::{CandidateSet, Word, WordArena, WordBank};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use tracing::{debug, info, info_span, instrument, warn};
+
+/// Largest candidate pool for which guesses are checked for dominance before being accepted
+const DOMINANCE_CHECK_LIMIT: usize = 500;
+
+/// Rungs on the hint ladder; the last one gives the full recommendation
+pub const HINT_LEVELS: usize = 3;
+
+/// Guesses listed for a probe request
+pub const PROBE_GUESSES: usize = 5;
 
 enum GameState {
     Continue,
@@ -16,50 +47,412 @@ pub enum UserAction {
     Guess(String),
     Exit,
     NewGame,
+    /// Ask for the next, more revealing hint
+    Hint,
+    /// Ask for the best guesses that test as many of these letters as possible
+    Probe(Vec<char>),
+    /// Keep these letters out of recommendations where possible; none clears the preference
+    Avoid(Vec<char>),
+    /// Narrow the candidates to those on this list of words
+    Restrict(Vec<String>),
+    /// Several rounds of the game so far, entered in one go
+    Rounds(Vec<(String, Vec<Feedback>)>),
+    /// Replace the feedback entered for a round (counting from 1) and replay the game with it
+    Correct {
+        round: usize,
+        feedback: Vec<Feedback>,
+    },
+    /// Ask why a word is no longer a candidate, or where it ranks if it still is
+    WhyNot(String),
+    /// Ask for the guess that best tells apart the candidates matching this pattern
+    Split(String),
+    /// Tag a round's guess (counting from 1) as forced, such as a fixed opener, so the
+    /// post-game score leaves it out, or as free again
+    Tag {
+        round: usize,
+        forced: bool,
+    },
+}
+
+/// Input read from the player
+#[derive(Debug, PartialEq, Eq)]
+pub enum InputResult<T> {
+    Ready(T),
+    /// The input was invalid and should be asked for again
+    Invalid,
+    /// Nothing was entered within the idle limit
+    Timeout,
+    /// The input has ended or can't be read, so the session is over
+    Closed,
+}
+
+impl<T> InputResult<T> {
+    /// The input, if any was read
+    #[must_use]
+    pub fn ready(self) -> Option<T> {
+        match self {
+            Self::Ready(input) => Some(input),
+            Self::Invalid | Self::Timeout | Self::Closed => None,
+        }
+    }
+}
+
+impl<T> From<Option<T>> for InputResult<T> {
+    fn from(input: Option<T>) -> Self {
+        input.map_or(Self::Invalid, Self::Ready)
+    }
+}
+
+/// What came of correcting an earlier round's feedback
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Correction<'a> {
+    /// The round now has this feedback, and the candidates were rebuilt from every round
+    Applied {
+        round: usize,
+        feedback: &'a [Feedback],
+    },
+    /// Only `rounds` rounds have been played this game
+    NoSuchRound { round: usize, rounds: usize },
+    /// The game is already solved, so there's nothing to correct
+    Solved,
+}
+
+/// What came of tagging a round's guess as forced or free
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tagging<'a> {
+    /// The round's guess now has this tag
+    Applied {
+        round: usize,
+        guess: &'a str,
+        forced: bool,
+    },
+    /// Only `rounds` rounds have been played this game
+    NoSuchRound { round: usize, rounds: usize },
+}
+
+/// One rung of the hint ladder, from a nudge to the whole answer
+#[derive(Clone, Debug)]
+pub enum Hint {
+    /// A letter whose presence would split the remaining candidates well
+    ProbeLetter(char),
+    /// The first letter of the recommended guess
+    FirstLetter(char),
+    /// The recommended guess itself
+    Full(Recommendation),
+}
+
+impl Hint {
+    /// Position on the ladder, from 1 to [`HINT_LEVELS`]
+    #[must_use]
+    pub const fn level(&self) -> usize {
+        match self {
+            Self::ProbeLetter(_) => 1,
+            Self::FirstLetter(_) => 2,
+            Self::Full(_) => 3,
+        }
+    }
 }
 
 /// Information about starting words to display
 pub struct StartingWordsInfo {
-    pub words: Vec<String>,
+    /// Best first, each with its expected pool size
+    pub words: Vec<(String, f64)>,
     pub used_cache: bool,
     pub cache_path: Option<PathBuf>,
 }
 
 /// Recommendation for the next guess
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct Recommendation {
-    pub guess: String,
+    pub guess: Word,
     pub score: f64,
     pub is_candidate: bool,
+    /// 95% confidence interval `(lower, upper)` for `score` when it was estimated by sampling
+    pub confidence_interval: Option<(f64, f64)>,
+    /// Whether the search stopped early, from a budget or cancellation, so `guess` is only the
+    /// best found so far
+    pub truncated: bool,
+}
+
+/// Warning raised when a guess can't be the answer and another guess is at least as informative
+pub struct GuessWarning {
+    pub guess: String,
+    pub score: f64,
+    pub better_guess: String,
+    pub better_score: f64,
+}
+
+/// Words eliminated and retained by the most recent feedback
+#[derive(Clone, Debug, Default)]
+pub struct CandidateDiff {
+    pub guess: String,
+    pub feedback: Vec<Feedback>,
+    pub eliminated: Vec<String>,
+    pub survived: Vec<String>,
+}
+
+impl CandidateDiff {
+    /// Number of pages needed to show both lists with `page_size` words per page
+    #[must_use]
+    pub fn page_count(&self, page_size: usize) -> usize {
+        let longest = self.eliminated.len().max(self.survived.len());
+        longest.div_ceil(page_size.max(1)).max(1)
+    }
+
+    /// Returns the `(eliminated, survived)` slices shown on a zero-based page
+    #[must_use]
+    pub fn page(&self, page: usize, page_size: usize) -> (&[String], &[String]) {
+        let page_size = page_size.max(1);
+        let start = page.saturating_mul(page_size);
+        let slice = |words: &'_ [String]| -> std::ops::Range<usize> {
+            let begin = start.min(words.len());
+            begin..(begin + page_size).min(words.len())
+        };
+        (
+            &self.eliminated[slice(&self.eliminated)],
+            &self.survived[slice(&self.survived)],
+        )
+    }
+}
+
+/// Where the last game of a session stood when the game loop exited.
+///
+/// An outcome of [`Outcome::InProgress`] means the player exited before the game ended.
+#[derive(Clone, Debug)]
+pub struct GameResult {
+    pub outcome: Outcome,
+    /// Guesses made in the last game
+    pub rounds: usize,
+    /// Words that could still have been the answer
+    pub candidates: Vec<String>,
+    /// Every guess of the last game with its feedback, oldest first
+    pub history: Vec<(String, Vec<Feedback>)>,
+    /// The session was given up after [`SessionOptions::max_invalid_inputs`] invalid inputs in
+    /// a row
+    pub aborted: bool,
+}
+
+/// Something the game loop wants shown to the player
+#[derive(Clone, Copy)]
+pub enum GameEvent<'a> {
+    /// The optimal starting words
+    StartingWords(&'a StartingWordsInfo),
+    /// Rounds that were entered in one go rather than guess by guess
+    Rounds(&'a [(String, Vec<Feedback>)]),
+    /// The current candidate words
+    Candidates(&'a [String]),
+    /// Which candidates the last feedback eliminated and which survived
+    CandidateDiff(&'a CandidateDiff),
+    /// A recommendation for the next guess
+    Recommendation(&'a Recommendation),
+    /// A hint the user asked for
+    Hint(&'a Hint),
+    /// Chances of solving in time by following the recommendations
+    WinOdds(&'a WinOdds),
+    /// Guesses testing the letters the user asked to probe, best first with their expected
+    /// pool sizes
+    Probe {
+        letters: &'a [char],
+        guesses: &'a [(String, f64)],
+    },
+    /// The letters recommendations now avoid, none once the preference is cleared
+    Avoid(&'a [char]),
+    /// The candidates were narrowed to those on a list of `listed` words, leaving
+    /// `remaining`. With none remaining the list wasn't applied.
+    Restrict { listed: usize, remaining: usize },
+    /// A round's feedback was corrected, or why it couldn't be
+    Correction(Correction<'a>),
+    /// A round's guess was tagged forced or free, or why it couldn't be
+    Tagging(Tagging<'a>),
+    /// The guess that best tells apart a cluster of candidates, asked for or pointed out
+    /// when the recommendation leaves more of a trap together
+    Splitter(&'a Splitter),
+    /// The candidates are a trap family with more words than guesses left, so guessing them
+    /// one at a time could lose; the splitter's guess tells them apart instead
+    Trap {
+        splitter: &'a Splitter,
+        guesses_left: usize,
+    },
+    /// Why a word the user asked about is or isn't a candidate
+    WhyNot {
+        word: &'a str,
+        explanation: &'a WhyNot,
+    },
+    /// A recommendation is being computed
+    Computing,
+    /// How long a step took (shown only when timing output is enabled)
+    Timing { label: &'a str, elapsed: Duration },
+    /// Approximate bytes the game takes, `scorer` of them in the incremental scorer (sent
+    /// only in low-memory mode)
+    MemoryUsage { total: usize, scorer: usize },
+    /// How far a long computation has got and the best guess found so far
+    Progress {
+        current: usize,
+        total: usize,
+        best: Option<(&'a str, f64)>,
+    },
+    /// No candidates remain
+    NoCandidates,
+    /// Only the solution remains
+    SolutionFound(&'a str),
+    /// Achievements the finished game unlocked
+    Achievements(&'a [Achievement]),
+    /// What each guess of the finished game told the player
+    Information(&'a [GuessInformation]),
+    /// The player is leaving
+    Exit,
+    /// A new game started over the whole wordbank
+    NewGame { word_count: usize },
+}
+
+impl GameEvent<'_> {
+    /// Calls the [`GameDisplay`] method for this event
+    pub fn dispatch<D: GameDisplay + ?Sized>(self, display: &mut D) {
+        match self {
+            Self::StartingWords(info) => display.display_starting_words(info),
+            Self::Rounds(rounds) => display.display_rounds(rounds),
+            Self::Candidates(candidates) => display.display_candidates(candidates),
+            Self::CandidateDiff(diff) => display.display_candidate_diff(diff),
+            Self::Recommendation(recommendation) => display.display_recommendation(recommendation),
+            Self::Hint(hint) => display.display_hint(hint),
+            Self::WinOdds(odds) => display.display_win_odds(odds),
+            Self::Probe { letters, guesses } => display.display_probe(letters, guesses),
+            Self::Avoid(letters) => display.display_avoid(letters),
+            Self::Restrict { listed, remaining } => display.display_restrict(listed, remaining),
+            Self::Correction(correction) => display.display_correction(correction),
+            Self::Tagging(tagging) => display.display_tagging(tagging),
+            Self::Splitter(splitter) => display.display_splitter(splitter),
+            Self::Trap {
+                splitter,
+                guesses_left,
+            } => display.display_trap(splitter, guesses_left),
+            Self::WhyNot { word, explanation } => display.display_why_not(word, explanation),
+            Self::Computing => display.display_computing_message(),
+            Self::Timing { label, elapsed } => display.display_timing(label, elapsed),
+            Self::MemoryUsage { total, scorer } => display.display_memory_usage(total, scorer),
+            Self::Progress {
+                current,
+                total,
+                best,
+            } => display.display_progress(current, total, best),
+            Self::NoCandidates => display.display_no_candidates_message(),
+            Self::SolutionFound(solution) => display.display_solution_found(solution),
+            Self::Achievements(unlocked) => display.display_achievements(unlocked),
+            Self::Information(info) => display.display_information(info),
+            Self::Exit => display.display_exit_message(),
+            Self::NewGame { word_count } => display.display_new_game_message(word_count),
+        }
+    }
 }
 
 /// Trait that abstracts the UI layer from game logic
 /// Implement this trait for different UIs: CLI, TUI, GUI, API, etc.
 pub trait GameInterface {
-    /// Display the optimal starting words
-    fn display_starting_words(&mut self, info: &StartingWordsInfo);
+    /// Show an event from the game loop
+    fn handle_event(&mut self, event: GameEvent<'_>);
 
     /// Read the user's guess, returns None if input was invalid and should retry
     fn read_guess(&mut self) -> Option<UserAction>;
 
-    /// Read feedback for a guess, returns None if input was invalid and should retry
-    fn read_feedback(&mut self) -> Option<Vec<Feedback>>;
+    /// Warn that a guess is dominated; returns true if the user wants to use it anyway
+    fn confirm_dominated_guess(&mut self, warning: &GuessWarning) -> bool;
+
+    /// Read feedback for a guess. [`InputResult::Invalid`] asks for it again, and
+    /// [`InputResult::Closed`] ends the session.
+    fn read_feedback(&mut self) -> InputResult<Vec<Feedback>>;
+
+    /// Like [`read_guess`](Self::read_guess), but gives up once there has been no input for
+    /// `idle`. The default ignores the limit and waits as long as it takes.
+    fn read_guess_within(&mut self, _idle: Duration) -> InputResult<UserAction> {
+        self.read_guess().into()
+    }
+
+    /// Like [`read_feedback`](Self::read_feedback), but gives up once there has been no input
+    /// for `idle`. The default ignores the limit and waits as long as it takes.
+    fn read_feedback_within(&mut self, _idle: Duration) -> InputResult<Vec<Feedback>> {
+        self.read_feedback()
+    }
+
+    /// A fresh token for the next long computation, which the interface may cancel while
+    /// showing its progress
+    fn cancel_token(&mut self) -> CancelToken {
+        CancelToken::new()
+    }
+}
+
+/// One method per [`GameEvent`], for interfaces that would rather not match on events.
+/// Implement it and forward [`GameInterface::handle_event`] with `event.dispatch(self)`.
+pub trait GameDisplay {
+    /// Display the optimal starting words
+    fn display_starting_words(&mut self, info: &StartingWordsInfo);
+
+    /// Show rounds that were entered in one go rather than guess by guess
+    fn display_rounds(&mut self, rounds: &[(String, Vec<Feedback>)]);
 
     /// Display the current candidate words
     fn display_candidates(&mut self, candidates: &[String]);
 
+    /// Record which candidates the last feedback eliminated and which survived
+    fn display_candidate_diff(&mut self, diff: &CandidateDiff);
+
     /// Display a recommendation for the next guess
     fn display_recommendation(&mut self, recommendation: &Recommendation);
 
+    /// Display a hint the user asked for
+    fn display_hint(&mut self, hint: &Hint);
+
+    /// Display the chances of solving in time by following the recommendations
+    fn display_win_odds(&mut self, odds: &WinOdds);
+
+    /// Display the guesses that test the most of `letters`, best first
+    fn display_probe(&mut self, letters: &[char], guesses: &[(String, f64)]);
+
+    /// Confirm which letters recommendations now avoid (none once cleared)
+    fn display_avoid(&mut self, letters: &[char]);
+
+    /// Report how many candidates were kept from a list of `listed` words (none means the
+    /// list wasn't applied)
+    fn display_restrict(&mut self, listed: usize, remaining: usize);
+
+    /// Confirm a corrected round, or say why it couldn't be corrected
+    fn display_correction(&mut self, correction: Correction<'_>);
+
+    /// Confirm a round's new tag, or say why it couldn't be tagged
+    fn display_tagging(&mut self, tagging: Tagging<'_>);
+
+    /// Display the guess that best tells apart a cluster of candidates
+    fn display_splitter(&mut self, splitter: &Splitter);
+
+    /// Warn that the candidates are a trap family and name the guess that splits them
+    fn display_trap(&mut self, splitter: &Splitter, guesses_left: usize);
+
+    /// Explain why `word` is or isn't a candidate
+    fn display_why_not(&mut self, word: &str, explanation: &WhyNot);
+
     /// Display a message when computing
     fn display_computing_message(&mut self);
 
+    /// Report how long a step took (shown only when timing output is enabled)
+    fn display_timing(&mut self, label: &str, elapsed: Duration);
+
+    /// Report roughly how much memory the game takes, and how much of it is the scorer's
+    fn display_memory_usage(&mut self, total: usize, scorer: usize);
+
+    /// Show how far a long computation has got and the best guess found so far
+    fn display_progress(&mut self, current: usize, total: usize, best: Option<(&str, f64)>);
+
     /// Display a message when no candidates remain
     fn display_no_candidates_message(&mut self);
 
     /// Display the solution when found
     fn display_solution_found(&mut self, solution: &str);
 
+    /// Announce achievements the finished game unlocked
+    fn display_achievements(&mut self, unlocked: &[Achievement]);
+
+    /// Chart how much each guess of the finished game narrowed the pool
+    fn display_information(&mut self, info: &[GuessInformation]);
+
     /// Display exit message
     fn display_exit_message(&mut self);
 
@@ -67,102 +460,1136 @@ pub trait GameInterface {
     fn display_new_game_message(&mut self, word_count: usize);
 }
 
-pub fn game_loop<I: GameInterface>(initial_wordbank: &[String], interface: &mut I) {
-    let start_path = get_wordle_start_path();
-    let (starting_words, used_cache) =
-        load_or_compute_starting_words(initial_wordbank, start_path.as_ref());
+/// Forwards solver progress to a [`GameInterface`]
+struct InterfaceProgress<'a, I>(&'a mut I);
+
+impl<I: GameInterface> ProgressSink for InterfaceProgress<'_, I> {
+    fn report(&mut self, current: usize, total: usize, best: Option<(&str, f64)>) {
+        self.0.handle_event(GameEvent::Progress {
+            current,
+            total,
+            best,
+        });
+    }
+}
+
+/// Hooks called as the game loop plays, for stats, logging or share grids that shouldn't
+/// need their own [`GameInterface`]. Every method does nothing unless overridden.
+pub trait GameObserver {
+    /// A round was played; `round` counts from 1 within the current game. Correcting a
+    /// round replays the game, so every round is reported again.
+    fn on_round_complete(&mut self, _round: usize, _diff: &CandidateDiff) {}
+
+    /// A recommendation was shown for the next guess
+    fn on_recommendation(&mut self, _recommendation: &Recommendation) {}
+
+    /// A game ended: solved, out of candidates, or abandoned by a new game or exit after at
+    /// least one guess. Called once per game, or again if correcting a round reopens a game
+    /// that had run out of candidates.
+    fn on_game_end(&mut self, _result: &GameResult) {}
+}
+
+/// Options for how a game-loop session runs, as opposed to how its guesses are scored
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SessionOptions {
+    /// Stats file that finished games and achievements are recorded in, if any
+    pub stats_path: Option<PathBuf>,
+    /// Rounds already played, applied before the first guess so a game can be resumed
+    pub initial_rounds: Vec<(String, Vec<Feedback>)>,
+    /// Start a new game when a game in progress sees no input for this long, for kiosks and
+    /// hosted sessions that get abandoned
+    pub idle_timeout: Option<Duration>,
+    /// Give up on the session after this many invalid inputs in a row, so a script feeding
+    /// bad data fails instead of being asked again forever
+    pub max_invalid_inputs: Option<usize>,
+    /// Compute the starting words afresh instead of reading them from the cache or the opener
+    /// memo, then cache them as usual
+    pub recompute: bool,
+    /// Only build the incremental scorer once the pool is small enough for it to fit in
+    /// [`LOW_MEMORY_LIMIT`], scoring guesses from scratch until then, and report roughly how
+    /// much memory the game takes after each recommendation
+    pub low_memory: bool,
+}
+
+/// Where a recommendation reports its progress and checks whether to stop
+pub(crate) struct Monitor<'a> {
+    pub(crate) progress: &'a mut dyn ProgressSink,
+    pub(crate) cancel: &'a CancelToken,
+}
+
+/// What a game's recommendations carry over from one round to the next
+#[derive(Default)]
+pub(crate) struct SearchCache {
+    /// Built on the first exact recommendation and shrunk as candidates are eliminated
+    pub(crate) scorer: Option<PoolScorer>,
+    /// Best guesses of the last search that scored every guess from scratch
+    pub(crate) warm_start: WarmStart,
+    /// Guesses left in the game, if it has a limit. With two or fewer left, recommendations
+    /// play to finish in time rather than to narrow the pool.
+    pub(crate) remaining_guesses: Option<usize>,
+    /// Hold off building `scorer` until it fits in [`LOW_MEMORY_LIMIT`]
+    pub(crate) low_memory: bool,
+}
+
+impl SearchCache {
+    /// Approximate bytes the scorer takes, if there is one
+    pub(crate) fn scorer_footprint(&self) -> usize {
+        self.scorer.as_ref().map_or(0, PoolScorer::memory_footprint)
+    }
+
+    /// Approximate bytes the scorer and warm start take
+    pub(crate) fn memory_footprint(&self) -> usize {
+        self.scorer_footprint() + words_footprint(self.warm_start.ranking())
+    }
+}
+
+/// Approximate bytes taken by `words`
+pub(crate) fn words_footprint(words: &[String]) -> usize {
+    words
+        .iter()
+        .map(|word| size_of::<String>() + word.capacity())
+        .sum()
+}
+
+pub fn game_loop<I: GameInterface>(initial_wordbank: &WordBank, interface: &mut I) -> GameResult {
+    game_loop_with_config(initial_wordbank, interface, &SolverConfig::default())
+}
+
+pub fn game_loop_with_config<I: GameInterface>(
+    initial_wordbank: &WordBank,
+    interface: &mut I,
+    config: &SolverConfig,
+) -> GameResult {
+    game_loop_with_observers(
+        initial_wordbank,
+        interface,
+        config,
+        &SessionOptions::default(),
+        &mut [],
+    )
+}
+
+/// Runs the game loop with the given session options, calling each of `observers` as rounds
+/// are played and games end
+#[instrument(skip_all, fields(words = initial_wordbank.len()))]
+pub fn game_loop_with_observers<I: GameInterface>(
+    initial_wordbank: &WordBank,
+    interface: &mut I,
+    config: &SolverConfig,
+    session: &SessionOptions,
+    observers: &mut [&mut dyn GameObserver],
+) -> GameResult {
+    // Owned so the letters to avoid can be changed mid-game
+    let mut config = config.clone();
+    let start_path = if config.use_cache {
+        get_wordle_start_path()
+    } else {
+        None
+    };
+    let (starting_words, used_cache) = load_or_compute_starting_words(
+        initial_wordbank,
+        &config,
+        start_path.as_ref(),
+        session.recompute,
+        interface,
+    );
 
     let info = StartingWordsInfo {
         words: starting_words.clone(),
         used_cache,
         cache_path: start_path.clone(),
     };
-    interface.display_starting_words(&info);
+    if config.auto_recommend {
+        interface.handle_event(GameEvent::StartingWords(&info));
+    }
 
     let mut candidates = initial_wordbank.to_vec();
+    let arena = WordArena::from_wordbank(initial_wordbank);
+    // The candidates as interned ids, kept in step with `candidates`
+    let full_set = || arena.as_ref().map(|arena| CandidateSet::full(arena.len()));
+    let mut alive = full_set();
+    let mut search = SearchCache {
+        remaining_guesses: remaining_guesses(0),
+        low_memory: session.low_memory,
+        ..SearchCache::default()
+    };
+    // Reseeded every game so each game with a given --seed plays out the same way
+    let mut rng = config.seed.map(Rng::new);
+    // Recommendation for the current candidates, once computed
+    let mut current: Option<Recommendation> = None;
+    let mut hint_level = 0;
+    // Rounds played this game; their letters make poor hints
+    let mut history: Vec<(String, Vec<Feedback>)> = Vec::new();
+    // Rounds tagged forced (true) or free this game; untagged rounds follow `fixed_opener`
+    let mut tags: BTreeMap<usize, bool> = BTreeMap::new();
+    // Set once the game has ended, so extra guesses don't record or report it twice
+    let mut ended = false;
+
+    // Invalid inputs in a row, and whether there were too many and the session was given up
+    let mut invalid_inputs = 0;
+    let mut aborted = false;
+
+    // An action to play before reading any more input, starting with any rounds to resume
+    // from, which are played as though pasted in before the first guess
+    let mut pending = Some(session.initial_rounds.clone())
+        .filter(|rounds| !rounds.is_empty())
+        .map(UserAction::Rounds);
 
     loop {
-        let action = loop {
-            if let Some(action) = interface.read_guess() {
-                break action;
-            }
+        let action = match pending.take() {
+            Some(action) => action,
+            None => loop {
+                match read_guess(interface, session.idle_timeout) {
+                    InputResult::Ready(action) => {
+                        invalid_inputs = 0;
+                        break action;
+                    }
+                    InputResult::Invalid => {
+                        if too_many_invalid(&mut invalid_inputs, session.max_invalid_inputs) {
+                            aborted = true;
+                            break UserAction::Exit;
+                        }
+                    }
+                    // An abandoned game is reset; a fresh one just keeps waiting
+                    InputResult::Timeout if !history.is_empty() => break UserAction::NewGame,
+                    InputResult::Timeout => {}
+                    InputResult::Closed => break UserAction::Exit,
+                }
+            },
         };
 
-        match action {
+        let rounds = match action {
             UserAction::Exit => {
-                interface.display_exit_message();
+                if !ended && !history.is_empty() {
+                    end_game(observers, &candidates, &history);
+                }
+                interface.handle_event(GameEvent::Exit);
                 break;
             }
             UserAction::NewGame => {
+                if !ended && !history.is_empty() {
+                    end_game(observers, &candidates, &history);
+                }
                 candidates = initial_wordbank.to_vec();
-                interface.display_new_game_message(candidates.len());
-                let info = StartingWordsInfo {
-                    words: starting_words.clone(),
-                    used_cache: true,
-                    cache_path: start_path.clone(),
+                alive = full_set();
+                search.scorer = None;
+                rng = config.seed.map(Rng::new);
+                current = None;
+                hint_level = 0;
+                history.clear();
+                tags.clear();
+                search.remaining_guesses = remaining_guesses(0);
+                ended = false;
+                interface.handle_event(GameEvent::NewGame {
+                    word_count: candidates.len(),
+                });
+                if config.auto_recommend {
+                    let info = StartingWordsInfo {
+                        words: starting_words.clone(),
+                        used_cache: true,
+                        cache_path: start_path.clone(),
+                    };
+                    interface.handle_event(GameEvent::StartingWords(&info));
+                }
+                continue;
+            }
+            UserAction::Hint => {
+                if candidates.is_empty() {
+                    interface.handle_event(GameEvent::NoCandidates);
+                    continue;
+                }
+                hint_level = (hint_level + 1).min(HINT_LEVELS);
+                if hint_level == 1 {
+                    let tried: Vec<char> = history.iter().flat_map(|(g, _)| g.chars()).collect();
+                    if let Some(letter) = probe_letter(&candidates, &tried) {
+                        interface.handle_event(GameEvent::Hint(&Hint::ProbeLetter(letter)));
+                        continue;
+                    }
+                    // Nothing left worth probing, so go straight to the next rung
+                    hint_level = 2;
+                }
+                let recommendation = match &current {
+                    Some(recommendation) => recommendation.clone(),
+                    // The cached starting words take no account of letters to avoid
+                    None if history.is_empty()
+                        && !starting_words.is_empty()
+                        && config.avoid.is_empty() =>
+                    {
+                        opening_recommendation(&starting_words[0].0, &candidates)
+                    }
+                    None => {
+                        let Some(recommendation) = recommend_with_feedback(
+                            interface,
+                            initial_wordbank,
+                            &candidates,
+                            &config,
+                            arena.as_ref(),
+                            &mut search,
+                            rng.as_mut(),
+                        ) else {
+                            interface.handle_event(GameEvent::NoCandidates);
+                            continue;
+                        };
+                        recommendation
+                    }
+                };
+                let hint = if hint_level == 2 {
+                    Hint::FirstLetter(recommendation.guess.chars().next().unwrap_or('?'))
+                } else {
+                    Hint::Full(recommendation.clone())
                 };
-                interface.display_starting_words(&info);
+                current = Some(recommendation);
+                interface.handle_event(GameEvent::Hint(&hint));
+                continue;
+            }
+            UserAction::Probe(letters) => {
+                if candidates.is_empty() {
+                    interface.handle_event(GameEvent::NoCandidates);
+                    continue;
+                }
+                let guesses = probe_guesses(initial_wordbank, &candidates, &letters, PROBE_GUESSES);
+                interface.handle_event(GameEvent::Probe {
+                    letters: &letters,
+                    guesses: &guesses,
+                });
+                continue;
+            }
+            UserAction::Split(pattern) => {
+                let splitter = best_splitter(initial_wordbank, &candidates, &pattern);
+                interface.handle_event(GameEvent::Splitter(&splitter));
+                continue;
+            }
+            UserAction::WhyNot(word) => {
+                let explanation = why_not(&word, initial_wordbank, &history, &candidates);
+                interface.handle_event(GameEvent::WhyNot {
+                    word: &word,
+                    explanation: &explanation,
+                });
+                continue;
+            }
+            UserAction::Tag { round, forced } => {
+                let Some((guess, _)) = round.checked_sub(1).and_then(|i| history.get(i)) else {
+                    interface.handle_event(GameEvent::Tagging(Tagging::NoSuchRound {
+                        round,
+                        rounds: history.len(),
+                    }));
+                    continue;
+                };
+                tags.insert(round, forced);
+                interface.handle_event(GameEvent::Tagging(Tagging::Applied {
+                    round,
+                    guess,
+                    forced,
+                }));
+                // A solved game's chart is scored again with the new tag
+                if ended && candidates.len() == 1 {
+                    let info = tagged_information(initial_wordbank, &history, &tags, &config);
+                    interface.handle_event(GameEvent::Information(&info));
+                }
+                continue;
+            }
+            UserAction::Avoid(letters) => {
+                config.avoid = letters;
+                interface.handle_event(GameEvent::Avoid(&config.avoid));
+                // Whatever was recommended took no account of the new preference
+                current = None;
+                if config.auto_recommend
+                    && !history.is_empty()
+                    && candidates.len() > 1
+                    && let Some(recommendation) = recommend_with_feedback(
+                        interface,
+                        initial_wordbank,
+                        &candidates,
+                        &config,
+                        arena.as_ref(),
+                        &mut search,
+                        rng.as_mut(),
+                    )
+                {
+                    interface.handle_event(GameEvent::Recommendation(&recommendation));
+                    current = Some(recommendation);
+                }
+                continue;
             }
             UserAction::Guess(guess) => {
+                if let Some(warning) = check_guess(&guess, &candidates, initial_wordbank.len())
+                    && !interface.confirm_dominated_guess(&warning)
+                {
+                    continue;
+                }
+
                 let feedback = loop {
-                    if let Some(fb) = interface.read_feedback() {
-                        break fb;
+                    match read_feedback(interface, session.idle_timeout) {
+                        InputResult::Ready(fb) => {
+                            invalid_inputs = 0;
+                            break Ok(fb);
+                        }
+                        InputResult::Invalid => {
+                            if too_many_invalid(&mut invalid_inputs, session.max_invalid_inputs) {
+                                aborted = true;
+                                break Err(UserAction::Exit);
+                            }
+                        }
+                        // Walked away mid-guess, so the guess goes along with the game
+                        InputResult::Timeout => break Err(UserAction::NewGame),
+                        InputResult::Closed => break Err(UserAction::Exit),
+                    }
+                };
+                let feedback = match feedback {
+                    Ok(feedback) => feedback,
+                    Err(action) => {
+                        pending = Some(action);
+                        continue;
                     }
                 };
+                vec![(guess, feedback)]
+            }
+            UserAction::Restrict(words) => {
+                let listed: HashSet<&str> = words.iter().map(String::as_str).collect();
+                // Over interned ids, the list is intersected with the candidate set
+                let restricted = arena.as_ref().zip(alive.as_ref()).map(|(arena, alive)| {
+                    let listed = CandidateSet::from_ids(arena.len(), &arena.ids_of(&words));
+                    (alive.intersection(&listed), alive.difference(&listed))
+                });
+                let (kept, removed): (Vec<String>, Vec<String>) =
+                    match (arena.as_ref(), &restricted) {
+                        (Some(arena), Some((kept, removed))) => {
+                            (arena.words(kept), arena.words(removed))
+                        }
+                        _ => candidates
+                            .iter()
+                            .cloned()
+                            .partition(|word| listed.contains(word.as_str())),
+                    };
+                interface.handle_event(GameEvent::Restrict {
+                    listed: listed.len(),
+                    remaining: kept.len(),
+                });
+                // A list sharing nothing with the candidates is more likely the wrong file
+                // than a reason to end the game
+                if kept.is_empty() || removed.is_empty() {
+                    continue;
+                }
+                debug!(
+                    listed = listed.len(),
+                    removed = removed.len(),
+                    "candidates restricted"
+                );
+                if let Some((kept, removed)) = restricted {
+                    if let Some(scorer) = search.scorer.as_mut() {
+                        for id in removed.iter() {
+                            scorer.remove(id);
+                        }
+                    }
+                    alive = Some(kept);
+                }
+                candidates = kept;
+                Vec::new()
+            }
+            UserAction::Rounds(rounds) => {
+                interface.handle_event(GameEvent::Rounds(&rounds));
+                rounds
+            }
+            UserAction::Correct { round, feedback } => {
+                let correction = if round == 0 || round > history.len() {
+                    Some(Correction::NoSuchRound {
+                        round,
+                        rounds: history.len(),
+                    })
+                } else if ended && candidates.len() == 1 {
+                    Some(Correction::Solved)
+                } else {
+                    None
+                };
+                if let Some(correction) = correction {
+                    interface.handle_event(GameEvent::Correction(correction));
+                    continue;
+                }
+                let mut rounds = std::mem::take(&mut history);
+                rounds[round - 1].1 = feedback;
+                interface.handle_event(GameEvent::Correction(Correction::Applied {
+                    round,
+                    feedback: &rounds[round - 1].1,
+                }));
+                debug!(round, "feedback corrected");
+                // Replayed over the whole wordbank, which also reopens a game the mistake had
+                // left without a solution
+                candidates = initial_wordbank.to_vec();
+                alive = full_set();
+                search.scorer = None;
+                ended = false;
+                rounds
+            }
+        };
 
-                candidates = filter_candidates(&candidates, &guess, &feedback);
-                interface.display_candidates(&candidates);
+        current = None;
+        hint_level = 0;
+        let _round = info_span!("round", number = history.len() + 1).entered();
 
-                match check_game_state(&candidates, interface) {
-                    GameState::Solved | GameState::NoSolution => {
-                        // Don't break, let the loop continue so user can start a new game
-                        // The game is now in GameOver state and will wait for N or ESC
-                    }
-                    GameState::Continue => {
-                        interface.display_computing_message();
-                        let (info_guess, info_score, is_candidate) =
-                            best_information_guess(initial_wordbank, &candidates);
-                        let recommendation = Recommendation {
-                            guess: info_guess.to_string(),
-                            score: info_score,
-                            is_candidate,
-                        };
-                        interface.display_recommendation(&recommendation);
+        // Only the last round's diff is shown when several are entered at once, and there's
+        // none when the candidates were restricted instead
+        let mut diff = None;
+        for (guess, feedback) in rounds {
+            history.push((guess.clone(), feedback.clone()));
+            let (survived, eliminated) = partition_round(
+                &candidates,
+                arena.as_ref().zip(alive.as_mut()),
+                search.scorer.as_mut(),
+                &guess,
+                &feedback,
+            );
+            debug!(
+                %guess,
+                eliminated = eliminated.len(),
+                survived = survived.len(),
+                "feedback applied"
+            );
+            let round = CandidateDiff {
+                guess,
+                feedback,
+                eliminated,
+                survived,
+            };
+            candidates.clone_from(&round.survived);
+            for observer in observers.iter_mut() {
+                observer.on_round_complete(history.len(), &round);
+            }
+            diff = Some(round);
+        }
+        search.remaining_guesses = remaining_guesses(history.len());
+        if let Some(diff) = &diff {
+            interface.handle_event(GameEvent::CandidateDiff(diff));
+        }
+        interface.handle_event(GameEvent::Candidates(&candidates));
+
+        match check_game_state(&candidates, interface) {
+            GameState::Solved if !ended => {
+                if let Some(path) = session.stats_path.as_deref() {
+                    let guesses: Vec<String> = history.iter().map(|(g, _)| g.clone()).collect();
+                    record_game(path, &guesses, &candidates[0], interface);
+                }
+                if !history.is_empty() {
+                    let info = tagged_information(initial_wordbank, &history, &tags, &config);
+                    interface.handle_event(GameEvent::Information(&info));
+                }
+                end_game(observers, &candidates, &history);
+                ended = true;
+            }
+            GameState::NoSolution if !ended => {
+                // Don't break, let the loop continue so user can start a new game
+                // The game is now in GameOver state and will wait for N or ESC
+                end_game(observers, &candidates, &history);
+                ended = true;
+            }
+            GameState::Solved | GameState::NoSolution => {}
+            GameState::Continue if config.auto_recommend => {
+                let Some(recommendation) = recommend_with_feedback(
+                    interface,
+                    initial_wordbank,
+                    &candidates,
+                    &config,
+                    arena.as_ref(),
+                    &mut search,
+                    rng.as_mut(),
+                ) else {
+                    continue;
+                };
+                interface.handle_event(GameEvent::Recommendation(&recommendation));
+                for observer in observers.iter_mut() {
+                    observer.on_recommendation(&recommendation);
+                }
+                if candidates.len() <= WIN_ODDS_LIMIT
+                    && let Some(pattern) = find_trap(&candidates)
+                {
+                    let mut splitter = best_splitter(initial_wordbank, &candidates, &pattern);
+                    let recommended = groups(&recommendation.guess, &splitter.cluster);
+                    let splits_better = splitter
+                        .guess
+                        .as_ref()
+                        .is_some_and(|&(_, split)| split > recommended);
+                    let guesses_left = search.remaining_guesses.unwrap_or(0);
+                    if is_trap(&splitter.cluster, guesses_left) {
+                        // Point to the recommendation when it does the job, so the advice
+                        // doesn't contradict itself
+                        if !splits_better {
+                            splitter.guess = Some((recommendation.guess.to_string(), recommended));
+                        }
+                        interface.handle_event(GameEvent::Trap {
+                            splitter: &splitter,
+                            guesses_left,
+                        });
+                    } else if splits_better {
+                        // Only worth pointing out when the recommendation leaves more of it
+                        // together
+                        interface.handle_event(GameEvent::Splitter(&splitter));
                     }
                 }
+                current = Some(recommendation);
+                if candidates.len() <= WIN_ODDS_LIMIT {
+                    let started = Instant::now();
+                    let odds = WinOdds::compute(
+                        initial_wordbank,
+                        &candidates,
+                        history.len(),
+                        config.strategy,
+                        arena.as_ref(),
+                    );
+                    interface.handle_event(GameEvent::Timing {
+                        label: "win odds",
+                        elapsed: started.elapsed(),
+                    });
+                    interface.handle_event(GameEvent::WinOdds(&odds));
+                }
             }
+            GameState::Continue => {}
+        }
+    }
+
+    GameResult {
+        outcome: Outcome::of(&candidates),
+        rounds: history.len(),
+        candidates,
+        history,
+        aborted,
+    }
+}
+
+/// What each guess told the player, with the forced ones marked: those tagged so, and the
+/// opener if `config.fixed_opener` is set and it wasn't tagged free
+fn tagged_information(
+    wordbank: &[String],
+    history: &[(String, Vec<Feedback>)],
+    tags: &BTreeMap<usize, bool>,
+    config: &SolverConfig,
+) -> Vec<GuessInformation> {
+    let mut info = information_per_guess(wordbank, history);
+    for (i, guess) in info.iter_mut().enumerate() {
+        let round = i + 1;
+        guess.forced = tags
+            .get(&round)
+            .copied()
+            .unwrap_or(config.fixed_opener && round == 1);
+    }
+    info
+}
+
+/// Counts another invalid input in a row, returning true once that makes too many
+fn too_many_invalid(invalid_inputs: &mut usize, max: Option<usize>) -> bool {
+    *invalid_inputs += 1;
+    max.is_some_and(|max| *invalid_inputs >= max)
+}
+
+/// Guesses left in a game of [`MAX_GUESSES`] after `rounds`, or `None` once a game has
+/// gone past the limit and plays on without one
+pub(crate) fn remaining_guesses(rounds: usize) -> Option<usize> {
+    MAX_GUESSES.checked_sub(rounds).filter(|&left| left > 0)
+}
+
+/// Splits `candidates` into the words that survive the feedback for `guess` and the words it
+/// eliminates. Given the interned candidate set, the split runs over its ids instead, the set
+/// is narrowed to match, and the eliminated ids are dropped from `scorer` too.
+pub(crate) fn partition_round(
+    candidates: &[String],
+    ids: Option<(&WordArena, &mut CandidateSet)>,
+    scorer: Option<&mut PoolScorer>,
+    guess: &str,
+    feedback: &[Feedback],
+) -> (Vec<String>, Vec<String>) {
+    let Some((arena, alive)) = ids else {
+        return partition_candidates(candidates, guess, feedback);
+    };
+    let (survived, eliminated) = partition_ids(arena, alive, guess, feedback);
+    if let Some(scorer) = scorer {
+        for id in eliminated.iter() {
+            scorer.remove(id);
         }
     }
+    *alive = survived;
+    (arena.words(alive), arena.words(&eliminated))
 }
 
-fn load_or_compute_starting_words(
+/// Reads a guess, giving up after `idle` without input if there is a limit
+fn read_guess<I: GameInterface>(
+    interface: &mut I,
+    idle: Option<Duration>,
+) -> InputResult<UserAction> {
+    match idle {
+        Some(idle) => interface.read_guess_within(idle),
+        None => interface.read_guess().into(),
+    }
+}
+
+/// Reads feedback, giving up after `idle` without input if there is a limit
+fn read_feedback<I: GameInterface>(
+    interface: &mut I,
+    idle: Option<Duration>,
+) -> InputResult<Vec<Feedback>> {
+    match idle {
+        Some(idle) => interface.read_feedback_within(idle),
+        None => interface.read_feedback(),
+    }
+}
+
+/// Tells every observer how the current game ended
+fn end_game(
+    observers: &mut [&mut dyn GameObserver],
+    candidates: &[String],
+    history: &[(String, Vec<Feedback>)],
+) {
+    let result = GameResult {
+        outcome: Outcome::of(candidates),
+        rounds: history.len(),
+        candidates: candidates.to_vec(),
+        history: history.to_vec(),
+        aborted: false,
+    };
+    info!(outcome = ?result.outcome, rounds = result.rounds, "game ended");
+    for observer in observers.iter_mut() {
+        observer.on_game_end(&result);
+    }
+}
+
+/// Adds a solved game to the stats at `path` and announces any achievements it unlocked
+fn record_game<I: GameInterface>(
+    path: &Path,
+    guesses: &[String],
+    solution: &str,
+    interface: &mut I,
+) {
+    let mut guesses = guesses.to_vec();
+    // Once only the answer is left, it is the next guess if it wasn't the last one
+    if guesses.last().is_none_or(|last| last != solution) {
+        guesses.push(solution.to_string());
+    }
+    let mut stats = Stats::load(path);
+    let unlocked = stats.record(GameRecord {
+        date: chrono::Local::now().date_naive(),
+        guesses,
+    });
+    // Stats are a nicety, so a failed write shouldn't interrupt the game
+    let _ = stats.save(path);
+    if !unlocked.is_empty() {
+        interface.handle_event(GameEvent::Achievements(&unlocked));
+    }
+}
+
+/// Runs [`recommend`] while showing the interface a computing message, progress, and timing
+fn recommend_with_feedback<I: GameInterface>(
+    interface: &mut I,
     wordbank: &[String],
+    candidates: &[String],
+    config: &SolverConfig,
+    arena: Option<&WordArena>,
+    search: &mut SearchCache,
+    rng: Option<&mut Rng>,
+) -> Option<Recommendation> {
+    interface.handle_event(GameEvent::Computing);
+    let started = Instant::now();
+    let cancel = interface.cancel_token();
+    let recommendation = recommend(
+        wordbank,
+        candidates,
+        config,
+        arena,
+        search,
+        rng,
+        Monitor {
+            progress: &mut InterfaceProgress(interface),
+            cancel: &cancel,
+        },
+    );
+    interface.handle_event(GameEvent::Timing {
+        label: "recommendation",
+        elapsed: started.elapsed(),
+    });
+    if search.low_memory {
+        interface.handle_event(GameEvent::MemoryUsage {
+            total: words_footprint(wordbank)
+                + words_footprint(candidates)
+                + arena.map_or(0, WordArena::memory_footprint)
+                + search.memory_footprint(),
+            scorer: search.scorer_footprint(),
+        });
+    }
+    recommendation
+}
+
+/// The top starting word as a recommendation, so the first hint needs no search
+pub(crate) fn opening_recommendation(word: &str, candidates: &[String]) -> Recommendation {
+    Recommendation {
+        guess: Word::from_checked(word.to_string()),
+        score: expected_pool_size(word, candidates),
+        is_candidate: candidates.iter().any(|c| c == word),
+        confidence_interval: None,
+        truncated: false,
+    }
+}
+
+fn load_or_compute_starting_words<I: GameInterface>(
+    wordbank: &WordBank,
+    config: &SolverConfig,
     start_path: Option<&PathBuf>,
-) -> (Vec<String>, bool) {
-    if let Some(path) = start_path
-        && let Some(words) = read_starting_words(path)
-    {
-        return (words, true);
+    recompute: bool,
+    interface: &mut I,
+) -> (Vec<(String, f64)>, bool) {
+    let count = config.starting_words;
+    if let Some(path) = start_path.filter(|_| !recompute) {
+        let started = Instant::now();
+        let cached = read_cached_starting_words(path, wordbank, config.strategy, count);
+        interface.handle_event(GameEvent::Timing {
+            label: "cache read",
+            elapsed: started.elapsed(),
+        });
+        if let Some(words) = cached {
+            return (words, true);
+        }
+    }
+    if let Some(words) = precomputed::starting_words(wordbank, count).filter(|_| !recompute) {
+        if let Some(path) = start_path {
+            write_cached_starting_words(path, wordbank, config.strategy, &words);
+        }
+        return (words, false);
     }
 
-    println!("Computing optimal starting words, please wait...");
-    let words = compute_best_starting_words(wordbank);
+    let checkpoint_path = start_path.map(|path| starting_checkpoint_path(path));
+    let done = checkpoint_path
+        .as_deref()
+        .map(|path| read_starting_checkpoint(path, wordbank))
+        .unwrap_or_default();
+    if done.is_empty() {
+        println!("Computing optimal starting words, please wait...");
+    } else {
+        println!(
+            "Resuming starting-word computation ({} of {} words already scored)...",
+            done.len(),
+            wordbank.len()
+        );
+    }
+    let started = Instant::now();
+    let cancel = interface.cancel_token();
+    let words = resume_scored_starting_words(
+        wordbank,
+        count,
+        done,
+        &mut |scored| {
+            if let Some(path) = &checkpoint_path {
+                write_starting_checkpoint(path, wordbank, scored);
+            }
+        },
+        &mut InterfaceProgress(interface),
+        &cancel,
+    );
+    interface.handle_event(GameEvent::Timing {
+        label: "starting-word computation",
+        elapsed: started.elapsed(),
+    });
 
-    if let Some(path) = start_path {
-        write_starting_words(path, &words);
+    // A cancelled run only ranked part of the wordbank, so don't cache it; its checkpoint is
+    // picked up next time instead
+    if let Some(path) = start_path.filter(|_| !cancel.is_cancelled()) {
+        write_cached_starting_words(path, wordbank, config.strategy, &words);
+        if let Some(checkpoint) = &checkpoint_path {
+            let _ = std::fs::remove_file(checkpoint);
+        }
     }
 
     (words, false)
 }
 
+/// Computes the next recommendation according to the solver configuration.
+///
+/// Exact greedy scoring over the whole wordbank goes through `search.scorer`, which is built
+/// from the interned wordbank on first use and then updated incrementally by the caller as
+/// candidates are eliminated. Without it, guesses are scored from scratch, starting with the
+/// best of the last such search in `search.warm_start`. In low-memory mode, the scorer isn't
+/// built until it fits in [`LOW_MEMORY_LIMIT`], by which point it only needs columns for the
+/// fewer candidates left. Exact scoring stops once `config.budget` is spent; sampling is
+/// already quick, so it ignores the budget.
+///
+/// With `search.remaining_guesses` down to two, the guess most likely to finish in time is
+/// recommended instead, and with one left, a candidate. Otherwise, with letters to avoid, the
+/// search goes through [`recommend_avoiding`]. Returns `None` if there is nothing to guess
+/// or no candidates are left.
+#[instrument(level = "debug", skip_all, fields(candidates = candidates.len()), ret)]
+pub(crate) fn recommend(
+    wordbank: &[String],
+    candidates: &[String],
+    config: &SolverConfig,
+    arena: Option<&WordArena>,
+    search: &mut SearchCache,
+    rng: Option<&mut Rng>,
+    monitor: Monitor<'_>,
+) -> Option<Recommendation> {
+    let guesses = match config.strategy {
+        Strategy::ExpectedSize => wordbank,
+        Strategy::CandidatesOnly => candidates,
+    };
+    let arena = arena.filter(|arena| {
+        !search.low_memory
+            || search.scorer.is_some()
+            || fits_low_memory(arena.len(), candidates.len())
+    });
+    if let Some((guess, score, is_candidate)) =
+        endgame_guess(guesses, candidates, search.remaining_guesses)
+    {
+        return Some(Recommendation {
+            guess: Word::from_checked(guess.clone()),
+            score,
+            is_candidate,
+            confidence_interval: None,
+            truncated: false,
+        });
+    }
+    #[cfg(feature = "scripting")]
+    if let Some(script) = &config.script
+        && let Some(recommendation) = recommend_scored(
+            "script",
+            script.scores(guesses, candidates),
+            guesses,
+            candidates,
+            config,
+            Monitor {
+                progress: &mut *monitor.progress,
+                cancel: monitor.cancel,
+            },
+        )
+    {
+        return Some(recommendation);
+    }
+    if let Some(scorer) = &config.external_scorer
+        && let Some(recommendation) = recommend_scored(
+            "external scorer",
+            scorer.scores(guesses, candidates, config.budget.max_time, monitor.cancel),
+            guesses,
+            candidates,
+            config,
+            Monitor {
+                progress: &mut *monitor.progress,
+                cancel: monitor.cancel,
+            },
+        )
+    {
+        return Some(recommendation);
+    }
+    if !config.avoid.is_empty() {
+        return recommend_avoiding(
+            guesses,
+            candidates,
+            config,
+            arena,
+            &mut search.scorer,
+            rng,
+            monitor,
+        );
+    }
+    // Baked in for the embedded wordbank, and the same as the exact search below would find
+    if config.strategy == Strategy::ExpectedSize
+        && config.lookahead == 0
+        && config
+            .sample_size
+            .is_none_or(|size| candidates.len() <= size)
+        && let Some(guess) = precomputed::best_guess(wordbank, candidates)
+    {
+        return Some(opening_recommendation(guess, candidates));
+    }
+    match config.sample_size {
+        Some(sample_size) if candidates.len() > sample_size => {
+            let (guess, estimate, is_candidate) = match rng {
+                Some(rng) => {
+                    best_guess_for_sample(guesses, candidates, &rng.sample(candidates, sample_size))
+                }
+                None => best_sampled_guess(guesses, candidates, sample_size),
+            }?;
+            Some(Recommendation {
+                guess: Word::from_checked(guess.clone()),
+                score: estimate.score,
+                is_candidate,
+                confidence_interval: Some((estimate.lower, estimate.upper)),
+                truncated: false,
+            })
+        }
+        _ => {
+            let progress =
+                &mut BudgetedProgress::new(monitor.progress, config.budget, monitor.cancel);
+            let (guess, score, is_candidate) = match arena {
+                Some(arena)
+                    if config.lookahead == 0 && config.strategy == Strategy::ExpectedSize =>
+                {
+                    let (id, score, is_candidate) = search
+                        .scorer
+                        .get_or_insert_with(|| PoolScorer::new(arena, &arena.ids_of(candidates)))
+                        .best_guess_with_progress(Some(arena), progress, monitor.cancel)?;
+                    (arena.get(id), score, is_candidate)
+                }
+                _ if config.lookahead > 0 => {
+                    let (guess, score, is_candidate) = best_lookahead_guess_with_progress(
+                        guesses,
+                        candidates,
+                        progress,
+                        monitor.cancel,
+                    )?;
+                    (guess.as_str(), score, is_candidate)
+                }
+                _ => {
+                    let (guess, score, is_candidate) = best_information_guess_warm(
+                        guesses,
+                        candidates,
+                        &mut search.warm_start,
+                        progress,
+                        monitor.cancel,
+                    )?;
+                    (guess.as_str(), score, is_candidate)
+                }
+            };
+            Some(Recommendation {
+                guess: Word::from_checked(guess.to_string()),
+                score,
+                is_candidate,
+                confidence_interval: None,
+                truncated: monitor.cancel.is_cancelled(),
+            })
+        }
+    }
+}
+
+/// Whether a scorer of `words` guesses against `candidates` fits in low-memory mode
+fn fits_low_memory(words: usize, candidates: usize) -> bool {
+    PoolScorer::estimated_size(words, candidates) <= LOW_MEMORY_LIMIT
+}
+
+/// The guess to play with two guesses or fewer left: the one most likely to finish in time,
+/// or with one left, a candidate. Returns the guess, its expected pool size, and whether it is
+/// a candidate, or `None` while there are more guesses left (or no limit) or no candidates.
+pub(crate) fn endgame_guess<'a>(
+    guesses: &'a [String],
+    candidates: &'a [String],
+    remaining_guesses: Option<usize>,
+) -> Option<(&'a String, f64, bool)> {
+    match remaining_guesses {
+        Some(1) => best_information_guess(candidates, candidates),
+        Some(2) => best_finishing_guess(guesses, candidates),
+        _ => None,
+    }
+}
+
+/// [`recommend`] with each guess's score penalised for the letters in `config.avoid`.
+///
+/// Guesses are scored the same way (sampled, or exact through `scorer` when it applies), but
+/// without lookahead, which would mostly rescore guesses the penalty has already passed over.
+fn recommend_avoiding(
+    guesses: &[String],
+    candidates: &[String],
+    config: &SolverConfig,
+    arena: Option<&WordArena>,
+    scorer: &mut Option<PoolScorer>,
+    rng: Option<&mut Rng>,
+    monitor: Monitor<'_>,
+) -> Option<Recommendation> {
+    let sample = match config.sample_size {
+        Some(sample_size) if candidates.len() > sample_size => Some(match rng {
+            Some(rng) => rng.sample(candidates, sample_size),
+            None => sample_candidates(candidates, sample_size),
+        }),
+        _ => None,
+    };
+    let exact = match arena {
+        Some(arena) if sample.is_none() && config.strategy == Strategy::ExpectedSize => {
+            let scorer =
+                scorer.get_or_insert_with(|| PoolScorer::new(arena, &arena.ids_of(candidates)));
+            Some((arena, &*scorer))
+        }
+        _ => None,
+    };
+    let mut score = |guess: &str| match (&sample, exact) {
+        (Some(sample), _) => estimate_pool_size(guess, sample, candidates.len()).score,
+        (None, Some((arena, scorer))) => arena.id(guess).map_or_else(
+            || expected_pool_size(guess, candidates),
+            |id| scorer.expected_pool_size(id),
+        ),
+        (None, None) => expected_pool_size(guess, candidates),
+    };
+    // As in `recommend`, sampling is quick enough to ignore the budget
+    let budget = if sample.is_some() {
+        Budget::UNLIMITED
+    } else {
+        config.budget
+    };
+    let progress = &mut BudgetedProgress::new(monitor.progress, budget, monitor.cancel);
+    let (guess, score) = best_avoiding_guess_with_progress(
+        guesses,
+        &config.avoid,
+        &mut score,
+        progress,
+        monitor.cancel,
+    )?;
+    let confidence_interval = sample.as_deref().map(|sample| {
+        let estimate = estimate_pool_size(guess, sample, candidates.len());
+        (estimate.lower, estimate.upper)
+    });
+    Some(Recommendation {
+        guess: Word::from_checked(guess.clone()),
+        score,
+        is_candidate: candidates.contains(guess),
+        confidence_interval,
+        truncated: monitor.cancel.is_cancelled(),
+    })
+}
+
+/// The guess with the lowest of `scores`, one per guess from a script or external scorer,
+/// penalised for avoided letters as usual. `None` if `source` failed, which is logged, so the
+/// next scoring in line can step in.
+fn recommend_scored(
+    source: &str,
+    scores: Result<Vec<f64>, String>,
+    guesses: &[String],
+    candidates: &[String],
+    config: &SolverConfig,
+    monitor: Monitor<'_>,
+) -> Option<Recommendation> {
+    let scores = scores
+        .inspect_err(|e| warn!("{source} failed, using the next scoring in line: {e}"))
+        .ok()?;
+    let scores: HashMap<&str, f64> = guesses.iter().map(String::as_str).zip(scores).collect();
+    let mut score = |guess: &str| scores.get(guess).copied().unwrap_or(f64::INFINITY);
+    let (guess, score) = best_avoiding_guess_with_progress(
+        guesses,
+        &config.avoid,
+        &mut score,
+        monitor.progress,
+        monitor.cancel,
+    )?;
+    Some(Recommendation {
+        guess: Word::from_checked(guess.clone()),
+        score,
+        is_candidate: candidates.contains(guess),
+        confidence_interval: None,
+        truncated: false,
+    })
+}
+
+/// Checks whether a guess is already ruled out as the answer and dominated by a candidate.
+///
+/// Skipped before any feedback is known and for pools too large to check quickly.
+fn check_guess(guess: &str, candidates: &[String], wordbank_len: usize) -> Option<GuessWarning> {
+    if candidates.len() >= wordbank_len || candidates.len() > DOMINANCE_CHECK_LIMIT {
+        return None;
+    }
+    find_dominating_guess(guess, candidates).map(|(better, score, better_score)| GuessWarning {
+        guess: guess.to_string(),
+        score,
+        better_guess: better.clone(),
+        better_score,
+    })
+}
+
 fn check_game_state<I: GameInterface>(candidates: &[String], interface: &mut I) -> GameState {
     match candidates.len() {
         0 => {
-            interface.display_no_candidates_message();
+            interface.handle_event(GameEvent::NoCandidates);
             GameState::NoSolution
         }
         1 => {
-            interface.display_solution_found(&candidates[0]);
+            interface.handle_event(GameEvent::SolutionFound(&candidates[0]));
             GameState::Solved
         }
         _ => GameState::Continue,
@@ -172,31 +1599,831 @@ fn check_game_state<I: GameInterface>(candidates: &[String], interface: &mut I)
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(feature = "cli")]
     use crate::cli::CliInterface;
+    use crate::external::ExternalScorer;
+    use crate::progress::NoProgress;
+    use crate::solver::{best_information_guess, get_feedback};
+    use std::collections::VecDeque;
+    #[cfg(feature = "cli")]
     use std::io::Cursor;
 
+    #[test]
+    fn test_candidate_diff_pagination() {
+        let diff = CandidateDiff {
+            guess: "CRANE".to_string(),
+            feedback: vec![Feedback::NoMatch; 5],
+            eliminated: (0..5).map(|i| format!("E{i}")).collect(),
+            survived: vec!["S0".to_string()],
+        };
+
+        assert_eq!(diff.page_count(2), 3);
+        let (eliminated, survived) = diff.page(0, 2);
+        assert_eq!(eliminated, ["E0", "E1"]);
+        assert_eq!(survived, ["S0"]);
+
+        let (eliminated, survived) = diff.page(2, 2);
+        assert_eq!(eliminated, ["E4"]);
+        assert!(survived.is_empty());
+
+        // Out-of-range pages are empty rather than panicking
+        let (eliminated, survived) = diff.page(10, 2);
+        assert!(eliminated.is_empty() && survived.is_empty());
+    }
+
+    #[test]
+    fn test_candidate_diff_empty_has_one_page() {
+        assert_eq!(CandidateDiff::default().page_count(10), 1);
+    }
+
+    #[cfg(feature = "cli")]
+    #[test]
+    fn test_game_loop_diff_command() {
+        let wordbank = WordBank::new(vec![
+            "CRANE".to_string(),
+            "SLATE".to_string(),
+            "RAISE".to_string(),
+        ])
+        .unwrap();
+        let input = "diff\nCRANE\nXXXXX\ndiff\ndiff 2\nexit\n";
+        let reader = Cursor::new(input);
+        let mut interface = CliInterface::new(reader);
+
+        // Showing the diff before and after a round doesn't consume a guess
+        let result = game_loop(&wordbank, &mut interface);
+        assert_eq!(result.rounds, 1);
+        assert_eq!(result.history.len(), 1);
+        assert_eq!(result.history[0].0, "CRANE");
+    }
+
+    #[test]
+    fn test_game_loop_reports_candidate_diff() {
+        use crate::headless::{Event, RecordingInterface};
+
+        let wordbank = WordBank::new(vec![
+            "CRANE".to_string(),
+            "SLATE".to_string(),
+            "RAISE".to_string(),
+        ])
+        .unwrap();
+        let config = SolverConfig {
+            use_cache: false,
+            ..SolverConfig::default()
+        };
+        let mut interface =
+            RecordingInterface::new().guess("CRANE", get_feedback("CRANE", "SLATE"));
+        game_loop_with_config(&wordbank, &mut interface, &config);
+
+        let diff = interface
+            .events()
+            .iter()
+            .find_map(|e| match e {
+                Event::CandidateDiff(diff) => Some(diff),
+                _ => None,
+            })
+            .expect("a diff after the round");
+        assert_eq!(diff.guess, "CRANE");
+        assert_eq!(diff.survived, ["SLATE"]);
+        assert!(diff.eliminated.contains(&"CRANE".to_string()));
+        assert!(diff.eliminated.contains(&"RAISE".to_string()));
+    }
+
+    #[test]
+    fn test_check_guess_skipped_before_feedback() {
+        let wordbank = WordBank::new(vec!["BBBBB".to_string(), "CCCCC".to_string()]).unwrap();
+        assert!(check_guess("ZZZZZ", &wordbank, wordbank.len()).is_none());
+    }
+
+    #[test]
+    fn test_check_guess_warns_on_dominated_guess() {
+        let candidates = vec!["BBBBB".to_string(), "CCCCC".to_string()];
+        let warning = check_guess("ZZZZZ", &candidates, 10).unwrap();
+        assert_eq!(warning.guess, "ZZZZZ");
+        assert!(candidates.contains(&warning.better_guess));
+        assert!(warning.better_score <= warning.score);
+    }
+
+    #[cfg(feature = "cli")]
+    #[test]
+    fn test_game_loop_dominated_guess_rejected_then_overridden() {
+        let wordbank = WordBank::new(vec![
+            "AAAAA".to_string(),
+            "BBBBB".to_string(),
+            "CCCCC".to_string(),
+            "DDDDD".to_string(),
+        ])
+        .unwrap();
+        // ZZZZZ is refused once, then accepted with a bare ENTER
+        let input = "AAAAA\nXXXXX\nZZZZZ\nn\nZZZZZ\n\nXXXXX\nexit\n";
+        let reader = Cursor::new(input);
+        let mut interface = CliInterface::new(reader);
+
+        let result = game_loop(&wordbank, &mut interface);
+        let guesses: Vec<&str> = result.history.iter().map(|(g, _)| g.as_str()).collect();
+        assert_eq!(guesses, ["AAAAA", "ZZZZZ"]);
+        assert_eq!(result.rounds, 2);
+    }
+
+    #[test]
+    fn test_recommend_exact_has_no_interval() {
+        let wordbank = WordBank::new(vec!["CRANE".to_string(), "SLATE".to_string()]).unwrap();
+        let rec = recommend(
+            &wordbank,
+            &wordbank,
+            &SolverConfig::default(),
+            None,
+            &mut SearchCache::default(),
+            None,
+            Monitor {
+                progress: &mut NoProgress,
+                cancel: &CancelToken::new(),
+            },
+        )
+        .unwrap();
+        assert!(rec.confidence_interval.is_none());
+    }
+
+    #[test]
+    fn test_recommend_sampled_reports_interval() {
+        let wordbank: Vec<String> = ["CRANE", "SLATE", "TRACE", "GRACE", "PLACE", "BRACE"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let config = SolverConfig {
+            sample_size: Some(3),
+            ..SolverConfig::default()
+        };
+        let rec = recommend(
+            &wordbank,
+            &wordbank,
+            &config,
+            None,
+            &mut SearchCache::default(),
+            None,
+            Monitor {
+                progress: &mut NoProgress,
+                cancel: &CancelToken::new(),
+            },
+        )
+        .unwrap();
+        let (lower, upper) = rec.confidence_interval.unwrap();
+        assert!(lower <= rec.score && rec.score <= upper);
+
+        // The same seed draws the same sample, so it recommends the same guess
+        let seeded = |seed| {
+            recommend(
+                &wordbank,
+                &wordbank,
+                &config,
+                None,
+                &mut SearchCache::default(),
+                Some(&mut Rng::new(seed)),
+                Monitor {
+                    progress: &mut NoProgress,
+                    cancel: &CancelToken::new(),
+                },
+            )
+            .unwrap()
+        };
+        let (a, b) = (seeded(5), seeded(5));
+        assert_eq!((a.guess, a.score), (b.guess, b.score));
+    }
+
+    #[test]
+    fn test_recommend_candidates_only_picks_candidate() {
+        let wordbank = WordBank::new(vec![
+            "CLAMP".to_string(),
+            "BATCH".to_string(),
+            "CATCH".to_string(),
+            "HATCH".to_string(),
+        ])
+        .unwrap();
+        let config = SolverConfig {
+            strategy: Strategy::CandidatesOnly,
+            ..SolverConfig::default()
+        };
+        let rec = recommend(
+            &wordbank,
+            &wordbank[1..],
+            &config,
+            None,
+            &mut SearchCache::default(),
+            None,
+            Monitor {
+                progress: &mut NoProgress,
+                cancel: &CancelToken::new(),
+            },
+        )
+        .unwrap();
+        assert!(rec.is_candidate);
+        assert_ne!(rec.guess, "CLAMP");
+    }
+
+    #[cfg(feature = "cli")]
+    #[test]
+    fn test_game_loop_with_each_profile() {
+        use crate::solver::Profile;
+
+        let wordbank = WordBank::new(vec![
+            "CRANE".to_string(),
+            "SLATE".to_string(),
+            "RAISE".to_string(),
+            "STARE".to_string(),
+        ])
+        .unwrap();
+        for profile in [Profile::Fast, Profile::Balanced, Profile::Exhaustive] {
+            let input = "CRANE\nXXXXX\nexit\n";
+            let mut interface = CliInterface::new(Cursor::new(input));
+            game_loop_with_config(&wordbank, &mut interface, &profile.config());
+        }
+    }
+
+    #[cfg(feature = "cli")]
+    #[test]
+    fn test_game_loop_with_timing() {
+        let wordbank = WordBank::new(vec![
+            "CRANE".to_string(),
+            "SLATE".to_string(),
+            "RAISE".to_string(),
+        ])
+        .unwrap();
+        let config = SolverConfig {
+            use_cache: false,
+            ..SolverConfig::default()
+        };
+        let input = "CRANE\nXXXXX\nexit\n";
+        let mut interface = CliInterface::new(Cursor::new(input)).with_timing(true);
+        game_loop_with_config(&wordbank, &mut interface, &config);
+    }
+
+    #[cfg(feature = "cli")]
+    #[test]
+    fn test_game_loop_hint_ladder_without_recommendations() {
+        let wordbank = WordBank::new(vec![
+            "CRANE".to_string(),
+            "SLATE".to_string(),
+            "RAISE".to_string(),
+        ])
+        .unwrap();
+        let config = SolverConfig {
+            use_cache: false,
+            auto_recommend: false,
+            ..SolverConfig::default()
+        };
+        // The third hint gives the full guess, which `use` then plays
+        let input = "hint\nhint\nhint\nhint\nuse\nGGGGG\nexit\n";
+        let mut interface = CliInterface::new(Cursor::new(input));
+        game_loop_with_config(&wordbank, &mut interface, &config);
+    }
+
+    #[cfg(feature = "cli")]
+    #[test]
+    fn test_game_loop_records_solved_game_once() {
+        let stats_path = std::env::temp_dir().join("test_game_loop_stats.txt");
+        let _ = std::fs::remove_file(&stats_path);
+        let wordbank = WordBank::new(vec!["CRANE".to_string(), "SLATE".to_string()]).unwrap();
+        let config = SolverConfig {
+            use_cache: false,
+            ..SolverConfig::default()
+        };
+        let session = SessionOptions {
+            stats_path: Some(stats_path.clone()),
+            ..SessionOptions::default()
+        };
+        // SLATE is the only candidate left after CRANE, so it counts as the second guess
+        let input = "CRANE\nXXGXG\nSLATE\nGGGGG\nexit\n";
+        let mut interface = CliInterface::new(Cursor::new(input));
+        game_loop_with_observers(&wordbank, &mut interface, &config, &session, &mut []);
+
+        let stats = Stats::load(&stats_path);
+        assert_eq!(stats.games.len(), 1);
+        assert_eq!(stats.games[0].guesses, ["CRANE", "SLATE"]);
+        assert!(stats.has(Achievement::TwoGuessSolve));
+
+        std::fs::remove_file(&stats_path).unwrap();
+    }
+
+    #[cfg(feature = "cli")]
+    #[test]
+    fn test_game_loop_rounds_entered_at_once() {
+        let wordbank = WordBank::new(vec![
+            "CRANE".to_string(),
+            "SLATE".to_string(),
+            "PLATE".to_string(),
+            "GRATE".to_string(),
+        ])
+        .unwrap();
+        let config = SolverConfig {
+            use_cache: false,
+            ..SolverConfig::default()
+        };
+        // The same state as entering each guess and its feedback separately
+        let input = "crane=xxgxg, slate=xgggg\nPLATE\nGGGGG\nexit\n";
+        let mut interface = CliInterface::new(Cursor::new(input));
+        game_loop_with_config(&wordbank, &mut interface, &config);
+    }
+
+    #[derive(Default)]
+    struct CountingObserver {
+        rounds: Vec<usize>,
+        recommendations: usize,
+        ends: Vec<Outcome>,
+    }
+
+    impl GameObserver for CountingObserver {
+        fn on_round_complete(&mut self, round: usize, _diff: &CandidateDiff) {
+            self.rounds.push(round);
+        }
+
+        fn on_recommendation(&mut self, _recommendation: &Recommendation) {
+            self.recommendations += 1;
+        }
+
+        fn on_game_end(&mut self, result: &GameResult) {
+            self.ends.push(result.outcome.clone());
+        }
+    }
+
+    #[test]
+    fn test_game_loop_calls_observers() {
+        use crate::headless::RecordingInterface;
+
+        let wordbank = WordBank::from_valid(["CRANE", "SLATE", "PLATE", "ELATE"]);
+        let config = SolverConfig {
+            use_cache: false,
+            ..SolverConfig::default()
+        };
+        let round = |guess: &str| (guess.to_string(), get_feedback(guess, "ELATE"));
+        // The first game is abandoned, the second solved and then played on past the end
+        let mut interface = RecordingInterface::new()
+            .guess("CRANE", get_feedback("CRANE", "PLATE"))
+            .action(UserAction::NewGame)
+            .action(UserAction::Rounds(vec![
+                round("CRANE"),
+                round("PLATE"),
+                round("SLATE"),
+            ]))
+            .guess("CRANE", vec![Feedback::NoMatch; 5]);
+        let mut observer = CountingObserver::default();
+        game_loop_with_observers(
+            &wordbank,
+            &mut interface,
+            &config,
+            &SessionOptions::default(),
+            &mut [&mut observer],
+        );
+
+        assert_eq!(observer.rounds, [1, 1, 2, 3, 4]);
+        assert_eq!(observer.recommendations, 1);
+        assert_eq!(
+            observer.ends,
+            [Outcome::InProgress, Outcome::Solved("ELATE".to_string())]
+        );
+    }
+
+    /// Plays queued guess and feedback inputs through the idle-limited read methods
+    struct IdleInterface {
+        guesses: VecDeque<InputResult<UserAction>>,
+        feedback: VecDeque<InputResult<Vec<Feedback>>>,
+        new_games: usize,
+    }
+
+    impl GameInterface for IdleInterface {
+        fn handle_event(&mut self, event: GameEvent<'_>) {
+            if let GameEvent::NewGame { .. } = event {
+                self.new_games += 1;
+            }
+        }
+
+        fn read_guess(&mut self) -> Option<UserAction> {
+            unreachable!("an idle limit is set")
+        }
+
+        fn confirm_dominated_guess(&mut self, _warning: &GuessWarning) -> bool {
+            true
+        }
+
+        fn read_feedback(&mut self) -> InputResult<Vec<Feedback>> {
+            unreachable!("an idle limit is set")
+        }
+
+        fn read_guess_within(&mut self, _idle: Duration) -> InputResult<UserAction> {
+            self.guesses
+                .pop_front()
+                .unwrap_or(InputResult::Ready(UserAction::Exit))
+        }
+
+        fn read_feedback_within(&mut self, _idle: Duration) -> InputResult<Vec<Feedback>> {
+            self.feedback.pop_front().unwrap()
+        }
+    }
+
+    #[test]
+    fn test_game_loop_idle_timeout_resets_abandoned_games() {
+        let wordbank = WordBank::new(vec![
+            "CRANE".to_string(),
+            "SLATE".to_string(),
+            "RAISE".to_string(),
+        ])
+        .unwrap();
+        let config = SolverConfig {
+            use_cache: false,
+            auto_recommend: false,
+            ..SolverConfig::default()
+        };
+        let session = SessionOptions {
+            idle_timeout: Some(Duration::from_secs(60)),
+            ..SessionOptions::default()
+        };
+        let guess = |word: &str| InputResult::Ready(UserAction::Guess(word.to_string()));
+        let mut interface = IdleInterface {
+            // Timing out mid-game resets it, but a fresh game just keeps waiting
+            guesses: VecDeque::from([
+                guess("CRANE"),
+                InputResult::Timeout,
+                InputResult::Timeout,
+                guess("SLATE"),
+            ]),
+            // Walking away while marking feedback resets the game too
+            feedback: VecDeque::from([
+                InputResult::Ready(vec![Feedback::NoMatch; 5]),
+                InputResult::Timeout,
+            ]),
+            new_games: 0,
+        };
+        let result =
+            game_loop_with_observers(&wordbank, &mut interface, &config, &session, &mut []);
+
+        assert_eq!(interface.new_games, 2);
+        assert_eq!(result.rounds, 0);
+        assert_eq!(result.candidates, *wordbank);
+    }
+
+    #[test]
+    fn test_game_loop_gives_up_after_too_many_invalid_inputs() {
+        let wordbank = WordBank::new(vec![
+            "CRANE".to_string(),
+            "SLATE".to_string(),
+            "RAISE".to_string(),
+        ])
+        .unwrap();
+        let config = SolverConfig {
+            use_cache: false,
+            auto_recommend: false,
+            ..SolverConfig::default()
+        };
+        let session = SessionOptions {
+            idle_timeout: Some(Duration::from_secs(60)),
+            max_invalid_inputs: Some(3),
+            ..SessionOptions::default()
+        };
+        let guess = |word: &str| InputResult::Ready(UserAction::Guess(word.to_string()));
+        let mut interface = IdleInterface {
+            // A valid input in between starts the count again, guesses and feedback alike
+            guesses: VecDeque::from([
+                InputResult::Invalid,
+                InputResult::Invalid,
+                guess("CRANE"),
+                InputResult::Invalid,
+            ]),
+            feedback: VecDeque::from([
+                InputResult::Invalid,
+                InputResult::Invalid,
+                InputResult::Ready(vec![Feedback::NoMatch; 5]),
+            ]),
+            new_games: 0,
+        };
+        let result =
+            game_loop_with_observers(&wordbank, &mut interface, &config, &session, &mut []);
+        assert!(!result.aborted);
+        assert_eq!(result.rounds, 1);
+
+        let mut interface = IdleInterface {
+            guesses: VecDeque::from([guess("CRANE")]),
+            feedback: VecDeque::from([
+                InputResult::Invalid,
+                InputResult::Invalid,
+                InputResult::Invalid,
+            ]),
+            new_games: 0,
+        };
+        let result =
+            game_loop_with_observers(&wordbank, &mut interface, &config, &session, &mut []);
+        assert!(result.aborted);
+        assert_eq!(result.rounds, 0);
+    }
+
+    #[cfg(feature = "cli")]
+    #[test]
+    fn test_game_loop_resumes_from_initial_rounds() {
+        let wordbank = WordBank::new(vec![
+            "CRANE".to_string(),
+            "SLATE".to_string(),
+            "PLATE".to_string(),
+        ])
+        .unwrap();
+        let config = SolverConfig {
+            use_cache: false,
+            ..SolverConfig::default()
+        };
+        let session = SessionOptions {
+            initial_rounds: vec![("SLATE".to_string(), get_feedback("SLATE", "PLATE"))],
+            ..SessionOptions::default()
+        };
+        let input = "use\nGGGGG\nexit\n";
+        let mut interface = CliInterface::new(Cursor::new(input));
+        game_loop_with_observers(&wordbank, &mut interface, &config, &session, &mut []);
+    }
+
+    #[test]
+    fn test_recommend_avoids_letters_where_it_can() {
+        let wordbank: Vec<String> = ["CRANE", "SLATE", "TRACE", "GRACE", "PLACE", "BRACE"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let arena = WordArena::new(&wordbank);
+        let avoiding = |avoid: &[char], arena: Option<&WordArena>| {
+            let config = SolverConfig {
+                avoid: avoid.to_vec(),
+                ..SolverConfig::default()
+            };
+            recommend(
+                &wordbank,
+                &wordbank,
+                &config,
+                arena,
+                &mut SearchCache::default(),
+                None,
+                Monitor {
+                    progress: &mut NoProgress,
+                    cancel: &CancelToken::new(),
+                },
+            )
+            .unwrap()
+        };
+        assert_eq!(avoiding(&[], None).guess, "TRACE");
+        for arena in [None, arena.as_ref()] {
+            // GRACE splits the pool as well as TRACE without the T
+            let rec = avoiding(&['T'], arena);
+            assert_eq!(rec.guess, "GRACE");
+            assert_eq!(rec.score, expected_pool_size("GRACE", &wordbank));
+            // Every word has an E, so the preference can't be met and changes nothing
+            assert_eq!(avoiding(&['E'], arena).guess, "TRACE");
+        }
+    }
+
+    #[test]
+    fn test_recommend_plays_to_finish_near_the_limit() {
+        let wordbank: Vec<String> = ["CLIMB", "BATCH", "CATCH", "HATCH", "LATCH"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let with_left = |candidates: &[String], remaining_guesses| {
+            recommend(
+                &wordbank,
+                candidates,
+                &SolverConfig::default(),
+                None,
+                &mut SearchCache {
+                    remaining_guesses,
+                    ..SearchCache::default()
+                },
+                None,
+                Monitor {
+                    progress: &mut NoProgress,
+                    cancel: &CancelToken::new(),
+                },
+            )
+            .unwrap()
+        };
+        // CLIMB tells every candidate apart, but can't be the answer
+        assert_eq!(with_left(&wordbank[1..], None).guess, "CLIMB");
+        assert_eq!(with_left(&wordbank[1..], Some(2)).guess, "CLIMB");
+        let last = with_left(&wordbank[1..], Some(1));
+        assert!(last.is_candidate);
+
+        // BATCH finishes in two just as surely and might win in one
+        assert_eq!(with_left(&wordbank[1..3], Some(3)).guess, "CLIMB");
+        assert_eq!(with_left(&wordbank[1..3], Some(2)).guess, "BATCH");
+    }
+
+    #[test]
+    fn test_remaining_guesses() {
+        assert_eq!(remaining_guesses(0), Some(MAX_GUESSES));
+        assert_eq!(remaining_guesses(MAX_GUESSES - 1), Some(1));
+        assert_eq!(remaining_guesses(MAX_GUESSES), None);
+        assert_eq!(remaining_guesses(MAX_GUESSES + 1), None);
+    }
+
+    #[test]
+    fn test_low_memory_defers_the_scorer() {
+        // A full-size wordbank's opening pool is far too big, but a late-game pool fits
+        assert!(!fits_low_memory(15_000, 15_000));
+        assert!(fits_low_memory(15_000, 100));
+
+        let wordbank = crate::wordbank::sample_wordbank();
+        let candidates = wordbank[..40].to_vec();
+        let arena = WordArena::new(&wordbank);
+        let [normal, low] = [false, true].map(|low_memory| {
+            recommend(
+                &wordbank,
+                &candidates,
+                &SolverConfig::default(),
+                arena.as_ref(),
+                &mut SearchCache {
+                    low_memory,
+                    ..SearchCache::default()
+                },
+                None,
+                Monitor {
+                    progress: &mut NoProgress,
+                    cancel: &CancelToken::new(),
+                },
+            )
+            .map(|rec| (rec.guess, rec.score))
+        });
+        assert!(normal.is_some());
+        assert_eq!(normal, low);
+    }
+
+    #[test]
+    fn test_external_scorer_ranks_guesses() {
+        let wordbank = crate::wordbank::sample_wordbank();
+        let candidates = wordbank[..10].to_vec();
+        let arena = WordArena::new(&wordbank);
+        let recommend_with = |scorer: &str| {
+            let config = SolverConfig {
+                external_scorer: ExternalScorer::new(scorer),
+                ..SolverConfig::default()
+            };
+            recommend(
+                &wordbank,
+                &candidates,
+                &config,
+                arena.as_ref(),
+                &mut SearchCache::default(),
+                None,
+                Monitor {
+                    progress: &mut NoProgress,
+                    cancel: &CancelToken::new(),
+                },
+            )
+            .map(|rec| (rec.guess, rec.score))
+        };
+        // A scorer that can't run leaves the recommendation to the built-in scoring
+        let built_in = recommend_with("");
+        assert_eq!(recommend_with("wordle-solver-no-such-scorer"), built_in);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            // Scores the guesses 999, 998, ... in order, so the last one wins
+            let path = std::env::temp_dir()
+                .join(format!("wordle-external-scorer-{}.sh", std::process::id()));
+            std::fs::write(
+                &path,
+                r#"#!/bin/sh
+sed 's/.*"guesses": \[\([^]]*\)\].*/\1/' | tr ',' '\n' |
+    awk 'BEGIN { printf "{\"scores\": [" } { printf "%s%d", (NR > 1 ? ", " : ""), 1000 - NR }
+        END { print "]}" }'
+"#,
+            )
+            .unwrap();
+            std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+            let external = recommend_with(path.to_str().unwrap());
+            let _ = std::fs::remove_file(&path);
+            let last = Word::new(wordbank.last().unwrap()).unwrap();
+            assert_eq!(external, Some((last, f64::from(1000 - 40))));
+        }
+    }
+
+    #[cfg(feature = "scripting")]
+    #[test]
+    fn test_script_ranks_guesses_before_external_scorer() {
+        use crate::script::ScriptScorer;
+        use crate::solver::feedback_code;
+
+        let wordbank = crate::wordbank::sample_wordbank();
+        let candidates = wordbank[..10].to_vec();
+        let arena = WordArena::new(&wordbank);
+        let recommend_with = |script: Option<&str>| {
+            let config = SolverConfig {
+                script: script.map(|script| ScriptScorer::compile(script).unwrap()),
+                external_scorer: ExternalScorer::new("wordle-solver-no-such-scorer"),
+                ..SolverConfig::default()
+            };
+            recommend(
+                &wordbank,
+                &candidates,
+                &config,
+                arena.as_ref(),
+                &mut SearchCache::default(),
+                None,
+                Monitor {
+                    progress: &mut NoProgress,
+                    cancel: &CancelToken::new(),
+                },
+            )
+            .map(|rec| (rec.guess, rec.score))
+        };
+        // Prefers guesses that split the candidates into the most patterns
+        let split = recommend_with(Some("fn score(guess, patterns) { -patterns.len() }")).unwrap();
+        let patterns = |guess: &str| {
+            candidates
+                .iter()
+                .map(|answer| feedback_code(guess.as_bytes(), answer.as_bytes()))
+                .collect::<HashSet<_>>()
+                .len()
+        };
+        let most = wordbank.iter().map(|guess| patterns(guess)).max().unwrap();
+        assert_eq!(patterns(&split.0), most);
+        #[allow(clippy::cast_precision_loss)]
+        let expected = -(most as f64);
+        assert_eq!(split.1, expected);
+
+        // A failing script falls through the failing scorer to the built-in scoring
+        let failing = recommend_with(Some("fn score(guess, patterns) { throw \"no\" }"));
+        assert_eq!(failing, recommend_with(None));
+    }
+
+    #[test]
+    fn test_recommend_reuses_scorer() {
+        let wordbank: Vec<String> = ["CRANE", "SLATE", "TRACE", "GRACE", "PLACE"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let config = SolverConfig::default();
+        let arena = WordArena::new(&wordbank);
+        let mut search = SearchCache::default();
+
+        let first = recommend(
+            &wordbank,
+            &wordbank,
+            &config,
+            arena.as_ref(),
+            &mut search,
+            None,
+            Monitor {
+                progress: &mut NoProgress,
+                cancel: &CancelToken::new(),
+            },
+        )
+        .unwrap();
+        assert!(search.scorer.is_some());
+
+        let remaining = wordbank[2..].to_vec();
+        for id in arena.as_ref().unwrap().ids_of(&wordbank[..2]) {
+            search.scorer.as_mut().unwrap().remove(id);
+        }
+        let second = recommend(
+            &wordbank,
+            &remaining,
+            &config,
+            arena.as_ref(),
+            &mut search,
+            None,
+            Monitor {
+                progress: &mut NoProgress,
+                cancel: &CancelToken::new(),
+            },
+        )
+        .unwrap();
+        let (expected, score, _) = best_information_guess(&wordbank, &remaining).unwrap();
+        assert_eq!(&second.guess, expected);
+        assert_eq!(second.score, score);
+        assert!(first.score >= 1.0);
+    }
+
+    #[cfg(feature = "cli")]
     #[test]
     fn test_game_loop_immediate_exit() {
-        let wordbank = vec![
+        let wordbank = WordBank::new(vec![
             "CRANE".to_string(),
             "SLATE".to_string(),
             "RAISE".to_string(),
-        ];
+        ])
+        .unwrap();
         let input = "exit\n";
         let reader = Cursor::new(input);
         let mut interface = CliInterface::new(reader);
 
         // Should not panic and should exit gracefully
-        game_loop(&wordbank, &mut interface);
+        let result = game_loop(&wordbank, &mut interface);
+        assert_eq!(result.outcome, Outcome::InProgress);
+        assert_eq!(result.rounds, 0);
+        assert_eq!(result.candidates, *wordbank);
     }
 
+    #[cfg(feature = "cli")]
     #[test]
     fn test_game_loop_invalid_guess_then_exit() {
-        let wordbank = vec![
+        let wordbank = WordBank::new(vec![
             "CRANE".to_string(),
             "SLATE".to_string(),
             "RAISE".to_string(),
-        ];
+        ])
+        .unwrap();
         let input = "abc\nexit\n";
         let reader = Cursor::new(input);
         let mut interface = CliInterface::new(reader);
@@ -205,13 +2432,15 @@ mod tests {
         game_loop(&wordbank, &mut interface);
     }
 
+    #[cfg(feature = "cli")]
     #[test]
     fn test_game_loop_new_game_command() {
-        let wordbank = vec![
+        let wordbank = WordBank::new(vec![
             "CRANE".to_string(),
             "SLATE".to_string(),
             "RAISE".to_string(),
-        ];
+        ])
+        .unwrap();
         let input = "next\nexit\n";
         let reader = Cursor::new(input);
         let mut interface = CliInterface::new(reader);
@@ -220,13 +2449,15 @@ mod tests {
         game_loop(&wordbank, &mut interface);
     }
 
+    #[cfg(feature = "cli")]
     #[test]
     fn test_game_loop_valid_guess_invalid_feedback() {
-        let wordbank = vec![
+        let wordbank = WordBank::new(vec![
             "CRANE".to_string(),
             "SLATE".to_string(),
             "RAISE".to_string(),
-        ];
+        ])
+        .unwrap();
         let input = "CRANE\nINVALID\nXXXXX\nexit\n";
         let reader = Cursor::new(input);
         let mut interface = CliInterface::new(reader);
@@ -235,13 +2466,15 @@ mod tests {
         game_loop(&wordbank, &mut interface);
     }
 
+    #[cfg(feature = "cli")]
     #[test]
     fn test_game_loop_valid_guess_short_feedback() {
-        let wordbank = vec![
+        let wordbank = WordBank::new(vec![
             "CRANE".to_string(),
             "SLATE".to_string(),
             "RAISE".to_string(),
-        ];
+        ])
+        .unwrap();
         // After short feedback, provide valid feedback to complete the guess, then exit
         let input = "CRANE\nGGG\nXXXXX\nexit\n";
         let reader = Cursor::new(input);
@@ -251,29 +2484,38 @@ mod tests {
         game_loop(&wordbank, &mut interface);
     }
 
+    #[cfg(feature = "cli")]
     #[test]
     fn test_game_loop_complete_game_win() {
-        let wordbank = vec![
+        let wordbank = WordBank::new(vec![
             "CRANE".to_string(),
             "SLATE".to_string(),
             "RAISE".to_string(),
-        ];
+        ])
+        .unwrap();
         let input = "CRANE\nGGGGG\nexit\n";
         let reader = Cursor::new(input);
         let mut interface = CliInterface::new(reader);
 
         // Should find the solution and exit
-        game_loop(&wordbank, &mut interface);
+        let result = game_loop(&wordbank, &mut interface);
+        assert_eq!(result.outcome, Outcome::Solved("CRANE".to_string()));
+        assert_eq!(
+            result.history,
+            [("CRANE".to_string(), vec![Feedback::Match; 5])]
+        );
     }
 
+    #[cfg(feature = "cli")]
     #[test]
     fn test_game_loop_narrowing_down() {
-        let wordbank = vec![
+        let wordbank = WordBank::new(vec![
             "CRANE".to_string(),
             "SLATE".to_string(),
             "RAISE".to_string(),
             "STARE".to_string(),
-        ];
+        ])
+        .unwrap();
         // First guess eliminates some candidates, second guess finds solution
         let input = "CRANE\nXXXXX\nSLATE\nGGGGG\nexit\n";
         let reader = Cursor::new(input);
@@ -282,21 +2524,26 @@ mod tests {
         game_loop(&wordbank, &mut interface);
     }
 
+    #[cfg(feature = "cli")]
     #[test]
     fn test_game_loop_no_candidates_remain() {
-        let wordbank = vec!["CRANE".to_string(), "SLATE".to_string()];
+        let wordbank = WordBank::new(vec!["CRANE".to_string(), "SLATE".to_string()]).unwrap();
         // Give feedback that eliminates all candidates
         let input = "CRANE\nXXXXX\nSLATE\nXXXXX\nexit\n";
         let reader = Cursor::new(input);
         let mut interface = CliInterface::new(reader);
 
         // Should detect no solution and exit
-        game_loop(&wordbank, &mut interface);
+        let result = game_loop(&wordbank, &mut interface);
+        assert_eq!(result.outcome, Outcome::NoSolution);
+        assert_eq!(result.rounds, 2);
+        assert!(result.candidates.is_empty());
     }
 
+    #[cfg(feature = "cli")]
     #[test]
     fn test_game_loop_case_insensitive_guess() {
-        let wordbank = vec!["CRANE".to_string(), "SLATE".to_string()];
+        let wordbank = WordBank::new(vec!["CRANE".to_string(), "SLATE".to_string()]).unwrap();
         let input = "crane\nGGGGG\nexit\n";
         let reader = Cursor::new(input);
         let mut interface = CliInterface::new(reader);
@@ -305,9 +2552,10 @@ mod tests {
         game_loop(&wordbank, &mut interface);
     }
 
+    #[cfg(feature = "cli")]
     #[test]
     fn test_game_loop_case_insensitive_feedback() {
-        let wordbank = vec!["CRANE".to_string(), "SLATE".to_string()];
+        let wordbank = WordBank::new(vec!["CRANE".to_string(), "SLATE".to_string()]).unwrap();
         let input = "CRANE\nggggg\nexit\n";
         let reader = Cursor::new(input);
         let mut interface = CliInterface::new(reader);
@@ -316,15 +2564,17 @@ mod tests {
         game_loop(&wordbank, &mut interface);
     }
 
+    #[cfg(feature = "cli")]
     #[test]
     fn test_game_loop_mixed_feedback() {
-        let wordbank = vec![
+        let wordbank = WordBank::new(vec![
             "CRANE".to_string(),
             "SLATE".to_string(),
             "RAISE".to_string(),
             "STARE".to_string(),
             "SPARE".to_string(),
-        ];
+        ])
+        .unwrap();
         // Give mixed feedback with greens, yellows, and grays
         let input = "CRANE\nXYGXX\nSLATE\nGGGGG\nexit\n";
         let reader = Cursor::new(input);
@@ -333,24 +2583,30 @@ mod tests {
         game_loop(&wordbank, &mut interface);
     }
 
+    #[cfg(feature = "cli")]
     #[test]
     fn test_game_loop_multiple_games() {
-        let wordbank = vec![
+        let wordbank = WordBank::new(vec![
             "CRANE".to_string(),
             "SLATE".to_string(),
             "RAISE".to_string(),
-        ];
+        ])
+        .unwrap();
         // Play one game, start new game, then exit
         let input = "CRANE\nGGGGG\nnext\nSLATE\nGGGGG\nexit\n";
         let reader = Cursor::new(input);
         let mut interface = CliInterface::new(reader);
 
-        game_loop(&wordbank, &mut interface);
+        // Only the last game is reported
+        let result = game_loop(&wordbank, &mut interface);
+        assert_eq!(result.outcome, Outcome::Solved("SLATE".to_string()));
+        assert_eq!(result.rounds, 1);
     }
 
+    #[cfg(feature = "cli")]
     #[test]
     fn test_game_loop_with_whitespace_in_input() {
-        let wordbank = vec!["CRANE".to_string(), "SLATE".to_string()];
+        let wordbank = WordBank::new(vec!["CRANE".to_string(), "SLATE".to_string()]).unwrap();
         let input = "  CRANE  \n  GGGGG  \nexit\n";
         let reader = Cursor::new(input);
         let mut interface = CliInterface::new(reader);
@@ -359,9 +2615,10 @@ mod tests {
         game_loop(&wordbank, &mut interface);
     }
 
+    #[cfg(feature = "cli")]
     #[test]
     fn test_game_loop_six_letter_word_rejected() {
-        let wordbank = vec!["CRANE".to_string(), "SLATE".to_string()];
+        let wordbank = WordBank::new(vec!["CRANE".to_string(), "SLATE".to_string()]).unwrap();
         let input = "CRANES\nexit\n";
         let reader = Cursor::new(input);
         let mut interface = CliInterface::new(reader);
@@ -370,9 +2627,10 @@ mod tests {
         game_loop(&wordbank, &mut interface);
     }
 
+    #[cfg(feature = "cli")]
     #[test]
     fn test_game_loop_four_letter_word_rejected() {
-        let wordbank = vec!["CRANE".to_string(), "SLATE".to_string()];
+        let wordbank = WordBank::new(vec!["CRANE".to_string(), "SLATE".to_string()]).unwrap();
         let input = "CRAN\nexit\n";
         let reader = Cursor::new(input);
         let mut interface = CliInterface::new(reader);
@@ -381,9 +2639,10 @@ mod tests {
         game_loop(&wordbank, &mut interface);
     }
 
+    #[cfg(feature = "cli")]
     #[test]
     fn test_game_loop_word_with_numbers_rejected() {
-        let wordbank = vec!["CRANE".to_string(), "SLATE".to_string()];
+        let wordbank = WordBank::new(vec!["CRANE".to_string(), "SLATE".to_string()]).unwrap();
         let input = "CR4NE\nexit\n";
         let reader = Cursor::new(input);
         let mut interface = CliInterface::new(reader);
@@ -392,16 +2651,18 @@ mod tests {
         game_loop(&wordbank, &mut interface);
     }
 
+    #[cfg(feature = "cli")]
     #[test]
     fn test_game_loop_progressive_narrowing() {
-        let wordbank = vec![
+        let wordbank = WordBank::new(vec![
             "AAAAA".to_string(),
             "BBBBB".to_string(),
             "CCCCC".to_string(),
             "DDDDD".to_string(),
             "EEEEE".to_string(),
             "FFFFF".to_string(),
-        ];
+        ])
+        .unwrap();
         // Progressively narrow down candidates
         let input = "AAAAA\nXXXXX\nBBBBB\nXXXXX\nCCCCC\nGGGGG\nexit\n";
         let reader = Cursor::new(input);
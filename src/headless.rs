@@ -0,0 +1,869 @@
+//! Game interfaces with no terminal behind them.
+//!
+//! [`NullInterface`] ignores everything and exits at the first prompt. [`RecordingInterface`]
+//! plays a queued script of actions and keeps every display as an [`Event`], so tests and
+//! simulations can run the real game loop and then inspect what it showed. [`TestInterface`]
+//! goes further and checks the displays against a script as the game runs.
+
+use crate::cluster::Splitter;
+use crate::elimination::WhyNot;
+use crate::game_state::{
+    CandidateDiff, Correction, GameEvent, GameInterface, GuessWarning, Hint, InputResult,
+    Recommendation, Tagging, UserAction,
+};
+use crate::information::GuessInformation;
+use crate::odds::{WinOdds, percent};
+use crate::solver::Feedback;
+use crate::stats::Achievement;
+use std::collections::VecDeque;
+use std::fmt;
+
+/// Shows nothing and exits as soon as it is asked for a guess
+pub struct NullInterface;
+
+impl GameInterface for NullInterface {
+    fn handle_event(&mut self, _event: GameEvent<'_>) {}
+
+    fn read_guess(&mut self) -> Option<UserAction> {
+        Some(UserAction::Exit)
+    }
+
+    fn confirm_dominated_guess(&mut self, _warning: &GuessWarning) -> bool {
+        true
+    }
+
+    fn read_feedback(&mut self) -> InputResult<Vec<Feedback>> {
+        // Never asked, since no guess is ever made
+        InputResult::Ready(vec![Feedback::NoMatch; 5])
+    }
+}
+
+/// Something the game loop showed, as recorded by [`RecordingInterface`].
+///
+/// Timing, memory and progress updates aren't recorded, since they vary from run to run or
+/// between platforms.
+#[derive(Clone, Debug)]
+pub enum Event {
+    StartingWords(Vec<String>),
+    DominatedGuess {
+        guess: String,
+        better_guess: String,
+    },
+    Rounds(Vec<(String, Vec<Feedback>)>),
+    Candidates(Vec<String>),
+    CandidateDiff(CandidateDiff),
+    Recommendation(Recommendation),
+    Hint(Hint),
+    WinOdds(WinOdds),
+    /// Guesses suggested for a probe, best first
+    Probe(Vec<String>),
+    /// Letters recommendations now avoid
+    Avoid(Vec<char>),
+    /// Candidates left after restricting to a list of words
+    Restrict {
+        listed: usize,
+        remaining: usize,
+    },
+    /// A round's feedback was corrected
+    Corrected {
+        round: usize,
+        feedback: Vec<Feedback>,
+    },
+    /// A correction was refused, since there's no such round or the game is solved
+    CorrectionRefused,
+    /// A round's guess was tagged forced or free
+    Tagged {
+        round: usize,
+        forced: bool,
+    },
+    /// A tag was refused, since there's no such round
+    TagRefused,
+    /// The best guess for telling apart a cluster of candidates
+    Splitter(Splitter),
+    /// A warning that the candidates are a trap family, with the guess that splits them
+    Trap {
+        splitter: Splitter,
+        guesses_left: usize,
+    },
+    /// Why a word is or isn't a candidate
+    WhyNot {
+        word: String,
+        explanation: WhyNot,
+    },
+    Computing,
+    NoCandidates,
+    SolutionFound(String),
+    Achievements(Vec<Achievement>),
+    Information(Vec<GuessInformation>),
+    Exit,
+    NewGame(usize),
+}
+
+impl Event {
+    /// The recorded form of a game loop event, or `None` for timing and progress
+    #[must_use]
+    pub fn from_game_event(event: GameEvent<'_>) -> Option<Self> {
+        Some(match event {
+            GameEvent::StartingWords(info) => {
+                Self::StartingWords(info.words.iter().map(|(word, _)| word.clone()).collect())
+            }
+            GameEvent::Rounds(rounds) => Self::Rounds(rounds.to_vec()),
+            GameEvent::Candidates(candidates) => Self::Candidates(candidates.to_vec()),
+            GameEvent::CandidateDiff(diff) => Self::CandidateDiff(diff.clone()),
+            GameEvent::Recommendation(recommendation) => {
+                Self::Recommendation(recommendation.clone())
+            }
+            GameEvent::Hint(hint) => Self::Hint(hint.clone()),
+            GameEvent::WinOdds(odds) => Self::WinOdds(odds.clone()),
+            GameEvent::Probe { guesses, .. } => {
+                Self::Probe(guesses.iter().map(|(word, _)| word.clone()).collect())
+            }
+            GameEvent::Avoid(letters) => Self::Avoid(letters.to_vec()),
+            GameEvent::Restrict { listed, remaining } => Self::Restrict { listed, remaining },
+            GameEvent::Correction(Correction::Applied { round, feedback }) => Self::Corrected {
+                round,
+                feedback: feedback.to_vec(),
+            },
+            GameEvent::Correction(_) => Self::CorrectionRefused,
+            GameEvent::Tagging(Tagging::Applied { round, forced, .. }) => {
+                Self::Tagged { round, forced }
+            }
+            GameEvent::Tagging(Tagging::NoSuchRound { .. }) => Self::TagRefused,
+            GameEvent::Splitter(splitter) => Self::Splitter(splitter.clone()),
+            GameEvent::Trap {
+                splitter,
+                guesses_left,
+            } => Self::Trap {
+                splitter: splitter.clone(),
+                guesses_left,
+            },
+            GameEvent::WhyNot { word, explanation } => Self::WhyNot {
+                word: word.to_string(),
+                explanation: explanation.clone(),
+            },
+            GameEvent::Computing => Self::Computing,
+            GameEvent::Timing { .. }
+            | GameEvent::MemoryUsage { .. }
+            | GameEvent::Progress { .. } => return None,
+            GameEvent::NoCandidates => Self::NoCandidates,
+            GameEvent::SolutionFound(solution) => Self::SolutionFound(solution.to_string()),
+            GameEvent::Achievements(unlocked) => Self::Achievements(unlocked.to_vec()),
+            GameEvent::Information(info) => Self::Information(info.to_vec()),
+            GameEvent::Exit => Self::Exit,
+            GameEvent::NewGame { word_count } => Self::NewGame(word_count),
+        })
+    }
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let join = |words: &[String]| words.join(", ");
+        match self {
+            Self::StartingWords(words) => write!(f, "starting words: {}", join(words)),
+            Self::DominatedGuess {
+                guess,
+                better_guess,
+            } => write!(f, "dominated guess: {guess} (better: {better_guess})"),
+            Self::Rounds(rounds) => {
+                let rounds: Vec<String> = rounds
+                    .iter()
+                    .map(|(guess, feedback)| {
+                        let feedback: String = feedback.iter().map(|fb| fb.as_char()).collect();
+                        format!("{guess}={feedback}")
+                    })
+                    .collect();
+                write!(f, "rounds: {}", join(&rounds))
+            }
+            Self::Candidates(candidates) => write!(f, "candidates: {}", join(candidates)),
+            Self::CandidateDiff(diff) => write!(
+                f,
+                "diff {}: {} eliminated, {} survived",
+                diff.guess,
+                diff.eliminated.len(),
+                diff.survived.len()
+            ),
+            Self::Recommendation(recommendation) => {
+                write!(f, "recommendation: {}", recommendation.guess)
+            }
+            Self::Hint(Hint::ProbeLetter(letter)) => write!(f, "hint: probe letter {letter}"),
+            Self::Hint(Hint::FirstLetter(letter)) => write!(f, "hint: first letter {letter}"),
+            Self::Hint(Hint::Full(recommendation)) => write!(f, "hint: {}", recommendation.guess),
+            Self::WinOdds(odds) => {
+                let shown: Vec<String> = odds
+                    .shown()
+                    .iter()
+                    .map(|&(guess, chance)| format!("{}% by {guess}", percent(chance)))
+                    .collect();
+                write!(f, "odds: {}", join(&shown))
+            }
+            Self::Probe(guesses) => write!(f, "probe: {}", join(guesses)),
+            Self::Avoid(letters) => {
+                let letters: String = letters.iter().collect();
+                write!(f, "avoid: {letters}")
+            }
+            Self::Restrict { listed, remaining } => {
+                write!(f, "restrict: {remaining} of {listed} listed")
+            }
+            Self::Corrected { round, feedback } => {
+                let feedback: String = feedback.iter().map(|fb| fb.as_char()).collect();
+                write!(f, "corrected: round {round} = {feedback}")
+            }
+            Self::CorrectionRefused => write!(f, "correction refused"),
+            Self::Tagged { round, forced } => {
+                let tag = if *forced { "forced" } else { "free" };
+                write!(f, "tagged: round {round} {tag}")
+            }
+            Self::TagRefused => write!(f, "tag refused"),
+            Self::Splitter(splitter) => match &splitter.guess {
+                Some((guess, groups)) => {
+                    write!(
+                        f,
+                        "splitter {}: {guess} ({groups} groups)",
+                        splitter.pattern
+                    )
+                }
+                None => write!(f, "splitter {}: none", splitter.pattern),
+            },
+            Self::Trap {
+                splitter,
+                guesses_left,
+            } => {
+                let guess = splitter
+                    .guess
+                    .as_ref()
+                    .map_or("", |(guess, _)| guess.as_str());
+                write!(
+                    f,
+                    "trap {}: {} words, {guesses_left} guesses left, play {guess}",
+                    splitter.pattern,
+                    splitter.cluster.len()
+                )
+            }
+            Self::WhyNot { word, explanation } => {
+                write!(f, "why-not: {}", explanation.describe(word))
+            }
+            Self::Computing => write!(f, "computing"),
+            Self::NoCandidates => write!(f, "no candidates"),
+            Self::SolutionFound(solution) => write!(f, "solution: {solution}"),
+            Self::Achievements(unlocked) => {
+                let keys: Vec<String> = unlocked.iter().map(|a| a.key().to_string()).collect();
+                write!(f, "achievements: {}", join(&keys))
+            }
+            Self::Information(info) => {
+                let bits: Vec<String> = info
+                    .iter()
+                    .map(|guess| format!("{} {:.1}/{:.1}", guess.guess, guess.bits, guess.max_bits))
+                    .collect();
+                write!(f, "information: {}", join(&bits))
+            }
+            Self::Exit => write!(f, "exit"),
+            Self::NewGame(word_count) => write!(f, "new game: {word_count} words"),
+        }
+    }
+}
+
+/// Plays a script of queued actions and records everything the game loop displays.
+///
+/// Once the script runs out the interface exits, so the game loop always returns.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::game_state::game_loop_with_config;
+/// use wordle_solver::headless::RecordingInterface;
+/// use wordle_solver::solver::SolverConfig;
+/// use wordle_solver::words::WordBank;
+/// use wordle_solver::get_feedback;
+///
+/// let wordbank = WordBank::from_valid(["CRANE", "SLATE", "TRACE"]);
+/// let config = SolverConfig { use_cache: false, ..SolverConfig::default() };
+/// let mut interface = RecordingInterface::new().guess("CRANE", get_feedback("CRANE", "TRACE"));
+///
+/// game_loop_with_config(&wordbank, &mut interface, &config);
+/// assert_eq!(interface.solution(), Some("TRACE"));
+/// ```
+#[derive(Default)]
+pub struct RecordingInterface {
+    actions: VecDeque<UserAction>,
+    feedback: VecDeque<Vec<Feedback>>,
+    /// Answers to dominated-guess warnings; any warning past the end is accepted
+    confirmations: VecDeque<bool>,
+    events: Vec<Event>,
+}
+
+impl RecordingInterface {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues an action to return from the next unanswered `read_guess`
+    #[must_use]
+    pub fn action(mut self, action: UserAction) -> Self {
+        self.actions.push_back(action);
+        self
+    }
+
+    /// Queues a guess together with the feedback it gets
+    #[must_use]
+    pub fn guess(mut self, word: &str, feedback: Vec<Feedback>) -> Self {
+        self.actions
+            .push_back(UserAction::Guess(word.to_uppercase()));
+        self.feedback.push_back(feedback);
+        self
+    }
+
+    /// Queues the answer to the next dominated-guess warning
+    #[must_use]
+    pub fn confirm(mut self, use_anyway: bool) -> Self {
+        self.confirmations.push_back(use_anyway);
+        self
+    }
+
+    /// Everything displayed so far, in order
+    #[must_use]
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    /// The last solution the game loop announced
+    #[must_use]
+    pub fn solution(&self) -> Option<&str> {
+        self.events.iter().rev().find_map(|event| match event {
+            Event::SolutionFound(solution) => Some(solution.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Every recommendation shown, in order
+    #[must_use]
+    pub fn recommendations(&self) -> Vec<&Recommendation> {
+        self.events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Recommendation(recommendation) => Some(recommendation),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+impl GameInterface for RecordingInterface {
+    fn handle_event(&mut self, event: GameEvent<'_>) {
+        self.events.extend(Event::from_game_event(event));
+    }
+
+    fn read_guess(&mut self) -> Option<UserAction> {
+        Some(self.actions.pop_front().unwrap_or(UserAction::Exit))
+    }
+
+    fn confirm_dominated_guess(&mut self, warning: &GuessWarning) -> bool {
+        self.events.push(Event::DominatedGuess {
+            guess: warning.guess.clone(),
+            better_guess: warning.better_guess.clone(),
+        });
+        self.confirmations.pop_front().unwrap_or(true)
+    }
+
+    /// # Panics
+    /// Panics if the script has no feedback left, which means a guess was queued with
+    /// [`action`](Self::action) instead of [`guess`](Self::guess).
+    fn read_feedback(&mut self) -> InputResult<Vec<Feedback>> {
+        let feedback = self.feedback.pop_front();
+        assert!(feedback.is_some(), "script ran out of feedback");
+        feedback.into()
+    }
+}
+
+/// One step of a [`TestInterface`] script
+#[derive(Debug)]
+enum Step {
+    /// A line the game loop must show before the next input, as an [`Event`] displays it
+    Expect(String),
+    Action(UserAction),
+    Feedback(Vec<Feedback>),
+    Confirm(bool),
+}
+
+/// Runs the game loop against a script of expected displays and inputs, panicking with a
+/// diff as soon as it strays.
+///
+/// Expected lines must all appear, in order, among the displays between one input and the
+/// next; displays the script doesn't mention are allowed. Once the script runs out the
+/// interface exits. Call [`finish`](Self::finish) after the loop returns to check that
+/// nothing was left unplayed.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::game_state::game_loop_with_config;
+/// use wordle_solver::headless::TestInterface;
+/// use wordle_solver::solver::SolverConfig;
+/// use wordle_solver::words::WordBank;
+/// use wordle_solver::get_feedback;
+///
+/// let wordbank = WordBank::from_valid(["CRANE", "SLATE", "TRACE"]);
+/// let config = SolverConfig { use_cache: false, ..SolverConfig::default() };
+/// let mut interface = TestInterface::new()
+///     .guess("CRANE", get_feedback("CRANE", "TRACE"))
+///     .expect("candidates: TRACE")
+///     .expect("solution: TRACE");
+///
+/// game_loop_with_config(&wordbank, &mut interface, &config);
+/// interface.finish();
+/// ```
+#[derive(Default)]
+pub struct TestInterface {
+    script: VecDeque<Step>,
+    /// Lines shown since the last input, checked against the script at the next one
+    shown: Vec<String>,
+}
+
+impl TestInterface {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Expects the game loop to show `line` before the next input
+    #[must_use]
+    pub fn expect(mut self, line: &str) -> Self {
+        self.script.push_back(Step::Expect(line.to_string()));
+        self
+    }
+
+    /// Answers the next request for a guess with `action`
+    #[must_use]
+    pub fn action(mut self, action: UserAction) -> Self {
+        self.script.push_back(Step::Action(action));
+        self
+    }
+
+    /// Makes a guess and answers the request for its feedback
+    #[must_use]
+    pub fn guess(mut self, word: &str, feedback: Vec<Feedback>) -> Self {
+        self.script
+            .push_back(Step::Action(UserAction::Guess(word.to_uppercase())));
+        self.script.push_back(Step::Feedback(feedback));
+        self
+    }
+
+    /// Answers the next dominated-guess warning
+    #[must_use]
+    pub fn confirm(mut self, use_anyway: bool) -> Self {
+        self.script.push_back(Step::Confirm(use_anyway));
+        self
+    }
+
+    /// Checks the displays since the last input and that the whole script was played.
+    ///
+    /// # Panics
+    /// Panics if an expected line wasn't shown or inputs are left over.
+    pub fn finish(&mut self) {
+        self.check_shown();
+        if let Some(step) = self.script.front() {
+            panic!("game loop finished with the script unplayed, next step: {step:?}");
+        }
+    }
+
+    fn show(&mut self, event: &Event) {
+        self.shown.push(event.to_string());
+    }
+
+    /// Matches the expectations at the front of the script against the lines shown
+    fn check_shown(&mut self) {
+        let mut cursor = 0;
+        while let Some(Step::Expect(line)) = self.script.front() {
+            match self.shown[cursor..].iter().position(|shown| shown == line) {
+                Some(i) => cursor += i + 1,
+                None => panic!(
+                    "game loop deviated from the script\n- {line}\n{}",
+                    self.shown
+                        .iter()
+                        .map(|shown| format!("+ {shown}"))
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                ),
+            }
+            self.script.pop_front();
+        }
+        self.shown.clear();
+    }
+
+    /// The next input step, once the displays before it have been checked
+    fn next_input(&mut self, asked_for: &str) -> Option<Step> {
+        // Expectations after the last input are about how the game ends, so leave them
+        // for `finish`
+        if self
+            .script
+            .iter()
+            .all(|step| matches!(step, Step::Expect(_)))
+        {
+            assert!(
+                asked_for == "a guess",
+                "script ran out when the game loop asked for {asked_for}"
+            );
+            return None;
+        }
+        self.check_shown();
+        self.script.pop_front()
+    }
+}
+
+impl GameInterface for TestInterface {
+    fn handle_event(&mut self, event: GameEvent<'_>) {
+        if let Some(event) = Event::from_game_event(event) {
+            self.show(&event);
+        }
+    }
+
+    fn read_guess(&mut self) -> Option<UserAction> {
+        match self.next_input("a guess") {
+            None => Some(UserAction::Exit),
+            Some(Step::Action(action)) => Some(action),
+            Some(step) => panic!("game loop asked for a guess, script has {step:?}"),
+        }
+    }
+
+    fn confirm_dominated_guess(&mut self, warning: &GuessWarning) -> bool {
+        self.show(&Event::DominatedGuess {
+            guess: warning.guess.clone(),
+            better_guess: warning.better_guess.clone(),
+        });
+        match self.next_input("a confirmation") {
+            Some(Step::Confirm(use_anyway)) => use_anyway,
+            step => panic!("game loop asked for a confirmation, script has {step:?}"),
+        }
+    }
+
+    fn read_feedback(&mut self) -> InputResult<Vec<Feedback>> {
+        match self.next_input("feedback") {
+            Some(Step::Feedback(feedback)) => InputResult::Ready(feedback),
+            step => panic!("game loop asked for feedback, script has {step:?}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::Outcome;
+    use crate::game_state::{game_loop, game_loop_with_config};
+    use crate::solver::{SolverConfig, get_feedback};
+    use crate::test_util::{bank, words};
+
+    fn no_cache() -> SolverConfig {
+        SolverConfig {
+            use_cache: false,
+            ..SolverConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_null_interface_exits_immediately() {
+        let mut interface = NullInterface;
+        game_loop(&bank(&["CRANE", "SLATE"]), &mut interface);
+    }
+
+    #[test]
+    fn test_recording_interface_records_a_game() {
+        let wordbank = bank(&["CRANE", "SLATE", "PLATE", "ELATE"]);
+        let mut interface = RecordingInterface::new()
+            .guess("crane", get_feedback("CRANE", "PLATE"))
+            .action(UserAction::Hint)
+            .action(UserAction::NewGame);
+        game_loop_with_config(&wordbank, &mut interface, &no_cache());
+
+        let events = interface.events();
+        assert!(matches!(&events[0], Event::StartingWords(words) if !words.is_empty()));
+        assert!(
+            events
+                .iter()
+                .any(|e| matches!(e, Event::Candidates(c) if c == &["SLATE", "PLATE", "ELATE"]))
+        );
+        assert!(matches!(events.last(), Some(Event::Exit)));
+        assert!(events.iter().any(|e| matches!(e, Event::NewGame(4))));
+        assert!(events.iter().any(|e| matches!(e, Event::Hint(_))));
+        assert!(interface.solution().is_none());
+        assert_eq!(interface.recommendations().len(), 1);
+    }
+
+    #[test]
+    fn test_recording_interface_records_probe() {
+        let wordbank = bank(&["CRANE", "SLANT", "ADIEU", "AUDIO"]);
+        let mut interface = RecordingInterface::new()
+            .action(UserAction::Probe(vec!['E', 'U']))
+            .action(UserAction::Probe(vec!['Z']));
+        game_loop_with_config(&wordbank, &mut interface, &no_cache());
+
+        let probes: Vec<String> = interface
+            .events()
+            .iter()
+            .filter(|e| matches!(e, Event::Probe(_)))
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(probes, ["probe: ADIEU", "probe: "]);
+    }
+
+    #[test]
+    fn test_recording_interface_records_splitter() {
+        let wordbank = bank(&[
+            "BATCH", "CATCH", "HATCH", "LATCH", "MATCH", "PATCH", "CLAMP",
+        ]);
+        let mut interface = RecordingInterface::new()
+            .action(UserAction::Split("_ATCH".to_string()))
+            .action(UserAction::Split("_LAMP".to_string()));
+        game_loop_with_config(&wordbank, &mut interface, &no_cache());
+
+        let splitters: Vec<String> = interface
+            .events()
+            .iter()
+            .filter(|e| matches!(e, Event::Splitter(_)))
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            splitters,
+            ["splitter _ATCH: CLAMP (5 groups)", "splitter _LAMP: none"]
+        );
+    }
+
+    #[test]
+    fn test_recording_interface_warns_of_a_trap() {
+        let family = [
+            "BATCH", "CATCH", "HATCH", "LATCH", "MATCH", "PATCH", "WATCH",
+        ];
+        let wordbank = bank(&[&family[..], &["CLAMP"]].concat());
+        let mut interface = RecordingInterface::new().guess("ZZZZZ", vec![Feedback::NoMatch; 5]);
+        game_loop_with_config(&wordbank, &mut interface, &no_cache());
+
+        // Seven words that differ in one letter, with five guesses left
+        let trap = interface
+            .events()
+            .iter()
+            .find_map(|e| match e {
+                Event::Trap {
+                    splitter,
+                    guesses_left,
+                } => Some((splitter.cluster.len(), *guesses_left)),
+                _ => None,
+            })
+            .expect("a trap warning");
+        assert_eq!(trap, (7, 5));
+    }
+
+    #[test]
+    fn test_recording_interface_records_avoid() {
+        let wordbank = bank(&["CRANE", "SLATE", "TRACE", "GRACE", "PLACE", "BRACE"]);
+        let mut interface = RecordingInterface::new()
+            .guess("ZZZZZ", vec![Feedback::NoMatch; 5])
+            .action(UserAction::Avoid(vec!['T']))
+            .action(UserAction::Avoid(Vec::new()));
+        game_loop_with_config(&wordbank, &mut interface, &no_cache());
+
+        let events: Vec<String> = interface
+            .events()
+            .iter()
+            .skip_while(|e| !matches!(e, Event::Avoid(_)))
+            .filter(|e| matches!(e, Event::Avoid(_) | Event::Recommendation(_)))
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            events,
+            [
+                "avoid: T",
+                "recommendation: GRACE",
+                "avoid: ",
+                "recommendation: TRACE"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_recording_interface_records_restrict() {
+        let wordbank = bank(&["CRANE", "SLATE", "TRACE", "GRACE", "PLACE", "BRACE"]);
+        let mut interface = RecordingInterface::new()
+            .guess("ZZZZZ", vec![Feedback::NoMatch; 5])
+            .action(UserAction::Restrict(words(&["JUMBO", "DITTY"])))
+            .action(UserAction::Restrict(words(&[
+                "GRACE", "BRACE", "SLATE", "FLAME",
+            ])));
+        let result = game_loop_with_config(&wordbank, &mut interface, &no_cache());
+
+        let events: Vec<String> = interface
+            .events()
+            .iter()
+            .skip_while(|e| !matches!(e, Event::Restrict { .. }))
+            .filter(|e| {
+                matches!(
+                    e,
+                    Event::Restrict { .. } | Event::Candidates(_) | Event::CandidateDiff(_)
+                )
+            })
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            events,
+            [
+                "restrict: 0 of 2 listed",
+                "restrict: 3 of 4 listed",
+                "candidates: SLATE, GRACE, BRACE"
+            ]
+        );
+        assert_eq!(result.rounds, 1);
+    }
+
+    #[test]
+    fn test_recording_interface_records_correction() {
+        let wordbank = bank(&["CRANE", "SLATE", "TRACE", "GRACE", "PLACE", "BRACE"]);
+        // GRACE is the answer, but CRANE's feedback was entered as all gray
+        let mut interface = RecordingInterface::new()
+            .guess("CRANE", vec![Feedback::NoMatch; 5])
+            .action(UserAction::Correct {
+                round: 2,
+                feedback: get_feedback("CRANE", "GRACE"),
+            })
+            .action(UserAction::Correct {
+                round: 1,
+                feedback: get_feedback("CRANE", "GRACE"),
+            });
+        let result = game_loop_with_config(&wordbank, &mut interface, &no_cache());
+
+        let events: Vec<String> = interface
+            .events()
+            .iter()
+            .filter(|e| {
+                matches!(
+                    e,
+                    Event::NoCandidates
+                        | Event::Corrected { .. }
+                        | Event::CorrectionRefused
+                        | Event::Candidates(_)
+                )
+            })
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            events,
+            [
+                "candidates: ",
+                "no candidates",
+                "correction refused",
+                "corrected: round 1 = YGGXG",
+                "candidates: TRACE, GRACE, BRACE"
+            ]
+        );
+        assert_eq!(result.rounds, 1);
+        assert_eq!(result.outcome, Outcome::InProgress);
+    }
+
+    #[test]
+    fn test_tagged_guesses_are_marked_forced() {
+        let wordbank = bank(&["CRANE", "SLATE", "TRACE", "GRACE", "PLACE", "BRACE"]);
+        let config = SolverConfig {
+            fixed_opener: true,
+            ..no_cache()
+        };
+        let mut interface = RecordingInterface::new()
+            .guess("SLATE", get_feedback("SLATE", "GRACE"))
+            .action(UserAction::Tag {
+                round: 2,
+                forced: true,
+            })
+            .guess("GRACE", get_feedback("GRACE", "GRACE"))
+            // Tagged once the game is solved, which scores it again
+            .action(UserAction::Tag {
+                round: 1,
+                forced: false,
+            });
+        game_loop_with_config(&wordbank, &mut interface, &config);
+
+        let tags: Vec<String> = interface
+            .events()
+            .iter()
+            .filter(|e| matches!(e, Event::Tagged { .. } | Event::TagRefused))
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(tags, ["tag refused", "tagged: round 1 free"]);
+        let charts: Vec<Vec<bool>> = interface
+            .events()
+            .iter()
+            .filter_map(|e| match e {
+                Event::Information(info) => Some(info.iter().map(|guess| guess.forced).collect()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(charts, [vec![true, false], vec![false, false]]);
+    }
+
+    #[test]
+    fn test_recording_interface_records_win_odds() {
+        let wordbank = bank(&["CRANE", "SLATE", "TRACE", "GRACE", "PLACE", "BRACE"]);
+        let mut interface = RecordingInterface::new()
+            .guess("ZZZZZ", vec![Feedback::NoMatch; 5])
+            .guess("TRACE", get_feedback("TRACE", "GRACE"));
+        game_loop_with_config(&wordbank, &mut interface, &no_cache());
+
+        let odds: Vec<String> = interface
+            .events()
+            .iter()
+            .filter(|e| matches!(e, Event::WinOdds(_)))
+            .map(ToString::to_string)
+            .collect();
+        // Once the pool is down to GRACE and BRACE, the last guesses always find it
+        assert_eq!(odds.len(), 2);
+        assert!(odds[1].starts_with("odds: 100% by "), "{}", odds[1]);
+    }
+
+    #[test]
+    fn test_recording_interface_refuses_dominated_guess() {
+        let wordbank = bank(&["AAAAA", "BBBBB", "CCCCC", "DDDDD"]);
+        let mut interface = RecordingInterface::new()
+            .guess("AAAAA", vec![Feedback::NoMatch; 5])
+            .action(UserAction::Guess("ZZZZZ".to_string()))
+            .confirm(false);
+        game_loop_with_config(&wordbank, &mut interface, &no_cache());
+
+        assert!(
+            interface
+                .events()
+                .iter()
+                .any(|e| matches!(e, Event::DominatedGuess { guess, .. } if guess == "ZZZZZ"))
+        );
+    }
+
+    #[test]
+    fn test_script_passes_when_followed() {
+        let wordbank = bank(&["CRANE", "SLATE", "PLATE", "ELATE"]);
+        let mut interface = TestInterface::new()
+            .expect("starting words: SLATE, PLATE, ELATE, CRANE")
+            .guess("crane", get_feedback("CRANE", "PLATE"))
+            .expect("diff CRANE: 1 eliminated, 3 survived")
+            .expect("candidates: SLATE, PLATE, ELATE")
+            .action(UserAction::NewGame)
+            .expect("new game: 4 words")
+            .expect("exit");
+        game_loop_with_config(&wordbank, &mut interface, &no_cache());
+        interface.finish();
+    }
+
+    #[test]
+    #[should_panic(expected = "- candidates: SLATE")]
+    fn test_script_panics_with_diff_on_deviation() {
+        let wordbank = bank(&["CRANE", "SLATE", "PLATE", "ELATE"]);
+        let mut interface = TestInterface::new()
+            .guess("CRANE", get_feedback("CRANE", "PLATE"))
+            .expect("candidates: SLATE");
+        game_loop_with_config(&wordbank, &mut interface, &no_cache());
+        interface.finish();
+    }
+
+    #[test]
+    #[should_panic(expected = "asked for feedback")]
+    fn test_script_panics_on_wrong_input() {
+        let wordbank = bank(&["CRANE", "SLATE"]);
+        let mut interface = TestInterface::new()
+            .action(UserAction::Guess("CRANE".to_string()))
+            .action(UserAction::Exit);
+        game_loop_with_config(&wordbank, &mut interface, &no_cache());
+    }
+}
@@ -0,0 +1,488 @@
+//! Translatable interface text.
+//!
+//! Every prompt, error and label in the game loop has a [`Message`] with English text built
+//! in. A [`Catalog`] parsed from a TOML file replaces any of them, so the interface can be
+//! translated independently of the wordbank's language; whatever a translation leaves out
+//! stays in English. Text may hold `{name}` placeholders, filled in by [`Message::fill`].
+//!
+//! Translations are tables of strings keyed like the messages, e.g.
+//!
+//! ```toml
+//! [prompt]
+//! feedback = "Feedback eingeben (G=grün, Y=gelb, X=grau, z. B. GYXXG):"
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+macro_rules! messages {
+    ($($(#[$doc:meta])* $name:ident = $key:literal => $english:literal,)*) => {
+        /// A piece of interface text
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum Message {
+            $($(#[$doc])* $name,)*
+        }
+
+        impl Message {
+            /// Every message, in declaration order
+            pub const ALL: &[Self] = &[$(Self::$name,)*];
+
+            /// The dotted key a translation file sets this message with
+            #[must_use]
+            pub const fn key(self) -> &'static str {
+                match self {
+                    $(Self::$name => $key,)*
+                }
+            }
+
+            /// The built-in English text
+            #[must_use]
+            pub const fn english(self) -> &'static str {
+                match self {
+                    $(Self::$name => $english,)*
+                }
+            }
+        }
+    };
+}
+
+messages! {
+    PromptGuess = "prompt.guess" =>
+        "Enter your guess (5 letters, 'hint' for help, 'exit' to quit, or 'next' to start a new game):",
+    PromptFeedback = "prompt.feedback" => "Enter feedback (G=green, Y=yellow, X=gray, e.g. GYXXG):",
+    PromptDominated = "prompt.dominated" =>
+        "Press ENTER to use {guess} anyway, or 'n' to choose another guess:",
+
+    ErrorInvalidGuess = "error.invalid_guess" => "Invalid guess. Please enter 5 letters.",
+    ErrorInvalidFeedback = "error.invalid_feedback" =>
+        "Invalid feedback. Please enter 5 of G, Y, X (or B), 2, 1, 0, or share squares.",
+    ErrorInvalidRounds = "error.invalid_rounds" =>
+        "Invalid rounds. Enter them as GUESS=FEEDBACK, e.g. CRANE=XYGXX SLATE=GGXXY.",
+    ErrorReadInput = "error.read_input" => "Couldn't read input: {error}",
+    ErrorTooManyInvalid = "error.too_many_invalid" =>
+        "Error: giving up after {count} invalid inputs in a row.",
+    ErrorInvalidPage = "error.invalid_page" =>
+        "Invalid page. Use 'diff' or 'diff <page>' (pages start at 1).",
+    ErrorInvalidProbe = "error.invalid_probe" =>
+        "Invalid probe. Enter the letters to test, e.g. 'probe AEIOU'.",
+    ErrorInvalidAvoid = "error.invalid_avoid" =>
+        "Invalid letters. Enter the letters to avoid, e.g. 'avoid S,Y', or 'avoid -' to clear.",
+    ErrorInvalidWhyNot = "error.invalid_why_not" =>
+        "Invalid word. Enter the word to explain, e.g. 'why-not CRANE'.",
+    ErrorInvalidSplit = "error.invalid_split" =>
+        "Invalid pattern. Enter 5 letters with _ for the ones that vary, e.g. 'split _ATCH'.",
+    ErrorInvalidTag = "error.invalid_tag" =>
+        "Give the round and whether its guess was forced or free, e.g. 'tag 1 forced'.",
+    ErrorNoSuchTagRound = "error.no_such_tag_round" =>
+        "There's no round {round} to tag; {rounds} played so far.",
+    ErrorInvalidExport = "error.invalid_export" =>
+        "Give a file to write to, e.g. 'export-candidates candidates.tsv'.",
+    ErrorNothingToExport = "error.nothing_to_export" =>
+        "No candidates to export until feedback has been entered.",
+    ErrorExport = "error.export" => "Couldn't export candidates: {error}",
+    ErrorInvalidRestrict = "error.invalid_restrict" =>
+        "Give a word list to restrict to, e.g. 'restrict themed.txt'.",
+    ErrorRestrict = "error.restrict" => "Couldn't read the word list: {error}",
+    ErrorInvalidCorrect = "error.invalid_correct" =>
+        "Give the round and its right feedback, e.g. 'correct 2 GYXXG'.",
+    ErrorNoSuchRound = "error.no_such_round" =>
+        "There's no round {round} to correct; {rounds} played so far.",
+    ErrorCorrectSolved = "error.correct_solved" =>
+        "The game is solved, so there's nothing to correct.",
+    ErrorNoSuggestion = "error.no_suggestion" => "No recommendation yet. Please enter 5 letters.",
+    ErrorNoRecommendation = "error.no_recommendation" => "No recommendation yet!",
+    ErrorNoDiff = "error.no_diff" => "No feedback entered yet; nothing to compare.",
+    ErrorNoCandidates = "error.no_candidates" => "No candidates remain. Check your inputs.",
+    ErrorGuessLength = "error.guess_length" => "Guess must be exactly {length} letters!",
+    ErrorNotALetter = "error.not_a_letter" =>
+        "Only letters are allowed! ('{char}' is not a letter)",
+    ErrorFeedbackLetter = "error.feedback_letter" =>
+        "Invalid feedback! Use G (green), Y (yellow), or X (gray). ('{char}' is not valid)",
+    ErrorNotFeedback = "error.not_feedback" =>
+        "Only letters G, Y, or X are allowed! ('{char}' is not valid)",
+
+    GameNewGame = "game.new_game" => "New game started. Loaded {count} words.",
+    GameComputing = "game.computing" => "Computing optimal guess, please wait...",
+    GameSolutionFound = "game.solution_found" => "Solution found: {solution}",
+    GameAchievement = "game.achievement" => "Achievement unlocked: {achievement}",
+    GameExiting = "game.exiting" => "Exiting.",
+    GameRecommended = "game.recommended" =>
+        "Recommended guess: {guess} (expected pool size {score}) [{category}]",
+    GameTruncated = "game.truncated" =>
+        "(search stopped early; this is the best guess found so far)",
+    GameDominated = "game.dominated" =>
+        "Warning: {guess} can't be the answer, and {better} (a possible answer) splits the pool at least as well (expected pool size {better_score} vs {score}).",
+    GameCandidates = "game.candidates" => "Possible candidates ({count})",
+    GameProbe = "game.probe" => "Guesses testing the most of {letters}:",
+    GameProbeGuess = "game.probe_guess" =>
+        "{number}. {guess} (tests {tested}; expected pool size {score})",
+    GameProbeNone = "game.probe_none" => "No word in the wordbank has any of {letters}.",
+    GameAvoid = "game.avoid" => "Recommendations now avoid {letters} where they can.",
+    GameAvoidNone = "game.avoid_none" => "Recommendations no longer avoid any letters.",
+    GameExported = "game.exported" => "Wrote {count} candidates to {path}",
+    GameRestrict = "game.restrict" =>
+        "Kept the {remaining} candidates on the list of {listed} words.",
+    GameRestrictNone = "game.restrict_none" =>
+        "None of the {listed} listed words is a candidate, so the list was not applied.",
+    GameCorrected = "game.corrected" =>
+        "Round {round} corrected to {feedback}; candidates rebuilt from every round.",
+    GameOdds = "game.odds" => "If you follow recommendations: {odds}",
+    /// One entry of the list in `game.odds`
+    GameOddsBy = "game.odds_by" => "{percent}% by guess {guess}",
+    GameInformationChart = "game.information_chart" =>
+        "Information per guess (bits gained, out of the most a guess could expect):",
+    /// One bar of `game.information_chart`
+    GameInformationGuess = "game.information_guess" =>
+        "{number}. {guess} {bar} {bits} of {max} bits ({expected} expected)",
+    /// A bar of `game.information_chart` for a guess tagged forced
+    GameInformationForced = "game.information_forced" =>
+        "{number}. {guess} {bar} {bits} of {max} bits ({expected} expected, forced)",
+    GameInformationScore = "game.information_score" =>
+        "Free guesses reached {percent}% of the information they could expect ({forced} forced left out).",
+    GameTaggedForced = "game.tagged_forced" =>
+        "Round {round} ({guess}) tagged forced; it's left out of the post-game score.",
+    GameTaggedFree = "game.tagged_free" =>
+        "Round {round} ({guess}) tagged free; it counts toward the post-game score.",
+    GameConstraints = "game.constraints" =>
+        "Letters still possible: {pattern}; in every candidate: {letters}",
+    GameWhyNotWordbank = "game.why_not_wordbank" =>
+        "{word} is not in the wordbank, so it was never a candidate.",
+    GameWhyNotCandidate = "game.why_not_candidate" =>
+        "{word} is still a candidate, ranked {rank} of {count} by expected pool size.",
+    GameWhyNotRestricted = "game.why_not_restricted" =>
+        "{word} fits every round, but a restrict list left it out.",
+    GameWhyNotEliminated = "game.why_not_eliminated" =>
+        "{word} was ruled out in round {round}, {guess} = {feedback}: {reason}",
+    /// The `{reason}` of `game.why_not_eliminated` for a green tile
+    GameWhyNotGreenHere = "game.why_not_green_here" =>
+        "{letter} is green in position {position}, but {word} has {actual} there.",
+    GameWhyNotYellowNotHere = "game.why_not_yellow_not_here" =>
+        "{letter} is yellow in position {position}, so it isn't there, but {word} has it there.",
+    GameWhyNotYellowInWord = "game.why_not_yellow_in_word" =>
+        "{letter} is in {count} green or yellow tile(s), but {word} has only {has}.",
+    GameWhyNotGrayNotHere = "game.why_not_gray_not_here" =>
+        "{letter} is gray in position {position}, so it isn't there, but {word} has it there.",
+    GameWhyNotGrayTooMany = "game.why_not_gray_too_many" =>
+        "{letter} is gray in position {position}, so the answer has no more {letter} than the {count} green or yellow tile(s), but {word} has {has}.",
+    GameSplitter = "game.splitter" =>
+        "To tell apart the {count} candidates matching {pattern} ({words}), try {guess}: it splits them into {groups} groups.",
+    GameSplitterNone = "game.splitter_none" =>
+        "Fewer than two candidates match {pattern}, so there is nothing to split.",
+    GameTrap = "game.trap" =>
+        "Trap: {count} candidates match {pattern}, so guessing them one at a time could take {count} guesses with {left} left. Play {guess} instead to split them into {groups} groups.",
+    GameRound = "game.round" => "Round {number}: {guess} = {feedback}",
+    /// Shown as the category of a guess that could be the answer
+    GameSolutionCandidate = "game.solution_candidate" => "solution candidate",
+    /// Shown as the category of a guess played only to narrow the pool
+    GameInformationGathering = "game.information_gathering" => "information-gathering",
+    HintProbeLetter = "hint.probe_letter" =>
+        "Hint {level}/{levels}: try a word with the letter {letter}",
+    HintFirstLetter = "hint.first_letter" =>
+        "Hint {level}/{levels}: the best guess starts with {letter}",
+    HintFull = "hint.full" => "Hint {level}/{levels}: play {guess}",
+
+    TuiGuesses = "tui.guesses" => "Guesses",
+    TuiInformation = "tui.information" => "Information",
+    TuiStatus = "tui.status" => "Status",
+    TuiKeyboard = "tui.keyboard" => "Keyboard",
+    TuiStatsTitle = "tui.stats_title" => "Wordbank Stats (F5/ESC: close)",
+    TuiLogTitle = "tui.log_title" => "Log (F12/ESC: close)",
+    TuiGridTitle = "tui.grid_title" => "Letters by Position (F9/ESC: close)",
+    TuiGridPosition = "tui.grid_position" => "Position",
+    TuiGridConfirmed = "tui.grid_confirmed" => "Confirmed",
+    TuiGridRuledOut = "tui.grid_ruled_out" => "Not here",
+    TuiGridCandidates = "tui.grid_candidates" => "Candidates ({count}):",
+    TuiStartingWords = "tui.starting_words" => "Suggested Starting Words:",
+    TuiStartingWord = "tui.starting_word" =>
+        "Suggested starting word: {word} (UP/DOWN to pick one, ENTER to guess it)",
+    TuiBestSoFar = "tui.best_so_far" => "Best so far: {guess} (score: {score}) [refining...]",
+    TuiRecommended = "tui.recommended" => "Recommended: {guess} (score: {score}) [{category}]",
+    TuiCandidates = "tui.candidates" => "Possible candidates ({count}, by {sort}):",
+    TuiMoreCandidates = "tui.more_candidates" => "... and {count} more",
+
+    StatusReady = "status.ready" => "Ready",
+    StatusFirstGuess = "status.first_guess" => "Ready - Enter your first {length}-letter guess",
+    StatusNextGuess = "status.next_guess" => "Enter your next guess (? for a hint)",
+    StatusUseAnyway = "status.use_anyway" => "Use {guess} anyway?",
+    StatusChooseAnother = "status.choose_another" => "Choose another guess",
+    StatusGuessEntered = "status.guess_entered" => "Guess entered: {guess} - Now mark feedback",
+    StatusMarkFeedback = "status.mark_feedback" =>
+        "Mark each letter: G (green), Y (yellow), or X (gray)",
+    StatusConfirmFeedback = "status.confirm_feedback" => "Press ENTER to confirm feedback",
+    StatusFeedbackRecorded = "status.feedback_recorded" => "Feedback recorded",
+    StatusComputing = "status.computing" => "Computing optimal next guess...",
+    StatusRecommendationReady = "status.recommendation_ready" => "Recommendation ready: {guess}",
+    StatusTruncated = "status.truncated" => "Stopped early - best found so far: {guess}",
+    StatusHintShown = "status.hint_shown" => "Hint shown - press ? again for more",
+    StatusGameOver = "status.game_over" => "Game Over - Solution: {solution}",
+    StatusNoCandidates = "status.no_candidates" => "Error: No valid candidates found",
+    StatusNewGame = "status.new_game" => "New game - Enter your first guess",
+    StatusExiting = "status.exiting" => "Exiting application...",
+
+    KeysGuess = "keys.guess" =>
+        "Type your {length}-letter guess | ENTER: Submit | TAB: Use recommendation | ?: Hint | F2: Candidate diff | F3: Sort | F4: Details | F5: Stats | F6/F7: Copy guess/grid | F8: Export candidates | F9: Letters by position | F12: Log | ESC: Quit",
+    KeysConfirmGuess = "keys.confirm_guess" =>
+        "ENTER: Use this guess anyway | N/BACKSPACE: Choose another guess",
+    KeysFeedback = "keys.feedback" =>
+        "G: Green (correct) | Y: Yellow (wrong position) | X: Gray (not in word) | Ctrl+V: Paste | BACKSPACE: Go back",
+    KeysConfirmFeedback = "keys.confirm_feedback" =>
+        "ENTER: Confirm feedback | BACKSPACE: Go back and edit",
+    KeysWaiting = "keys.waiting" => "Press any key to continue | ESC: Quit",
+    KeysGameOver = "keys.game_over" => "N: New Game | F7: Copy share grid | ESC: Quit",
+}
+
+impl Message {
+    /// The message with this key, if there is one
+    #[must_use]
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|message| message.key() == key)
+    }
+
+    /// This message's text in the installed catalog, or in English if none is installed
+    #[must_use]
+    pub fn text(self) -> &'static str {
+        CATALOG
+            .get()
+            .map_or(self.english(), |catalog| catalog.get(self))
+    }
+
+    /// [`Self::text`] with each `{name}` placeholder replaced by its value in `args`
+    #[must_use]
+    pub fn fill(self, args: &[(&str, &dyn fmt::Display)]) -> String {
+        fill(self.text(), args)
+    }
+}
+
+/// Replaces each `{name}` in `template` with its value in `args`, leaving unknown placeholders
+/// as they are.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::i18n::fill;
+///
+/// let text = fill("Round {number}: {guess}", &[("number", &2), ("guess", &"CRANE")]);
+/// assert_eq!(text, "Round 2: CRANE");
+/// ```
+#[must_use]
+pub fn fill(template: &str, args: &[(&str, &dyn fmt::Display)]) -> String {
+    let mut text = template.to_string();
+    for (name, value) in args {
+        text = text.replace(&format!("{{{name}}}"), &value.to_string());
+    }
+    text
+}
+
+/// The `{name}` placeholders in `text`
+fn placeholders(text: &str) -> Vec<&str> {
+    text.split('{')
+        .skip(1)
+        .filter_map(|rest| rest.split_once('}').map(|(name, _)| name))
+        .collect()
+}
+
+/// Translated text for some or all messages
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Catalog {
+    text: HashMap<Message, String>,
+}
+
+impl Catalog {
+    /// Parses a TOML translation, describing each entry that had to be skipped: unknown keys,
+    /// values that aren't strings, and placeholders the English text doesn't have.
+    ///
+    /// # Errors
+    /// Returns an error if `data` isn't valid TOML.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wordle_solver::i18n::{Catalog, Message};
+    ///
+    /// let data = "[game]\nexiting = \"Tschüss.\"\nsolution_found = \"Lösung: {answer}\"";
+    /// let (catalog, problems) = Catalog::parse(data).unwrap();
+    /// assert_eq!(catalog.get(Message::GameExiting), "Tschüss.");
+    /// assert_eq!(catalog.get(Message::GameSolutionFound), "Solution found: {solution}");
+    /// assert_eq!(problems, ["game.solution_found: unknown placeholder {answer}"]);
+    /// ```
+    pub fn parse(data: &str) -> Result<(Self, Vec<String>), String> {
+        let table: toml::Table = data
+            .parse()
+            .map_err(|e: toml::de::Error| e.message().to_string())?;
+        let mut catalog = Self::default();
+        let mut problems = Vec::new();
+        let mut entries: Vec<(String, toml::Value)> = table.into_iter().collect();
+        while let Some((key, value)) = entries.pop() {
+            match value {
+                toml::Value::Table(table) => entries.extend(
+                    table
+                        .into_iter()
+                        .map(|(name, value)| (format!("{key}.{name}"), value)),
+                ),
+                toml::Value::String(text) => match Message::from_key(&key) {
+                    Some(message) => {
+                        let known = placeholders(message.english());
+                        match placeholders(&text)
+                            .into_iter()
+                            .find(|name| !known.contains(name))
+                        {
+                            Some(name) => {
+                                problems.push(format!("{key}: unknown placeholder {{{name}}}"));
+                            }
+                            None => {
+                                catalog.text.insert(message, text);
+                            }
+                        }
+                    }
+                    None => problems.push(format!("{key}: unknown message")),
+                },
+                _ => problems.push(format!("{key}: expected a string")),
+            }
+        }
+        problems.sort();
+        Ok((catalog, problems))
+    }
+
+    /// Text for `message`, in English if this catalog doesn't translate it
+    #[must_use]
+    pub fn get(&self, message: Message) -> &str {
+        self.text
+            .get(&message)
+            .map_or(message.english(), String::as_str)
+    }
+
+    /// Messages this catalog leaves in English
+    #[must_use]
+    pub fn untranslated(&self) -> Vec<Message> {
+        Message::ALL
+            .iter()
+            .copied()
+            .filter(|message| !self.text.contains_key(message))
+            .collect()
+    }
+}
+
+static CATALOG: OnceLock<Catalog> = OnceLock::new();
+
+/// Makes `catalog` the text every [`Message`] shows from now on.
+///
+/// # Errors
+/// Returns `catalog` back if one is already installed; only the first call has an effect.
+pub fn install(catalog: Catalog) -> Result<(), Catalog> {
+    CATALOG.set(catalog)
+}
+
+/// Translations shipped with the binary, by language code
+const BUILT_IN: &[(&str, &str)] = &[("de", include_str!("../locales/de.toml"))];
+
+/// Directory searched for `<language>.toml` translations that aren't built in
+#[must_use]
+pub fn get_wordle_locales_path() -> Option<PathBuf> {
+    dirs::home_dir().map(|mut path| {
+        path.push(".wordle_locales");
+        path
+    })
+}
+
+/// Loads the translation for `language`: `en` for the built-in English, a built-in language
+/// code, a code with a `<code>.toml` file in `~/.wordle_locales`, or a path to a `.toml` file.
+/// Returns the catalog along with any entries [`Catalog::parse`] skipped.
+///
+/// # Errors
+/// Returns an error if no translation is found or it can't be read or parsed.
+pub fn load_catalog(language: &str) -> Result<(Catalog, Vec<String>), String> {
+    if language.eq_ignore_ascii_case("en") {
+        return Ok((Catalog::default(), Vec::new()));
+    }
+    if let Some((_, data)) = BUILT_IN
+        .iter()
+        .find(|(code, _)| code.eq_ignore_ascii_case(language))
+    {
+        return Catalog::parse(data);
+    }
+    let path = if Path::new(language)
+        .extension()
+        .is_some_and(|ext| ext == "toml")
+    {
+        PathBuf::from(language)
+    } else {
+        get_wordle_locales_path()
+            .ok_or_else(|| format!("no translation for '{language}'"))?
+            .join(format!("{language}.toml"))
+    };
+    let data = std::fs::read_to_string(&path)
+        .map_err(|e| format!("no translation for '{language}' ({}: {e})", path.display()))?;
+    Catalog::parse(&data).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keys_are_unique_and_round_trip() {
+        for message in Message::ALL {
+            assert_eq!(Message::from_key(message.key()), Some(*message));
+        }
+        assert_eq!(Message::from_key("game"), None);
+    }
+
+    #[test]
+    fn test_parse_skips_bad_entries() {
+        let data = "colour = \"Farbe\"\n[error]\ninvalid_guess = 5\nno_diff = \"Noch nichts zu vergleichen.\"";
+        let (catalog, problems) = Catalog::parse(data).unwrap();
+        assert_eq!(
+            catalog.get(Message::ErrorNoDiff),
+            "Noch nichts zu vergleichen."
+        );
+        assert_eq!(
+            catalog.get(Message::ErrorInvalidGuess),
+            Message::ErrorInvalidGuess.english()
+        );
+        assert_eq!(
+            problems,
+            [
+                "colour: unknown message",
+                "error.invalid_guess: expected a string"
+            ]
+        );
+        assert_eq!(catalog.untranslated().len(), Message::ALL.len() - 1);
+        assert!(Catalog::parse("[game").is_err());
+    }
+
+    #[test]
+    fn test_built_in_translations_are_complete() {
+        for (code, _) in BUILT_IN {
+            let (catalog, problems) = load_catalog(code).unwrap();
+            assert!(problems.is_empty(), "{code}: {problems:?}");
+            assert!(
+                catalog.untranslated().is_empty(),
+                "{code}: {:?}",
+                catalog.untranslated()
+            );
+            for message in Message::ALL {
+                let mut expected = placeholders(message.english());
+                let mut found = placeholders(catalog.get(*message));
+                expected.sort_unstable();
+                found.sort_unstable();
+                assert_eq!(found, expected, "{code}: {}", message.key());
+            }
+        }
+    }
+
+    #[test]
+    fn test_load_catalog_from_path() {
+        assert_eq!(load_catalog("EN").unwrap().0, Catalog::default());
+        let path = std::env::temp_dir().join("test_wordle_locale.toml");
+        std::fs::write(&path, "[game]\nexiting = \"Au revoir.\"").unwrap();
+        let (catalog, _) = load_catalog(path.to_str().unwrap()).unwrap();
+        assert_eq!(catalog.get(Message::GameExiting), "Au revoir.");
+        std::fs::remove_file(&path).unwrap();
+        assert!(load_catalog(path.to_str().unwrap()).is_err());
+    }
+}
@@ -0,0 +1,277 @@
+//! How much each guess of a finished game narrowed the pool, in bits.
+//!
+//! A guess that leaves half the candidates gains one bit. No guess can be expected to gain
+//! more than `log2` of the number of feedback patterns it could split the pool into, so
+//! [`information_chart`] draws each guess against that ceiling to show which guesses left
+//! information on the table.
+//!
+//! Guesses can be tagged forced, such as an opener played every day whatever the solver
+//! says. The chart still shows them, but [`information_score`] only rates the free ones.
+
+use crate::i18n::Message;
+use crate::solver::{Feedback, PATTERN_COUNT, feedback_code, filter_candidates};
+use std::collections::HashMap;
+
+/// Width of the longest bar in [`information_chart`]
+pub const CHART_WIDTH: usize = 20;
+
+/// What one guess of a game told the player
+#[derive(Clone, Debug, PartialEq)]
+pub struct GuessInformation {
+    pub guess: String,
+    /// Candidates before the guess
+    pub pool_before: usize,
+    /// Candidates left by its feedback
+    pub pool_after: usize,
+    /// Bits the feedback actually gained
+    pub bits: f64,
+    /// Bits the guess was expected to gain, averaged over the candidates it could have met
+    pub expected_bits: f64,
+    /// Most bits any guess could be expected to gain from this pool
+    pub max_bits: f64,
+    /// Played for reasons of the player's own, such as a fixed opener, rather than chosen
+    pub forced: bool,
+}
+
+/// Bits gained by narrowing `before` candidates to `after`. Leaving none counts as leaving
+/// one, since the game is over either way.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::information::bits_gained;
+///
+/// assert_eq!(bits_gained(8, 1), 3.0);
+/// assert_eq!(bits_gained(8, 8), 0.0);
+/// assert_eq!(bits_gained(8, 0), 3.0);
+/// ```
+#[allow(clippy::cast_precision_loss)] // pools are far smaller than 2^52
+#[must_use]
+pub fn bits_gained(before: usize, after: usize) -> f64 {
+    (before as f64 / after.max(1) as f64).log2()
+}
+
+/// Entropy in bits of the feedback `guess` gets across `candidates`, each equally likely
+/// to be the answer
+#[allow(clippy::cast_precision_loss)]
+#[must_use]
+pub fn expected_bits(guess: &str, candidates: &[String]) -> f64 {
+    let mut pattern_counts: HashMap<u8, usize> = HashMap::new();
+    for solution in candidates {
+        *pattern_counts
+            .entry(feedback_code(guess.as_bytes(), solution.as_bytes()))
+            .or_default() += 1;
+    }
+    let total = candidates.len() as f64;
+    let mut counts: Vec<usize> = pattern_counts.into_values().collect();
+    // Summed in a fixed order, so the map's iteration order can't change the result
+    counts.sort_unstable();
+    counts
+        .iter()
+        .map(|&count| {
+            let p = count as f64 / total;
+            -p * p.log2()
+        })
+        .sum::<f64>()
+}
+
+/// Replays `history` over `wordbank` and works out what each guess told the player.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::information::information_per_guess;
+/// use wordle_solver::get_feedback;
+///
+/// let wordbank: Vec<String> = ["BATCH", "CATCH", "HATCH", "CLIMB"]
+///     .iter()
+///     .map(|s| s.to_string())
+///     .collect();
+/// let history = vec![("CLIMB".to_string(), get_feedback("CLIMB", "HATCH"))];
+/// let info = information_per_guess(&wordbank, &history);
+/// assert_eq!((info[0].pool_before, info[0].pool_after), (4, 1));
+/// assert_eq!(info[0].bits, 2.0);
+/// ```
+#[must_use]
+pub fn information_per_guess(
+    wordbank: &[String],
+    history: &[(String, Vec<Feedback>)],
+) -> Vec<GuessInformation> {
+    let mut candidates = wordbank.to_vec();
+    history
+        .iter()
+        .map(|(guess, feedback)| {
+            let remaining = filter_candidates(&candidates, guess, feedback);
+            #[allow(clippy::cast_precision_loss)]
+            let max_bits = (candidates.len().min(PATTERN_COUNT) as f64).log2();
+            let info = GuessInformation {
+                guess: guess.clone(),
+                pool_before: candidates.len(),
+                pool_after: remaining.len(),
+                bits: bits_gained(candidates.len(), remaining.len()),
+                expected_bits: expected_bits(guess, &candidates),
+                max_bits: max_bits.max(0.0),
+                forced: false,
+            };
+            candidates = remaining;
+            info
+        })
+        .collect()
+}
+
+/// How close the free guesses came to the most information they could expect, from 0 to 1:
+/// the mean of each one's expected bits over its ceiling. `None` if no free guess had more
+/// than one candidate to narrow.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::information::{information_per_guess, information_score};
+/// use wordle_solver::get_feedback;
+///
+/// let wordbank: Vec<String> = ["BATCH", "CATCH", "HATCH", "CLIMB"]
+///     .iter()
+///     .map(|s| s.to_string())
+///     .collect();
+/// let history = vec![("CLIMB".to_string(), get_feedback("CLIMB", "HATCH"))];
+/// let mut info = information_per_guess(&wordbank, &history);
+/// assert_eq!(information_score(&info), Some(1.0));
+/// info[0].forced = true;
+/// assert_eq!(information_score(&info), None);
+/// ```
+#[must_use]
+pub fn information_score(info: &[GuessInformation]) -> Option<f64> {
+    let shares: Vec<f64> = info
+        .iter()
+        .filter(|guess| !guess.forced && guess.max_bits > 0.0)
+        .map(|guess| guess.expected_bits / guess.max_bits)
+        .collect();
+    #[allow(clippy::cast_precision_loss)] // a handful of guesses
+    (!shares.is_empty()).then(|| shares.iter().sum::<f64>() / shares.len() as f64)
+}
+
+/// One line per guess: a bar of the bits it gained (`█`) within the most it could have been
+/// expected to (`░`), scaled so the largest ceiling is [`CHART_WIDTH`] wide. Forced guesses
+/// are marked, and a last line gives the [`information_score`] when there is one.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::information::{GuessInformation, information_chart};
+///
+/// let info = GuessInformation {
+///     guess: "CLIMB".to_string(),
+///     pool_before: 4,
+///     pool_after: 1,
+///     bits: 2.0,
+///     expected_bits: 2.0,
+///     max_bits: 2.0,
+///     forced: false,
+/// };
+/// let chart = information_chart(&[info]);
+/// assert!(chart[1].starts_with(&format!("1. CLIMB {}", "█".repeat(20))));
+/// ```
+#[must_use]
+pub fn information_chart(info: &[GuessInformation]) -> Vec<String> {
+    let scale = info.iter().map(|guess| guess.max_bits).fold(0.0, f64::max);
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // within 0..=CHART_WIDTH
+    let columns = |bits: f64| {
+        if scale > 0.0 {
+            (bits.clamp(0.0, scale) / scale * CHART_WIDTH as f64).round() as usize
+        } else {
+            0
+        }
+    };
+    let mut lines = vec![Message::GameInformationChart.text().to_string()];
+    for (i, guess) in info.iter().enumerate() {
+        let ceiling = columns(guess.max_bits);
+        let gained = columns(guess.bits).min(ceiling);
+        let bar = format!(
+            "{}{}{}",
+            "█".repeat(gained),
+            "░".repeat(ceiling - gained),
+            " ".repeat(CHART_WIDTH - ceiling)
+        );
+        let message = if guess.forced {
+            Message::GameInformationForced
+        } else {
+            Message::GameInformationGuess
+        };
+        lines.push(message.fill(&[
+            ("number", &(i + 1)),
+            ("guess", &guess.guess),
+            ("bar", &bar),
+            ("bits", &format!("{:.1}", guess.bits)),
+            ("max", &format!("{:.1}", guess.max_bits)),
+            ("expected", &format!("{:.1}", guess.expected_bits)),
+        ]));
+    }
+    if let Some(score) = information_score(info) {
+        let forced = info.iter().filter(|guess| guess.forced).count();
+        lines.push(Message::GameInformationScore.fill(&[
+            ("percent", &format!("{:.0}", score * 100.0)),
+            ("forced", &forced),
+        ]));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::get_feedback;
+    use crate::test_util::words;
+    use crate::wordbank::sample_wordbank;
+
+    #[test]
+    fn test_expected_bits_of_a_split() {
+        let candidates = words(&["BATCH", "CATCH", "HATCH", "CLIMB"]);
+        // CLIMB tells all four apart; BATCH leaves CATCH and HATCH together
+        assert_eq!(expected_bits("CLIMB", &candidates), 2.0);
+        assert_eq!(expected_bits("BATCH", &candidates), 1.5);
+        assert_eq!(expected_bits("ZZZZZ", &candidates), 0.0);
+    }
+
+    #[test]
+    fn test_information_per_guess_follows_the_pool() {
+        let wordbank = sample_wordbank();
+        let history: Vec<(String, Vec<Feedback>)> = ["CRANE", "MOIST", "QUOTA"]
+            .iter()
+            .map(|guess| (guess.to_string(), get_feedback(guess, "QUOTA")))
+            .collect();
+        let info = information_per_guess(&wordbank, &history);
+        assert_eq!(info.len(), 3);
+        assert_eq!(info[0].pool_before, wordbank.len());
+        for pair in info.windows(2) {
+            assert_eq!(pair[0].pool_after, pair[1].pool_before);
+        }
+        assert_eq!(info[2].pool_after, 1);
+        for guess in &info {
+            assert!(guess.expected_bits <= guess.max_bits + 1e-9, "{guess:?}");
+        }
+
+        let chart = information_chart(&info);
+        // A title, a bar per guess and the score
+        assert_eq!(chart.len(), 5);
+        assert!(chart[3].contains("QUOTA"));
+        assert!(information_chart(&[]).len() == 1);
+    }
+
+    #[test]
+    fn test_forced_guesses_are_not_scored() {
+        let wordbank = sample_wordbank();
+        let history: Vec<(String, Vec<Feedback>)> = ["QUOTA", "SLATE", "SHALE"]
+            .iter()
+            .map(|guess| (guess.to_string(), get_feedback(guess, "SHALE")))
+            .collect();
+        let mut info = information_per_guess(&wordbank, &history);
+        let free = information_score(&info[1..]);
+        info[0].forced = true;
+        assert_eq!(information_score(&info), free);
+
+        let chart = information_chart(&info);
+        assert!(chart[1].contains("forced"), "{}", chart[1]);
+        assert!(!chart[2].contains("forced"), "{}", chart[2]);
+        assert!(chart.last().unwrap().contains("1 forced"), "{chart:?}");
+    }
+}
@@ -1,15 +1,53 @@
 // Library interface for wordle-solver
 // This allows integration tests to access internal modules
 
+#[cfg(feature = "cli")]
 pub mod cli;
+pub mod clipboard;
+pub mod cluster;
+pub mod config;
+#[cfg(feature = "tui")]
+pub mod doctor;
+pub mod elimination;
+pub mod exit;
+pub mod export;
+pub mod external;
+pub mod game;
 pub mod game_state;
-pub mod logging;
+pub mod headless;
+pub mod i18n;
+pub mod information;
+#[cfg(feature = "tui")]
+pub mod log_buffer;
+pub mod noise;
+pub mod odds;
+pub mod play;
+pub mod precomputed;
+pub mod progress;
+pub mod puzzle;
+#[cfg(feature = "tui")]
+pub mod recording;
+pub mod rng;
+#[cfg(feature = "scripting")]
+pub mod script;
+pub mod session;
+pub mod setup;
+pub mod share;
 pub mod solver;
+pub mod sources;
+pub mod stats;
+#[cfg(test)]
+mod test_util;
+#[cfg(feature = "tui")]
+pub mod tui;
 pub mod wordbank;
+pub mod words;
 
 // Re-export commonly used functions for easier testing
 pub use game_state::game_loop;
 pub use solver::{
-    Feedback, best_information_guess, compute_best_starting_words, filter_candidates, get_feedback,
+    Feedback, WordleError, best_information_guess, compute_best_starting_words, filter_candidates,
+    get_feedback, partition_candidates, try_filter_candidates, try_get_feedback,
 };
 pub use wordbank::{load_wordbank_from_file, load_wordbank_from_str};
+pub use words::{Word, WordBank};
@@ -0,0 +1,154 @@
+//! In-memory tail of the trace log.
+//!
+//! The TUI runs on the alternate screen, so anything written to stderr is hidden while it is
+//! up. A [`LogBuffer`] keeps the most recent trace lines in memory instead, where the TUI's
+//! log panel can show them. It is a `tracing_subscriber` writer, so it can sit alongside the
+//! `--trace-output` file.
+
+use std::collections::VecDeque;
+use std::io;
+use std::sync::{Arc, Mutex, PoisonError};
+use tracing_subscriber::fmt::MakeWriter;
+
+/// Lines kept by default
+pub const LOG_BUFFER_LINES: usize = 500;
+
+/// Shared ring buffer of the most recent log lines.
+///
+/// Clones share the buffer, so one can be given to the subscriber and another to the TUI.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+/// use wordle_solver::log_buffer::LogBuffer;
+///
+/// let buffer = LogBuffer::new(2);
+/// let mut writer = buffer.writer();
+/// writer.write_all(b"one\ntwo\nthree\n").unwrap();
+/// assert_eq!(buffer.tail(5), ["two", "three"]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct LogBuffer {
+    lines: Arc<Mutex<VecDeque<String>>>,
+    capacity: usize,
+}
+
+impl Default for LogBuffer {
+    fn default() -> Self {
+        Self::new(LOG_BUFFER_LINES)
+    }
+}
+
+impl LogBuffer {
+    /// A buffer keeping the last `capacity` lines
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            lines: Arc::new(Mutex::new(VecDeque::with_capacity(capacity))),
+            capacity,
+        }
+    }
+
+    /// Adds a line, dropping the oldest once full
+    pub fn push(&self, line: &str) {
+        // A panic mid-push leaves at worst a missing line, so poisoning is harmless here
+        let mut lines = self.lines.lock().unwrap_or_else(PoisonError::into_inner);
+        if lines.len() == self.capacity {
+            lines.pop_front();
+        }
+        if self.capacity > 0 {
+            lines.push_back(line.to_string());
+        }
+    }
+
+    /// The last `n` lines, oldest first
+    #[must_use]
+    pub fn tail(&self, n: usize) -> Vec<String> {
+        let lines = self.lines.lock().unwrap_or_else(PoisonError::into_inner);
+        lines
+            .iter()
+            .skip(lines.len().saturating_sub(n))
+            .cloned()
+            .collect()
+    }
+
+    /// A writer that adds each line written to it to the buffer
+    #[must_use]
+    pub fn writer(&self) -> LogBufferWriter {
+        LogBufferWriter {
+            buffer: self.clone(),
+            partial: Vec::new(),
+        }
+    }
+}
+
+impl<'a> MakeWriter<'a> for LogBuffer {
+    type Writer = LogBufferWriter;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.writer()
+    }
+}
+
+/// Splits what is written into lines for a [`LogBuffer`]. Anything after the last newline is
+/// added when the writer is flushed or dropped.
+pub struct LogBufferWriter {
+    buffer: LogBuffer,
+    partial: Vec<u8>,
+}
+
+impl io::Write for LogBufferWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.partial.extend_from_slice(buf);
+        while let Some(end) = self.partial.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = self.partial.drain(..=end).collect();
+            self.buffer
+                .push(String::from_utf8_lossy(&line[..end]).trim_end_matches('\r'));
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if !self.partial.is_empty() {
+            self.buffer.push(&String::from_utf8_lossy(&self.partial));
+            self.partial.clear();
+        }
+        Ok(())
+    }
+}
+
+impl Drop for LogBufferWriter {
+    fn drop(&mut self) {
+        let _ = io::Write::flush(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn test_keeps_only_the_last_lines() {
+        let buffer = LogBuffer::new(3);
+        for i in 0..5 {
+            buffer.push(&i.to_string());
+        }
+        assert_eq!(buffer.tail(10), ["2", "3", "4"]);
+        assert_eq!(buffer.tail(1), ["4"]);
+        assert!(LogBuffer::new(0).tail(1).is_empty());
+    }
+
+    #[test]
+    fn test_writer_joins_partial_writes() {
+        let buffer = LogBuffer::default();
+        {
+            let mut writer = buffer.make_writer();
+            writer.write_all(b"first ha").unwrap();
+            writer.write_all(b"lf\r\nsecond").unwrap();
+            assert_eq!(buffer.tail(5), ["first half"]);
+        }
+        assert_eq!(buffer.tail(5), ["first half", "second"]);
+    }
+}
@@ -1,86 +1,515 @@
-mod cli;
-mod game_state;
-#[macro_use]
-mod logging;
-mod solver;
-mod tui;
-mod wordbank;
-
-use cli::{CliInterface, UiMode, parse_cli};
-use game_state::game_loop;
+use std::fs::File;
 use std::io;
-use tui::TuiWrapper;
-use wordbank::load_wordbank;
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tracing::info;
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::fmt::writer::{BoxMakeWriter, MakeWriterExt};
+use wordle_solver::cli::{
+    CliInterface, Command, OutputOptions, UiMode, WordbankCommand, display_share_analysis,
+    display_timing, display_wordbank_stats, parse_cli,
+};
+use wordle_solver::config::{Config, get_wordle_config_path};
+use wordle_solver::doctor::{Status, run_checks};
+use wordle_solver::exit::{self, game_exit_code};
+use wordle_solver::game::Game;
+use wordle_solver::game_state::{GameResult, SessionOptions, game_loop_with_observers};
+use wordle_solver::i18n::{Message, install, load_catalog};
+use wordle_solver::log_buffer::LogBuffer;
+use wordle_solver::play::{GuessTimer, Race, TimeoutAction, Versus, pick_answer};
+use wordle_solver::precomputed;
+use wordle_solver::progress::{CancelToken, NoProgress};
+use wordle_solver::puzzle::{PuzzleInfo, puzzle_for};
+use wordle_solver::recording::{Recorder, Replay};
+use wordle_solver::rng::Rng;
+use wordle_solver::setup::Setup;
+use wordle_solver::share::{analyze_share, parse_share_grid, parse_share_number};
+use wordle_solver::solver::{
+    DEFAULT_STARTING_WORDS, SolverConfig, Strategy, resume_scored_starting_words,
+};
+use wordle_solver::stats::{Stats, get_wordle_stats_path};
+use wordle_solver::tui::{
+    InterruptSaver, TuiWrapper, exit_on_interrupt, install_panic_hook, run_play,
+};
+use wordle_solver::wordbank::{
+    WordbankDiff, WordbankStats, encode_wordbank, get_wordle_start_path, load_wordbank,
+    load_wordbank_quietly, read_cached_starting_words, read_starting_checkpoint,
+    read_wordbank_entries, starting_checkpoint_path, wordbank_source, write_cached_starting_words,
+    write_starting_checkpoint,
+};
+use wordle_solver::words::WordBank;
 
 fn main() {
-    // Initialize logger only in debug builds
-    #[cfg(debug_assertions)]
-    {
-        use std::io::Write;
-
-        let log_file = std::fs::File::create("output.txt").expect("Failed to create log file");
-        let log_file = std::io::LineWriter::new(log_file); // Use LineWriter for immediate flushing
-
-        env_logger::Builder::from_default_env()
-            .target(env_logger::Target::Pipe(Box::new(log_file)))
-            .filter_level(log::LevelFilter::Debug)
-            .format(|buf, record| {
-                writeln!(
-                    buf,
-                    "[{} {} {}:{}] {}",
-                    chrono::Local::now().format("%Y-%m-%d %H:%M:%S%.3f"),
-                    record.level(),
-                    record.file().unwrap_or("unknown"),
-                    record.line().unwrap_or(0),
-                    record.args()
-                )
-            })
-            .init();
-
-        info_log!("Application started");
+    let cli = parse_cli();
+    let log_buffer = init_tracing(cli.trace_output.as_deref());
+    info!("Application started");
+
+    // Raw mode swallows SIGINT, so this mostly fires in CLI mode; the TUI handles Ctrl+C as a key
+    if let Err(e) = ctrlc::set_handler(|| exit_on_interrupt()) {
+        eprintln!("Warning: could not install Ctrl+C handler: {e}");
     }
 
-    let cli = parse_cli();
-    info_log!(
+    info!(
         "CLI parsed: ui_mode={:?}, wordbank_path={:?}",
-        cli.ui_mode,
-        cli.wordbank_path
+        cli.ui_mode, cli.wordbank_path
     );
 
-    match cli.ui_mode {
+    init_language(cli.language.as_deref());
+
+    if let Some(command @ (Command::Versus | Command::Race)) = cli.command {
+        let wordbank = load_wordbank(cli.wordbank_path);
+        // A seed makes the hidden words repeat, e.g. for a rematch on the same words
+        let mut rng = cli.seed.map_or_else(Rng::from_entropy, Rng::new);
+        let answer = pick_answer(&wordbank, &mut rng);
+        let is_race = matches!(command, Command::Race);
+        // The solver's opener is only needed if it might have to play
+        let opener = if is_race || cli.on_timeout == TimeoutAction::Recommend {
+            solver_opener(&wordbank, cli.no_cache, cli.recompute)
+        } else {
+            String::new()
+        };
+        let timer = cli
+            .guess_timer
+            .map(|secs| GuessTimer::new(Duration::from_secs(secs), cli.on_timeout, opener.clone()));
+        install_panic_hook();
+        let result = if is_race {
+            run_play(
+                &wordbank,
+                rng,
+                Race::new(answer, opener),
+                timer,
+                cli.accessible_tiles,
+            )
+        } else {
+            run_play(
+                &wordbank,
+                rng,
+                Versus::new(answer),
+                timer,
+                cli.accessible_tiles,
+            )
+        };
+        if let Err(e) = result {
+            eprintln!("TUI Error: {e}");
+            std::process::exit(exit::FAILURE);
+        }
+        return;
+    }
+
+    if let Some(Command::Doctor) = cli.command {
+        let checks = run_checks(wordbank_source(cli.wordbank_path).as_ref());
+        for check in &checks {
+            println!("{check}");
+        }
+        if checks.iter().any(|check| check.status == Status::Error) {
+            std::process::exit(exit::FAILURE);
+        }
+        return;
+    }
+
+    if let Some(Command::Setup) = cli.command {
+        let wordbank = load_wordbank_quietly(cli.wordbank_path.clone());
+        println!(
+            "{}",
+            Setup::new(&cli.solver_config(), &cli.session_options(), &wordbank)
+        );
+        return;
+    }
+
+    if let Some(Command::Streak) = cli.command {
+        let stats = get_wordle_stats_path()
+            .map(|path| Stats::load(&path))
+            .unwrap_or_default();
+        println!("{}", stats.daily_streak(chrono::Local::now().date_naive()));
+        return;
+    }
+
+    if let Some(Command::Puzzle) = cli.command {
+        match puzzle_for(chrono::Local::now().date_naive()) {
+            Some((puzzle, true)) => println!("{puzzle}"),
+            Some((puzzle, false)) => println!("{puzzle} (offline, worked out from the date)"),
+            None => {
+                eprintln!("Your clock is set to before Wordle launched.");
+                std::process::exit(exit::FAILURE);
+            }
+        }
+        return;
+    }
+
+    if let Some(Command::ImportShare { answer }) = cli.command {
+        let wordbank = load_wordbank(cli.wordbank_path);
+        if let Err(e) = import_share(&wordbank, &answer) {
+            eprintln!("Couldn't analyse the share grid: {e}");
+            std::process::exit(exit::INPUT_ERROR);
+        }
+        return;
+    }
+
+    if let Some(Command::Wordbank { action }) = cli.command {
+        match action {
+            WordbankCommand::Stats => {
+                let wordbank = load_wordbank(cli.wordbank_path);
+                display_wordbank_stats(&WordbankStats::new(&wordbank));
+            }
+            WordbankCommand::Compile { output } => {
+                let wordbank = load_wordbank(cli.wordbank_path);
+                if let Err(e) = compile_wordbank(&wordbank, &output) {
+                    eprintln!("Failed to write '{}': {e}", output.display());
+                    std::process::exit(exit::FAILURE);
+                }
+            }
+            // Compares the two files given, not the loaded wordbank
+            WordbankCommand::Diff { old, new } => diff_wordbanks(&old, &new),
+        }
+        return;
+    }
+
+    let config = cli.solver_config();
+    let mut session = cli.session_options();
+    session.stats_path = get_wordle_stats_path();
+    session.initial_rounds = cli.initial_rounds().unwrap_or_else(|e| {
+        eprintln!("{e}");
+        std::process::exit(exit::INPUT_ERROR);
+    });
+    let saved_game = cli.resume.as_deref().map(|path| {
+        std::fs::read_to_string(path).unwrap_or_else(|e| {
+            eprintln!("Couldn't read the saved game {}: {e}", path.display());
+            std::process::exit(exit::INPUT_ERROR);
+        })
+    });
+    #[cfg(feature = "scripting")]
+    if let Some(script) = &config.script {
+        // Tried once up front, like the external scorer, so a failing script is reported early
+        let probe = vec!["CRANE".to_string()];
+        if let Err(e) = script.scores(&probe, &probe) {
+            eprintln!("Script: {e}");
+            std::process::exit(exit::INPUT_ERROR);
+        }
+    }
+    if let Some(scorer) = &config.external_scorer {
+        // Tried once up front, so a scorer that can't run is reported before the game starts
+        let probe = vec!["CRANE".to_string()];
+        if let Err(e) = scorer.scores(&probe, &probe, config.budget.max_time, &CancelToken::new()) {
+            eprintln!("External scorer: {e}");
+            std::process::exit(exit::INPUT_ERROR);
+        }
+    }
+    info!("Solver config: {:?}", config);
+    info!("Session options: {:?}", session);
+
+    let output = cli.output_options();
+    let result = match cli.ui_mode {
         UiMode::Cli => {
-            info_log!("Starting CLI mode");
+            info!("Starting CLI mode");
             // Use CLI mode
-            app_cli(cli.wordbank_path);
+            app_cli(
+                cli.wordbank_path,
+                &config,
+                &session,
+                saved_game.as_deref(),
+                output,
+                cli.setup.as_ref(),
+            )
         }
         UiMode::Tui => {
-            info_log!("Starting TUI mode");
+            info!("Starting TUI mode");
             // Use TUI mode (default)
             let wordbank_path = cli.wordbank_path;
-            if let Err(e) = app_tui(wordbank_path.clone()) {
-                eprintln!("TUI Error: {e}. Falling back to CLI mode.");
-                info_log!("TUI failed with error: {}, falling back to CLI", e);
-                app_cli(wordbank_path);
+            let options = TuiOptions {
+                time: cli.time,
+                accessible: cli.accessible,
+                tile_glyphs: cli.accessible_tiles,
+                recorder: cli.record.as_deref().map(|path| {
+                    Recorder::create(path).unwrap_or_else(|e| {
+                        eprintln!("Couldn't record to {}: {e}", path.display());
+                        std::process::exit(exit::INPUT_ERROR);
+                    })
+                }),
+                replay: cli.replay.as_deref().map(|path| {
+                    Replay::load(path, cli.replay_speed).unwrap_or_else(|e| {
+                        eprintln!("Couldn't replay {}: {e}", path.display());
+                        std::process::exit(exit::INPUT_ERROR);
+                    })
+                }),
+            };
+            match app_tui(
+                wordbank_path.clone(),
+                &config,
+                &session,
+                saved_game.as_deref(),
+                options,
+                log_buffer,
+                cli.setup.as_ref(),
+            ) {
+                Ok(result) => result,
+                Err(e) => {
+                    eprintln!("TUI Error: {e}. Falling back to CLI mode.");
+                    info!("TUI failed with error: {}, falling back to CLI", e);
+                    app_cli(
+                        wordbank_path,
+                        &config,
+                        &session,
+                        saved_game.as_deref(),
+                        output,
+                        cli.setup.as_ref(),
+                    )
+                }
             }
         }
+    };
+    info!(
+        "Last game: {:?} after {} rounds, {} candidates left",
+        result.outcome,
+        result.rounds,
+        result.candidates.len()
+    );
+    if result.aborted {
+        let count = session.max_invalid_inputs.unwrap_or_default();
+        eprintln!(
+            "{}",
+            Message::ErrorTooManyInvalid.fill(&[("count", &count)])
+        );
     }
 
-    info_log!("Application exiting");
+    let code = game_exit_code(&result);
+    info!("Application exiting with code {code}");
+    std::process::exit(code);
 }
 
-fn app_cli(wordbank_path: Option<String>) {
-    let initial_wordbank = load_wordbank(wordbank_path);
-    info_log!("Loaded {} words for CLI", initial_wordbank.len());
-    let stdin = io::stdin();
-    let mut interface = CliInterface::new(stdin.lock());
-    game_loop(&initial_wordbank, &mut interface);
+/// The solver's first guess in play modes: the top cached starting word, computed and cached
+/// if there isn't one for this wordbank yet or `recompute` is set
+fn solver_opener(wordbank: &WordBank, no_cache: bool, recompute: bool) -> String {
+    let start_path = get_wordle_start_path().filter(|_| !no_cache);
+    if let Some(word) = start_path
+        .as_deref()
+        .filter(|_| !recompute)
+        .and_then(|path| read_cached_starting_words(path, wordbank, Strategy::default(), 1))
+        .and_then(|words| words.into_iter().next())
+    {
+        return word.0;
+    }
+    if let Some(word) = precomputed::starting_words(wordbank, 1).filter(|_| !recompute) {
+        return word[0].0.clone();
+    }
+    println!("Computing optimal starting words, please wait...");
+    // Picks up from, and keeps, the same checkpoint as the solver's own computation
+    let checkpoint = start_path.as_deref().map(starting_checkpoint_path);
+    let done = checkpoint
+        .as_deref()
+        .map(|path| read_starting_checkpoint(path, wordbank))
+        .unwrap_or_default();
+    let words = resume_scored_starting_words(
+        wordbank,
+        DEFAULT_STARTING_WORDS,
+        done,
+        &mut |scored| {
+            if let Some(path) = &checkpoint {
+                write_starting_checkpoint(path, wordbank, scored);
+            }
+        },
+        &mut NoProgress,
+        &CancelToken::new(),
+    );
+    if let (Some(path), Some(checkpoint)) = (&start_path, &checkpoint) {
+        write_cached_starting_words(path, wordbank, Strategy::default(), &words);
+        let _ = std::fs::remove_file(checkpoint);
+    }
+    words[0].0.clone()
+}
+
+fn import_share(wordbank: &[String], answer: &str) -> Result<(), String> {
+    println!("Paste the share grid, then press Ctrl+D (Ctrl+Z then Enter on Windows):");
+    let grid = io::read_to_string(io::stdin()).map_err(|e| e.to_string())?;
+    let rows = parse_share_grid(&grid)?;
+    if let Some(puzzle) = parse_share_number(&grid).and_then(PuzzleInfo::for_number) {
+        println!("{puzzle}");
+    }
+    display_share_analysis(&analyze_share(wordbank, &rows, answer)?);
+    Ok(())
 }
 
-fn app_tui(wordbank_path: Option<String>) -> Result<(), io::Error> {
-    let initial_wordbank = load_wordbank(wordbank_path);
-    info_log!("Loaded {} words for TUI", initial_wordbank.len());
-    let mut interface = TuiWrapper::new()?;
-    info_log!("TUI interface initialized");
-    game_loop(&initial_wordbank, &mut interface);
+fn compile_wordbank(wordbank: &[String], output: &Path) -> io::Result<()> {
+    let packed = encode_wordbank(wordbank).map_err(io::Error::other)?;
+    std::fs::write(output, &packed)?;
+    println!(
+        "Wrote {} words to {} ({} bytes).",
+        wordbank.len(),
+        output.display(),
+        packed.len()
+    );
     Ok(())
 }
+
+/// Prints how the word list at `new` differs from the one at `old`, exiting if either can't
+/// be read
+fn diff_wordbanks(old: &Path, new: &Path) {
+    let read = |path: &Path| {
+        read_wordbank_entries(path).unwrap_or_else(|e| {
+            eprintln!("Failed to read '{}': {e}", path.display());
+            std::process::exit(exit::WORDBANK_ERROR);
+        })
+    };
+    for line in WordbankDiff::new(&read(old), &read(new)).report() {
+        println!("{line}");
+    }
+}
+
+/// Loads the wordbank, warning if it isn't the one a `--setup` code was made with
+fn load_wordbank_timed(
+    wordbank_path: Option<String>,
+    time: bool,
+    quiet: bool,
+    setup: Option<&Setup>,
+) -> WordBank {
+    let started = Instant::now();
+    let wordbank = if quiet {
+        load_wordbank_quietly(wordbank_path)
+    } else {
+        load_wordbank(wordbank_path)
+    };
+    if time {
+        display_timing("wordbank load", started.elapsed());
+    }
+    if setup.is_some_and(|setup| !setup.matches_wordbank(&wordbank)) {
+        eprintln!(
+            "Warning: the setup code was made with a different wordbank, so guesses may differ"
+        );
+    }
+    wordbank
+}
+
+/// `session` resuming `saved_game`, once [`Game::resume`] has checked it against `wordbank`.
+/// Exits if the save was made for another wordbank or doesn't add up.
+fn resume_saved_game(
+    config: &SolverConfig,
+    session: &SessionOptions,
+    wordbank: &WordBank,
+    saved_game: Option<&str>,
+) -> SessionOptions {
+    let mut session = session.clone();
+    if let Some(saved) = saved_game {
+        match Game::resume(wordbank, config.clone(), saved) {
+            Ok(game) => session.initial_rounds = game.history().to_vec(),
+            Err(e) => {
+                eprintln!("Couldn't resume the saved game: {e}");
+                std::process::exit(exit::INPUT_ERROR);
+            }
+        }
+    }
+    session
+}
+
+fn app_cli(
+    wordbank_path: Option<String>,
+    config: &SolverConfig,
+    session: &SessionOptions,
+    saved_game: Option<&str>,
+    output: OutputOptions,
+    setup: Option<&Setup>,
+) -> GameResult {
+    let initial_wordbank =
+        load_wordbank_timed(wordbank_path, output.show_timing(), output.quiet(), setup);
+    info!("Loaded {} words for CLI", initial_wordbank.len());
+    let session = &resume_saved_game(config, session, &initial_wordbank, saved_game);
+    let stdin = io::stdin();
+    let mut interface = CliInterface::new(stdin.lock()).with_output(output);
+    game_loop_with_observers(
+        &initial_wordbank,
+        &mut interface,
+        config,
+        session,
+        &mut [&mut InterruptSaver::new(&initial_wordbank)],
+    )
+}
+
+/// How the TUI shows the game, and where its input is recorded or replayed from
+struct TuiOptions {
+    time: bool,
+    accessible: bool,
+    tile_glyphs: bool,
+    recorder: Option<Recorder>,
+    replay: Option<Replay>,
+}
+
+fn app_tui(
+    wordbank_path: Option<String>,
+    config: &SolverConfig,
+    session: &SessionOptions,
+    saved_game: Option<&str>,
+    options: TuiOptions,
+    log_buffer: LogBuffer,
+    setup: Option<&Setup>,
+) -> Result<GameResult, io::Error> {
+    let initial_wordbank = load_wordbank_timed(wordbank_path, options.time, false, setup);
+    info!("Loaded {} words for TUI", initial_wordbank.len());
+    let session = &resume_saved_game(config, session, &initial_wordbank, saved_game);
+    install_panic_hook();
+    let mut interface = TuiWrapper::new()?
+        .with_timing(options.time)
+        .with_accessible(options.accessible)
+        .with_tile_glyphs(options.tile_glyphs)
+        .with_wordbank_stats(WordbankStats::new(&initial_wordbank))
+        .with_log_buffer(log_buffer);
+    if let Some(recorder) = options.recorder {
+        interface = interface.with_recorder(recorder);
+    }
+    if let Some(replay) = options.replay {
+        info!("Replaying {} recorded input events", replay.remaining());
+        interface = interface.with_replay(replay);
+    }
+    info!("TUI interface initialized");
+    Ok(game_loop_with_observers(
+        &initial_wordbank,
+        &mut interface,
+        config,
+        session,
+        &mut [&mut InterruptSaver::new(&initial_wordbank)],
+    ))
+}
+
+/// Installs the translation for `language`, or the one saved in the config if not given.
+/// Problems are reported but never stop the solver; it falls back to English.
+fn init_language(language: Option<&str>) {
+    let language = language.map(str::to_string).or_else(|| {
+        get_wordle_config_path()
+            .map(|path| Config::load(&path))
+            .and_then(|config| config.language)
+    });
+    let Some(language) = language else {
+        return;
+    };
+    match load_catalog(&language) {
+        Ok((catalog, problems)) => {
+            for problem in problems {
+                eprintln!("Warning: skipped translation entry {problem}");
+            }
+            let _ = install(catalog);
+        }
+        Err(e) => eprintln!("Warning: {e}; using English"),
+    }
+}
+
+/// Sends tracing spans and events to the returned buffer, for the TUI's log panel, and to
+/// `path`. Debug builds also trace to output.txt without one.
+fn init_tracing(path: Option<&Path>) -> LogBuffer {
+    let buffer = LogBuffer::default();
+    let path = path.or_else(|| cfg!(debug_assertions).then(|| Path::new("output.txt")));
+    let writer = match path.map(|path| (path, File::create(path))) {
+        None => BoxMakeWriter::new(buffer.clone()),
+        Some((_, Ok(file))) => BoxMakeWriter::new(buffer.clone().and(Mutex::new(file))),
+        Some((path, Err(e))) => {
+            eprintln!("Error: could not create trace file {}: {e}", path.display());
+            std::process::exit(exit::FAILURE);
+        }
+    };
+    // Closed spans are logged with their busy and idle time, so scoring can be profiled
+    tracing_subscriber::fmt()
+        .with_writer(writer)
+        .with_max_level(tracing::Level::DEBUG)
+        .with_file(true)
+        .with_line_number(true)
+        .with_span_events(FmtSpan::CLOSE)
+        .init();
+    buffer
+}
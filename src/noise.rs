@@ -0,0 +1,297 @@
+//! Simulated marking mistakes.
+//!
+//! Players sometimes enter the wrong colour for a tile, and the solver then rules out words
+//! on false grounds. [`simulate_game`] plays a game with a [`NoiseModel`] standing in for the
+//! player, to measure how often that costs extra guesses or rules out the answer altogether.
+
+use crate::game::{Game, Outcome};
+use crate::play::MAX_GUESSES;
+use crate::rng::Rng;
+use crate::solver::{Feedback, SolverConfig, get_feedback};
+use crate::words::{Word, WordBank};
+
+/// How likely the player is to mark each tile wrong
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NoiseModel {
+    /// Chance of each tile being entered as one of the other two colours
+    pub error_rate: f64,
+}
+
+impl NoiseModel {
+    /// A player who never makes a mistake
+    pub const NONE: Self = Self { error_rate: 0.0 };
+
+    #[must_use]
+    pub const fn new(error_rate: f64) -> Self {
+        Self { error_rate }
+    }
+
+    /// The feedback as the player enters it: each tile, with chance `error_rate`, swapped
+    /// for one of the other two colours, picked evenly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wordle_solver::noise::NoiseModel;
+    /// use wordle_solver::rng::Rng;
+    /// use wordle_solver::Feedback;
+    ///
+    /// let mut rng = Rng::new(1);
+    /// let feedback = [Feedback::Match; 5];
+    /// assert_eq!(NoiseModel::NONE.mark(&feedback, &mut rng), feedback);
+    /// let marked = NoiseModel::new(1.0).mark(&feedback, &mut rng);
+    /// assert!(marked.iter().all(|&f| f != Feedback::Match));
+    /// ```
+    #[must_use]
+    pub fn mark(&self, feedback: &[Feedback], rng: &mut Rng) -> Vec<Feedback> {
+        feedback
+            .iter()
+            .map(|&tile| {
+                if chance(rng) >= self.error_rate {
+                    return tile;
+                }
+                let others: Vec<Feedback> =
+                    [Feedback::NoMatch, Feedback::PartialMatch, Feedback::Match]
+                        .into_iter()
+                        .filter(|&other| other != tile)
+                        .collect();
+                others[rng.below(others.len())]
+            })
+            .collect()
+    }
+}
+
+/// A uniform draw from `0.0..1.0`
+#[allow(clippy::cast_precision_loss)] // 53 bits are all an f64 holds
+fn chance(rng: &mut Rng) -> f64 {
+    (rng.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// How a simulated game ended
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SimulatedEnd {
+    /// The answer was played on this guess
+    Solved(usize),
+    /// Every guess was used up with the answer still a candidate
+    OutOfGuesses,
+    /// A mis-marked tile ruled out the answer on this guess, so it could no longer be found
+    Unsolvable(usize),
+}
+
+/// Plays `answer` by following the recommendations, with `noise` marking each round's
+/// feedback. Guessing the answer wins however it's marked, as in the real game.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::noise::{NoiseModel, SimulatedEnd, simulate_game};
+/// use wordle_solver::rng::Rng;
+/// use wordle_solver::solver::SolverConfig;
+/// use wordle_solver::wordbank::sample_wordbank;
+///
+/// let config = SolverConfig {
+///     use_cache: false,
+///     ..SolverConfig::default()
+/// };
+/// let mut rng = Rng::new(1);
+/// let end = simulate_game(&sample_wordbank(), "QUOTA", &config, NoiseModel::NONE, &mut rng);
+/// assert!(matches!(end, SimulatedEnd::Solved(_)));
+/// ```
+#[must_use]
+pub fn simulate_game(
+    wordbank: &WordBank,
+    answer: &str,
+    config: &SolverConfig,
+    noise: NoiseModel,
+    rng: &mut Rng,
+) -> SimulatedEnd {
+    simulate_game_observed(wordbank, answer, config, noise, rng, |_| {})
+}
+
+/// [`simulate_game`], calling `observe` with the game before each guess is picked, as a
+/// player would see it
+pub fn simulate_game_observed(
+    wordbank: &WordBank,
+    answer: &str,
+    config: &SolverConfig,
+    noise: NoiseModel,
+    rng: &mut Rng,
+    mut observe: impl FnMut(&Game),
+) -> SimulatedEnd {
+    let mut game = Game::new(wordbank, config.clone());
+    for guess_number in 1..=MAX_GUESSES {
+        observe(&game);
+        let guess = match game.outcome() {
+            // The game shows the last candidate as the solution, which is then played
+            Outcome::Solved(word) => word,
+            Outcome::InProgress => match game.recommendation() {
+                Some(recommendation) => recommendation.guess.into_string(),
+                None => return SimulatedEnd::Unsolvable(guess_number),
+            },
+            Outcome::NoSolution => return SimulatedEnd::Unsolvable(guess_number),
+        };
+        if guess == answer {
+            return SimulatedEnd::Solved(guess_number);
+        }
+        let feedback = noise.mark(&get_feedback(&guess, answer), rng);
+        game.guess(&Word::from_checked(guess), &feedback);
+        if !game.candidates().iter().any(|word| word == answer) {
+            return SimulatedEnd::Unsolvable(guess_number);
+        }
+    }
+    SimulatedEnd::OutOfGuesses
+}
+
+/// How far a batch of simulated games has got, for showing while it runs
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SimulationProgress {
+    /// What is being simulated, e.g. the strategy's name
+    pub label: String,
+    /// Games played so far, out of `total`
+    pub played: usize,
+    pub total: usize,
+    /// Games solved so far, and the guesses they took between them
+    pub solved: usize,
+    pub guesses: usize,
+    /// The answer that has gone worst so far, with the guesses it took, or `None` if it
+    /// wasn't solved. Ties go to the first.
+    pub worst: Option<(String, Option<usize>)>,
+}
+
+impl SimulationProgress {
+    #[must_use]
+    pub fn new(label: &str, total: usize) -> Self {
+        Self {
+            label: label.to_string(),
+            total,
+            ..Self::default()
+        }
+    }
+
+    /// Records how the game against `answer` ended.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wordle_solver::noise::{SimulatedEnd, SimulationProgress};
+    ///
+    /// let mut progress = SimulationProgress::new("expected-size", 3);
+    /// progress.record("CRANE", SimulatedEnd::Solved(3));
+    /// progress.record("EERIE", SimulatedEnd::Solved(5));
+    /// assert_eq!(progress.average(), Some(4.0));
+    /// assert_eq!(progress.worst, Some(("EERIE".to_string(), Some(5))));
+    /// ```
+    pub fn record(&mut self, answer: &str, end: SimulatedEnd) {
+        self.played += 1;
+        let took = match end {
+            SimulatedEnd::Solved(guesses) => {
+                self.solved += 1;
+                self.guesses += guesses;
+                Some(guesses)
+            }
+            SimulatedEnd::OutOfGuesses | SimulatedEnd::Unsolvable(_) => None,
+        };
+        // A game that wasn't solved is worse than any that was
+        let badness = |took: Option<usize>| took.unwrap_or(usize::MAX);
+        if self
+            .worst
+            .as_ref()
+            .is_none_or(|(_, worst)| badness(took) > badness(*worst))
+        {
+            self.worst = Some((answer.to_string(), took));
+        }
+    }
+
+    /// Average guesses taken by the games solved so far, or `None` before any were
+    #[allow(clippy::cast_precision_loss)] // a handful of games
+    #[must_use]
+    pub fn average(&self) -> Option<f64> {
+        (self.solved > 0).then(|| self.guesses as f64 / self.solved as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wordbank::sample_wordbank;
+
+    fn config() -> SolverConfig {
+        SolverConfig {
+            use_cache: false,
+            ..SolverConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_mark_swaps_tiles_at_the_error_rate() {
+        let mut rng = Rng::new(3);
+        let feedback = vec![Feedback::PartialMatch; 1000];
+        let marked = NoiseModel::new(0.1).mark(&feedback, &mut rng);
+        let wrong = marked
+            .iter()
+            .filter(|&&f| f != Feedback::PartialMatch)
+            .count();
+        assert!((50..150).contains(&wrong), "{wrong} of 1000 mis-marked");
+        assert!(marked.contains(&Feedback::Match) && marked.contains(&Feedback::NoMatch));
+    }
+
+    #[test]
+    fn test_simulate_game_with_and_without_noise() {
+        let wordbank = sample_wordbank();
+        let mut rng = Rng::new(5);
+        for answer in &wordbank {
+            let end = simulate_game(&wordbank, answer, &config(), NoiseModel::NONE, &mut rng);
+            assert!(
+                matches!(end, SimulatedEnd::Solved(n) if n <= MAX_GUESSES),
+                "{answer}: {end:?}"
+            );
+        }
+
+        // Marking every tile wrong rules out the answer straight away, unless it's the opener
+        let ends: Vec<SimulatedEnd> = wordbank
+            .iter()
+            .map(|answer| {
+                simulate_game(&wordbank, answer, &config(), NoiseModel::new(1.0), &mut rng)
+            })
+            .collect();
+        assert_eq!(
+            ends.iter()
+                .filter(|&&end| end == SimulatedEnd::Unsolvable(1))
+                .count(),
+            wordbank.len() - 1
+        );
+    }
+
+    #[test]
+    fn test_simulation_progress_tracks_the_worst_answer() {
+        let mut progress = SimulationProgress::new("candidates-only", 4);
+        assert_eq!(progress.average(), None);
+        progress.record("CRANE", SimulatedEnd::Solved(4));
+        progress.record("SLATE", SimulatedEnd::Solved(4));
+        assert_eq!(progress.worst, Some(("CRANE".to_string(), Some(4))));
+        progress.record("EERIE", SimulatedEnd::OutOfGuesses);
+        progress.record("QUOTA", SimulatedEnd::Solved(6));
+        // Unsolved stays worst, and the average only counts solved games
+        assert_eq!(progress.worst, Some(("EERIE".to_string(), None)));
+        assert_eq!((progress.played, progress.solved), (4, 3));
+        assert_eq!(progress.average(), Some(14.0 / 3.0));
+    }
+
+    #[test]
+    fn test_observe_sees_the_game_before_each_guess() {
+        let wordbank = sample_wordbank();
+        let mut seen = Vec::new();
+        let end = simulate_game_observed(
+            &wordbank,
+            "QUOTA",
+            &config(),
+            NoiseModel::NONE,
+            &mut Rng::new(1),
+            |game| seen.push(game.history().len()),
+        );
+        let SimulatedEnd::Solved(guesses) = end else {
+            panic!("QUOTA should be solved, not {end:?}");
+        };
+        assert_eq!(seen, (0..guesses).collect::<Vec<_>>());
+    }
+}
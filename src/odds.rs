@@ -0,0 +1,347 @@
+//! Chances of solving in time by following the recommendations.
+//!
+//! [`WinOdds::compute`] plays out the rest of the game against every remaining candidate,
+//! choosing each guess the way the recommender would (including its endgame rules), and
+//! counts the guess each one is solved on.
+
+use crate::game_state::{endgame_guess, remaining_guesses};
+use crate::i18n::Message;
+use crate::play::MAX_GUESSES;
+use crate::solver::{PATTERN_COUNT, PoolScorer, Strategy, best_information_guess, feedback_code};
+use crate::words::WordArena;
+use std::collections::BTreeMap;
+
+/// Largest candidate pool the odds are worked out for; bigger pools take too long to play out
+pub const WIN_ODDS_LIMIT: usize = 100;
+
+/// Chances of having solved the game by each guess when following the recommendations, with
+/// every remaining candidate equally likely to be the answer.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::odds::WinOdds;
+/// use wordle_solver::solver::Strategy;
+///
+/// let wordbank: Vec<String> = ["BATCH", "CATCH", "HATCH", "CLIMB"]
+///     .iter()
+///     .map(|s| s.to_string())
+///     .collect();
+/// // Two rounds played; CLIMB tells the three candidates apart, so guess 4 finds the answer
+/// let odds = WinOdds::compute(&wordbank, &wordbank[..3], 2, Strategy::ExpectedSize, None);
+/// assert_eq!(odds.by_guess(3), 0.0);
+/// assert_eq!(odds.by_guess(4), 1.0);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct WinOdds {
+    /// Chance of having solved it by each guess number, from the next guess to the last one
+    /// allowed
+    pub by_guess: Vec<(usize, f64)>,
+}
+
+impl WinOdds {
+    /// Plays out the game from `candidates` after `rounds` guesses, with guesses drawn from
+    /// `wordbank` (or only the candidates, as `strategy` says). Exact scoring goes through a
+    /// [`PoolScorer`] when `arena` holds the wordbank.
+    #[allow(clippy::cast_precision_loss)] // don't care about this
+    #[must_use]
+    pub fn compute(
+        wordbank: &[String],
+        candidates: &[String],
+        rounds: usize,
+        strategy: Strategy,
+        arena: Option<&WordArena>,
+    ) -> Self {
+        let policy = Policy {
+            wordbank,
+            strategy,
+            arena,
+        };
+        let mut solved_on = [0usize; MAX_GUESSES + 1];
+        policy.play_out(candidates, rounds, &mut solved_on);
+        let total = candidates.len().max(1) as f64;
+        let mut solved = 0;
+        let by_guess = (rounds + 1..=MAX_GUESSES)
+            .map(|guess| {
+                solved += solved_on[guess];
+                (guess, solved as f64 / total)
+            })
+            .collect();
+        Self { by_guess }
+    }
+
+    /// Chance of having solved it by guess number `guess`
+    #[must_use]
+    pub fn by_guess(&self, guess: usize) -> f64 {
+        self.by_guess
+            .iter()
+            .take_while(|&&(number, _)| number <= guess)
+            .last()
+            .map_or(0.0, |&(_, chance)| chance)
+    }
+
+    /// The chances worth showing, latest guess first: each guess with any chance of having
+    /// solved it, up to the first that is certain to. With no chance at all, just the last
+    /// guess.
+    #[must_use]
+    pub fn shown(&self) -> Vec<(usize, f64)> {
+        let mut shown: Vec<(usize, f64)> = Vec::new();
+        for &(guess, chance) in &self.by_guess {
+            if chance > 0.0 {
+                shown.push((guess, chance));
+            }
+            if chance >= 1.0 {
+                break;
+            }
+        }
+        if shown.is_empty() {
+            shown.extend(self.by_guess.last());
+        }
+        shown.reverse();
+        shown
+    }
+
+    /// The chances as one line, e.g. "If you follow recommendations: 100% by guess 5, 87% by
+    /// guess 4"
+    #[must_use]
+    pub fn summary(&self) -> String {
+        let chances: Vec<String> = self
+            .shown()
+            .iter()
+            .map(|&(guess, chance)| {
+                Message::GameOddsBy.fill(&[("percent", &percent(chance)), ("guess", &guess)])
+            })
+            .collect();
+        Message::GameOdds.fill(&[("odds", &chances.join(", "))])
+    }
+}
+
+/// How closely [`WinOdds`] predictions match what then happened, over many games. Each
+/// prediction of solving by some guess is recorded alongside whether the game was.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::odds::{Calibration, WinOdds};
+///
+/// let odds = WinOdds { by_guess: vec![(5, 0.5), (6, 1.0)] };
+/// let mut calibration = Calibration::default();
+/// calibration.record(&odds, Some(5));
+/// calibration.record(&odds, Some(6));
+/// // Half the games were solved by guess 5 and all by guess 6, as predicted
+/// assert_eq!(calibration.error(), 0.0);
+///
+/// calibration.record(&odds, None);
+/// assert!(calibration.error() > 0.0);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Calibration {
+    /// Each predicted chance, and whether it came true
+    predictions: Vec<(f64, bool)>,
+}
+
+/// Predictions whose chances fall in one tenth of the range
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CalibrationBin {
+    pub count: usize,
+    /// Average chance predicted
+    pub predicted: f64,
+    /// Share of predictions that came true
+    pub observed: f64,
+}
+
+impl Calibration {
+    /// Records what `odds` predicted for a game that was then solved on guess `solved_on`,
+    /// or `None` if it never was
+    pub fn record(&mut self, odds: &WinOdds, solved_on: Option<usize>) {
+        for &(guess, chance) in &odds.by_guess {
+            let solved = solved_on.is_some_and(|solved_on| solved_on <= guess);
+            self.predictions.push((chance, solved));
+        }
+    }
+
+    /// Number of predictions recorded
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.predictions.len()
+    }
+
+    /// Whether no predictions have been recorded
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.predictions.is_empty()
+    }
+
+    /// The predictions grouped into tenths by chance, with chances of 1 in the last tenth.
+    /// Empty tenths are left out.
+    #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)] // chances are from 0 to 1
+    #[must_use]
+    pub fn bins(&self) -> Vec<CalibrationBin> {
+        let mut sums = [(0usize, 0.0, 0usize); 10];
+        for &(chance, solved) in &self.predictions {
+            let bin = &mut sums[((chance * 10.0) as usize).min(9)];
+            bin.0 += 1;
+            bin.1 += chance;
+            bin.2 += usize::from(solved);
+        }
+        sums.iter()
+            .filter(|&&(count, _, _)| count > 0)
+            .map(|&(count, predicted, solved)| CalibrationBin {
+                count,
+                predicted: predicted / count as f64,
+                observed: solved as f64 / count as f64,
+            })
+            .collect()
+    }
+
+    /// Expected calibration error: the gap between each tenth's average predicted chance and
+    /// how often its predictions came true, weighted by how many fell in it. 0 means the
+    /// chances shown can be taken at face value.
+    #[allow(clippy::cast_precision_loss)] // don't care about this
+    #[must_use]
+    pub fn error(&self) -> f64 {
+        let total = self.len().max(1) as f64;
+        self.bins()
+            .iter()
+            .map(|bin| bin.count as f64 / total * (bin.predicted - bin.observed).abs())
+            .sum()
+    }
+}
+
+/// A chance as a whole percentage, rounded down so only a sure thing shows as 100
+#[must_use]
+pub fn percent(chance: f64) -> String {
+    format!("{:.0}", (chance * 100.0).floor())
+}
+
+/// How the recommender picks guesses, for playing the game out
+struct Policy<'a> {
+    wordbank: &'a [String],
+    strategy: Strategy,
+    arena: Option<&'a WordArena>,
+}
+
+impl Policy<'_> {
+    /// The guess the recommender would make with `candidates` left after `rounds`, if there
+    /// is anything to guess
+    fn next_guess(&self, candidates: &[String], rounds: usize) -> Option<String> {
+        let guesses = match self.strategy {
+            Strategy::ExpectedSize => self.wordbank,
+            Strategy::CandidatesOnly => candidates,
+        };
+        if let Some((guess, _, _)) = endgame_guess(guesses, candidates, remaining_guesses(rounds)) {
+            return Some(guess.clone());
+        }
+        match self.arena {
+            Some(arena) if self.strategy == Strategy::ExpectedSize => {
+                let (id, _, _) = PoolScorer::new(arena, &arena.ids_of(candidates)).best_guess()?;
+                Some(arena.get(id).to_string())
+            }
+            _ => best_information_guess(guesses, candidates).map(|best| best.0.clone()),
+        }
+    }
+
+    /// Adds up, for each guess number, how many of `candidates` would be solved on it
+    fn play_out(&self, candidates: &[String], rounds: usize, solved_on: &mut [usize]) {
+        if rounds >= MAX_GUESSES || candidates.is_empty() {
+            return;
+        }
+        // With one candidate left the game shows it as the solution, which is then played
+        let guess = match candidates {
+            [solution] => solution.clone(),
+            _ => match self.next_guess(candidates, rounds) {
+                Some(guess) => guess,
+                None => return,
+            },
+        };
+        let mut buckets: BTreeMap<u8, Vec<String>> = BTreeMap::new();
+        for solution in candidates {
+            buckets
+                .entry(feedback_code(guess.as_bytes(), solution.as_bytes()))
+                .or_default()
+                .push(solution.clone());
+        }
+        for (code, bucket) in buckets {
+            if usize::from(code) == PATTERN_COUNT - 1 {
+                solved_on[rounds + 1] += bucket.len();
+            } else {
+                self.play_out(&bucket, rounds + 1, solved_on);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::words;
+
+    #[test]
+    fn test_odds_with_and_without_a_splitter() {
+        let family = words(&["BATCH", "CATCH", "HATCH", "LATCH", "MATCH", "PATCH"]);
+        // With only the family to guess from, each guess finds one word at best
+        let odds = WinOdds::compute(&family, &family, 3, Strategy::ExpectedSize, None);
+        assert_eq!(odds.by_guess.len(), 3);
+        assert!(odds.by_guess(6) < 1.0);
+        assert!(odds.by_guess(4) <= odds.by_guess(5));
+
+        let mut wordbank = family.clone();
+        wordbank.push("CHAMP".to_string());
+        wordbank.push("BLIMP".to_string());
+        let arena = WordArena::new(&wordbank);
+        let with_arena = WinOdds::compute(
+            &wordbank,
+            &family,
+            0,
+            Strategy::ExpectedSize,
+            arena.as_ref(),
+        );
+        let without = WinOdds::compute(&wordbank, &family, 0, Strategy::ExpectedSize, None);
+        assert_eq!(with_arena, without);
+        assert_eq!(with_arena.by_guess(MAX_GUESSES), 1.0);
+    }
+
+    #[test]
+    fn test_shown_stops_at_the_first_certain_guess() {
+        let odds = WinOdds {
+            by_guess: vec![(3, 0.0), (4, 0.875), (5, 1.0), (6, 1.0)],
+        };
+        assert_eq!(odds.shown(), [(5, 1.0), (4, 0.875)]);
+        assert_eq!(percent(0.875), "87");
+        assert_eq!(percent(0.999), "99");
+
+        let hopeless = WinOdds {
+            by_guess: vec![(6, 0.0)],
+        };
+        assert_eq!(hopeless.shown(), [(6, 0.0)]);
+    }
+
+    #[test]
+    fn test_calibration_bins_group_by_tenth() {
+        let mut calibration = Calibration::default();
+        assert!(calibration.is_empty());
+        assert_eq!(calibration.error(), 0.0);
+
+        let odds = WinOdds {
+            by_guess: vec![(4, 0.25), (5, 0.75), (6, 1.0)],
+        };
+        calibration.record(&odds, Some(5));
+        calibration.record(&odds, None);
+        assert_eq!(calibration.len(), 6);
+        let bins = calibration.bins();
+        assert_eq!(bins.len(), 3);
+        assert_eq!(
+            bins[0],
+            CalibrationBin {
+                count: 2,
+                predicted: 0.25,
+                observed: 0.0
+            }
+        );
+        assert_eq!(bins[2].predicted, 1.0);
+        assert_eq!(bins[2].observed, 0.5);
+        // Off by 0.25, 0.25 and 0.5 in equal parts
+        assert!((calibration.error() - 1.0 / 3.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,729 @@
+//! Play mode: guessing a hidden word, with feedback worked out automatically.
+//!
+//! The types here hold the rules and bookkeeping; the TUI draws any [`PlayMode`] and feeds in
+//! guesses.
+
+use crate::rng::Rng;
+use crate::solver::{Feedback, best_information_guess, get_feedback, is_consistent};
+use crate::words::WORD_LENGTH;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// Guesses each player gets at the hidden word
+pub const MAX_GUESSES: usize = 6;
+
+/// What the guesses so far reveal about one letter, in order of how much they reveal
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LetterHint {
+    #[default]
+    Unknown,
+    Absent,
+    Present,
+    Correct,
+}
+
+/// The best-known hint for every letter, as shown on an on-screen keyboard
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct KeyboardState {
+    hints: [LetterHint; 26],
+}
+
+impl KeyboardState {
+    /// Folds in the feedback for a guess. A letter never loses what earlier guesses revealed,
+    /// so a gray repeat of a yellow letter stays yellow.
+    pub fn record(&mut self, guess: &str, feedback: &[Feedback]) {
+        for (letter, fb) in guess.bytes().zip(feedback) {
+            if !letter.is_ascii_uppercase() {
+                continue;
+            }
+            let hint = match fb {
+                Feedback::Match => LetterHint::Correct,
+                Feedback::PartialMatch => LetterHint::Present,
+                Feedback::NoMatch => LetterHint::Absent,
+            };
+            let slot = &mut self.hints[usize::from(letter - b'A')];
+            *slot = (*slot).max(hint);
+        }
+    }
+
+    /// # Examples
+    ///
+    /// ```
+    /// use wordle_solver::play::{KeyboardState, LetterHint};
+    /// use wordle_solver::solver::get_feedback;
+    ///
+    /// let mut keyboard = KeyboardState::default();
+    /// keyboard.record("CRANE", &get_feedback("CRANE", "REACT"));
+    /// assert_eq!(keyboard.hint('A'), LetterHint::Correct);
+    /// assert_eq!(keyboard.hint('N'), LetterHint::Absent);
+    /// assert_eq!(keyboard.hint('Z'), LetterHint::Unknown);
+    /// ```
+    #[must_use]
+    pub fn hint(&self, letter: char) -> LetterHint {
+        let letter = letter.to_ascii_uppercase();
+        if letter.is_ascii_uppercase() {
+            self.hints[usize::from(letter as u8 - b'A')]
+        } else {
+            LetterHint::Unknown
+        }
+    }
+}
+
+/// What the guesses so far reveal about one position of the answer
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PositionKnowledge {
+    /// The letter a green tile put here, if any
+    pub confirmed: Option<char>,
+    /// Letters guessed here that weren't green, alphabetically
+    pub ruled_out: Vec<char>,
+    /// How many candidates have each letter here, most first and then alphabetically
+    pub distribution: Vec<(char, usize)>,
+}
+
+/// What the guesses and the candidates left say about each position of the answer, for a
+/// grid of per-position constraints
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Knowledge {
+    /// One entry per letter of the word
+    pub positions: Vec<PositionKnowledge>,
+    /// Candidates the distributions are counted over
+    pub candidates: usize,
+}
+
+impl Knowledge {
+    /// # Examples
+    ///
+    /// ```
+    /// use wordle_solver::play::Knowledge;
+    /// use wordle_solver::solver::get_feedback;
+    ///
+    /// let history = vec![("CRANE".to_string(), get_feedback("CRANE", "TRACE"))];
+    /// let candidates = vec!["TRACE".to_string(), "GRACE".to_string()];
+    /// let knowledge = Knowledge::new(&history, &candidates);
+    /// assert_eq!(knowledge.positions[1].confirmed, Some('R'));
+    /// assert_eq!(knowledge.positions[0].ruled_out, ['C']);
+    /// assert_eq!(knowledge.positions[0].distribution, [('G', 1), ('T', 1)]);
+    /// ```
+    #[must_use]
+    pub fn new(history: &[(String, Vec<Feedback>)], candidates: &[String]) -> Self {
+        let positions = (0..WORD_LENGTH)
+            .map(|position| {
+                let mut known = PositionKnowledge::default();
+                for (guess, feedback) in history {
+                    let (Some(letter), Some(&fb)) =
+                        (guess.chars().nth(position), feedback.get(position))
+                    else {
+                        continue;
+                    };
+                    if fb == Feedback::Match {
+                        known.confirmed = Some(letter);
+                    } else if !known.ruled_out.contains(&letter) {
+                        known.ruled_out.push(letter);
+                    }
+                }
+                known.ruled_out.sort_unstable();
+
+                let mut counts = [0usize; 26];
+                for letter in candidates
+                    .iter()
+                    .filter_map(|word| word.as_bytes().get(position).copied())
+                {
+                    if letter.is_ascii_uppercase() {
+                        counts[usize::from(letter - b'A')] += 1;
+                    }
+                }
+                known.distribution = (b'A'..=b'Z')
+                    .zip(counts)
+                    .filter(|&(_, count)| count > 0)
+                    .map(|(letter, count)| (char::from(letter), count))
+                    .collect();
+                known
+                    .distribution
+                    .sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+                known
+            })
+            .collect();
+        Self {
+            positions,
+            candidates: candidates.len(),
+        }
+    }
+}
+
+/// One player's guesses at the hidden word
+#[derive(Clone, Debug, Default)]
+pub struct Board {
+    pub guesses: Vec<(String, Vec<Feedback>)>,
+    /// Gave up, e.g. by running out of time, and takes no more guesses
+    pub forfeited: bool,
+}
+
+impl Board {
+    #[must_use]
+    pub fn is_solved(&self) -> bool {
+        self.guesses
+            .last()
+            .is_some_and(|(_, feedback)| feedback.iter().all(|f| *f == Feedback::Match))
+    }
+
+    /// Solved, forfeited, or out of guesses
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.is_solved() || self.forfeited || self.guesses.len() >= MAX_GUESSES
+    }
+
+    /// The solver's pick for this board's next guess: `opener` on an empty board, otherwise
+    /// the best guess over what its feedback leaves possible.
+    ///
+    /// Returns `None` if no word in `wordbank` fits the feedback.
+    #[must_use]
+    pub fn next_guess(&self, wordbank: &[String], opener: &str) -> Option<String> {
+        if self.guesses.is_empty() {
+            return Some(opener.to_string());
+        }
+        let candidates: Vec<String> = wordbank
+            .iter()
+            .filter(|word| {
+                self.guesses
+                    .iter()
+                    .all(|(guess, feedback)| is_consistent(word, guess, feedback))
+            })
+            .cloned()
+            .collect();
+        match candidates.len() {
+            0 => None,
+            1 | 2 => Some(candidates[0].clone()),
+            _ => best_information_guess(wordbank, &candidates).map(|best| best.0.clone()),
+        }
+    }
+}
+
+/// What happens when a player's guess timer runs out
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TimeoutAction {
+    /// The player gives up on this game (default)
+    #[default]
+    Forfeit,
+    /// The solver's recommended guess is played for them
+    Recommend,
+}
+
+impl FromStr for TimeoutAction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "forfeit" => Ok(Self::Forfeit),
+            "recommend" => Ok(Self::Recommend),
+            _ => Err(format!(
+                "unknown timeout action '{s}' (expected forfeit or recommend)"
+            )),
+        }
+    }
+}
+
+/// Picks a hidden word from the wordbank.
+///
+/// # Panics
+/// Panics if the wordbank is empty
+pub fn pick_answer(wordbank: &[String], rng: &mut Rng) -> String {
+    wordbank[rng.below(wordbank.len())].clone()
+}
+
+/// Countdown for each guess in a play mode
+pub struct GuessTimer {
+    limit: Duration,
+    pub action: TimeoutAction,
+    /// Played on an empty board when the action is [`TimeoutAction::Recommend`]
+    pub opener: String,
+    deadline: Instant,
+}
+
+impl GuessTimer {
+    #[must_use]
+    pub fn new(limit: Duration, action: TimeoutAction, opener: String) -> Self {
+        Self {
+            limit,
+            action,
+            opener,
+            deadline: Instant::now() + limit,
+        }
+    }
+
+    /// Starts a fresh countdown for the next guess
+    pub fn restart(&mut self, now: Instant) {
+        self.deadline = now + self.limit;
+    }
+
+    /// Time left before the deadline, zero once it has passed
+    #[must_use]
+    pub fn remaining(&self, now: Instant) -> Duration {
+        self.deadline.saturating_duration_since(now)
+    }
+
+    #[must_use]
+    pub fn expired(&self, now: Instant) -> bool {
+        now >= self.deadline
+    }
+}
+
+/// How a two-board game ended
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    /// Index of the winning board
+    Winner(usize),
+    /// Both boards solved it in the same number of guesses
+    Tie,
+    /// Both boards ran out of guesses
+    NoWinner,
+}
+
+/// A game played on two boards against one hidden word
+pub trait PlayMode {
+    /// Who plays on each board, for titles and the final summary
+    fn player_name(&self, board: usize) -> String;
+
+    fn boards(&self) -> &[Board; 2];
+
+    /// What has been revealed to whoever is typing guesses
+    fn keyboard(&self) -> &KeyboardState;
+
+    /// The hidden word, which should only be shown once the game is over
+    fn answer(&self) -> &str;
+
+    /// Index of the board the next typed guess goes on
+    fn turn(&self) -> usize;
+
+    /// `None` while the game is still going
+    fn outcome(&self) -> Option<Outcome>;
+
+    /// Plays a typed guess.
+    ///
+    /// # Errors
+    /// Returns a message if the game is over or `guess` isn't a word in `wordbank`.
+    fn submit(&mut self, guess: &str, wordbank: &[String]) -> Result<(), String>;
+
+    /// Gives up the rest of the game for the board on turn
+    fn forfeit_turn(&mut self, wordbank: &[String]);
+
+    /// Starts over against a new hidden word
+    fn restart(&mut self, answer: String);
+
+    /// One line saying who won and how many guesses each board took
+    fn summary(&self) -> Option<String> {
+        let counts = (0..2)
+            .map(|board| {
+                let guesses = &self.boards()[board];
+                let result = match guesses.guesses.len() {
+                    _ if guesses.forfeited => "forfeited".to_string(),
+                    _ if !guesses.is_solved() => "unsolved".to_string(),
+                    1 => "1 guess".to_string(),
+                    n => format!("{n} guesses"),
+                };
+                format!("{}: {result}", self.player_name(board))
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let headline = match self.outcome()? {
+            Outcome::Winner(board) => format!("{} wins!", self.player_name(board)),
+            Outcome::Tie => "It's a tie!".to_string(),
+            Outcome::NoWinner => "Nobody solved it.".to_string(),
+        };
+        Some(format!(
+            "{headline} The word was {} ({counts})",
+            self.answer()
+        ))
+    }
+}
+
+/// Checks that a typed guess can be played
+fn validate_guess(guess: &str, wordbank: &[String]) -> Result<String, String> {
+    let guess = guess.to_uppercase();
+    if wordbank.contains(&guess) {
+        Ok(guess)
+    } else {
+        Err(format!("{guess} is not in the wordbank"))
+    }
+}
+
+/// Two players taking turns at the same hidden word, sharing what the keyboard shows
+pub struct Versus {
+    answer: String,
+    pub boards: [Board; 2],
+    pub keyboard: KeyboardState,
+    turn: usize,
+}
+
+impl Versus {
+    #[must_use]
+    pub fn new(answer: String) -> Self {
+        Self {
+            answer,
+            boards: [Board::default(), Board::default()],
+            keyboard: KeyboardState::default(),
+            turn: 0,
+        }
+    }
+
+    /// Hands the turn to the other player unless they are out of guesses
+    fn pass_turn(&mut self) {
+        let other = 1 - self.turn;
+        if !self.boards[other].is_finished() {
+            self.turn = other;
+        }
+    }
+}
+
+impl PlayMode for Versus {
+    fn player_name(&self, board: usize) -> String {
+        format!("Player {}", board + 1)
+    }
+
+    fn boards(&self) -> &[Board; 2] {
+        &self.boards
+    }
+
+    fn keyboard(&self) -> &KeyboardState {
+        &self.keyboard
+    }
+
+    fn answer(&self) -> &str {
+        &self.answer
+    }
+
+    fn turn(&self) -> usize {
+        self.turn
+    }
+
+    fn outcome(&self) -> Option<Outcome> {
+        if let Some(winner) = self.boards.iter().position(Board::is_solved) {
+            Some(Outcome::Winner(winner))
+        } else if self.boards.iter().all(Board::is_finished) {
+            Some(Outcome::NoWinner)
+        } else {
+            None
+        }
+    }
+
+    /// Plays `guess` for the current player, then passes the turn to the other player unless
+    /// they are out of guesses.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wordle_solver::play::{Outcome, PlayMode, Versus};
+    ///
+    /// let wordbank = vec!["CRANE".to_string(), "SLATE".to_string()];
+    /// let mut game = Versus::new("SLATE".to_string());
+    /// game.submit("CRANE", &wordbank).unwrap();
+    /// assert_eq!(game.turn(), 1);
+    /// game.submit("SLATE", &wordbank).unwrap();
+    /// assert_eq!(game.outcome(), Some(Outcome::Winner(1)));
+    /// ```
+    fn submit(&mut self, guess: &str, wordbank: &[String]) -> Result<(), String> {
+        if self.outcome().is_some() {
+            return Err("The game is over".to_string());
+        }
+        let guess = validate_guess(guess, wordbank)?;
+        let feedback = get_feedback(&guess, &self.answer);
+        self.keyboard.record(&guess, &feedback);
+        self.boards[self.turn].guesses.push((guess, feedback));
+
+        self.pass_turn();
+        Ok(())
+    }
+
+    fn forfeit_turn(&mut self, _wordbank: &[String]) {
+        self.boards[self.turn].forfeited = true;
+        self.pass_turn();
+    }
+
+    fn restart(&mut self, answer: String) {
+        *self = Self::new(answer);
+    }
+}
+
+/// You against the solver on the same hidden word. Each time you guess, the solver makes its
+/// own best guess on the second board, so you never see its move before making yours.
+pub struct Race {
+    answer: String,
+    /// The solver's first guess, usually the top cached starting word
+    opener: String,
+    /// Yours first, then the solver's
+    pub boards: [Board; 2],
+    /// Only your own guesses, so the solver's board doesn't give anything away
+    pub keyboard: KeyboardState,
+}
+
+impl Race {
+    #[must_use]
+    pub fn new(answer: String, opener: String) -> Self {
+        Self {
+            answer,
+            opener,
+            boards: [Board::default(), Board::default()],
+            keyboard: KeyboardState::default(),
+        }
+    }
+
+    /// Lets the solver play out its board once you are done
+    fn finish_solver_board(&mut self, wordbank: &[String]) {
+        while !self.boards[1].is_finished() {
+            self.play_solver_guess(wordbank);
+        }
+    }
+
+    fn play_solver_guess(&mut self, wordbank: &[String]) {
+        let guess = self.boards[1]
+            .next_guess(wordbank, &self.opener)
+            .unwrap_or_else(|| self.answer.clone());
+        let feedback = get_feedback(&guess, &self.answer);
+        self.boards[1].guesses.push((guess, feedback));
+    }
+}
+
+impl PlayMode for Race {
+    fn player_name(&self, board: usize) -> String {
+        ["You", "Solver"][board].to_string()
+    }
+
+    fn boards(&self) -> &[Board; 2] {
+        &self.boards
+    }
+
+    fn keyboard(&self) -> &KeyboardState {
+        &self.keyboard
+    }
+
+    fn answer(&self) -> &str {
+        &self.answer
+    }
+
+    fn turn(&self) -> usize {
+        0
+    }
+
+    fn outcome(&self) -> Option<Outcome> {
+        if !self.boards.iter().all(Board::is_finished) {
+            return None;
+        }
+        let score = |board: &Board| board.is_solved().then_some(board.guesses.len());
+        Some(match (score(&self.boards[0]), score(&self.boards[1])) {
+            (None, None) => Outcome::NoWinner,
+            (Some(_), None) => Outcome::Winner(0),
+            (None, Some(_)) => Outcome::Winner(1),
+            (Some(you), Some(solver)) if you == solver => Outcome::Tie,
+            (Some(you), Some(solver)) => Outcome::Winner(usize::from(solver < you)),
+        })
+    }
+
+    /// Plays your guess, then the solver's guess for the same round. Once you are done the
+    /// solver plays out the rest of its board so the guess counts can be compared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wordle_solver::play::{Outcome, PlayMode, Race};
+    ///
+    /// let wordbank = vec!["CRANE".to_string(), "SLATE".to_string()];
+    /// let mut race = Race::new("SLATE".to_string(), "CRANE".to_string());
+    /// race.submit("SLATE", &wordbank).unwrap();
+    /// assert_eq!(race.outcome(), Some(Outcome::Winner(0)));
+    /// assert_eq!(race.boards[1].guesses.len(), 2);
+    /// ```
+    fn submit(&mut self, guess: &str, wordbank: &[String]) -> Result<(), String> {
+        if self.boards[0].is_finished() {
+            return Err("The game is over".to_string());
+        }
+        let guess = validate_guess(guess, wordbank)?;
+        let feedback = get_feedback(&guess, &self.answer);
+        self.keyboard.record(&guess, &feedback);
+        self.boards[0].guesses.push((guess, feedback));
+
+        if !self.boards[1].is_finished() {
+            self.play_solver_guess(wordbank);
+        }
+        if self.boards[0].is_finished() {
+            self.finish_solver_board(wordbank);
+        }
+        Ok(())
+    }
+
+    fn forfeit_turn(&mut self, wordbank: &[String]) {
+        self.boards[0].forfeited = true;
+        self.finish_solver_board(wordbank);
+    }
+
+    fn restart(&mut self, answer: String) {
+        *self = Self::new(answer, std::mem::take(&mut self.opener));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::words;
+
+    #[test]
+    fn test_knowledge_tracks_each_position() {
+        let history = vec![
+            ("CRANE".to_string(), get_feedback("CRANE", "PLATE")),
+            ("SLATE".to_string(), get_feedback("SLATE", "PLATE")),
+        ];
+        let candidates = words(&["PLATE"]);
+        let knowledge = Knowledge::new(&history, &candidates);
+        assert_eq!(knowledge.candidates, 1);
+        assert_eq!(knowledge.positions.len(), WORD_LENGTH);
+
+        let first = &knowledge.positions[0];
+        assert_eq!(first.confirmed, None);
+        assert_eq!(first.ruled_out, ['C', 'S']);
+        assert_eq!(first.distribution, [('P', 1)]);
+        // Green on both guesses, so nothing is ruled out here
+        let third = &knowledge.positions[2];
+        assert_eq!(third.confirmed, Some('A'));
+        assert!(third.ruled_out.is_empty());
+
+        let empty = Knowledge::new(&[], &[]);
+        assert!(
+            empty
+                .positions
+                .iter()
+                .all(|p| *p == PositionKnowledge::default())
+        );
+    }
+
+    #[test]
+    fn test_keyboard_keeps_best_hint() {
+        let mut keyboard = KeyboardState::default();
+        // SPEED against ABIDE: the first E is yellow and the second is gray
+        keyboard.record("SPEED", &get_feedback("SPEED", "ABIDE"));
+        assert_eq!(keyboard.hint('E'), LetterHint::Present);
+        assert_eq!(keyboard.hint('D'), LetterHint::Present);
+
+        keyboard.record("ABIDE", &get_feedback("ABIDE", "ABIDE"));
+        assert_eq!(keyboard.hint('e'), LetterHint::Correct);
+        keyboard.record("SPEED", &get_feedback("SPEED", "ABIDE"));
+        assert_eq!(keyboard.hint('E'), LetterHint::Correct);
+    }
+
+    #[test]
+    fn test_versus_rejects_unknown_words() {
+        let wordbank = words(&["CRANE", "SLATE"]);
+        let mut game = Versus::new("SLATE".to_string());
+        assert!(game.submit("ZZZZZ", &wordbank).is_err());
+        assert_eq!(game.turn(), 0);
+        assert!(game.boards[0].guesses.is_empty());
+    }
+
+    #[test]
+    fn test_versus_no_winner_after_both_boards_fill() {
+        let wordbank = words(&["CRANE", "SLATE"]);
+        let mut game = Versus::new("SLATE".to_string());
+        for _ in 0..MAX_GUESSES * 2 {
+            assert_eq!(game.outcome(), None);
+            game.submit("crane", &wordbank).unwrap();
+        }
+        assert_eq!(game.outcome(), Some(Outcome::NoWinner));
+        assert!(game.submit("SLATE", &wordbank).is_err());
+    }
+
+    #[test]
+    fn test_versus_keeps_turn_when_other_player_is_out() {
+        let wordbank = words(&["CRANE", "SLATE"]);
+        let mut game = Versus::new("SLATE".to_string());
+        game.boards[1].guesses = vec![("CRANE".to_string(), get_feedback("CRANE", "SLATE")); 6];
+        game.submit("CRANE", &wordbank).unwrap();
+        assert_eq!(game.turn(), 0);
+    }
+
+    #[test]
+    fn test_race_solver_guesses_once_per_round() {
+        let wordbank = words(&["CRANE", "SLATE", "RAISE", "STARE", "PLATE"]);
+        let mut race = Race::new("PLATE".to_string(), "CRANE".to_string());
+        race.submit("RAISE", &wordbank).unwrap();
+        assert_eq!(race.boards[1].guesses.len(), 1);
+        assert_eq!(race.boards[1].guesses[0].0, "CRANE");
+        // The keyboard only shows your letters
+        assert_eq!(race.keyboard.hint('C'), LetterHint::Unknown);
+        assert_eq!(race.outcome(), None);
+    }
+
+    #[test]
+    fn test_race_summary_compares_counts() {
+        let wordbank = words(&["CRANE", "SLATE"]);
+        let mut race = Race::new("SLATE".to_string(), "SLATE".to_string());
+        race.submit("CRANE", &wordbank).unwrap();
+        assert_eq!(race.boards[1].guesses.len(), 1);
+        race.submit("SLATE", &wordbank).unwrap();
+        assert_eq!(race.outcome(), Some(Outcome::Winner(1)));
+        assert_eq!(
+            race.summary().unwrap(),
+            "Solver wins! The word was SLATE (You: 2 guesses, Solver: 1 guess)"
+        );
+
+        // A rematch keeps the opener
+        race.restart("CRANE".to_string());
+        race.submit("SLATE", &wordbank).unwrap();
+        assert_eq!(race.outcome(), None);
+        assert_eq!(race.boards[1].guesses[0].0, "SLATE");
+        race.submit("CRANE", &wordbank).unwrap();
+        assert_eq!(race.outcome(), Some(Outcome::Tie));
+        race.submit("SLATE", &wordbank).unwrap_err();
+    }
+
+    #[test]
+    fn test_forfeit_passes_turn_and_ends_game() {
+        let wordbank = words(&["CRANE", "SLATE"]);
+        let mut game = Versus::new("SLATE".to_string());
+        game.forfeit_turn(&wordbank);
+        assert_eq!(game.turn(), 1);
+        assert!(game.submit("CRANE", &wordbank).is_ok());
+        assert_eq!(game.turn(), 1);
+        game.forfeit_turn(&wordbank);
+        assert_eq!(game.outcome(), Some(Outcome::NoWinner));
+        assert!(game.summary().unwrap().contains("Player 1: forfeited"));
+    }
+
+    #[test]
+    fn test_next_guess() {
+        let wordbank = words(&["CRANE", "SLATE", "PLATE"]);
+        let mut board = Board::default();
+        assert_eq!(board.next_guess(&wordbank, "SLATE").unwrap(), "SLATE");
+        board
+            .guesses
+            .push(("SLATE".to_string(), get_feedback("SLATE", "PLATE")));
+        assert_eq!(board.next_guess(&wordbank, "SLATE").unwrap(), "PLATE");
+        board
+            .guesses
+            .push(("CRANE".to_string(), get_feedback("CRANE", "CRANE")));
+        assert_eq!(board.next_guess(&wordbank, "SLATE"), None);
+        assert_eq!("Recommend".parse(), Ok(TimeoutAction::Recommend));
+    }
+
+    #[test]
+    fn test_guess_timer_counts_down() {
+        let start = Instant::now();
+        let mut timer = GuessTimer::new(
+            Duration::from_secs(10),
+            TimeoutAction::Forfeit,
+            String::new(),
+        );
+        timer.restart(start);
+        assert_eq!(timer.remaining(start), Duration::from_secs(10));
+        assert!(!timer.expired(start + Duration::from_secs(9)));
+        assert!(timer.expired(start + Duration::from_secs(10)));
+        assert_eq!(
+            timer.remaining(start + Duration::from_secs(11)),
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn test_pick_answer_is_seeded() {
+        let wordbank = words(&["CRANE", "SLATE", "RAISE", "STARE"]);
+        let a = pick_answer(&wordbank, &mut Rng::new(11));
+        assert_eq!(a, pick_answer(&wordbank, &mut Rng::new(11)));
+        assert!(wordbank.contains(&a));
+    }
+}
@@ -0,0 +1,142 @@
+//! Strategy data baked into the binary for the embedded wordbank.
+//!
+//! With the `precomputed` feature, the build script scores the embedded wordbank's starting
+//! words, and for the best of them the best second guess after each feedback it can get. A
+//! release build then recommends its first two guesses without computing anything, even on
+//! the first run. Without the feature there's no data, and everything is computed as usual.
+//!
+//! The data only applies to the embedded wordbank, and gives the same guesses and scores as
+//! computing them would.
+
+use crate::solver::{Feedback, filter_candidates, get_feedback};
+use crate::wordbank::embedded_wordbank;
+use crate::words::WordBank;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// What the build script wrote: `start WORD SUM` lines best first, where `SUM` is the sum of
+/// squared bucket sizes over the wordbank, then `second PATTERN WORD` lines
+const DATA: &str = include_str!(concat!(env!("OUT_DIR"), "/precomputed.txt"));
+
+struct Book {
+    /// Starting words with their sums of squared bucket sizes, best first
+    starting: Vec<(&'static str, usize)>,
+    /// Best second guess after the top starting word, by its feedback
+    second: HashMap<Vec<Feedback>, &'static str>,
+    wordbank: WordBank,
+}
+
+/// The baked data, or `None` if the binary was built without it
+fn book() -> Option<&'static Book> {
+    static BOOK: OnceLock<Option<Book>> = OnceLock::new();
+    BOOK.get_or_init(|| {
+        let mut starting = Vec::new();
+        let mut second = HashMap::new();
+        for line in DATA.lines().filter(|line| !line.starts_with('#')) {
+            match line.split_whitespace().collect::<Vec<_>>()[..] {
+                ["start", word, sum] => starting.push((word, sum.parse().ok()?)),
+                ["second", pattern, word] => {
+                    let feedback: Option<Vec<Feedback>> =
+                        pattern.chars().map(Feedback::from_char).collect();
+                    second.insert(feedback?, word);
+                }
+                _ => return None,
+            }
+        }
+        (!starting.is_empty()).then(|| Book {
+            starting,
+            second,
+            wordbank: embedded_wordbank(),
+        })
+    })
+    .as_ref()
+}
+
+/// Whether the binary was built with baked data
+#[must_use]
+pub fn is_available() -> bool {
+    book().is_some()
+}
+
+/// The `count` best starting words with their expected pool sizes, if `wordbank` is the
+/// embedded one and at least that many were baked in
+#[allow(clippy::cast_precision_loss)] // sums are far below 2^52
+#[must_use]
+pub fn starting_words(wordbank: &[String], count: usize) -> Option<Vec<(String, f64)>> {
+    let book = book().filter(|book| *book.wordbank == *wordbank)?;
+    (count <= book.starting.len()).then(|| {
+        book.starting[..count]
+            .iter()
+            .map(|&(word, sum)| (word.to_string(), sum as f64 / wordbank.len() as f64))
+            .collect()
+    })
+}
+
+/// The best guess from `wordbank` for `candidates`, if it was baked in: the top starting word
+/// while every word is a candidate, or the second guess for the pool its feedback leaves.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::precomputed;
+/// use wordle_solver::wordbank::sample_wordbank;
+///
+/// // Only the embedded wordbank has baked data
+/// let wordbank = sample_wordbank();
+/// assert_eq!(precomputed::best_guess(&wordbank, &wordbank), None);
+/// ```
+#[must_use]
+pub fn best_guess(wordbank: &[String], candidates: &[String]) -> Option<&'static str> {
+    let book = book().filter(|book| *book.wordbank == *wordbank)?;
+    let opener = book.starting.first()?.0;
+    if candidates.len() == wordbank.len() {
+        return Some(opener);
+    }
+    // The pool the opener's feedback would leave for the first candidate, which is this one
+    // only if the candidates came from playing the opener
+    let feedback = get_feedback(opener, candidates.first()?);
+    let guess = book.second.get(&feedback)?;
+    (filter_candidates(wordbank, opener, &feedback) == candidates).then_some(*guess)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wordbank::sample_wordbank;
+
+    #[test]
+    fn test_no_data_for_other_wordbanks() {
+        let wordbank = sample_wordbank();
+        assert_eq!(starting_words(&wordbank, 1), None);
+        assert_eq!(best_guess(&wordbank, &wordbank[..3]), None);
+        if !cfg!(feature = "precomputed") {
+            assert!(!is_available());
+        }
+    }
+
+    #[cfg(feature = "precomputed")]
+    #[test]
+    fn test_baked_data_matches_the_solver() {
+        use crate::solver::{best_information_guess, expected_pool_size};
+
+        let wordbank = embedded_wordbank();
+        let starting = starting_words(&wordbank, 3).expect("built with the data");
+        let opener = best_guess(&wordbank, &wordbank).unwrap();
+        assert_eq!(opener, starting[0].0);
+        for (word, score) in &starting {
+            assert_eq!(*score, expected_pool_size(word, &wordbank));
+        }
+        assert!(starting.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+
+        // Spot-check a few second guesses against a search over their pools
+        let pools = wordbank
+            .iter()
+            .map(|answer| filter_candidates(&wordbank, opener, &get_feedback(opener, answer)));
+        for pool in pools.filter(|pool| pool.len() > 1).take(3) {
+            let (expected, _, _) = best_information_guess(&wordbank, &pool).unwrap();
+            assert_eq!(best_guess(&wordbank, &pool), Some(expected.as_str()));
+            // A pool the opener didn't leave gets nothing
+            assert_eq!(best_guess(&wordbank, &pool[1..]), None);
+        }
+    }
+}
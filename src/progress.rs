@@ -0,0 +1,203 @@
+//! Progress reporting for long solver computations.
+//!
+//! Solver functions that score many guesses take a [`ProgressSink`] so the CLI and TUI can
+//! show how far along they are without the solver knowing anything about either. They also
+//! take a [`CancelToken`] so a caller can stop them early and keep the best result so far.
+//! A [`Budget`] builds on both to stop a computation after a set time or amount of work.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+/// Guesses scored between progress reports
+pub const REPORT_INTERVAL: usize = 64;
+
+/// Receives progress from long-running solver calls
+pub trait ProgressSink {
+    /// Called every [`REPORT_INTERVAL`] guesses and once at the end with how many of
+    /// `total` guesses have been scored and the best guess and score found so far.
+    fn report(&mut self, current: usize, total: usize, best: Option<(&str, f64)>);
+}
+
+/// Discards all progress reports
+pub struct NoProgress;
+
+impl ProgressSink for NoProgress {
+    fn report(&mut self, _current: usize, _total: usize, _best: Option<(&str, f64)>) {}
+}
+
+/// Any matching closure can be used as a sink.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::progress::{CancelToken, ProgressSink};
+/// use wordle_solver::solver::compute_scored_starting_words_with_progress;
+/// use wordle_solver::words::WordBank;
+///
+/// let words = WordBank::from_valid(["CRANE", "SLATE"]);
+/// let mut last = (0, 0);
+/// let mut sink = |current, total, _best: Option<(&str, f64)>| last = (current, total);
+/// compute_scored_starting_words_with_progress(&words, 5, &mut sink, &CancelToken::new());
+/// assert_eq!(last, (2, 2));
+/// ```
+impl<F: FnMut(usize, usize, Option<(&str, f64)>)> ProgressSink for F {
+    fn report(&mut self, current: usize, total: usize, best: Option<(&str, f64)>) {
+        self(current, total, best);
+    }
+}
+
+/// Whether to report after scoring `done` of `total` guesses
+#[must_use]
+pub const fn should_report(done: usize, total: usize) -> bool {
+    done.is_multiple_of(REPORT_INTERVAL) || done == total
+}
+
+/// Shared flag asking a long computation to stop.
+///
+/// Clones share the flag, so one can be handed to the solver while another is cancelled from
+/// an input handler or another thread.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::progress::CancelToken;
+///
+/// let token = CancelToken::new();
+/// let handle = token.clone();
+/// handle.cancel();
+/// assert!(token.is_cancelled());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Cap on the work one computation may do, so hosted deployments can bound response latency
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Budget {
+    /// Wall-clock time allowed
+    pub max_time: Option<Duration>,
+    /// Guesses that may be scored
+    pub max_evaluations: Option<usize>,
+}
+
+impl Budget {
+    /// No limit on either time or work
+    pub const UNLIMITED: Self = Self {
+        max_time: None,
+        max_evaluations: None,
+    };
+
+    /// Whether `evaluations` guesses scored in `elapsed` use up the budget
+    #[must_use]
+    pub fn is_spent(&self, evaluations: usize, elapsed: Duration) -> bool {
+        self.max_evaluations.is_some_and(|max| evaluations >= max)
+            || self.max_time.is_some_and(|max| elapsed >= max)
+    }
+}
+
+/// Forwards progress to another sink and cancels the computation once a [`Budget`] is spent.
+///
+/// The budget is checked at each report, so a computation may score up to
+/// [`REPORT_INTERVAL`] guesses past it before stopping.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::progress::{Budget, BudgetedProgress, CancelToken, NoProgress};
+/// use wordle_solver::solver::best_information_guess_with_progress;
+///
+/// let words: Vec<String> = (0..200).map(|i| format!("W{i:04}")).collect();
+/// let budget = Budget { max_evaluations: Some(64), ..Budget::UNLIMITED };
+/// let cancel = CancelToken::new();
+/// let mut inner = NoProgress;
+/// let mut sink = BudgetedProgress::new(&mut inner, budget, &cancel);
+/// best_information_guess_with_progress(&words, &words, &mut sink, &cancel);
+/// assert!(sink.exhausted());
+/// ```
+pub struct BudgetedProgress<'a> {
+    inner: &'a mut dyn ProgressSink,
+    budget: Budget,
+    cancel: &'a CancelToken,
+    started: Instant,
+    exhausted: bool,
+}
+
+impl<'a> BudgetedProgress<'a> {
+    /// Starts the clock on `budget`, cancelling `cancel` when it runs out
+    pub fn new(inner: &'a mut dyn ProgressSink, budget: Budget, cancel: &'a CancelToken) -> Self {
+        Self {
+            inner,
+            budget,
+            cancel,
+            started: Instant::now(),
+            exhausted: false,
+        }
+    }
+
+    /// Whether the budget ran out before the computation finished
+    #[must_use]
+    pub const fn exhausted(&self) -> bool {
+        self.exhausted
+    }
+}
+
+impl ProgressSink for BudgetedProgress<'_> {
+    fn report(&mut self, current: usize, total: usize, best: Option<(&str, f64)>) {
+        self.inner.report(current, total, best);
+        if current < total && self.budget.is_spent(current, self.started.elapsed()) {
+            self.exhausted = true;
+            self.cancel.cancel();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_report() {
+        assert!(!should_report(1, 1000));
+        assert!(should_report(REPORT_INTERVAL, 1000));
+        assert!(should_report(1000, 1000));
+        assert!(should_report(3, 3));
+    }
+
+    #[test]
+    fn test_budget_cancels_unfinished_work_only() {
+        let budget = Budget {
+            max_evaluations: Some(10),
+            ..Budget::UNLIMITED
+        };
+        let cancel = CancelToken::new();
+        let mut inner = NoProgress;
+        let mut sink = BudgetedProgress::new(&mut inner, budget, &cancel);
+        sink.report(10, 10, None);
+        assert!(!sink.exhausted() && !cancel.is_cancelled());
+        sink.report(10, 20, None);
+        assert!(sink.exhausted() && cancel.is_cancelled());
+
+        let timed = Budget {
+            max_time: Some(Duration::ZERO),
+            ..Budget::UNLIMITED
+        };
+        assert!(timed.is_spent(0, Duration::ZERO));
+        assert!(!Budget::UNLIMITED.is_spent(usize::MAX, Duration::MAX));
+    }
+}
@@ -0,0 +1,155 @@
+//! Official Wordle puzzle numbers.
+//!
+//! Puzzles are numbered by days since launch, so the number for any date can be worked out
+//! offline. With the `online` feature, today's puzzle can also be fetched from the New York
+//! Times, which is right even if the schedule ever slips.
+
+use chrono::{Days, NaiveDate};
+use std::fmt;
+
+/// Date of puzzle 0
+pub const WORDLE_EPOCH: NaiveDate = match NaiveDate::from_ymd_opt(2021, 6, 19) {
+    Some(date) => date,
+    None => panic!("invalid epoch"),
+};
+
+/// An official puzzle's number and date
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PuzzleInfo {
+    pub number: u32,
+    pub date: NaiveDate,
+}
+
+impl PuzzleInfo {
+    /// The puzzle published on `date`, or `None` before launch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use wordle_solver::puzzle::PuzzleInfo;
+    ///
+    /// let puzzle = PuzzleInfo::for_date(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap()).unwrap();
+    /// assert_eq!(puzzle.number, 926);
+    /// assert_eq!(puzzle.to_string(), "Wordle 926 (2024-01-01)");
+    /// ```
+    #[must_use]
+    pub fn for_date(date: NaiveDate) -> Option<Self> {
+        let days = date.signed_duration_since(WORDLE_EPOCH).num_days();
+        u32::try_from(days).ok().map(|number| Self { number, date })
+    }
+
+    /// The puzzle with the given number
+    #[must_use]
+    pub fn for_number(number: u32) -> Option<Self> {
+        WORDLE_EPOCH
+            .checked_add_days(Days::new(u64::from(number)))
+            .map(|date| Self { number, date })
+    }
+
+    /// Reads the puzzle number and date from the official puzzle JSON
+    #[must_use]
+    pub fn from_json(json: &str) -> Option<Self> {
+        let number = json_field(json, "days_since_launch")?.parse().ok()?;
+        let date = json_field(json, "print_date")?.parse().ok()?;
+        Some(Self { number, date })
+    }
+}
+
+impl fmt::Display for PuzzleInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Wordle {} ({})", group_thousands(self.number), self.date)
+    }
+}
+
+/// A number with commas between each group of three digits, as in share headers
+pub(crate) fn group_thousands(n: u32) -> String {
+    let digits = n.to_string();
+    let mut grouped = String::new();
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+/// The raw value of a top-level string or number field in a flat JSON object
+fn json_field<'a>(json: &'a str, key: &str) -> Option<&'a str> {
+    let rest = &json[json.find(&format!("\"{key}\""))? + key.len() + 2..];
+    let rest = rest.trim_start().strip_prefix(':')?.trim_start();
+    match rest.strip_prefix('"') {
+        Some(quoted) => quoted.split('"').next(),
+        None => rest.split([',', '}']).next().map(str::trim),
+    }
+}
+
+/// Fetches the official puzzle for `date`.
+///
+/// # Errors
+/// Returns an error if the request fails or the response isn't puzzle JSON.
+#[cfg(feature = "online")]
+pub fn fetch_puzzle(date: NaiveDate) -> Result<PuzzleInfo, String> {
+    let url = format!("https://www.nytimes.com/svc/wordle/v2/{date}.json");
+    let body = ureq::get(&url)
+        .call()
+        .map_err(|e| e.to_string())?
+        .body_mut()
+        .read_to_string()
+        .map_err(|e| e.to_string())?;
+    PuzzleInfo::from_json(&body).ok_or_else(|| "unexpected response".to_string())
+}
+
+/// Fetches the official puzzle for `date`.
+///
+/// # Errors
+/// Always fails; fetching needs the `online` feature.
+#[cfg(not(feature = "online"))]
+pub fn fetch_puzzle(_date: NaiveDate) -> Result<PuzzleInfo, String> {
+    Err("fetching puzzles needs the `online` feature (cargo build --features online)".to_string())
+}
+
+/// The puzzle for `date`, fetched if possible and otherwise worked out from the date.
+/// The flag says whether it came from the official source.
+#[must_use]
+pub fn puzzle_for(date: NaiveDate) -> Option<(PuzzleInfo, bool)> {
+    match fetch_puzzle(date) {
+        Ok(puzzle) => Some((puzzle, true)),
+        Err(_) => PuzzleInfo::for_date(date).map(|puzzle| (puzzle, false)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_number_and_date_roundtrip() {
+        let date = NaiveDate::from_ymd_opt(2022, 3, 1).unwrap();
+        let puzzle = PuzzleInfo::for_date(date).unwrap();
+        assert_eq!(PuzzleInfo::for_number(puzzle.number), Some(puzzle));
+        assert_eq!(PuzzleInfo::for_date(WORDLE_EPOCH).unwrap().number, 0);
+        assert!(PuzzleInfo::for_date(NaiveDate::from_ymd_opt(2021, 1, 1).unwrap()).is_none());
+    }
+
+    #[test]
+    fn test_from_json() {
+        let json = r#"{"id":1,"solution":"hello","print_date":"2024-01-01","days_since_launch": 926,"editor":"x"}"#;
+        assert_eq!(
+            PuzzleInfo::from_json(json),
+            Some(PuzzleInfo {
+                number: 926,
+                date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap()
+            })
+        );
+        assert_eq!(PuzzleInfo::from_json("<html>"), None);
+    }
+
+    #[test]
+    fn test_group_thousands() {
+        assert_eq!(group_thousands(7), "7");
+        assert_eq!(group_thousands(1234), "1,234");
+        assert_eq!(group_thousands(1_234_567), "1,234,567");
+    }
+}
@@ -0,0 +1,465 @@
+//! Recorded TUI sessions, so a report of the TUI getting stuck comes with the keys that led
+//! there and can be played back.
+//!
+//! `--record PATH` writes every input event the TUI reads, and each state it moves to, with
+//! the milliseconds since the TUI started:
+//!
+//! ```json
+//! {"version": 1, "events": [
+//!   {"at": 0, "state": "EnteringGuess"},
+//!   {"at": 1532, "key": "c"},
+//!   {"at": 2210, "key": "Enter"},
+//!   {"at": 2215, "state": "MarkingFeedback { marking_index: 0 }"},
+//!   {"at": 4120, "paste": "XXGXY"}
+//! ]}
+//! ```
+//!
+//! Entries are written as they happen, one per line, so a session that had to be killed
+//! still leaves a file that replays up to that point. `--replay PATH` feeds the recorded input
+//! back to the TUI at its recorded pace, or faster with `--replay-speed`, then hands the
+//! keyboard back. The states are there to read alongside; the replay doesn't need them.
+
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
+use tracing::warn;
+
+/// Version written in the header, for telling future formats apart
+pub const FORMAT_VERSION: u32 = 1;
+
+/// One entry of a recording
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Entry {
+    /// An input event the TUI read
+    Input(Event),
+    /// A state the TUI moved to, as it's printed for debugging
+    State(String),
+}
+
+/// Writes a session to a file as it happens
+pub struct Recorder {
+    file: File,
+    started: Instant,
+    entries: usize,
+    last_state: Option<String>,
+    /// Set after a failed write, so a full disk is reported once rather than per key
+    failed: bool,
+}
+
+impl Recorder {
+    /// Creates the file at `path` and writes the header.
+    ///
+    /// # Errors
+    /// Returns an error if the file can't be created or written.
+    pub fn create(path: &Path) -> io::Result<Self> {
+        let mut file = File::create(path)?;
+        write!(file, "{{\"version\": {FORMAT_VERSION}, \"events\": [")?;
+        Ok(Self {
+            file,
+            started: Instant::now(),
+            entries: 0,
+            last_state: None,
+            failed: false,
+        })
+    }
+
+    /// Counts times from now, for when the TUI starts after the recorder was made
+    pub fn start(&mut self) {
+        self.started = Instant::now();
+    }
+
+    /// Records an input event. Key releases and mouse and focus events, which the TUI
+    /// ignores, are skipped.
+    pub fn input(&mut self, event: &Event) {
+        self.write(&Entry::Input(event.clone()));
+    }
+
+    /// Records a state, unless it's the one recorded last
+    pub fn state(&mut self, state: &str) {
+        if self.last_state.as_deref() != Some(state) {
+            self.last_state = Some(state.to_string());
+            self.write(&Entry::State(state.to_string()));
+        }
+    }
+
+    fn write(&mut self, entry: &Entry) {
+        if self.failed {
+            return;
+        }
+        let Some(json) = entry_json(self.started.elapsed(), entry) else {
+            return;
+        };
+        let separator = if self.entries == 0 { "" } else { "," };
+        if let Err(e) = write!(self.file, "{separator}\n  {json}") {
+            warn!("Couldn't write to the session recording: {e}");
+            self.failed = true;
+        }
+        self.entries += 1;
+    }
+}
+
+impl Drop for Recorder {
+    fn drop(&mut self) {
+        if !self.failed {
+            let _ = writeln!(self.file, "\n]}}");
+        }
+    }
+}
+
+/// The JSON line for `entry` recorded `at` into the session, or `None` for events that aren't
+/// recorded.
+///
+/// # Examples
+///
+/// ```
+/// use crossterm::event::{Event, KeyCode, KeyEvent};
+/// use std::time::Duration;
+/// use wordle_solver::recording::{Entry, entry_json};
+///
+/// let enter = Entry::Input(Event::Key(KeyEvent::from(KeyCode::Enter)));
+/// assert_eq!(
+///     entry_json(Duration::from_millis(1532), &enter).unwrap(),
+///     r#"{"at": 1532, "key": "Enter"}"#
+/// );
+/// assert_eq!(entry_json(Duration::ZERO, &Entry::Input(Event::FocusLost)), None);
+/// ```
+#[must_use]
+pub fn entry_json(at: Duration, entry: &Entry) -> Option<String> {
+    let (field, value) = match entry {
+        Entry::Input(Event::Key(key)) if key.kind == KeyEventKind::Press => {
+            ("key", json_string(&key_name(key)?))
+        }
+        Entry::Input(Event::Paste(text)) => ("paste", json_string(text)),
+        Entry::Input(Event::Resize(width, height)) => ("resize", format!("[{width}, {height}]")),
+        Entry::Input(_) => return None,
+        Entry::State(state) => ("state", json_string(state)),
+    };
+    Some(format!(
+        "{{\"at\": {}, \"{field}\": {value}}}",
+        at.as_millis()
+    ))
+}
+
+/// Reads back a line written by [`entry_json`], ignoring a leading comma
+#[must_use]
+pub fn parse_entry(line: &str) -> Option<(Duration, Entry)> {
+    let rest = line
+        .trim()
+        .trim_start_matches(',')
+        .trim_start()
+        .strip_prefix("{\"at\":")?
+        .trim_start();
+    let (at, rest) = rest.split_once(',')?;
+    let at = Duration::from_millis(at.trim().parse().ok()?);
+    let rest = rest.trim().strip_suffix(',').unwrap_or(rest.trim());
+    let (field, value) = rest.strip_suffix('}')?.trim().split_once(':')?;
+    let value = value.trim();
+    let entry = match field.trim() {
+        "\"key\"" => Entry::Input(Event::Key(parse_key(&parse_json_string(value)?)?)),
+        "\"paste\"" => Entry::Input(Event::Paste(parse_json_string(value)?)),
+        "\"resize\"" => {
+            let (width, height) = value
+                .strip_prefix('[')?
+                .strip_suffix(']')?
+                .split_once(',')?;
+            Entry::Input(Event::Resize(
+                width.trim().parse().ok()?,
+                height.trim().parse().ok()?,
+            ))
+        }
+        "\"state\"" => Entry::State(parse_json_string(value)?),
+        _ => return None,
+    };
+    Some((at, entry))
+}
+
+/// A key press as recorded: the character or key name, after any `Ctrl+`, `Alt+` and
+/// `Shift+` held with it.
+///
+/// # Examples
+///
+/// ```
+/// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+/// use wordle_solver::recording::{key_name, parse_key};
+///
+/// let paste = KeyEvent::new(KeyCode::Char('v'), KeyModifiers::CONTROL);
+/// assert_eq!(key_name(&paste).as_deref(), Some("Ctrl+v"));
+/// assert_eq!(parse_key("Ctrl+v"), Some(paste));
+/// assert_eq!(parse_key("F12"), Some(KeyEvent::from(KeyCode::F(12))));
+/// ```
+#[must_use]
+pub fn key_name(key: &KeyEvent) -> Option<String> {
+    let code = match key.code {
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::F(n) => format!("F{n}"),
+        code => NAMED_KEYS
+            .iter()
+            .find(|(_, named)| *named == code)
+            .map(|(name, _)| (*name).to_string())?,
+    };
+    let mut name = String::new();
+    for (prefix, modifier) in MODIFIERS {
+        if key.modifiers.contains(modifier) {
+            name.push_str(prefix);
+        }
+    }
+    name.push_str(&code);
+    Some(name)
+}
+
+/// Reads a key press written by [`key_name`]
+#[must_use]
+pub fn parse_key(name: &str) -> Option<KeyEvent> {
+    let mut rest = name;
+    let mut modifiers = KeyModifiers::NONE;
+    // A bare "+" is the plus key, not a modifier
+    while rest.len() > 1 {
+        let Some((prefix, modifier)) = MODIFIERS.iter().find(|(p, _)| rest.starts_with(p)) else {
+            break;
+        };
+        modifiers |= *modifier;
+        rest = &rest[prefix.len()..];
+    }
+    let mut chars = rest.chars();
+    let code = match (chars.next(), chars.next()) {
+        (Some(c), None) => KeyCode::Char(c),
+        _ => match rest.strip_prefix('F').and_then(|n| n.parse().ok()) {
+            Some(n) => KeyCode::F(n),
+            None => NAMED_KEYS.iter().find(|(named, _)| *named == rest)?.1,
+        },
+    };
+    Some(KeyEvent::new(code, modifiers))
+}
+
+const MODIFIERS: [(&str, KeyModifiers); 3] = [
+    ("Ctrl+", KeyModifiers::CONTROL),
+    ("Alt+", KeyModifiers::ALT),
+    ("Shift+", KeyModifiers::SHIFT),
+];
+
+const NAMED_KEYS: [(&str, KeyCode); 14] = [
+    ("Enter", KeyCode::Enter),
+    ("Esc", KeyCode::Esc),
+    ("Backspace", KeyCode::Backspace),
+    ("Tab", KeyCode::Tab),
+    ("BackTab", KeyCode::BackTab),
+    ("Delete", KeyCode::Delete),
+    ("Left", KeyCode::Left),
+    ("Right", KeyCode::Right),
+    ("Up", KeyCode::Up),
+    ("Down", KeyCode::Down),
+    ("Home", KeyCode::Home),
+    ("End", KeyCode::End),
+    ("PageUp", KeyCode::PageUp),
+    ("PageDown", KeyCode::PageDown),
+];
+
+fn json_string(text: &str) -> String {
+    let mut json = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => json.push_str(&format!("\\u{:04x}", u32::from(c))),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+fn parse_json_string(json: &str) -> Option<String> {
+    let mut chars = json.strip_prefix('"')?.strip_suffix('"')?.chars();
+    let mut text = String::new();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            text.push(c);
+            continue;
+        }
+        text.push(match chars.next()? {
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            'u' => {
+                let hex: String = chars.by_ref().take(4).collect();
+                char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?
+            }
+            escaped => escaped,
+        });
+    }
+    Some(text)
+}
+
+/// A recorded session's input, played back at its recorded pace
+pub struct Replay {
+    events: VecDeque<(Duration, Event)>,
+    speed: f64,
+    started: Instant,
+}
+
+impl Replay {
+    /// Reads the recording at `path`, to play `speed` times faster than it was recorded.
+    ///
+    /// # Errors
+    /// Returns an error if the file can't be read or isn't a recording.
+    pub fn load(path: &Path, speed: f64) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+        Self::parse(&text, speed)
+    }
+
+    /// Reads a recording, to play `speed` times faster than it was recorded.
+    ///
+    /// # Errors
+    /// Returns an error naming the first line that isn't part of a recording.
+    pub fn parse(text: &str, speed: f64) -> Result<Self, String> {
+        let mut lines = text.lines().enumerate();
+        let header = format!("{{\"version\": {FORMAT_VERSION},");
+        if !lines
+            .next()
+            .is_some_and(|(_, line)| line.trim_start().starts_with(&header))
+        {
+            return Err("not a session recording".to_string());
+        }
+        let mut events = VecDeque::new();
+        for (index, line) in lines {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with(']') {
+                continue;
+            }
+            match parse_entry(line).ok_or_else(|| format!("line {} isn't an entry", index + 1))? {
+                (at, Entry::Input(event)) => events.push_back((at, event)),
+                (_, Entry::State(_)) => {}
+            }
+        }
+        Ok(Self {
+            events,
+            speed,
+            started: Instant::now(),
+        })
+    }
+
+    /// Counts times from now, for when the TUI starts after the replay was loaded
+    pub fn start(&mut self) {
+        self.started = Instant::now();
+    }
+
+    /// Input events left to play
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.events.len()
+    }
+
+    /// The next recorded event if it's due `elapsed` into the replay, or how much longer
+    /// until it is. `None` once the recording has run out.
+    pub fn pop_due(&mut self, elapsed: Duration) -> Option<Result<Event, Duration>> {
+        let &(at, _) = self.events.front()?;
+        let due = at.div_f64(self.speed);
+        if due <= elapsed {
+            self.events.pop_front().map(|(_, event)| Ok(event))
+        } else {
+            Some(Err(due - elapsed))
+        }
+    }
+
+    /// The next input event, waiting up to `timeout`. Keys pressed meanwhile come first, so
+    /// Ctrl+C and ESC work during a replay, and once it's over the keyboard takes over.
+    ///
+    /// # Errors
+    /// Returns an error if the terminal can't be read.
+    pub fn next_event(&mut self, timeout: Duration) -> io::Result<Option<Event>> {
+        let wait = match self.pop_due(self.started.elapsed()) {
+            Some(Ok(event)) => return Ok(Some(event)),
+            Some(Err(wait)) => wait,
+            None => timeout,
+        };
+        if event::poll(wait.min(timeout))? {
+            return event::read().map(Some);
+        }
+        Ok(match self.pop_due(self.started.elapsed()) {
+            Some(Ok(event)) => Some(event),
+            _ => None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entries_round_trip() {
+        let entries = [
+            Entry::Input(Event::Key(KeyEvent::from(KeyCode::Char('c')))),
+            Entry::Input(Event::Key(KeyEvent::new(
+                KeyCode::Left,
+                KeyModifiers::SHIFT,
+            ))),
+            Entry::Input(Event::Key(KeyEvent::from(KeyCode::Char('+')))),
+            Entry::Input(Event::Paste("CRANE=\"XY\\\"\n🟩\u{7}".to_string())),
+            Entry::Input(Event::Resize(80, 31)),
+            Entry::State("MarkingFeedback { marking_index: 0 }".to_string()),
+        ];
+        for (ms, entry) in (0..).zip(entries) {
+            let at = Duration::from_millis(ms * 250);
+            let json = entry_json(at, &entry).unwrap();
+            assert_eq!(parse_entry(&json), Some((at, entry.clone())), "{json}");
+            assert_eq!(parse_entry(&format!(",  {json}")), Some((at, entry)));
+        }
+        assert_eq!(parse_entry("{\"at\": 5, \"mouse\": 1}"), None);
+    }
+
+    #[test]
+    fn test_recorded_session_replays() {
+        let path = std::env::temp_dir().join(format!("wordle-session-{}.json", std::process::id()));
+        {
+            let mut recorder = Recorder::create(&path).unwrap();
+            recorder.state("EnteringGuess");
+            recorder.input(&Event::Key(KeyEvent::from(KeyCode::Char('c'))));
+            recorder.input(&Event::FocusGained);
+            recorder.state("EnteringGuess");
+            recorder.input(&Event::Paste("CRANE".to_string()));
+        }
+        let text = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(text.lines().count(), 5, "{text}");
+
+        let mut replay = Replay::parse(&text, 2.0).unwrap();
+        assert_eq!(replay.remaining(), 2);
+        assert!(matches!(
+            replay.pop_due(Duration::from_secs(60)),
+            Some(Ok(Event::Key(_)))
+        ));
+        assert!(matches!(
+            replay.pop_due(Duration::from_secs(60)),
+            Some(Ok(Event::Paste(text))) if text == "CRANE"
+        ));
+        assert!(replay.pop_due(Duration::from_secs(60)).is_none());
+
+        // A session cut short still replays what was written
+        let cut = text.lines().take(3).collect::<Vec<_>>().join("\n");
+        assert_eq!(Replay::parse(&cut, 1.0).unwrap().remaining(), 1);
+        assert!(Replay::parse("{}", 1.0).is_err());
+        assert!(Replay::parse(&format!("{cut}\n  nonsense"), 1.0).is_err());
+    }
+
+    #[test]
+    fn test_replay_keeps_the_recorded_pace() {
+        let text = "{\"version\": 1, \"events\": [\n  {\"at\": 1000, \"key\": \"c\"}\n]}";
+        let mut replay = Replay::parse(text, 4.0).unwrap();
+        // At four times the speed, a key pressed after a second is due after 250ms
+        assert_eq!(
+            replay.pop_due(Duration::from_millis(100)),
+            Some(Err(Duration::from_millis(150)))
+        );
+        assert!(matches!(
+            replay.pop_due(Duration::from_millis(250)),
+            Some(Ok(_))
+        ));
+    }
+}
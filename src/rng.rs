@@ -0,0 +1,130 @@
+//! Seedable random numbers.
+//!
+//! Everything random in the solver draws from an [`Rng`], so running with the same `--seed`
+//! reproduces a run exactly.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// `SplitMix64` generator: tiny and fast, fine for sampling but not for cryptography
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// A generator seeded from the clock and process id, for when no seed was given
+    #[must_use]
+    pub fn from_entropy() -> Self {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos());
+        #[allow(clippy::cast_possible_truncation)] // only the low bits matter for a seed
+        Self::new(nanos as u64 ^ (u64::from(std::process::id()) << 32))
+    }
+
+    pub const fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A uniform index in `0..n`.
+    ///
+    /// # Panics
+    /// Panics if `n` is zero
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "can't pick from an empty range");
+        // Multiply-shift keeps the bias negligible without a rejection loop
+        #[allow(clippy::cast_possible_truncation)] // the result is < n
+        let index = ((u128::from(self.next_u64()) * n as u128) >> 64) as usize;
+        index
+    }
+
+    /// Shuffles `items` in place (Fisher-Yates)
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+
+    /// Picks `n` distinct items uniformly at random, keeping their original order.
+    ///
+    /// Returns all items if there are no more than `n`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wordle_solver::rng::Rng;
+    ///
+    /// let items: Vec<u32> = (0..100).collect();
+    /// let sample = Rng::new(7).sample(&items, 10);
+    /// assert_eq!(sample.len(), 10);
+    /// assert_eq!(sample, Rng::new(7).sample(&items, 10));
+    /// ```
+    pub fn sample<T: Clone>(&mut self, items: &[T], n: usize) -> Vec<T> {
+        if items.len() <= n {
+            return items.to_vec();
+        }
+        let mut indices: Vec<usize> = (0..items.len()).collect();
+        for i in 0..n {
+            let j = i + self.below(items.len() - i);
+            indices.swap(i, j);
+        }
+        let mut chosen = indices[..n].to_vec();
+        chosen.sort_unstable();
+        chosen.into_iter().map(|i| items[i].clone()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_same_sequence() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        let mut c = Rng::new(43);
+        let seq_a: Vec<u64> = (0..5).map(|_| a.next_u64()).collect();
+        let seq_b: Vec<u64> = (0..5).map(|_| b.next_u64()).collect();
+        let seq_c: Vec<u64> = (0..5).map(|_| c.next_u64()).collect();
+        assert_eq!(seq_a, seq_b);
+        assert_ne!(seq_a, seq_c);
+    }
+
+    #[test]
+    fn test_below_stays_in_range_and_covers_it() {
+        let mut rng = Rng::new(1);
+        let mut seen = [false; 6];
+        for _ in 0..200 {
+            seen[rng.below(6)] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+    }
+
+    #[test]
+    fn test_shuffle_is_a_permutation() {
+        let mut items: Vec<u32> = (0..50).collect();
+        Rng::new(9).shuffle(&mut items);
+        assert_ne!(items, (0..50).collect::<Vec<_>>());
+        items.sort_unstable();
+        assert_eq!(items, (0..50).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_sample_distinct_in_order() {
+        let items: Vec<u32> = (0..1000).collect();
+        let sample = Rng::new(3).sample(&items, 50);
+        assert_eq!(sample.len(), 50);
+        assert!(sample.windows(2).all(|w| w[0] < w[1]));
+
+        assert_eq!(Rng::new(3).sample(&items[..10], 50), &items[..10]);
+    }
+}
@@ -0,0 +1,203 @@
+//! Guess scoring written in [Rhai](https://rhai.rs), loaded at runtime with the `scripting`
+//! feature.
+//!
+//! A script defines `score(guess, patterns)`, where `patterns` maps each feedback pattern the
+//! guess could get, such as `"GYXXG"`, to the number of candidates that would give it. It
+//! returns a number, lower being better as with expected pool size. This one scores guesses
+//! the way the solver does:
+//!
+//! ```rhai
+//! fn score(guess, patterns) {
+//!     let total = 0;
+//!     let squares = 0;
+//!     for count in patterns.values() {
+//!         total += count;
+//!         squares += count * count;
+//!     }
+//!     squares.to_float() / total
+//! }
+//! ```
+
+use crate::solver::{PATTERN_COUNT, feedback_code};
+use crate::words::WORD_LENGTH;
+use rhai::{AST, Dynamic, Engine, Map, Scope};
+use std::fmt;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Name of the function a script must define
+pub const SCORE_FN: &str = "score";
+
+/// A compiled scoring script
+#[derive(Clone)]
+pub struct ScriptScorer {
+    source: String,
+    compiled: Arc<(Engine, AST)>,
+}
+
+impl ScriptScorer {
+    /// Compiles `source`, which must define `score(guess, patterns)`.
+    ///
+    /// # Errors
+    /// Returns an error if the script doesn't compile or lacks the function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wordle_solver::script::ScriptScorer;
+    ///
+    /// let scorer = ScriptScorer::compile("fn score(guess, patterns) { patterns.len() * -1 }").unwrap();
+    /// let candidates: Vec<String> = ["BATCH", "CATCH", "HATCH"].iter().map(|s| s.to_string()).collect();
+    /// // CHUBS tells all three apart, BATCH can't tell CATCH from HATCH
+    /// assert_eq!(scorer.score("CHUBS", &candidates), Ok(-3.0));
+    /// assert_eq!(scorer.score("BATCH", &candidates), Ok(-2.0));
+    /// assert!(ScriptScorer::compile("fn rank(guess) { 1 }").is_err());
+    /// ```
+    pub fn compile(source: &str) -> Result<Self, String> {
+        let engine = Engine::new();
+        let ast = engine.compile(source).map_err(|e| e.to_string())?;
+        if !ast
+            .iter_functions()
+            .any(|f| f.name == SCORE_FN && f.params.len() == 2)
+        {
+            return Err(format!(
+                "the script doesn't define {SCORE_FN}(guess, patterns)"
+            ));
+        }
+        Ok(Self {
+            source: source.to_string(),
+            compiled: Arc::new((engine, ast)),
+        })
+    }
+
+    /// Reads and compiles the script at `path`.
+    ///
+    /// # Errors
+    /// Returns an error if the file can't be read or the script doesn't compile.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let source = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+        Self::compile(&source)
+    }
+
+    /// The script's score for `guess` against `candidates`.
+    ///
+    /// # Errors
+    /// Returns an error if the script fails or doesn't return a number.
+    pub fn score(&self, guess: &str, candidates: &[String]) -> Result<f64, String> {
+        let (engine, ast) = &*self.compiled;
+        let result: Dynamic = engine
+            .call_fn(
+                &mut Scope::new(),
+                ast,
+                SCORE_FN,
+                (guess.to_string(), patterns(guess, candidates)),
+            )
+            .map_err(|e| e.to_string())?;
+        #[allow(clippy::cast_precision_loss)] // scores are far below 2^52
+        let score = result
+            .as_float()
+            .or_else(|_| result.as_int().map(|n| n as f64))
+            .map_err(|kind| format!("{SCORE_FN} returned {kind}, not a number"))?;
+        Ok(score)
+    }
+
+    /// The script's score for each of `guesses` against `candidates`, in order.
+    ///
+    /// # Errors
+    /// Returns the first error the script gives.
+    pub fn scores(&self, guesses: &[String], candidates: &[String]) -> Result<Vec<f64>, String> {
+        guesses
+            .iter()
+            .map(|guess| self.score(guess, candidates))
+            .collect()
+    }
+}
+
+impl PartialEq for ScriptScorer {
+    fn eq(&self, other: &Self) -> bool {
+        self.source == other.source
+    }
+}
+
+impl Eq for ScriptScorer {}
+
+impl fmt::Debug for ScriptScorer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ScriptScorer")
+            .field("source_len", &self.source.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// How many of `candidates` give each feedback pattern for `guess`, keyed like `"GYXXG"`
+fn patterns(guess: &str, candidates: &[String]) -> Map {
+    let mut counts = [0i64; PATTERN_COUNT];
+    for solution in candidates {
+        counts[usize::from(feedback_code(guess.as_bytes(), solution.as_bytes()))] += 1;
+    }
+    counts
+        .iter()
+        .enumerate()
+        .filter(|&(_, &count)| count > 0)
+        .map(|(code, &count)| {
+            // The first position is the lowest digit of the code
+            let pattern: String = (0..WORD_LENGTH)
+                .scan(code, |rest, _| {
+                    let digit = *rest % 3;
+                    *rest /= 3;
+                    Some(['X', 'Y', 'G'][digit])
+                })
+                .collect();
+            (pattern.into(), Dynamic::from_int(count))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::expected_pool_size;
+    use crate::wordbank::sample_wordbank;
+
+    const EXPECTED_SIZE: &str = "
+        fn score(guess, patterns) {
+            let total = 0;
+            let squares = 0;
+            for count in patterns.values() {
+                total += count;
+                squares += count * count;
+            }
+            squares.to_float() / total
+        }
+    ";
+
+    #[test]
+    fn test_script_matches_expected_pool_size() {
+        let scorer = ScriptScorer::compile(EXPECTED_SIZE).unwrap();
+        let wordbank = sample_wordbank();
+        for guess in ["CRANE", "QUOTA", "STILT"] {
+            let script = scorer.score(guess, &wordbank).unwrap();
+            assert!((script - expected_pool_size(guess, &wordbank)).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_patterns_are_keyed_by_feedback() {
+        let candidates = vec!["ELEGY".to_string()];
+        let patterns = patterns("EERIE", &candidates);
+        let expected = crate::solver::get_feedback("EERIE", "ELEGY");
+        let key: String = expected.iter().map(|fb| fb.as_char()).collect();
+        assert_eq!(patterns.len(), 1);
+        assert_eq!(patterns[key.as_str()].as_int(), Ok(1));
+    }
+
+    #[test]
+    fn test_script_errors() {
+        assert!(ScriptScorer::compile("fn score(guess, patterns) {").is_err());
+        let scorer = ScriptScorer::compile("fn score(guess, patterns) { \"best\" }").unwrap();
+        let words = vec!["CRANE".to_string()];
+        assert!(scorer.score("CRANE", &words).is_err());
+        let failing = ScriptScorer::compile("fn score(guess, patterns) { throw \"no\" }").unwrap();
+        assert!(failing.scores(&words, &words).is_err());
+    }
+}
@@ -0,0 +1,188 @@
+//! Many games at once, for servers and bots.
+//!
+//! A [`SessionManager`] keeps one [`Game`] per session id, starting games on first use and
+//! evicting those left idle for longer than a time-to-live. Each game can be capped in size so
+//! that a burst of sessions can't exhaust memory. The manager isn't synchronised itself; wrap
+//! it in a `Mutex` to share it between request handlers.
+
+use crate::game::Game;
+use crate::solver::SolverConfig;
+use crate::words::WordBank;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+struct Session {
+    game: Game,
+    last_used: Instant,
+}
+
+/// Games keyed by session id, evicted after going unused for a while.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::{Duration, Instant};
+/// use wordle_solver::session::SessionManager;
+/// use wordle_solver::solver::SolverConfig;
+/// use wordle_solver::words::{Word, WordBank};
+/// use wordle_solver::get_feedback;
+///
+/// let wordbank = WordBank::from_valid(["CRANE", "SLATE", "TRACE"]);
+/// let mut sessions = SessionManager::new(&wordbank, SolverConfig::default(), Duration::from_secs(600));
+///
+/// let now = Instant::now();
+/// let crane = Word::new("CRANE").unwrap();
+/// sessions.game("alice", now).guess(&crane, &get_feedback("CRANE", "TRACE"));
+/// assert_eq!(sessions.game("alice", now).candidates(), ["TRACE"]);
+/// assert_eq!(sessions.game("bob", now).candidates().len(), 3);
+///
+/// assert_eq!(sessions.evict_expired(now + Duration::from_secs(601)), 2);
+/// assert!(sessions.is_empty());
+/// ```
+pub struct SessionManager {
+    wordbank: WordBank,
+    config: SolverConfig,
+    ttl: Duration,
+    /// Cap passed on to every game, if any
+    memory_limit: Option<usize>,
+    sessions: HashMap<String, Session>,
+}
+
+impl SessionManager {
+    /// Sessions over `wordbank`, each playing with `config` and dropped once unused for `ttl`
+    #[must_use]
+    pub fn new(wordbank: &WordBank, config: SolverConfig, ttl: Duration) -> Self {
+        Self {
+            wordbank: wordbank.clone(),
+            config,
+            ttl,
+            memory_limit: None,
+            sessions: HashMap::new(),
+        }
+    }
+
+    /// Caps each session's game at roughly `bytes`; see [`Game::with_memory_limit`]
+    #[must_use]
+    pub const fn with_memory_limit(mut self, bytes: usize) -> Self {
+        self.memory_limit = Some(bytes);
+        self
+    }
+
+    /// The game for session `id`, started if there isn't one. Marks the session used at `now`
+    /// and evicts any that have expired first, so an expired session starts over.
+    pub fn game(&mut self, id: &str, now: Instant) -> &mut Game {
+        self.evict_expired(now);
+        let session = self.sessions.entry(id.to_string()).or_insert_with(|| {
+            let game = Game::new(&self.wordbank, self.config.clone());
+            Session {
+                game: match self.memory_limit {
+                    Some(bytes) => game.with_memory_limit(bytes),
+                    None => game,
+                },
+                last_used: now,
+            }
+        });
+        session.last_used = now;
+        &mut session.game
+    }
+
+    /// The game for session `id` if it exists and hasn't expired, marking it used at `now`
+    pub fn get(&mut self, id: &str, now: Instant) -> Option<&mut Game> {
+        let ttl = self.ttl;
+        let session = self
+            .sessions
+            .get_mut(id)
+            .filter(|session| now.saturating_duration_since(session.last_used) <= ttl)?;
+        session.last_used = now;
+        Some(&mut session.game)
+    }
+
+    /// Ends session `id`, returning its game
+    pub fn remove(&mut self, id: &str) -> Option<Game> {
+        self.sessions.remove(id).map(|session| session.game)
+    }
+
+    /// Drops every session unused for longer than the time-to-live as of `now`, returning
+    /// how many were dropped
+    pub fn evict_expired(&mut self, now: Instant) -> usize {
+        let before = self.sessions.len();
+        let ttl = self.ttl;
+        self.sessions
+            .retain(|_, session| now.saturating_duration_since(session.last_used) <= ttl);
+        before - self.sessions.len()
+    }
+
+    /// Number of live sessions
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.sessions.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.sessions.is_empty()
+    }
+
+    /// Approximate bytes taken by all sessions' games
+    #[must_use]
+    pub fn memory_footprint(&self) -> usize {
+        self.sessions
+            .values()
+            .map(|session| session.game.memory_footprint())
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::get_feedback;
+    use crate::test_util::word;
+
+    fn manager() -> SessionManager {
+        let wordbank = WordBank::from_valid(["CRANE", "SLATE", "PLATE", "ELATE"]);
+        let config = SolverConfig {
+            use_cache: false,
+            ..SolverConfig::default()
+        };
+        SessionManager::new(&wordbank, config, Duration::from_secs(60))
+    }
+
+    #[test]
+    fn test_sessions_are_independent_and_expire() {
+        let mut sessions = manager();
+        let start = Instant::now();
+        sessions
+            .game("a", start)
+            .guess(&word("CRANE"), &get_feedback("CRANE", "PLATE"));
+        sessions.game("b", start + Duration::from_secs(50));
+        assert_eq!(sessions.len(), 2);
+
+        // b was last used 50 seconds ago and a 100 seconds ago
+        let later = start + Duration::from_secs(100);
+        assert!(sessions.get("b", later).is_some());
+        assert!(sessions.get("a", later).is_none());
+        assert_eq!(sessions.evict_expired(later), 1);
+
+        // An expired session starts over
+        assert_eq!(sessions.game("a", later).history().len(), 0);
+        assert!(sessions.remove("a").is_some());
+        assert_eq!(sessions.len(), 1);
+    }
+
+    #[test]
+    fn test_memory_limit_skips_scorer() {
+        let now = Instant::now();
+        let mut unlimited = manager();
+        unlimited.game("a", now).recommendation();
+
+        let mut capped = manager().with_memory_limit(1);
+        let capped_guess = capped.game("a", now).recommendation().unwrap().guess;
+        assert!(capped.memory_footprint() < unlimited.memory_footprint());
+        // Computed without the scorer, but the same recommendation
+        assert_eq!(
+            capped_guess,
+            unlimited.game("a", now).recommendation().unwrap().guess
+        );
+    }
+}
@@ -0,0 +1,377 @@
+//! Solver setups as short codes, for sharing how the solver was run.
+//!
+//! A [`Setup`] holds the solver options that change which guesses get recommended, along
+//! with a fingerprint of the wordbank, and encodes them as a string of about two dozen
+//! URL-safe base64 characters. Pasted into a bug report or a comparison, `--setup CODE`
+//! runs the solver exactly the same way. Of the session options, only the recompute and
+//! low-memory switches are part of it; the stats file, idle timeout and the like aren't.
+
+use crate::game_state::SessionOptions;
+use crate::progress::Budget;
+use crate::solver::{MAX_LOOKAHEAD, SolverConfig, Strategy};
+use crate::wordbank::wordbank_fingerprint;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Format of the encoded bytes, bumped whenever they change
+const VERSION: u8 = 1;
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+// Bits of the flags byte
+const USE_CACHE: u8 = 1;
+const AUTO_RECOMMEND: u8 = 1 << 1;
+const RECOMPUTE: u8 = 1 << 2;
+const LOW_MEMORY: u8 = 1 << 3;
+const HAS_SEED: u8 = 1 << 4;
+const HAS_SAMPLE: u8 = 1 << 5;
+const HAS_MAX_TIME: u8 = 1 << 6;
+const HAS_MAX_EVALUATIONS: u8 = 1 << 7;
+
+/// A solver configuration and the wordbank it was used with
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Setup {
+    /// The shared options, with everything else left at its default
+    pub config: SolverConfig,
+    /// The shared session switches, with everything else left at its default
+    pub session: SessionOptions,
+    /// [`wordbank_fingerprint`] of the wordbank
+    pub wordbank: u64,
+}
+
+impl Setup {
+    /// The setup of `config` and `session` used with `wordbank`, keeping only the options a
+    /// code carries
+    #[must_use]
+    pub fn new(config: &SolverConfig, session: &SessionOptions, wordbank: &[String]) -> Self {
+        let config = SolverConfig {
+            strategy: config.strategy,
+            sample_size: config.sample_size,
+            lookahead: config.lookahead,
+            use_cache: config.use_cache,
+            seed: config.seed,
+            auto_recommend: config.auto_recommend,
+            budget: config.budget,
+            starting_words: config.starting_words,
+            avoid: config.avoid.clone(),
+            ..SolverConfig::default()
+        };
+        let session = SessionOptions {
+            recompute: session.recompute,
+            low_memory: session.low_memory,
+            ..SessionOptions::default()
+        };
+        Self {
+            config,
+            session,
+            wordbank: wordbank_fingerprint(wordbank),
+        }
+    }
+
+    /// Whether the setup was made with `wordbank`
+    #[must_use]
+    pub fn matches_wordbank(&self, wordbank: &[String]) -> bool {
+        self.wordbank == wordbank_fingerprint(wordbank)
+    }
+
+    /// The setup as a short code that [`Setup::from_str`] reads back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wordle_solver::game_state::SessionOptions;
+    /// use wordle_solver::setup::Setup;
+    /// use wordle_solver::solver::{Profile, SolverConfig};
+    /// use wordle_solver::wordbank::sample_wordbank;
+    ///
+    /// let config = SolverConfig { seed: Some(7), ..Profile::Fast.config() };
+    /// let setup = Setup::new(&config, &SessionOptions::default(), &sample_wordbank());
+    /// let code = setup.encode();
+    /// assert!(code.len() < 32);
+    /// assert_eq!(code.parse::<Setup>(), Ok(setup));
+    /// ```
+    #[must_use]
+    pub fn encode(&self) -> String {
+        let (config, session) = (&self.config, &self.session);
+        let mut flags = 0;
+        for (set, bit) in [
+            (config.use_cache, USE_CACHE),
+            (config.auto_recommend, AUTO_RECOMMEND),
+            (session.recompute, RECOMPUTE),
+            (session.low_memory, LOW_MEMORY),
+            (config.seed.is_some(), HAS_SEED),
+            (config.sample_size.is_some(), HAS_SAMPLE),
+            (config.budget.max_time.is_some(), HAS_MAX_TIME),
+            (config.budget.max_evaluations.is_some(), HAS_MAX_EVALUATIONS),
+        ] {
+            if set {
+                flags |= bit;
+            }
+        }
+        let mut bytes = vec![VERSION];
+        bytes.extend(self.wordbank.to_le_bytes());
+        bytes.push(match config.strategy {
+            Strategy::ExpectedSize => 0,
+            Strategy::CandidatesOnly => 1,
+        });
+        bytes.push(flags);
+        let avoid = config
+            .avoid
+            .iter()
+            .filter(|letter| letter.is_ascii_uppercase())
+            .fold(0, |mask, &letter| {
+                mask | 1 << (u32::from(letter) - u32::from('A'))
+            });
+        let max_time = config
+            .budget
+            .max_time
+            .map(|time| u64::try_from(time.as_millis()).unwrap_or(u64::MAX));
+        for value in [
+            Some(config.lookahead as u64),
+            Some(config.starting_words as u64),
+            Some(avoid),
+            config.seed,
+            config.sample_size.map(|size| size as u64),
+            max_time,
+            config.budget.max_evaluations.map(|max| max as u64),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            push_varint(&mut bytes, value);
+        }
+        to_base64(&bytes)
+    }
+}
+
+impl FromStr for Setup {
+    type Err = String;
+
+    /// Reads a code made by [`Setup::encode`]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = from_base64(s.trim()).ok_or_else(|| format!("'{s}' isn't a setup code"))?;
+        let mut reader = Reader(&bytes);
+        let version = reader.byte()?;
+        if version != VERSION {
+            return Err(format!(
+                "setup code version {version} isn't supported (expected {VERSION})"
+            ));
+        }
+        let wordbank = u64::from_le_bytes(reader.take::<8>()?);
+        let strategy = match reader.byte()? {
+            0 => Strategy::ExpectedSize,
+            1 => Strategy::CandidatesOnly,
+            other => return Err(format!("unknown strategy {other} in setup code")),
+        };
+        let flags = reader.byte()?;
+        let lookahead = reader.size()?;
+        let starting_words = reader.size()?;
+        let avoid_mask = reader.varint()?;
+        let mut optional = |bit: u8| -> Result<Option<u64>, String> {
+            (flags & bit != 0).then(|| reader.varint()).transpose()
+        };
+        let seed = optional(HAS_SEED)?;
+        let sample_size = optional(HAS_SAMPLE)?.map(to_size).transpose()?;
+        let max_time = optional(HAS_MAX_TIME)?.map(Duration::from_millis);
+        let max_evaluations = optional(HAS_MAX_EVALUATIONS)?.map(to_size).transpose()?;
+        if !reader.0.is_empty() {
+            return Err("setup code has bytes left over".to_string());
+        }
+        if starting_words == 0 || lookahead > MAX_LOOKAHEAD || avoid_mask >> 26 != 0 {
+            return Err("setup code has values out of range".to_string());
+        }
+        let avoid = ('A'..='Z')
+            .enumerate()
+            .filter(|&(i, _)| avoid_mask & 1 << i != 0)
+            .map(|(_, letter)| letter)
+            .collect();
+        Ok(Self {
+            config: SolverConfig {
+                strategy,
+                sample_size,
+                lookahead,
+                use_cache: flags & USE_CACHE != 0,
+                seed,
+                auto_recommend: flags & AUTO_RECOMMEND != 0,
+                budget: Budget {
+                    max_time,
+                    max_evaluations,
+                },
+                starting_words,
+                avoid,
+                ..SolverConfig::default()
+            },
+            session: SessionOptions {
+                recompute: flags & RECOMPUTE != 0,
+                low_memory: flags & LOW_MEMORY != 0,
+                ..SessionOptions::default()
+            },
+            wordbank,
+        })
+    }
+}
+
+impl fmt::Display for Setup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.encode())
+    }
+}
+
+/// Appends `value` seven bits at a time, low bits first, with the top bit marking more to come
+fn push_varint(bytes: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        bytes.push((value & 0x7f) as u8 | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+fn to_size(value: u64) -> Result<usize, String> {
+    usize::try_from(value).map_err(|_| "setup code has values out of range".to_string())
+}
+
+/// Decoded bytes not yet read
+struct Reader<'a>(&'a [u8]);
+
+impl Reader<'_> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], String> {
+        let (head, rest) = self
+            .0
+            .split_first_chunk()
+            .ok_or_else(|| "setup code is cut short".to_string())?;
+        self.0 = rest;
+        Ok(*head)
+    }
+
+    fn byte(&mut self) -> Result<u8, String> {
+        self.take::<1>().map(|[byte]| byte)
+    }
+
+    fn varint(&mut self) -> Result<u64, String> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.byte()?;
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err("setup code has values out of range".to_string())
+    }
+
+    fn size(&mut self) -> Result<usize, String> {
+        self.varint().and_then(to_size)
+    }
+}
+
+/// URL-safe base64 without padding, so codes survive being pasted into links and chat
+fn to_base64(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let group = chunk.iter().enumerate().fold(0u32, |group, (i, &byte)| {
+            group | u32::from(byte) << (16 - 8 * i)
+        });
+        for i in 0..=chunk.len() {
+            out.push(char::from(
+                ALPHABET[(group >> (18 - 6 * i) & 0x3f) as usize],
+            ));
+        }
+    }
+    out
+}
+
+/// Reads [`to_base64`]'s output, or `None` if `text` isn't valid
+fn from_base64(text: &str) -> Option<Vec<u8>> {
+    let digits: Vec<u32> = text
+        .bytes()
+        .map(|c| ALPHABET.iter().position(|&a| a == c).map(|d| d as u32))
+        .collect::<Option<_>>()?;
+    let mut bytes = Vec::with_capacity(digits.len() * 3 / 4);
+    for chunk in digits.chunks(4) {
+        if chunk.len() == 1 {
+            return None;
+        }
+        let group = chunk
+            .iter()
+            .enumerate()
+            .fold(0, |group, (i, &digit)| group | digit << (18 - 6 * i));
+        for i in 0..chunk.len() - 1 {
+            bytes.push((group >> (16 - 8 * i)) as u8);
+        }
+    }
+    Some(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::Profile;
+    use crate::wordbank::sample_wordbank;
+
+    #[test]
+    fn test_setup_round_trips_every_option() {
+        let wordbank = sample_wordbank();
+        let config = SolverConfig {
+            strategy: Strategy::CandidatesOnly,
+            sample_size: Some(300),
+            lookahead: 1,
+            use_cache: false,
+            seed: Some(u64::MAX),
+            auto_recommend: false,
+            budget: Budget {
+                max_time: Some(Duration::from_millis(250)),
+                max_evaluations: Some(5000),
+            },
+            starting_words: 12,
+            avoid: vec!['S', 'Z'],
+            ..SolverConfig::default()
+        };
+        let session = SessionOptions {
+            recompute: true,
+            low_memory: true,
+            // Not shared
+            max_invalid_inputs: Some(3),
+            ..SessionOptions::default()
+        };
+        let setup = Setup::new(&config, &session, &wordbank);
+        assert_eq!(setup.session.max_invalid_inputs, None);
+        assert_eq!(setup.encode().parse::<Setup>(), Ok(setup.clone()));
+        assert!(setup.matches_wordbank(&wordbank));
+        assert!(!setup.matches_wordbank(&wordbank[1..]));
+
+        for profile in [Profile::Fast, Profile::Balanced, Profile::Exhaustive] {
+            let setup = Setup::new(&profile.config(), &SessionOptions::default(), &wordbank);
+            assert_eq!(
+                setup.to_string().parse::<Setup>().unwrap().config,
+                profile.config()
+            );
+        }
+    }
+
+    #[test]
+    fn test_setup_rejects_bad_codes() {
+        let code = Setup::new(
+            &SolverConfig::default(),
+            &SessionOptions::default(),
+            &sample_wordbank(),
+        )
+        .encode();
+        assert!("not a code!".parse::<Setup>().is_err());
+        assert!(code[..code.len() - 2].parse::<Setup>().is_err());
+        assert!(format!("{code}AA").parse::<Setup>().is_err());
+        let mut bytes = from_base64(&code).unwrap();
+        bytes[0] = VERSION + 1;
+        assert!(to_base64(&bytes).parse::<Setup>().is_err());
+    }
+
+    #[test]
+    fn test_base64_round_trips() {
+        for len in 0..8 {
+            let bytes: Vec<u8> = (0..len).map(|i| (i * 97 + 200) as u8).collect();
+            assert_eq!(from_base64(&to_base64(&bytes)), Some(bytes));
+        }
+        assert_eq!(to_base64(b"Man"), "TWFu");
+        assert_eq!(to_base64(b"Ma"), "TWE");
+    }
+}
@@ -0,0 +1,287 @@
+//! Analysis of pasted Wordle share grids.
+//!
+//! A share grid only shows the colours of each guess, so for every row we list the words that
+//! would have produced those colours against the known answer. Rounds are scored by assuming
+//! the player made the most informative of those guesses, then comparing it with the
+//! solver's best guess for the same pool.
+
+use crate::play::MAX_GUESSES;
+use crate::puzzle::group_thousands;
+use crate::solver::{
+    Feedback, best_information_guess, expected_pool_size, filter_candidates, get_feedback,
+};
+
+/// One row of the grid, reconstructed
+pub struct RoundAnalysis {
+    pub feedback: Vec<Feedback>,
+    /// Every word in the wordbank that gives this row's colours against the answer
+    pub possible_guesses: Vec<String>,
+    /// The most informative of the possible guesses
+    pub likely_guess: String,
+    /// Expected pool size after `likely_guess`
+    pub score: f64,
+    pub best_guess: String,
+    pub best_score: f64,
+    /// Candidates left before this round
+    pub pool_size: usize,
+}
+
+impl RoundAnalysis {
+    /// How close the likely guess came to the solver's best, from 0 to 1
+    #[must_use]
+    pub fn efficiency(&self) -> f64 {
+        if self.score <= 0.0 {
+            1.0
+        } else {
+            (self.best_score / self.score).min(1.0)
+        }
+    }
+}
+
+/// Full reconstruction of a shared game
+pub struct ShareAnalysis {
+    pub answer: String,
+    pub rounds: Vec<RoundAnalysis>,
+}
+
+impl ShareAnalysis {
+    /// Number of guess sequences consistent with the grid
+    #[must_use]
+    pub fn sequence_count(&self) -> usize {
+        self.rounds
+            .iter()
+            .map(|r| r.possible_guesses.len())
+            .fold(1, usize::saturating_mul)
+    }
+
+    /// Mean efficiency over the rounds before the answer was guessed, from 0 to 1
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // don't care about this
+    pub fn efficiency(&self) -> f64 {
+        let scored = &self.rounds[..self.rounds.len().saturating_sub(1)];
+        if scored.is_empty() {
+            return 1.0;
+        }
+        let rounds = scored.len() as f64;
+        scored.iter().map(RoundAnalysis::efficiency).sum::<f64>() / rounds
+    }
+}
+
+/// Parses the coloured squares of a share grid, one row per line.
+///
+/// Lines without squares (such as the `Wordle 1,234 4/6` header) are skipped. Both the dark
+/// and light themes and the high-contrast colours are understood.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::Feedback;
+/// use wordle_solver::share::parse_share_grid;
+///
+/// let rows = parse_share_grid("Wordle 1,234 2/6\n\n⬛🟨⬛⬛⬛\n🟩🟩🟩🟩🟩").unwrap();
+/// assert_eq!(rows.len(), 2);
+/// assert_eq!(rows[0][1], Feedback::PartialMatch);
+/// ```
+///
+/// # Errors
+/// Returns an error if a row doesn't have exactly five squares or the grid has no rows.
+pub fn parse_share_grid(text: &str) -> Result<Vec<Vec<Feedback>>, String> {
+    let mut rows = Vec::new();
+    for line in text.lines() {
+        let squares: Vec<char> = line.chars().filter(|c| !c.is_whitespace()).collect();
+        if !squares.iter().any(|&c| square_feedback(c).is_some()) {
+            continue;
+        }
+        let row: Option<Vec<Feedback>> = squares.into_iter().map(square_feedback).collect();
+        match row {
+            Some(row) if row.len() == 5 => rows.push(row),
+            _ => return Err(format!("'{}' is not a row of five squares", line.trim())),
+        }
+    }
+    if rows.is_empty() {
+        return Err("no grid rows found".to_string());
+    }
+    Ok(rows)
+}
+
+/// The puzzle number from a share header such as `Wordle 1,234 4/6`
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::share::parse_share_number;
+///
+/// assert_eq!(parse_share_number("Wordle 1,234 4/6\n🟩🟩🟩🟩🟩"), Some(1234));
+/// assert_eq!(parse_share_number("🟩🟩🟩🟩🟩"), None);
+/// ```
+#[must_use]
+pub fn parse_share_number(text: &str) -> Option<u32> {
+    text.lines().find_map(|line| {
+        let number = line
+            .trim()
+            .strip_prefix("Wordle ")?
+            .split_whitespace()
+            .next()?;
+        number.replace([',', '.'], "").parse().ok()
+    })
+}
+
+/// A share grid for `rows` in the official format, headed with the puzzle number if known.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::Feedback;
+/// use wordle_solver::share::share_text;
+///
+/// let rows = [vec![Feedback::NoMatch; 5], vec![Feedback::Match; 5]];
+/// assert_eq!(share_text(Some(1234), &rows), "Wordle 1,234 2/6\n\n⬛⬛⬛⬛⬛\n🟩🟩🟩🟩🟩");
+/// ```
+#[must_use]
+pub fn share_text(puzzle: Option<u32>, rows: &[Vec<Feedback>]) -> String {
+    let solved = rows
+        .last()
+        .is_some_and(|row| row.iter().all(|&f| f == Feedback::Match));
+    let score = if solved {
+        rows.len().to_string()
+    } else {
+        "X".to_string()
+    };
+    let header = match puzzle {
+        Some(number) => format!("Wordle {} {score}/{MAX_GUESSES}", group_thousands(number)),
+        None => format!("Wordle {score}/{MAX_GUESSES}"),
+    };
+    let grid: Vec<String> = rows
+        .iter()
+        .map(|row| row.iter().map(|&f| feedback_square(f)).collect())
+        .collect();
+    format!("{header}\n\n{}", grid.join("\n"))
+}
+
+/// The dark-theme square for one letter of feedback
+#[must_use]
+pub const fn feedback_square(feedback: Feedback) -> char {
+    match feedback {
+        Feedback::Match => '🟩',
+        Feedback::PartialMatch => '🟨',
+        Feedback::NoMatch => '⬛',
+    }
+}
+
+const fn square_feedback(c: char) -> Option<Feedback> {
+    match c {
+        '🟩' | '🟧' => Some(Feedback::Match),
+        '🟨' | '🟦' => Some(Feedback::PartialMatch),
+        '⬛' | '⬜' => Some(Feedback::NoMatch),
+        _ => None,
+    }
+}
+
+/// Reconstructs the guesses behind `rows` for `answer` and scores each round.
+///
+/// # Errors
+/// Returns an error if the answer isn't in the wordbank or a row matches no word.
+pub fn analyze_share(
+    wordbank: &[String],
+    rows: &[Vec<Feedback>],
+    answer: &str,
+) -> Result<ShareAnalysis, String> {
+    let answer = answer.to_uppercase();
+    if !wordbank.contains(&answer) {
+        return Err(format!("{answer} is not in the wordbank"));
+    }
+    let mut pool = wordbank.to_vec();
+    let mut rounds = Vec::with_capacity(rows.len());
+    for (i, feedback) in rows.iter().enumerate() {
+        let possible_guesses: Vec<String> = wordbank
+            .iter()
+            .filter(|word| get_feedback(word, &answer) == *feedback)
+            .cloned()
+            .collect();
+        let (likely_guess, score) = possible_guesses
+            .iter()
+            .map(|guess| (guess, expected_pool_size(guess, &pool)))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .ok_or_else(|| format!("row {} matches no word in the wordbank", i + 1))?;
+        let likely_guess = likely_guess.clone();
+        let (best_guess, best_score, _) = best_information_guess(wordbank, &pool)
+            .ok_or_else(|| format!("no candidates are left by row {}", i + 1))?;
+        let best_guess = best_guess.clone();
+        let pool_size = pool.len();
+        pool = filter_candidates(&pool, &likely_guess, feedback);
+        rounds.push(RoundAnalysis {
+            feedback: feedback.clone(),
+            possible_guesses,
+            likely_guess,
+            score,
+            best_guess,
+            best_score,
+            pool_size,
+        });
+    }
+    Ok(ShareAnalysis { answer, rounds })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::words;
+
+    #[test]
+    fn test_parse_share_grid_themes() {
+        let rows = parse_share_grid("⬜🟦⬜⬜🟧\n🟩🟩🟩🟩🟩\n").unwrap();
+        assert_eq!(
+            rows[0],
+            [
+                Feedback::NoMatch,
+                Feedback::PartialMatch,
+                Feedback::NoMatch,
+                Feedback::NoMatch,
+                Feedback::Match
+            ]
+        );
+        assert_eq!(rows[1], [Feedback::Match; 5]);
+    }
+
+    #[test]
+    fn test_parse_share_grid_rejects_short_rows_and_empty_input() {
+        assert!(parse_share_grid("🟩🟩🟩🟩").is_err());
+        assert!(parse_share_grid("Wordle 1,234 X/6").is_err());
+    }
+
+    #[test]
+    fn test_share_text_roundtrips_through_parser() {
+        let rows = vec![
+            get_feedback("SLATE", "CRATE"),
+            get_feedback("CRANE", "CRATE"),
+        ];
+        let text = share_text(Some(7), &rows);
+        assert!(text.starts_with("Wordle 7 X/6"));
+        assert_eq!(parse_share_number(&text), Some(7));
+        assert_eq!(parse_share_grid(&text).unwrap(), rows);
+    }
+
+    #[test]
+    fn test_analyze_share_reconstructs_guesses() {
+        let wordbank = words(&["CRANE", "SLATE", "PLATE", "CRATE", "GRATE"]);
+        let rows = vec![get_feedback("SLATE", "CRATE"), vec![Feedback::Match; 5]];
+        let analysis = analyze_share(&wordbank, &rows, "crate").unwrap();
+
+        assert_eq!(analysis.answer, "CRATE");
+        // PLATE gives the same colours as SLATE against CRATE
+        assert_eq!(analysis.rounds[0].possible_guesses, ["SLATE", "PLATE"]);
+        assert_eq!(analysis.rounds[1].possible_guesses, ["CRATE"]);
+        assert_eq!(analysis.sequence_count(), 2);
+        assert_eq!(analysis.rounds[0].pool_size, wordbank.len());
+        let efficiency = analysis.efficiency();
+        assert!(efficiency > 0.0 && efficiency <= 1.0);
+    }
+
+    #[test]
+    fn test_analyze_share_errors() {
+        let wordbank = words(&["CRANE", "SLATE"]);
+        assert!(analyze_share(&wordbank, &[vec![Feedback::Match; 5]], "PLATE").is_err());
+        let impossible = vec![vec![Feedback::PartialMatch; 5]];
+        assert!(analyze_share(&wordbank, &impossible, "CRANE").is_err());
+    }
+}
@@ -1,4 +1,273 @@
-use std::collections::HashMap;
+//! Candidate filtering and guess scoring.
+//!
+//! # Determinism
+//!
+//! Recommendations depend only on their inputs: the same wordbank, candidates and
+//! [`SolverConfig`] give the same guess and score on every run and platform. Nothing is
+//! iterated in hash order, and scores are summed in a fixed order so floating-point rounding
+//! can't differ. When guesses tie on score, the one that comes first in the wordbank wins, so
+//! the order words are loaded in is part of the input. Sampled scoring is deterministic too,
+//! as long as the sample is (see [`sample_candidates`]).
+
+use crate::external::ExternalScorer;
+use crate::progress::{Budget, CancelToken, NoProgress, ProgressSink, should_report};
+#[cfg(feature = "scripting")]
+use crate::script::ScriptScorer;
+use crate::words::{CandidateSet, WORD_LENGTH, Word, WordArena, WordBank, WordId};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::str::FromStr;
+use tracing::instrument;
+
+/// z-score for a two-sided 95% confidence interval
+const Z_95: f64 = 1.96;
+
+/// Number of top-ranked guesses re-scored when looking ahead
+const LOOKAHEAD_WIDTH: usize = 10;
+
+/// Sample size used by the fast profile
+const FAST_SAMPLE_SIZE: usize = 250;
+
+/// Bytes the incremental scorer may take in low-memory mode
+pub const LOW_MEMORY_LIMIT: usize = 32 * 1024 * 1024;
+
+/// Top guesses of a search kept by [`WarmStart`] to try first in the next round
+pub const WARM_START_SIZE: usize = 64;
+
+/// Starting words shown and cached unless configured otherwise
+pub const DEFAULT_STARTING_WORDS: usize = 5;
+
+/// Words scored between checkpoints of a starting-word computation
+pub const CHECKPOINT_INTERVAL: usize = 256;
+
+/// Deepest lookahead the search supports: one step past the guess being ranked
+pub const MAX_LOOKAHEAD: usize = 1;
+
+/// A word with its expected pool size
+pub type ScoredWord = (String, f64);
+
+/// How each board counts towards a guess's score when solving several boards at once
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BoardWeighting {
+    /// Every board counts the same, so the biggest pools dominate (default)
+    #[default]
+    Equal,
+    /// Boards count in inverse proportion to their pool size, favouring guesses that close
+    /// out nearly solved boards before the shared guesses run out
+    NearSolved,
+}
+
+impl BoardWeighting {
+    /// Weight of a board with `pool` candidates left
+    #[allow(clippy::cast_precision_loss)] // don't care about this
+    #[must_use]
+    pub fn weight(self, pool: usize) -> f64 {
+        match self {
+            Self::Equal => 1.0,
+            Self::NearSolved => 1.0 / pool as f64,
+        }
+    }
+}
+
+impl FromStr for BoardWeighting {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "equal" => Ok(Self::Equal),
+            "near-solved" => Ok(Self::NearSolved),
+            _ => Err(format!(
+                "unknown board weighting '{s}' (expected equal or near-solved)"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for BoardWeighting {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Equal => "equal",
+            Self::NearSolved => "near-solved",
+        })
+    }
+}
+
+/// Input the checked solver functions ([`try_get_feedback`], [`try_filter_candidates`]) reject
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WordleError {
+    /// A guess or solution that isn't a valid [`Word`]
+    WordLength(String),
+    /// Feedback with this many values instead of one per letter
+    FeedbackLength(usize),
+}
+
+impl fmt::Display for WordleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WordLength(word) => {
+                write!(f, "'{word}' is not a {WORD_LENGTH}-letter word")
+            }
+            Self::FeedbackLength(len) => {
+                write!(f, "expected {WORD_LENGTH} feedback values, got {len}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for WordleError {}
+
+/// Factor a guess's score is multiplied by for each avoided letter it contains
+pub const AVOID_PENALTY: f64 = 1.25;
+
+/// Number of distinct feedback patterns for a 5-letter word (3^5)
+pub const PATTERN_COUNT: usize = 243;
+
+/// Which words are considered as guesses
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Strategy {
+    /// Score every word in the wordbank (default)
+    #[default]
+    ExpectedSize,
+    /// Only score words that could still be the answer (much faster, slightly weaker)
+    CandidatesOnly,
+}
+
+impl FromStr for Strategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "expected-size" => Ok(Self::ExpectedSize),
+            "candidates-only" => Ok(Self::CandidatesOnly),
+            _ => Err(format!(
+                "unknown strategy '{s}' (expected expected-size or candidates-only)"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Strategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::ExpectedSize => "expected-size",
+            Self::CandidatesOnly => "candidates-only",
+        })
+    }
+}
+
+/// Tuning options for how guesses are scored
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SolverConfig {
+    pub strategy: Strategy,
+    /// When set, pools larger than this are scored against a sample of this many candidates
+    pub sample_size: Option<usize>,
+    /// Extra guesses to look ahead when ranking (0 = greedy, 1 = re-rank the top guesses
+    /// by the pool expected after the following guess too). Anything above
+    /// [`MAX_LOOKAHEAD`] searches the same as 1.
+    pub lookahead: usize,
+    /// Read and write the starting-words cache
+    pub use_cache: bool,
+    /// Seed for random sampling. Without one, samples are evenly spaced instead.
+    pub seed: Option<u64>,
+    /// Show the starting words and a recommendation after every guess. When off, help only
+    /// comes from asking for hints.
+    pub auto_recommend: bool,
+    /// Limit on the time or guesses scored for each recommendation. The best guess found
+    /// within it is returned and marked truncated.
+    pub budget: Budget,
+    /// How many of the best starting words to show and cache
+    pub starting_words: usize,
+    /// Letters to keep out of recommendations where a comparable guess does without them,
+    /// e.g. ones known not to be in the answer or being saved for another board
+    pub avoid: Vec<char>,
+    /// Tag the first guess of every game as forced, for players who always open with the same
+    /// word, so the post-game score only rates the guesses they chose
+    pub fixed_opener: bool,
+    /// Command whose scores replace expected pool size when ranking guesses after the first.
+    /// If it fails, the built-in scoring is used for that recommendation.
+    pub external_scorer: Option<ExternalScorer>,
+    /// Rhai script whose `score` function ranks guesses after the first, tried before the
+    /// external scorer. If it fails, the next scoring in line is used for that recommendation.
+    #[cfg(feature = "scripting")]
+    pub script: Option<ScriptScorer>,
+}
+
+impl Default for SolverConfig {
+    fn default() -> Self {
+        Profile::Balanced.config()
+    }
+}
+
+/// Named presets bundling the solver tuning options
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Profile {
+    /// Candidate-only guesses scored against a sample: near-instant on any wordbank
+    Fast,
+    /// Exact greedy scoring over the whole wordbank (default)
+    #[default]
+    Balanced,
+    /// Exact scoring with one step of lookahead and no time or evaluation budget
+    Exhaustive,
+}
+
+impl Profile {
+    #[must_use]
+    pub fn config(self) -> SolverConfig {
+        let balanced = SolverConfig {
+            strategy: Strategy::ExpectedSize,
+            sample_size: None,
+            lookahead: 0,
+            use_cache: true,
+            seed: None,
+            auto_recommend: true,
+            budget: Budget::UNLIMITED,
+            starting_words: DEFAULT_STARTING_WORDS,
+            avoid: Vec::new(),
+            fixed_opener: false,
+            external_scorer: None,
+            #[cfg(feature = "scripting")]
+            script: None,
+        };
+        match self {
+            Self::Fast => SolverConfig {
+                strategy: Strategy::CandidatesOnly,
+                sample_size: Some(FAST_SAMPLE_SIZE),
+                ..balanced
+            },
+            Self::Balanced => balanced,
+            // The starting words don't depend on lookahead, so the cached ones still hold
+            Self::Exhaustive => SolverConfig {
+                lookahead: 1,
+                ..balanced
+            },
+        }
+    }
+}
+
+impl FromStr for Profile {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "fast" => Ok(Self::Fast),
+            "balanced" => Ok(Self::Balanced),
+            "exhaustive" => Ok(Self::Exhaustive),
+            _ => Err(format!(
+                "unknown profile '{s}' (expected fast, balanced, or exhaustive)"
+            )),
+        }
+    }
+}
+
+/// Expected pool size estimated from a sample of candidates
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScoreEstimate {
+    pub score: f64,
+    /// Lower bound of the 95% confidence interval
+    pub lower: f64,
+    /// Upper bound of the 95% confidence interval
+    pub upper: f64,
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Feedback {
@@ -29,7 +298,20 @@ impl Feedback {
         }
     }
 
-    /// Parse a character into a Feedback variant
+    /// The colour Wordle shows for this feedback, as a word
+    #[must_use]
+    pub const fn color_name(self) -> &'static str {
+        match self {
+            Self::Match => "green",
+            Self::PartialMatch => "yellow",
+            Self::NoMatch => "gray",
+        }
+    }
+
+    /// Parse a character into a Feedback variant.
+    ///
+    /// Besides G, Y and X, this takes B (black) for gray, the digits 2, 1 and 0, and the share
+    /// grid squares, including the high-contrast orange and blue ones.
     ///
     /// # Examples
     ///
@@ -39,14 +321,17 @@ impl Feedback {
     /// assert_eq!(Feedback::from_char('G'), Some(Feedback::Match));
     /// assert_eq!(Feedback::from_char('Y'), Some(Feedback::PartialMatch));
     /// assert_eq!(Feedback::from_char('X'), Some(Feedback::NoMatch));
+    /// assert_eq!(Feedback::from_char('B'), Some(Feedback::NoMatch));
+    /// assert_eq!(Feedback::from_char('2'), Some(Feedback::Match));
+    /// assert_eq!(Feedback::from_char('🟨'), Some(Feedback::PartialMatch));
     /// assert_eq!(Feedback::from_char('Z'), None);
     /// ```
     #[must_use]
     pub const fn from_char(c: char) -> Option<Self> {
         match c {
-            'G' => Some(Self::Match),
-            'Y' => Some(Self::PartialMatch),
-            'X' => Some(Self::NoMatch),
+            'G' | '2' | '🟩' | '🟧' => Some(Self::Match),
+            'Y' | '1' | '🟨' | '🟦' => Some(Self::PartialMatch),
+            'X' | 'B' | '0' | '⬛' | '⬜' => Some(Self::NoMatch),
             _ => None,
         }
     }
@@ -54,6 +339,9 @@ impl Feedback {
 
 /// Filters candidates based on feedback from a guess.
 ///
+/// Never panics on mismatched lengths: candidates of another length than `guess` are dropped,
+/// and [`try_filter_candidates`] rejects a bad guess or feedback outright.
+///
 /// # Examples
 ///
 /// ```
@@ -70,57 +358,165 @@ impl Feedback {
 /// ```
 #[must_use]
 pub fn filter_candidates(candidates: &[String], guess: &str, feedback: &[Feedback]) -> Vec<String> {
-    let guess_chars: Vec<char> = guess.chars().collect();
+    candidates
+        .iter()
+        .filter(|word| is_consistent(word, guess, feedback))
+        .cloned()
+        .collect()
+}
 
-    let mut filtered = Vec::new();
-    'word: for word in candidates {
-        let word_chars: Vec<char> = word.chars().collect();
+/// Like [`filter_candidates`], but rejects a guess that isn't a [`Word`] or feedback of the
+/// wrong length instead of filtering with it. Candidates of another length are dropped as usual.
+///
+/// # Errors
+/// Returns an error if `guess` isn't [`WORD_LENGTH`] letters or `feedback` doesn't have one
+/// value per letter.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::solver::{Feedback, WordleError, try_filter_candidates};
+///
+/// let candidates = vec!["CRANE".to_string(), "BRAIN".to_string()];
+/// let feedback = [Feedback::Match; 5];
+/// assert_eq!(try_filter_candidates(&candidates, "CRANE", &feedback), Ok(vec!["CRANE".to_string()]));
+/// assert_eq!(
+///     try_filter_candidates(&candidates, "CRANE", &feedback[..3]),
+///     Err(WordleError::FeedbackLength(3))
+/// );
+/// ```
+pub fn try_filter_candidates(
+    candidates: &[String],
+    guess: &str,
+    feedback: &[Feedback],
+) -> Result<Vec<String>, WordleError> {
+    let guess = to_word(guess)?;
+    if feedback.len() != WORD_LENGTH {
+        return Err(WordleError::FeedbackLength(feedback.len()));
+    }
+    Ok(filter_candidates(candidates, &guess, feedback))
+}
+
+/// Splits candidates into those consistent with the feedback and those it eliminates.
+///
+/// Returns `(survived, eliminated)`, both in their original order.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::{get_feedback, partition_candidates};
+///
+/// let candidates = vec!["CRANE".to_string(), "BRAIN".to_string()];
+/// let feedback = get_feedback("CRANE", "BRAIN");
+/// let (survived, eliminated) = partition_candidates(&candidates, "CRANE", &feedback);
+///
+/// assert_eq!(survived, vec!["BRAIN"]);
+/// assert_eq!(eliminated, vec!["CRANE"]);
+/// ```
+#[must_use]
+pub fn partition_candidates(
+    candidates: &[String],
+    guess: &str,
+    feedback: &[Feedback],
+) -> (Vec<String>, Vec<String>) {
+    candidates
+        .iter()
+        .cloned()
+        .partition(|word| is_consistent(word, guess, feedback))
+}
 
-        // First pass: check matches (green)
-        for (i, (&g, &f)) in guess_chars.iter().zip(feedback.iter()).enumerate() {
-            if f == Feedback::Match && word_chars[i] != g {
-                continue 'word;
+/// [`partition_candidates`] over interned words: splits `candidates` into the ids still
+/// consistent with `feedback` for `guess` and the ids it eliminates.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::get_feedback;
+/// use wordle_solver::solver::partition_ids;
+/// use wordle_solver::words::{CandidateSet, WordArena};
+///
+/// let arena = WordArena::new(&["CRANE".to_string(), "BRAIN".to_string()]).unwrap();
+/// let candidates = CandidateSet::full(arena.len());
+/// let feedback = get_feedback("CRANE", "BRAIN");
+/// let (survived, eliminated) = partition_ids(&arena, &candidates, "CRANE", &feedback);
+///
+/// assert_eq!(arena.words(&survived), ["BRAIN"]);
+/// assert_eq!(arena.words(&eliminated), ["CRANE"]);
+/// ```
+#[must_use]
+pub fn partition_ids(
+    arena: &WordArena,
+    candidates: &CandidateSet,
+    guess: &str,
+    feedback: &[Feedback],
+) -> (CandidateSet, CandidateSet) {
+    let mut survived = candidates.clone();
+    let mut eliminated = CandidateSet::new(candidates.capacity());
+    for id in candidates.iter() {
+        if !is_consistent(arena.get(id), guess, feedback) {
+            survived.remove(id);
+            eliminated.insert(id);
+        }
+    }
+    (survived, eliminated)
+}
+
+/// Returns true if `word` could still be the solution given the feedback for `guess`.
+///
+/// A word of a different length than `guess` never is.
+#[must_use]
+pub fn is_consistent(word: &str, guess: &str, feedback: &[Feedback]) -> bool {
+    let guess_chars: Vec<char> = guess.chars().collect();
+    let word_chars: Vec<char> = word.chars().collect();
+    if word_chars.len() != guess_chars.len() {
+        return false;
+    }
+
+    // First pass: check matches (green)
+    for (i, (&g, &f)) in guess_chars.iter().zip(feedback.iter()).enumerate() {
+        if f == Feedback::Match && word_chars[i] != g {
+            return false;
+        }
+    }
+    // Second pass: check partial matches (yellow)
+    for (i, (&g, &f)) in guess_chars.iter().zip(feedback.iter()).enumerate() {
+        if f == Feedback::PartialMatch {
+            if word_chars[i] == g {
+                return false;
+            }
+            if !word_chars.contains(&g) {
+                return false;
             }
         }
-        // Second pass: check partial matches (yellow)
-        for (i, (&g, &f)) in guess_chars.iter().zip(feedback.iter()).enumerate() {
-            if f == Feedback::PartialMatch {
+    }
+    // Third pass: check no matches (gray)
+    for (i, (&g, &f)) in guess_chars.iter().zip(feedback.iter()).enumerate() {
+        if f == Feedback::NoMatch {
+            let elsewhere = guess_chars.iter().zip(feedback.iter()).any(|(&gc, &fc)| {
+                gc == g && (fc == Feedback::Match || fc == Feedback::PartialMatch)
+            });
+            if elsewhere {
+                // Must not be at this position
                 if word_chars[i] == g {
-                    continue 'word;
+                    return false;
                 }
-                if !word_chars.contains(&g) {
-                    continue 'word;
+            } else {
+                // Must not be anywhere
+                if word_chars.contains(&g) {
+                    return false;
                 }
             }
         }
-        // Third pass: check no matches (gray)
-        for (i, (&g, &f)) in guess_chars.iter().zip(feedback.iter()).enumerate() {
-            if f == Feedback::NoMatch {
-                let elsewhere = guess_chars.iter().zip(feedback.iter()).any(|(&gc, &fc)| {
-                    gc == g && (fc == Feedback::Match || fc == Feedback::PartialMatch)
-                });
-                if elsewhere {
-                    // Must not be at this position
-                    if word_chars[i] == g {
-                        continue 'word;
-                    }
-                } else {
-                    // Must not be anywhere
-                    if word_chars.contains(&g) {
-                        continue 'word;
-                    }
-                }
-            }
-        }
-        filtered.push(word.clone());
     }
-    filtered
+    true
 }
 
 /// Generates feedback for a guess compared to the solution.
 ///
-/// Returns a vector of 5 feedback values indicating how each letter in the guess
-/// matches the solution (green for correct position, yellow for wrong position, gray for not in word).
+/// Returns one feedback value per letter of the guess, indicating how it matches the solution
+/// (green for correct position, yellow for wrong position, gray for not in word). Words of
+/// different lengths are compared position by position, so guess letters past the end of the
+/// solution can only be yellow or gray. Use [`try_get_feedback`] to reject such words instead.
 ///
 /// # Examples
 ///
@@ -136,27 +532,325 @@ pub fn filter_candidates(candidates: &[String], guess: &str, feedback: &[Feedbac
 /// ```
 #[must_use]
 pub fn get_feedback(guess: &str, solution: &str) -> Vec<Feedback> {
-    let mut feedback = [Feedback::NoMatch; 5];
-    let mut solution_chars: Vec<char> = solution.chars().collect();
+    let mut solution_chars: Vec<Option<char>> = solution.chars().map(Some).collect();
     let guess_chars: Vec<char> = guess.chars().collect();
+    let mut feedback = vec![Feedback::NoMatch; guess_chars.len()];
     // First pass: matches (green)
-    for i in 0..5 {
-        if guess_chars[i] == solution_chars[i] {
+    for (i, &g) in guess_chars.iter().enumerate() {
+        if let Some(slot) = solution_chars.get_mut(i).filter(|c| **c == Some(g)) {
             feedback[i] = Feedback::Match;
-            solution_chars[i] = '_'; // Mark as used
+            *slot = None; // Mark as used
         }
     }
     // Second pass: partial matches (yellow)
-    for i in 0..5 {
+    for (i, &g) in guess_chars.iter().enumerate() {
         if feedback[i] == Feedback::Match {
             continue;
         }
-        if let Some(pos) = solution_chars.iter().position(|&c| c == guess_chars[i]) {
+        if let Some(slot) = solution_chars.iter_mut().find(|c| **c == Some(g)) {
             feedback[i] = Feedback::PartialMatch;
-            solution_chars[pos] = '_'; // Mark as used
+            *slot = None; // Mark as used
         }
     }
-    feedback.to_vec()
+    feedback
+}
+
+/// Like [`get_feedback`], but rejects words that aren't [`WORD_LENGTH`] letters instead of
+/// comparing them, and ignores case.
+///
+/// # Errors
+/// Returns an error naming the first of `guess` and `solution` that isn't a [`Word`].
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::solver::{Feedback, WordleError, try_get_feedback};
+///
+/// assert_eq!(try_get_feedback("CRANE", "CRANE"), Ok(vec![Feedback::Match; 5]));
+/// assert_eq!(
+///     try_get_feedback("CRANE", "CRANES"),
+///     Err(WordleError::WordLength("CRANES".to_string()))
+/// );
+/// ```
+pub fn try_get_feedback(guess: &str, solution: &str) -> Result<Vec<Feedback>, WordleError> {
+    Ok(get_feedback(&to_word(guess)?, &to_word(solution)?))
+}
+
+fn to_word(text: &str) -> Result<Word, WordleError> {
+    Word::new(text).ok_or_else(|| WordleError::WordLength(text.to_string()))
+}
+
+/// Encodes feedback as a base-3 number (gray = 0, yellow = 1, green = 2), first letter lowest.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::solver::{PATTERN_COUNT, pattern_code};
+/// use wordle_solver::Feedback;
+///
+/// assert_eq!(pattern_code(&[Feedback::NoMatch; 5]), 0);
+/// assert_eq!(usize::from(pattern_code(&[Feedback::Match; 5])), PATTERN_COUNT - 1);
+/// ```
+#[must_use]
+pub fn pattern_code(feedback: &[Feedback]) -> u8 {
+    feedback.iter().rev().fold(0, |code, f| {
+        code * 3
+            + match f {
+                Feedback::NoMatch => 0,
+                Feedback::PartialMatch => 1,
+                Feedback::Match => 2,
+            }
+    })
+}
+
+/// Pattern code of `guess` against `solution` computed directly on ASCII bytes.
+///
+/// Equivalent to `pattern_code(&get_feedback(guess, solution))` without allocating.
+///
+/// # Panics
+/// Panics if either word is shorter than [`WORD_LENGTH`]; wordbanks only hold words of that
+/// length.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::solver::{feedback_code, pattern_code};
+/// use wordle_solver::get_feedback;
+///
+/// assert_eq!(
+///     feedback_code(b"EERIE", b"ELEGY"),
+///     pattern_code(&get_feedback("EERIE", "ELEGY"))
+/// );
+/// ```
+#[must_use]
+pub fn feedback_code(guess: &[u8], solution: &[u8]) -> u8 {
+    let mut digits = [0u8; WORD_LENGTH];
+    let mut used = [false; WORD_LENGTH];
+    // First pass: matches (green)
+    for i in 0..WORD_LENGTH {
+        if guess[i] == solution[i] {
+            digits[i] = 2;
+            used[i] = true;
+        }
+    }
+    // Second pass: partial matches (yellow)
+    for i in 0..WORD_LENGTH {
+        if digits[i] == 2 {
+            continue;
+        }
+        if let Some(pos) = (0..WORD_LENGTH).find(|&j| !used[j] && solution[j] == guess[i]) {
+            digits[i] = 1;
+            used[pos] = true;
+        }
+    }
+    digits.iter().rev().fold(0, |code, d| code * 3 + d)
+}
+
+/// Incrementally maintained expected pool sizes for every word of a [`WordArena`].
+///
+/// Building the scorer computes the feedback pattern of every guess against every candidate
+/// once. Removing a candidate afterwards only touches that candidate's column, so scoring a
+/// shrinking pool round after round avoids recomputing feedback from scratch.
+pub struct PoolScorer {
+    /// `patterns[g][slot]` is the pattern code of guess `g` against the candidate in `slot`
+    patterns: Vec<Vec<u8>>,
+    /// Candidate slot for each word id, if the word was in the initial pool
+    slots: Vec<Option<usize>>,
+    /// `counts[g][p]` is how many live candidates give pattern `p` for guess `g`
+    counts: Vec<[u32; PATTERN_COUNT]>,
+    /// Sum of squared bucket sizes per guess, i.e. the numerator of the expected pool size
+    sum_squares: Vec<u64>,
+    alive: CandidateSet,
+    alive_count: usize,
+}
+
+impl PoolScorer {
+    /// Scores every word in `arena` as a guess against the `candidates` pool
+    #[must_use]
+    #[instrument(level = "debug", skip_all, fields(words = arena.len(), candidates = candidates.len()))]
+    pub fn new(arena: &WordArena, candidates: &[WordId]) -> Self {
+        let patterns: Vec<Vec<u8>> = arena
+            .ids()
+            .map(|g| {
+                candidates
+                    .iter()
+                    .map(|&c| feedback_code(arena.bytes(g), arena.bytes(c)))
+                    .collect()
+            })
+            .collect();
+        let counts: Vec<[u32; PATTERN_COUNT]> = patterns
+            .iter()
+            .map(|row| {
+                let mut counts = [0; PATTERN_COUNT];
+                for &p in row {
+                    counts[usize::from(p)] += 1;
+                }
+                counts
+            })
+            .collect();
+        let sum_squares = counts
+            .iter()
+            .map(|c| c.iter().map(|&n| u64::from(n) * u64::from(n)).sum())
+            .collect();
+        let mut slots = vec![None; arena.len()];
+        for (slot, &c) in candidates.iter().enumerate() {
+            slots[c.index()] = Some(slot);
+        }
+
+        Self {
+            patterns,
+            slots,
+            counts,
+            sum_squares,
+            alive: CandidateSet::from_ids(arena.len(), candidates),
+            alive_count: candidates.len(),
+        }
+    }
+
+    /// Number of candidates still in the pool
+    #[must_use]
+    pub const fn candidate_count(&self) -> usize {
+        self.alive_count
+    }
+
+    /// Approximate bytes taken by a scorer of `words` guesses against `candidates`, so callers
+    /// can check before building one
+    #[must_use]
+    pub const fn estimated_size(words: usize, candidates: usize) -> usize {
+        let per_word = candidates
+            + size_of::<Vec<u8>>()
+            + size_of::<Option<usize>>()
+            + size_of::<[u32; PATTERN_COUNT]>()
+            + size_of::<u64>();
+        words * per_word + words.div_ceil(64) * size_of::<u64>()
+    }
+
+    /// Approximate bytes this scorer takes
+    #[must_use]
+    pub fn memory_footprint(&self) -> usize {
+        let candidates = self.patterns.first().map_or(0, Vec::len);
+        Self::estimated_size(self.patterns.len(), candidates)
+    }
+
+    /// Removes a candidate from the pool. Returns false if it was unknown or already removed.
+    pub fn remove(&mut self, id: WordId) -> bool {
+        let Some(slot) = self.slots.get(id.index()).copied().flatten() else {
+            return false;
+        };
+        if !self.alive.remove(id) {
+            return false;
+        }
+        self.alive_count -= 1;
+        for (g, row) in self.patterns.iter().enumerate() {
+            let count = &mut self.counts[g][usize::from(row[slot])];
+            // (n - 1)^2 = n^2 - (2n - 1)
+            self.sum_squares[g] -= 2 * u64::from(*count) - 1;
+            *count -= 1;
+        }
+        true
+    }
+
+    /// Returns true if `id` is a candidate still in the pool
+    #[must_use]
+    pub fn is_alive(&self, id: WordId) -> bool {
+        self.alive.contains(id)
+    }
+
+    /// The live candidates
+    #[must_use]
+    pub const fn candidates(&self) -> &CandidateSet {
+        &self.alive
+    }
+
+    /// Expected pool size of `guess` over the live candidates
+    #[allow(clippy::cast_precision_loss)] // don't care about this
+    #[must_use]
+    pub fn expected_pool_size(&self, guess: WordId) -> f64 {
+        self.sum_squares[guess.index()] as f64 / self.alive_count as f64
+    }
+
+    /// Same result as [`best_information_guess`] over the live candidates, or `None` if
+    /// the arena is empty or no candidates are left
+    #[must_use]
+    pub fn best_guess(&self) -> Option<(WordId, f64, bool)> {
+        self.best_guess_with_progress(None, &mut NoProgress, &CancelToken::new())
+    }
+
+    /// [`Self::best_guess`], reporting progress to `progress` and stopping early with the
+    /// best guess so far once `cancel` is set. Best-so-far words are only included in reports
+    /// when `arena` is given to look them up.
+    pub fn best_guess_with_progress(
+        &self,
+        arena: Option<&WordArena>,
+        progress: &mut dyn ProgressSink,
+        cancel: &CancelToken,
+    ) -> Option<(WordId, f64, bool)> {
+        let total = self.patterns.len();
+        if total == 0 || self.alive_count == 0 {
+            return None;
+        }
+        let mut best = WordId(0);
+        let mut best_score = f64::INFINITY;
+        for index in 0..total {
+            #[allow(clippy::cast_possible_truncation)] // arenas hold at most u16::MAX + 1 words
+            let guess = WordId(index as u16);
+            let score = self.expected_pool_size(guess);
+            if score < best_score {
+                best = guess;
+                best_score = score;
+            }
+            if should_report(index + 1, total) {
+                progress.report(
+                    index + 1,
+                    total,
+                    arena.map(|arena| (arena.get(best), best_score)),
+                );
+            }
+            if cancel.is_cancelled() {
+                break;
+            }
+        }
+        Some((best, best_score, self.is_alive(best)))
+    }
+}
+
+/// Sum of the squared sizes of the buckets the feedback of `guess` splits `candidates` into,
+/// which is `candidates.len()` times its expected pool size.
+///
+/// Buckets only grow as candidates are added, so once the sum passes `bound` the guess can't
+/// come back under it. Counting stops there, and the sum returned is then more than `bound`
+/// but short of the full sum. For a clearly worse guess, that skips most of the work.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::solver::pool_sum_of_squares;
+///
+/// let candidates: Vec<String> = ["BATCH", "CATCH", "HATCH", "CLIMB"]
+///     .iter()
+///     .map(|s| s.to_string())
+///     .collect();
+/// // CLIMB tells all four apart; ZZZZZ leaves them in one bucket of four
+/// assert_eq!(pool_sum_of_squares("CLIMB", &candidates, usize::MAX), 4);
+/// assert_eq!(pool_sum_of_squares("ZZZZZ", &candidates, usize::MAX), 16);
+/// assert_eq!(pool_sum_of_squares("ZZZZZ", &candidates, 4), 9);
+/// ```
+#[must_use]
+pub fn pool_sum_of_squares(guess: &str, candidates: &[String], bound: usize) -> usize {
+    let mut pattern_counts: HashMap<Vec<Feedback>, usize> = HashMap::new();
+    let mut sum = 0;
+    for solution in candidates {
+        let count = pattern_counts
+            .entry(get_feedback(guess, solution))
+            .or_insert(0);
+        // (n + 1)^2 - n^2
+        sum += 2 * *count + 1;
+        *count += 1;
+        if sum > bound {
+            break;
+        }
+    }
+    sum
 }
 
 #[allow(clippy::cast_precision_loss)] // don't care about this
@@ -168,47 +862,817 @@ pub fn expected_pool_size(guess: &str, candidates: &[String]) -> f64 {
         *pattern_counts.entry(pattern).or_insert(0) += 1;
     }
     let total = candidates.len() as f64;
+    // Summed as integers, so the map's iteration order can't change the result
     pattern_counts
         .values()
-        .map(|&count| (count as f64).powi(2))
+        .map(|&count| count * count)
+        .sum::<usize>() as f64
+        / total
+}
+
+/// How many of `words` contain each letter, indexed A-Z. Repeated letters count once per word.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::solver::letter_counts;
+///
+/// let counts = letter_counts(&["CRANE".to_string(), "EERIE".to_string()]);
+/// assert_eq!(counts[usize::from(b'E' - b'A')], 2);
+/// assert_eq!(counts[usize::from(b'C' - b'A')], 1);
+/// ```
+#[must_use]
+pub fn letter_counts(words: &[String]) -> [usize; 26] {
+    let mut counts = [0; 26];
+    for word in words {
+        let mut seen = [false; 26];
+        for b in word.bytes().filter(u8::is_ascii_uppercase) {
+            seen[usize::from(b - b'A')] = true;
+        }
+        for (count, _) in counts.iter_mut().zip(seen).filter(|(_, seen)| *seen) {
+            *count += 1;
+        }
+    }
+    counts
+}
+
+/// The letter outside `exclude` whose presence splits `candidates` closest to half, for
+/// hinting at what to probe next. Ties go to the earlier letter.
+///
+/// Returns `None` if no such letter splits the candidates at all.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::solver::probe_letter;
+///
+/// let candidates: Vec<String> = ["CRANE", "CRATE", "GRAPE", "GRADE"]
+///     .iter()
+///     .map(|s| s.to_string())
+///     .collect();
+/// // C and G are each in half the words; R, A and E are in all of them
+/// assert_eq!(probe_letter(&candidates, &[]), Some('C'));
+/// assert_eq!(probe_letter(&candidates, &['C']), Some('G'));
+/// ```
+#[must_use]
+pub fn probe_letter(candidates: &[String], exclude: &[char]) -> Option<char> {
+    let total = candidates.len();
+    (b'A'..=b'Z')
+        .map(char::from)
+        .zip(letter_counts(candidates))
+        .filter(|&(letter, count)| count > 0 && count < total && !exclude.contains(&letter))
+        .min_by_key(|&(_, count)| count.abs_diff(total - count))
+        .map(|(letter, _)| letter)
+}
+
+/// Up to `count` guesses from `wordbank` that contain as many of `letters` as any word does,
+/// best expected pool size over `candidates` first, for deliberately testing a set of letters
+/// the recommendation passes over.
+///
+/// Returns nothing if no word contains any of the letters.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::solver::probe_guesses;
+///
+/// let words = |list: &[&str]| -> Vec<String> { list.iter().map(|s| s.to_string()).collect() };
+/// let wordbank = words(&["AUDIO", "ADIEU", "CRANE", "SLATE"]);
+/// let candidates = words(&["CRANE", "SLANT"]);
+/// // ADIEU and AUDIO both hold four vowels, and only ADIEU's E tells CRANE from SLANT
+/// let guesses = probe_guesses(&wordbank, &candidates, &['a', 'e', 'i', 'o', 'u'], 5);
+/// assert_eq!(guesses, [("ADIEU".to_string(), 1.0), ("AUDIO".to_string(), 2.0)]);
+/// ```
+#[must_use]
+pub fn probe_guesses(
+    wordbank: &[String],
+    candidates: &[String],
+    letters: &[char],
+    count: usize,
+) -> Vec<(String, f64)> {
+    let mut letters: Vec<char> = letters.iter().map(char::to_ascii_uppercase).collect();
+    letters.sort_unstable();
+    letters.dedup();
+    let covered = |word: &str| {
+        letters
+            .iter()
+            .filter(|&&letter| word.contains(letter))
+            .count()
+    };
+    let most = wordbank.iter().map(|word| covered(word)).max().unwrap_or(0);
+    if most == 0 {
+        return Vec::new();
+    }
+    let mut guesses: Vec<(String, f64)> = wordbank
+        .iter()
+        .filter(|word| covered(word) == most)
+        .map(|word| (word.clone(), expected_pool_size(word, candidates)))
+        .collect();
+    guesses.sort_by(|a, b| a.1.total_cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+    guesses.truncate(count);
+    guesses
+}
+
+/// Chance of solving within two guesses when `guess` is played now and the last guess goes
+/// to a remaining candidate, with every candidate equally likely.
+///
+/// Each feedback pattern leaves one last guess, which wins for one of the candidates giving
+/// that pattern (and a correct `guess` wins outright), so this is the number of distinct
+/// patterns over the number of candidates.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::solver::finish_probability;
+///
+/// let candidates: Vec<String> = ["BATCH", "CATCH", "HATCH", "LATCH"]
+///     .iter()
+///     .map(|s| s.to_string())
+///     .collect();
+/// // Guessing a candidate leaves the other three looking alike
+/// assert_eq!(finish_probability("BATCH", &candidates), 0.5);
+/// // CLIMB tells all four apart
+/// assert_eq!(finish_probability("CLIMB", &candidates), 1.0);
+/// ```
+#[allow(clippy::cast_precision_loss)] // don't care about this
+#[must_use]
+pub fn finish_probability(guess: &str, candidates: &[String]) -> f64 {
+    let mut seen = [false; PATTERN_COUNT];
+    for solution in candidates {
+        seen[usize::from(feedback_code(guess.as_bytes(), solution.as_bytes()))] = true;
+    }
+    seen.iter().filter(|&&seen| seen).count() as f64 / candidates.len() as f64
+}
+
+/// The guess from `wordbank` most likely to solve within two guesses (see
+/// [`finish_probability`]), preferring a candidate and then the smaller expected pool size
+/// among equally likely ones. Returns the guess, its expected pool size, and whether it is a
+/// candidate, or `None` if `wordbank` or `candidates` is empty.
+#[must_use]
+pub fn best_finishing_guess<'a>(
+    wordbank: &'a [String],
+    candidates: &[String],
+) -> Option<(&'a String, f64, bool)> {
+    if candidates.is_empty() {
+        return None;
+    }
+    let chances: Vec<f64> = wordbank
+        .iter()
+        .map(|guess| finish_probability(guess, candidates))
+        .collect();
+    let best_chance = chances.iter().copied().fold(0.0, f64::max);
+    // Only the guesses tied for the best chance need the slower pool-size tie-break
+    wordbank
+        .iter()
+        .zip(&chances)
+        .filter(|&(_, &chance)| chance == best_chance)
+        .map(|(guess, _)| {
+            (
+                guess,
+                expected_pool_size(guess, candidates),
+                candidates.contains(guess),
+            )
+        })
+        .reduce(|best, next| {
+            let better = next.2.cmp(&best.2).then(best.1.total_cmp(&next.1)).is_gt();
+            if better { next } else { best }
+        })
+}
+
+/// Factor to multiply the score of `guess` by for the letters of `avoid` it contains:
+/// [`AVOID_PENALTY`] for each one, or 1 when it has none.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::solver::{AVOID_PENALTY, avoid_penalty};
+///
+/// assert_eq!(avoid_penalty("CRANE", &['S', 'Y']), 1.0);
+/// assert_eq!(avoid_penalty("SLATE", &['S', 'Y']), AVOID_PENALTY);
+/// assert_eq!(avoid_penalty("SASSY", &['S', 'Y']), AVOID_PENALTY * AVOID_PENALTY);
+/// ```
+#[must_use]
+pub fn avoid_penalty(guess: &str, avoid: &[char]) -> f64 {
+    let count = avoid
+        .iter()
+        .filter(|&&letter| guess.contains(letter))
+        .count();
+    AVOID_PENALTY.powi(i32::try_from(count).unwrap_or(i32::MAX))
+}
+
+/// Candidates expected to be left on a board after `guess`, counting a solve as none left
+#[allow(clippy::cast_precision_loss)] // don't care about this
+#[must_use]
+pub fn expected_remaining(guess: &str, candidates: &[String]) -> f64 {
+    let solved = if candidates.iter().any(|c| c == guess) {
+        1.0 / candidates.len() as f64
+    } else {
+        0.0
+    };
+    expected_pool_size(guess, candidates) - solved
+}
+
+/// The best single guess for several boards played together (as in Quordle), with its joint
+/// score: the [`expected_remaining`] candidates on each board, weighted by `weighting`, summed.
+///
+/// Boards with no candidates are skipped. Returns `None` if no board has any, or the wordbank
+/// is empty. Ties keep wordbank order.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::solver::{BoardWeighting, best_joint_guess};
+///
+/// let words = |list: &str| -> Vec<String> { list.split(' ').map(String::from).collect() };
+/// let big = words("BATCH CATCH HATCH LATCH MATCH PATCH");
+/// let small = words("CRANE CRATE");
+/// let wordbank = words("BATCH CATCH HATCH LATCH MATCH PATCH CRANE CRATE BLIMP");
+/// let boards = [big.as_slice(), small.as_slice()];
+///
+/// // BLIMP splits the big board, while CRANE closes out the small one half the time
+/// let (guess, _) = best_joint_guess(&wordbank, &boards, BoardWeighting::Equal).unwrap();
+/// assert_eq!(guess, "BLIMP");
+/// let (guess, _) = best_joint_guess(&wordbank, &boards, BoardWeighting::NearSolved).unwrap();
+/// assert_eq!(guess, "CRANE");
+/// ```
+#[must_use]
+pub fn best_joint_guess<'a>(
+    wordbank: &'a [String],
+    boards: &[&[String]],
+    weighting: BoardWeighting,
+) -> Option<(&'a String, f64)> {
+    let boards: Vec<(&[String], f64)> = boards
+        .iter()
+        .filter(|board| !board.is_empty())
+        .map(|&board| (board, weighting.weight(board.len())))
+        .collect();
+    if boards.is_empty() {
+        return None;
+    }
+    let mut best: Option<(&String, f64)> = None;
+    for guess in wordbank {
+        let score: f64 = boards
+            .iter()
+            .map(|&(board, weight)| weight * expected_remaining(guess, board))
+            .sum();
+        if best.is_none_or(|(_, best_score)| score < best_score) {
+            best = Some((guess, score));
+        }
+    }
+    best
+}
+
+/// The guess from `wordbank` with the lowest `score` once penalised by [`avoid_penalty`], so
+/// avoided letters only make it into a guess that is worth it anyway. Returns the guess and
+/// its unpenalised score, or `None` if `wordbank` is empty, reporting progress to `progress`
+/// and stopping early with the best guess so far once `cancel` is set.
+pub fn best_avoiding_guess_with_progress<'a>(
+    wordbank: &'a [String],
+    avoid: &[char],
+    score: &mut dyn FnMut(&str) -> f64,
+    progress: &mut dyn ProgressSink,
+    cancel: &CancelToken,
+) -> Option<(&'a String, f64)> {
+    let mut best_word = wordbank.first()?;
+    let mut best_score = f64::INFINITY;
+    let mut best_penalised = f64::INFINITY;
+    for (i, guess) in wordbank.iter().enumerate() {
+        let score = score(guess);
+        let penalised = score * avoid_penalty(guess, avoid);
+        if penalised < best_penalised {
+            best_word = guess;
+            best_score = score;
+            best_penalised = penalised;
+        }
+        if should_report(i + 1, wordbank.len()) {
+            progress.report(i + 1, wordbank.len(), Some((best_word, best_score)));
+        }
+        if cancel.is_cancelled() {
+            break;
+        }
+    }
+    Some((best_word, best_score))
+}
+
+/// Picks an evenly spaced sample of `sample_size` candidates.
+///
+/// Returns all candidates if the pool is no larger than the sample.
+#[must_use]
+pub fn sample_candidates(candidates: &[String], sample_size: usize) -> Vec<String> {
+    if sample_size == 0 || candidates.len() <= sample_size {
+        return candidates.to_vec();
+    }
+    (0..sample_size)
+        .map(|i| candidates[i * candidates.len() / sample_size].clone())
+        .collect()
+}
+
+/// Estimates the expected pool size of `guess` over `population` candidates from a sample.
+///
+/// Each sampled answer contributes an estimate of the size of its feedback bucket in the
+/// full pool; the mean of those is the score and their spread gives a 95% confidence
+/// interval (with finite population correction, so a full sample has zero width). A sample
+/// of fewer than two answers says nothing about how the rest of the pool splits, so its
+/// interval spans every possible pool size, from 1 to `population`.
+#[allow(clippy::cast_precision_loss)] // don't care about this
+#[must_use]
+pub fn estimate_pool_size(guess: &str, sample: &[String], population: usize) -> ScoreEstimate {
+    let m = sample.len();
+    if population <= m {
+        let score = expected_pool_size(guess, sample);
+        return ScoreEstimate {
+            score,
+            lower: score,
+            upper: score,
+        };
+    }
+    if m < 2 {
+        return ScoreEstimate {
+            score: 1.0,
+            lower: 1.0,
+            upper: population as f64,
+        };
+    }
+
+    let patterns: Vec<Vec<Feedback>> = sample.iter().map(|s| get_feedback(guess, s)).collect();
+    let mut pattern_counts: HashMap<&[Feedback], usize> = HashMap::new();
+    for pattern in &patterns {
+        *pattern_counts.entry(pattern.as_slice()).or_insert(0) += 1;
+    }
+
+    let (m, n) = (m as f64, population as f64);
+    let bucket_sizes: Vec<f64> = patterns
+        .iter()
+        .map(|p| 1.0 + (n - 1.0) * (pattern_counts[p.as_slice()] as f64 - 1.0) / (m - 1.0))
+        .collect();
+    let mean = bucket_sizes.iter().sum::<f64>() / m;
+    let variance = bucket_sizes.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (m - 1.0);
+    let fpc = ((n - m) / (n - 1.0)).sqrt();
+    let half_width = Z_95 * (variance / m).sqrt() * fpc;
+
+    ScoreEstimate {
+        score: mean,
+        lower: (mean - half_width).max(1.0),
+        upper: mean + half_width,
+    }
+}
+
+/// Like [`best_information_guess`], but scores each guess against a sample of candidates.
+///
+/// Returns the best guess, its score estimate with a confidence interval, and whether it is
+/// a candidate, or `None` if `wordbank` or `candidates` is empty.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::solver::best_sampled_guess;
+///
+/// let words: Vec<String> = ["CRANE", "SLATE", "TRACE", "GRACE", "PLACE", "BRACE"]
+///     .iter()
+///     .map(|s| s.to_string())
+///     .collect();
+/// let (guess, estimate, _) = best_sampled_guess(&words, &words, 3).unwrap();
+/// assert!(words.contains(guess));
+/// assert!(estimate.lower <= estimate.score && estimate.score <= estimate.upper);
+/// ```
+#[must_use]
+pub fn best_sampled_guess<'a>(
+    wordbank: &'a [String],
+    candidates: &'a [String],
+    sample_size: usize,
+) -> Option<(&'a String, ScoreEstimate, bool)> {
+    best_guess_for_sample(
+        wordbank,
+        candidates,
+        &sample_candidates(candidates, sample_size),
+    )
+}
+
+/// Like [`best_sampled_guess`], but with a sample chosen by the caller (e.g. a seeded random
+/// one from [`Rng::sample`](crate::rng::Rng::sample)).
+#[must_use]
+pub fn best_guess_for_sample<'a>(
+    wordbank: &'a [String],
+    candidates: &[String],
+    sample: &[String],
+) -> Option<(&'a String, ScoreEstimate, bool)> {
+    let mut best_word = wordbank.first().filter(|_| !candidates.is_empty())?;
+    let mut best = ScoreEstimate {
+        score: f64::INFINITY,
+        lower: f64::INFINITY,
+        upper: f64::INFINITY,
+    };
+    // The estimate grows with the sample's sum of squared bucket sizes, so a guess whose sum
+    // already passes the best one's can't win and is cut off early
+    let mut best_sum = usize::MAX;
+    for guess in wordbank {
+        let sum = pool_sum_of_squares(guess, sample, best_sum);
+        if sum > best_sum {
+            continue;
+        }
+        let estimate = estimate_pool_size(guess, sample, candidates.len());
+        if estimate.score < best.score {
+            best_word = guess;
+            best = estimate;
+            best_sum = sum;
+        }
+    }
+    Some((best_word, best, candidates.contains(best_word)))
+}
+
+/// Scores every guess by expected pool size, best first.
+///
+/// Ties keep wordbank order.
+#[must_use]
+pub fn rank_guesses<'a>(wordbank: &'a [String], candidates: &[String]) -> Vec<(&'a String, f64)> {
+    let mut ranked: Vec<(&String, f64)> = wordbank
+        .iter()
+        .map(|guess| (guess, expected_pool_size(guess, candidates)))
+        .collect();
+    ranked.sort_by(|a, b| a.1.total_cmp(&b.1));
+    ranked
+}
+
+/// Expected pool size after playing `guess` and then the best greedy follow-up for
+/// whichever feedback comes back.
+#[allow(clippy::cast_precision_loss)] // don't care about this
+#[must_use]
+pub fn lookahead_pool_size(guess: &str, wordbank: &[String], candidates: &[String]) -> f64 {
+    // Ordered by pattern so the follow-up scores are always summed in the same order
+    let mut buckets: BTreeMap<u8, Vec<String>> = BTreeMap::new();
+    for solution in candidates {
+        buckets
+            .entry(feedback_code(guess.as_bytes(), solution.as_bytes()))
+            .or_default()
+            .push(solution.clone());
+    }
+    let total = candidates.len() as f64;
+    buckets
+        .values()
+        .map(|bucket| {
+            // With nothing to guess next, the bucket stays as it is
+            let follow_up = if bucket.len() <= 1 {
+                1.0
+            } else {
+                best_information_guess(wordbank, bucket).map_or(bucket.len() as f64, |best| best.1)
+            };
+            bucket.len() as f64 * follow_up
+        })
         .sum::<f64>()
         / total
 }
 
+/// Like [`best_information_guess`], but re-ranks the top greedy guesses by
+/// [`lookahead_pool_size`].
+///
+/// The returned score is still the one-step expected pool size of the chosen guess. Returns
+/// `None` if `wordbank` or `candidates` is empty.
+#[must_use]
+pub fn best_lookahead_guess<'a>(
+    wordbank: &'a [String],
+    candidates: &'a [String],
+) -> Option<(&'a String, f64, bool)> {
+    best_lookahead_guess_with_progress(wordbank, candidates, &mut NoProgress, &CancelToken::new())
+}
+
+/// [`best_lookahead_guess`], streaming the greedy best guess to `progress` as soon as it is
+/// known and then each refinement as lookahead rescores the shortlist. Stops early with the
+/// best guess so far once `cancel` is set.
+pub fn best_lookahead_guess_with_progress<'a>(
+    wordbank: &'a [String],
+    candidates: &'a [String],
+    progress: &mut dyn ProgressSink,
+    cancel: &CancelToken,
+) -> Option<(&'a String, f64, bool)> {
+    if candidates.is_empty() {
+        return None;
+    }
+    let ranked = rank_guesses(wordbank, candidates);
+    let shortlist = &ranked[..ranked.len().min(LOOKAHEAD_WIDTH)];
+    let mut best = *ranked.first()?;
+    let mut best_lookahead = f64::INFINITY;
+    progress.report(0, shortlist.len(), Some((best.0, best.1)));
+    for (i, &(guess, score)) in shortlist.iter().enumerate() {
+        if cancel.is_cancelled() {
+            break;
+        }
+        let lookahead = lookahead_pool_size(guess, wordbank, candidates);
+        if lookahead < best_lookahead {
+            best = (guess, score);
+            best_lookahead = lookahead;
+        }
+        progress.report(i + 1, shortlist.len(), Some((best.0, best.1)));
+    }
+    Some((best.0, best.1, candidates.contains(best.0)))
+}
+
+/// The guess from `wordbank` with the smallest expected pool size over `candidates`.
+///
+/// Returns the guess, its score and whether it is a candidate, or `None` if `wordbank` or
+/// `candidates` is empty. Ties go to the word that comes first in `wordbank`.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::best_information_guess;
+///
+/// let words: Vec<String> = ["CRANE", "SLATE"].iter().map(|w| w.to_string()).collect();
+/// assert!(best_information_guess(&words, &words).is_some());
+/// assert!(best_information_guess(&[], &words).is_none());
+/// assert!(best_information_guess(&words, &[]).is_none());
+/// ```
+#[must_use]
+pub fn best_information_guess<'a>(
+    wordbank: &'a [String],
+    candidates: &'a [String],
+) -> Option<(&'a String, f64, bool)> {
+    best_information_guess_with_progress(wordbank, candidates, &mut NoProgress, &CancelToken::new())
+}
+
+/// [`best_information_guess`], reporting progress to `progress` and stopping early with the
+/// best guess so far once `cancel` is set
+pub fn best_information_guess_with_progress<'a>(
+    wordbank: &'a [String],
+    candidates: &'a [String],
+    progress: &mut dyn ProgressSink,
+    cancel: &CancelToken,
+) -> Option<(&'a String, f64, bool)> {
+    best_information_guess_warm(
+        wordbank,
+        candidates,
+        &mut WarmStart::default(),
+        progress,
+        cancel,
+    )
+}
+
+/// The best guesses of one search, tried first by the next.
+///
+/// The top guesses rarely change much from one round to the next, so scoring last round's
+/// best first finds a strong guess early. Every guess after it can then stop being scored as
+/// soon as its buckets are already worse, since adding candidates never shrinks them.
+#[derive(Clone, Debug, Default)]
+pub struct WarmStart {
+    ranking: Vec<String>,
+}
+
+impl WarmStart {
+    /// The guesses kept from the last search, best first
+    #[must_use]
+    pub fn ranking(&self) -> &[String] {
+        &self.ranking
+    }
+
+    /// Indices into `wordbank` in the order to score them: the ranked guesses it contains,
+    /// then the rest in wordbank order
+    fn order(&self, wordbank: &[String]) -> Vec<usize> {
+        let ranks: HashMap<&str, usize> = self
+            .ranking
+            .iter()
+            .enumerate()
+            .map(|(rank, word)| (word.as_str(), rank))
+            .collect();
+        let mut ranked = vec![None; self.ranking.len()];
+        let mut rest = Vec::with_capacity(wordbank.len());
+        for (i, word) in wordbank.iter().enumerate() {
+            match ranks.get(word.as_str()) {
+                Some(&rank) if ranked[rank].is_none() => ranked[rank] = Some(i),
+                _ => rest.push(i),
+            }
+        }
+        ranked.into_iter().flatten().chain(rest).collect()
+    }
+}
+
+/// [`best_information_guess_with_progress`], scoring the guesses `warm` ranked best last time
+/// first and keeping this search's best in `warm` for the next.
+///
+/// Each guess stops being scored once its [`pool_sum_of_squares`] passes the best so far, so
+/// the result, ties included, is the same as scoring every guess in full.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::solver::{WarmStart, best_information_guess, best_information_guess_warm};
+/// use wordle_solver::progress::{CancelToken, NoProgress};
+/// use wordle_solver::{filter_candidates, get_feedback};
+/// use wordle_solver::wordbank::sample_wordbank;
+///
+/// let wordbank = sample_wordbank();
+/// let mut warm = WarmStart::default();
+/// let mut search = |candidates: &[String]| {
+///     best_information_guess_warm(&wordbank, candidates, &mut warm, &mut NoProgress, &CancelToken::new())
+///         .map(|(guess, score, _)| (guess.clone(), score))
+/// };
+/// search(&wordbank);
+///
+/// // The second round starts from the first round's best guesses, and finds the same answer
+/// let candidates = filter_candidates(&wordbank, "CRANE", &get_feedback("CRANE", "QUOTA"));
+/// let (guess, score, _) = best_information_guess(&wordbank, &candidates).unwrap();
+/// assert_eq!(search(&candidates), Some((guess.clone(), score)));
+/// ```
+pub fn best_information_guess_warm<'a>(
+    wordbank: &'a [String],
+    candidates: &'a [String],
+    warm: &mut WarmStart,
+    progress: &mut dyn ProgressSink,
+    cancel: &CancelToken,
+) -> Option<(&'a String, f64, bool)> {
+    if wordbank.is_empty() || candidates.is_empty() {
+        return None;
+    }
+    let order = warm.order(wordbank);
+    // Sums of squared bucket sizes, which order guesses the same way as expected pool size.
+    // A guess cut off early keeps its partial sum, which is still more than the best.
+    let mut sums = Vec::with_capacity(order.len());
+    let (mut best, mut best_sum) = (order[0], usize::MAX);
+    for (scored, &i) in order.iter().enumerate() {
+        let sum = pool_sum_of_squares(&wordbank[i], candidates, best_sum);
+        if sum < best_sum || (sum == best_sum && i < best) {
+            best = i;
+            best_sum = sum;
+        }
+        sums.push((sum, i));
+        if should_report(scored + 1, order.len()) {
+            progress.report(
+                scored + 1,
+                order.len(),
+                Some((&wordbank[best], mean_pool_size(best_sum, candidates.len()))),
+            );
+        }
+        if cancel.is_cancelled() {
+            break;
+        }
+    }
+    let keep = WARM_START_SIZE.min(sums.len());
+    if keep < sums.len() {
+        sums.select_nth_unstable(keep);
+    }
+    sums.truncate(keep);
+    sums.sort_unstable();
+    warm.ranking = sums.iter().map(|&(_, i)| wordbank[i].clone()).collect();
+    let best_word = &wordbank[best];
+    // Checked once for the winner rather than for every improvement inside the loop
+    Some((
+        best_word,
+        mean_pool_size(best_sum, candidates.len()),
+        candidates.contains(best_word),
+    ))
+}
+
+/// Expected pool size from a sum of squared bucket sizes over `total` candidates
+#[allow(clippy::cast_precision_loss)]
+fn mean_pool_size(sum_squares: usize, total: usize) -> f64 {
+    sum_squares as f64 / total as f64
+}
+
+/// Finds a remaining candidate that is at least as informative as `guess`.
+///
+/// A guess that can no longer be the answer is dominated when some candidate splits the
+/// pool at least as well, since that candidate could also win outright. Returns the
+/// dominating candidate, the expected pool size of `guess`, and that of the candidate.
+/// Returns `None` if `guess` is itself a candidate or nothing dominates it.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::solver::find_dominating_guess;
+///
+/// let candidates = vec!["BBBBB".to_string(), "CCCCC".to_string(), "DDDDD".to_string()];
+/// // ZZZZZ shares no letters with any candidate, so it gains no information
+/// let (better, score, better_score) = find_dominating_guess("ZZZZZ", &candidates).unwrap();
+/// assert!(candidates.contains(better));
+/// assert!(better_score <= score);
+///
+/// assert!(find_dominating_guess("BBBBB", &candidates).is_none());
+/// ```
+#[must_use]
+pub fn find_dominating_guess<'a>(
+    guess: &str,
+    candidates: &'a [String],
+) -> Option<(&'a String, f64, f64)> {
+    if candidates.iter().any(|c| c == guess) {
+        return None;
+    }
+    let guess_sum = pool_sum_of_squares(guess, candidates, usize::MAX);
+    // Only a candidate splitting the pool at least as well matters, so the rest stop early
+    candidates
+        .iter()
+        .find(|c| pool_sum_of_squares(c, candidates, guess_sum) <= guess_sum)
+        .map(|c| {
+            (
+                c,
+                mean_pool_size(guess_sum, candidates.len()),
+                expected_pool_size(c, candidates),
+            )
+        })
+}
+
+/// The [`DEFAULT_STARTING_WORDS`] best starting words, best first
+///
+/// # Panics
+/// Panics if the expected pool size comparison fails (should never happen with valid f64 values).
 #[must_use]
-pub fn best_information_guess<'a>(
-    wordbank: &'a [String],
-    candidates: &'a [String],
-) -> (&'a String, f64, bool) {
-    let mut best_word = &wordbank[0];
-    let mut best_score = f64::INFINITY;
-    let mut is_candidate = false;
-    for guess in wordbank {
-        let score = expected_pool_size(guess, candidates);
-        if score < best_score {
-            best_word = guess;
-            best_score = score;
-            is_candidate = candidates.contains(guess);
-        }
-    }
-    (best_word, best_score, is_candidate)
+pub fn compute_best_starting_words(wordbank: &WordBank) -> Vec<String> {
+    compute_scored_starting_words(wordbank, DEFAULT_STARTING_WORDS)
+        .into_iter()
+        .map(|(word, _)| word)
+        .collect()
 }
 
+/// The `count` best starting words with their expected pool sizes, best first.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::solver::compute_scored_starting_words;
+/// use wordle_solver::words::WordBank;
+///
+/// let words = WordBank::from_valid(["CRANE", "SLATE", "PLATE"]);
+/// let best = compute_scored_starting_words(&words, 2);
+/// assert_eq!(best.len(), 2);
+/// assert!(best[0].1 <= best[1].1);
+/// ```
+///
 /// # Panics
 /// Panics if the expected pool size comparison fails (should never happen with valid f64 values).
 #[must_use]
-pub fn compute_best_starting_words(wordbank: &[String]) -> Vec<String> {
-    let mut scored: Vec<(String, f64)> = wordbank
-        .iter()
-        .map(|w| (w.clone(), expected_pool_size(w, wordbank)))
-        .collect();
+pub fn compute_scored_starting_words(wordbank: &WordBank, count: usize) -> Vec<(String, f64)> {
+    compute_scored_starting_words_with_progress(
+        wordbank,
+        count,
+        &mut NoProgress,
+        &CancelToken::new(),
+    )
+}
+
+/// [`compute_scored_starting_words`], reporting progress to `progress`. Once `cancel` is set
+/// it stops and ranks only the words scored so far.
+///
+/// # Panics
+/// Panics if the expected pool size comparison fails (should never happen with valid f64 values).
+#[instrument(skip_all, fields(words = wordbank.len(), count))]
+pub fn compute_scored_starting_words_with_progress(
+    wordbank: &WordBank,
+    count: usize,
+    progress: &mut dyn ProgressSink,
+    cancel: &CancelToken,
+) -> Vec<(String, f64)> {
+    resume_scored_starting_words(wordbank, count, Vec::new(), &mut |_| {}, progress, cancel)
+}
+
+/// [`compute_scored_starting_words_with_progress`], carrying on after the words already scored
+/// in `done` by an interrupted run. Every word scored so far, in wordbank order, is handed to
+/// `checkpoint` every [`CHECKPOINT_INTERVAL`] words and when stopped early, so the caller can
+/// save it to resume from later. `done` is ignored unless it is a prefix of `wordbank`.
+///
+/// # Panics
+/// Panics if the expected pool size comparison fails (should never happen with valid f64 values).
+#[instrument(skip_all, fields(words = wordbank.len(), count, resumed = done.len()))]
+pub fn resume_scored_starting_words(
+    wordbank: &WordBank,
+    count: usize,
+    done: Vec<(String, f64)>,
+    checkpoint: &mut dyn FnMut(&[ScoredWord]),
+    progress: &mut dyn ProgressSink,
+    cancel: &CancelToken,
+) -> Vec<(String, f64)> {
+    let resumable = done.len() <= wordbank.len()
+        && done
+            .iter()
+            .zip(wordbank)
+            .all(|((done, _), word)| done == word);
+    let mut scored = if resumable { done } else { Vec::new() };
+    let start = scored.len();
+    scored.reserve(wordbank.len() - start);
+    let mut best = (0..start).min_by(|&a, &b| scored[a].1.total_cmp(&scored[b].1));
+    for (i, word) in wordbank.iter().enumerate().skip(start) {
+        let score = expected_pool_size(word, wordbank);
+        if best.is_none_or(|b| score < scored[b].1) {
+            best = Some(i);
+        }
+        scored.push((word.clone(), score));
+        if should_report(i + 1, wordbank.len()) {
+            let best = best.map(|b| (scored[b].0.as_str(), scored[b].1));
+            progress.report(i + 1, wordbank.len(), best);
+        }
+        let stopping = cancel.is_cancelled();
+        if stopping || (i + 1).is_multiple_of(CHECKPOINT_INTERVAL) {
+            checkpoint(&scored);
+        }
+        if stopping {
+            break;
+        }
+    }
     scored.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
-    scored.into_iter().take(5).map(|(w, _)| w).collect()
+    scored.truncate(count);
+    scored
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::progress::REPORT_INTERVAL;
 
     #[test]
     fn test_feedback_from_char() {
@@ -217,6 +1681,11 @@ mod tests {
         assert_eq!(Feedback::from_char('X'), Some(Feedback::NoMatch));
         assert_eq!(Feedback::from_char('Z'), None);
         assert_eq!(Feedback::from_char('g'), None);
+        assert_eq!(Feedback::from_char('0'), Some(Feedback::NoMatch));
+        assert_eq!(Feedback::from_char('1'), Some(Feedback::PartialMatch));
+        assert_eq!(Feedback::from_char('⬜'), Some(Feedback::NoMatch));
+        assert_eq!(Feedback::from_char('🟧'), Some(Feedback::Match));
+        assert_eq!(Feedback::from_char('3'), None);
     }
 
     #[test]
@@ -334,6 +1803,67 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_mismatched_lengths_dont_panic() {
+        use Feedback::{Match, NoMatch, PartialMatch};
+        assert_eq!(
+            get_feedback("CRANE", "CAR"),
+            [Match, PartialMatch, PartialMatch, NoMatch, NoMatch]
+        );
+        assert_eq!(get_feedback("AB", "BANANA"), [PartialMatch, PartialMatch]);
+        assert!(get_feedback("", "CRANE").is_empty());
+        // Used letters are tracked apart from the text, so no character stands in for them
+        assert_eq!(get_feedback("_____", "CRANE"), [NoMatch; 5]);
+
+        let candidates: Vec<String> = ["CRANE", "CRAN", "CRANES", ""]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(
+            filter_candidates(&candidates, "CRANE", &[Match; 5]),
+            ["CRANE"]
+        );
+        assert_eq!(
+            filter_candidates(&candidates, "CRANE", &[Match; 2]),
+            ["CRANE"]
+        );
+        assert!(filter_candidates(&candidates, "CRANE", &[]).contains(&"CRANE".to_string()));
+        assert!(filter_candidates(&candidates, "", &[Match; 5]).contains(&String::new()));
+    }
+
+    #[test]
+    fn test_checked_variants_reject_bad_lengths() {
+        let candidates = vec!["CRANE".to_string(), "CRAN".to_string()];
+        assert_eq!(
+            try_get_feedback("CRAN", "CRANE"),
+            Err(WordleError::WordLength("CRAN".to_string()))
+        );
+        assert_eq!(
+            try_get_feedback("crane", "CRAN3"),
+            Err(WordleError::WordLength("CRAN3".to_string()))
+        );
+        assert_eq!(
+            try_get_feedback("crane", "CRANE"),
+            Ok(vec![Feedback::Match; 5])
+        );
+        assert_eq!(
+            try_filter_candidates(&candidates, "CRANES", &[Feedback::Match; 5]),
+            Err(WordleError::WordLength("CRANES".to_string()))
+        );
+        assert_eq!(
+            try_filter_candidates(&candidates, "CRANE", &[Feedback::Match; 6]),
+            Err(WordleError::FeedbackLength(6))
+        );
+        assert_eq!(
+            try_filter_candidates(&candidates, "CRANE", &[Feedback::Match; 5]),
+            Ok(vec!["CRANE".to_string()])
+        );
+        assert_eq!(
+            WordleError::FeedbackLength(6).to_string(),
+            "expected 5 feedback values, got 6"
+        );
+    }
+
     #[test]
     fn test_filter_candidates_all_green() {
         let candidates = vec![
@@ -436,6 +1966,72 @@ mod tests {
         assert_eq!(result, vec!["SHELF"]);
     }
 
+    #[test]
+    fn test_partition_candidates_matches_filter() {
+        let candidates = vec![
+            "CRANE".to_string(),
+            "BRAIN".to_string(),
+            "TRAIN".to_string(),
+            "STAIN".to_string(),
+        ];
+        let feedback = get_feedback("TRAIN", "BRAIN");
+        let (survived, eliminated) = partition_candidates(&candidates, "TRAIN", &feedback);
+
+        assert_eq!(survived, filter_candidates(&candidates, "TRAIN", &feedback));
+        assert_eq!(survived.len() + eliminated.len(), candidates.len());
+        assert!(eliminated.contains(&"TRAIN".to_string()));
+        assert!(eliminated.contains(&"CRANE".to_string()));
+    }
+
+    #[test]
+    fn test_partition_ids_matches_partition_candidates() {
+        let candidates = vec![
+            "CRANE".to_string(),
+            "BRAIN".to_string(),
+            "TRAIN".to_string(),
+            "STAIN".to_string(),
+        ];
+        let arena = WordArena::new(&candidates).unwrap();
+        let mut alive = CandidateSet::full(arena.len());
+        alive.remove(WordId(3));
+        let feedback = get_feedback("TRAIN", "BRAIN");
+        let (survived, eliminated) = partition_ids(&arena, &alive, "TRAIN", &feedback);
+
+        let (expected_survived, expected_eliminated) =
+            partition_candidates(&candidates[..3], "TRAIN", &feedback);
+        assert_eq!(arena.words(&survived), expected_survived);
+        assert_eq!(arena.words(&eliminated), expected_eliminated);
+    }
+
+    #[test]
+    fn test_probe_letter_skips_letters_every_candidate_shares() {
+        let candidates: Vec<String> = ["BATCH", "CATCH", "HATCH", "LATCH", "MATCH"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        // A, T, C and H are in every word; the rest are in one each
+        assert_eq!(probe_letter(&candidates, &[]), Some('B'));
+        assert_eq!(probe_letter(&candidates, &['B', 'L', 'M']), None);
+        assert_eq!(probe_letter(&candidates[..1], &[]), None);
+    }
+
+    #[test]
+    fn test_letter_counts_counts_words_not_occurrences() {
+        let words = vec![
+            "EERIE".to_string(),
+            "SLATE".to_string(),
+            "crane".to_string(),
+        ];
+        let counts = letter_counts(&words);
+        let count = |c: u8| counts[usize::from(c - b'A')];
+
+        assert_eq!(count(b'E'), 2);
+        assert_eq!(count(b'S'), 1);
+        // Lowercase letters are skipped
+        assert_eq!(count(b'C'), 0);
+        assert_eq!(counts.iter().sum::<usize>(), 3 + 5);
+    }
+
     #[test]
     fn test_expected_pool_size_single_candidate() {
         let candidates = vec!["CRANE".to_string()];
@@ -479,7 +2075,7 @@ mod tests {
             "STARE".to_string(),
         ];
         let candidates = vec!["CRANE".to_string(), "SLATE".to_string()];
-        let (guess, score, is_candidate) = best_information_guess(&wordbank, &candidates);
+        let (guess, score, is_candidate) = best_information_guess(&wordbank, &candidates).unwrap();
 
         // Should return a valid word from wordbank
         assert!(wordbank.contains(&guess.to_string()));
@@ -490,6 +2086,91 @@ mod tests {
         assert_eq!(is_candidate, candidates.contains(guess));
     }
 
+    #[test]
+    fn test_warm_start_matches_a_full_search() {
+        let wordbank = crate::wordbank::sample_wordbank();
+        // The first guess scoring lowest, as a search with no cutoffs finds it
+        let full_search = |candidates: &[String]| {
+            wordbank
+                .iter()
+                .map(|guess| (guess, expected_pool_size(guess, candidates)))
+                .fold(
+                    None,
+                    |best: Option<(&String, f64)>, (guess, score)| match best {
+                        Some((_, best_score)) if best_score <= score => best,
+                        _ => Some((guess, score)),
+                    },
+                )
+        };
+        for answer in ["QUOTA", "CRANE", "EERIE"] {
+            let mut warm = WarmStart::default();
+            let mut candidates = wordbank.to_vec();
+            while candidates.len() > 1 {
+                let (guess, score, _) = best_information_guess_warm(
+                    &wordbank,
+                    &candidates,
+                    &mut warm,
+                    &mut NoProgress,
+                    &CancelToken::new(),
+                )
+                .unwrap();
+                assert_eq!(Some((guess, score)), full_search(&candidates), "{answer}");
+                assert_eq!(warm.ranking().first(), Some(guess));
+                assert!(warm.ranking().len() <= WARM_START_SIZE);
+                let feedback = get_feedback(guess, answer);
+                candidates = filter_candidates(&candidates, guess, &feedback);
+            }
+        }
+
+        // A ranking from another wordbank only changes the order guesses are scored in
+        let mut warm = WarmStart {
+            ranking: vec![
+                "ZZZZZ".to_string(),
+                "CCCCC".to_string(),
+                "AAAAA".to_string(),
+            ],
+        };
+        let wordbank = vec![
+            "AAAAA".to_string(),
+            "BBBBB".to_string(),
+            "CCCCC".to_string(),
+        ];
+        let result = best_information_guess_warm(
+            &wordbank,
+            &wordbank,
+            &mut warm,
+            &mut NoProgress,
+            &CancelToken::new(),
+        );
+        // All three split the pool alike, so the first in the wordbank still wins the tie
+        assert_eq!(result, best_information_guess(&wordbank, &wordbank));
+        assert_eq!(result.map(|(guess, _, _)| guess.as_str()), Some("AAAAA"));
+    }
+
+    #[test]
+    fn test_guess_pickers_return_none_without_words() {
+        let words = vec!["CRANE".to_string(), "SLATE".to_string()];
+        let none: &[String] = &[];
+        for (wordbank, candidates) in [(none, words.as_slice()), (words.as_slice(), none)] {
+            assert!(best_information_guess(wordbank, candidates).is_none());
+            assert!(best_lookahead_guess(wordbank, candidates).is_none());
+            assert!(best_finishing_guess(wordbank, candidates).is_none());
+            assert!(best_sampled_guess(wordbank, candidates, 1).is_none());
+        }
+        let mut score = |_: &str| 1.0;
+        let avoiding = best_avoiding_guess_with_progress(
+            none,
+            &['E'],
+            &mut score,
+            &mut NoProgress,
+            &CancelToken::new(),
+        );
+        assert!(avoiding.is_none());
+
+        let arena = WordArena::new(&words).unwrap();
+        assert!(PoolScorer::new(&arena, &[]).best_guess().is_none());
+    }
+
     #[test]
     fn test_best_information_guess_prefers_lower_score() {
         let wordbank = vec![
@@ -505,7 +2186,7 @@ mod tests {
             "TRAIN".to_string(),
             "BRAIN".to_string(),
         ];
-        let (guess, _, _) = best_information_guess(&wordbank, &candidates);
+        let (guess, _, _) = best_information_guess(&wordbank, &candidates).unwrap();
 
         // One of the actual candidates should be better than words with no shared letters
         assert!(
@@ -516,8 +2197,409 @@ mod tests {
     }
 
     #[test]
-    fn test_compute_best_starting_words_returns_five() {
+    fn test_find_dominating_guess_uninformative_guess() {
+        let candidates = vec![
+            "CRANE".to_string(),
+            "TRAIN".to_string(),
+            "BRAIN".to_string(),
+        ];
+        let (better, score, better_score) = find_dominating_guess("ZZZZZ", &candidates).unwrap();
+        assert!(candidates.contains(better));
+        assert_eq!(score, 3.0);
+        assert!(better_score <= score);
+    }
+
+    #[test]
+    fn test_find_dominating_guess_candidate_never_dominated() {
+        let candidates = vec!["CRANE".to_string(), "TRAIN".to_string()];
+        assert!(find_dominating_guess("CRANE", &candidates).is_none());
+    }
+
+    #[test]
+    fn test_find_dominating_guess_informative_guess() {
+        // Classic trap family: any candidate guess leaves the other five together,
+        // while CLAMP separates almost all of them
+        let candidates: Vec<String> = ["BATCH", "CATCH", "HATCH", "LATCH", "MATCH", "PATCH"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert!(find_dominating_guess("CLAMP", &candidates).is_none());
+    }
+
+    #[test]
+    fn test_pruned_scoring_matches_full_scoring() {
+        let wordbank = crate::wordbank::sample_wordbank();
+        let candidates = filter_candidates(&wordbank, "CRANE", &get_feedback("CRANE", "QUOTA"));
+        for guess in &wordbank {
+            let full = pool_sum_of_squares(guess, &candidates, usize::MAX);
+            assert_eq!(
+                full as f64 / candidates.len() as f64,
+                expected_pool_size(guess, &candidates)
+            );
+            // Cut off past the bound, but never below the full sum or the bound
+            let cut = pool_sum_of_squares(guess, &candidates, full / 2);
+            assert!(cut > full / 2 && cut <= full, "{guess}");
+        }
+
+        // Sampled scoring skips clearly worse guesses without changing the pick
+        let sample = sample_candidates(&wordbank, 50);
+        let best = wordbank
+            .iter()
+            .map(|guess| (guess, estimate_pool_size(guess, &sample, wordbank.len())))
+            .fold(
+                None,
+                |best: Option<(&String, ScoreEstimate)>, (guess, estimate)| match best {
+                    Some((_, ref b)) if b.score <= estimate.score => best,
+                    _ => Some((guess, estimate)),
+                },
+            )
+            .unwrap();
+        let (guess, estimate, _) = best_guess_for_sample(&wordbank, &wordbank, &sample).unwrap();
+        assert_eq!((guess, estimate.score), (best.0, best.1.score));
+    }
+
+    #[test]
+    fn test_sample_candidates_evenly_spaced() {
+        let candidates: Vec<String> = (0..10).map(|i| format!("W{i}")).collect();
+        let sample = sample_candidates(&candidates, 5);
+        assert_eq!(sample, vec!["W0", "W2", "W4", "W6", "W8"]);
+
+        // Small pools are returned whole
+        assert_eq!(sample_candidates(&candidates, 20), candidates);
+    }
+
+    #[test]
+    fn test_estimate_pool_size_full_sample_is_exact() {
+        let candidates = vec![
+            "CRANE".to_string(),
+            "CRATE".to_string(),
+            "CRAZE".to_string(),
+        ];
+        let estimate = estimate_pool_size("CRATE", &candidates, candidates.len());
+        assert_eq!(estimate.score, expected_pool_size("CRATE", &candidates));
+        assert_eq!(estimate.lower, estimate.upper);
+    }
+
+    #[test]
+    fn test_estimate_pool_size_single_answer_sample_is_unbounded() {
+        let sample = vec!["CRANE".to_string()];
+        let estimate = estimate_pool_size("CRATE", &sample, 10);
+        assert_eq!(estimate.lower, 1.0);
+        assert_eq!(estimate.upper, 10.0);
+        assert!(estimate.lower <= estimate.score && estimate.score <= estimate.upper);
+    }
+
+    #[test]
+    fn test_estimate_pool_size_interval_contains_score() {
+        let candidates: Vec<String> = [
+            "CRANE", "SLATE", "RAISE", "STARE", "ARISE", "IRATE", "ATONE", "STONE", "SHONE",
+            "PHONE", "PLACE", "GRACE", "TRACE", "SPACE", "BRACE",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+        let sample = sample_candidates(&candidates, 6);
+        let estimate = estimate_pool_size("STARE", &sample, candidates.len());
+
+        assert!(estimate.lower <= estimate.score);
+        assert!(estimate.score <= estimate.upper);
+        assert!(estimate.lower >= 1.0);
+    }
+
+    #[test]
+    fn test_profile_from_str() {
+        assert_eq!("fast".parse::<Profile>(), Ok(Profile::Fast));
+        assert_eq!("Balanced".parse::<Profile>(), Ok(Profile::Balanced));
+        assert_eq!("EXHAUSTIVE".parse::<Profile>(), Ok(Profile::Exhaustive));
+        assert!("slow".parse::<Profile>().is_err());
+    }
+
+    #[test]
+    fn test_default_config_is_balanced() {
+        assert_eq!(SolverConfig::default(), Profile::Balanced.config());
+        assert!(Profile::Fast.config().sample_size.is_some());
+        assert_eq!(Profile::Exhaustive.config().lookahead, 1);
+        assert!(Profile::Exhaustive.config().use_cache);
+    }
+
+    #[test]
+    fn test_strategy_round_trip() {
+        for strategy in [Strategy::ExpectedSize, Strategy::CandidatesOnly] {
+            assert_eq!(strategy.to_string().parse::<Strategy>(), Ok(strategy));
+        }
+    }
+
+    #[test]
+    fn test_board_weighting_round_trip() {
+        for weighting in [BoardWeighting::Equal, BoardWeighting::NearSolved] {
+            assert_eq!(
+                weighting.to_string().parse::<BoardWeighting>(),
+                Ok(weighting)
+            );
+        }
+        assert!("closest".parse::<BoardWeighting>().is_err());
+    }
+
+    #[test]
+    fn test_best_joint_guess_skips_empty_boards() {
+        let wordbank = vec!["CRANE".to_string(), "SLATE".to_string()];
+        let solved = vec!["SLATE".to_string()];
+        assert!(best_joint_guess(&wordbank, &[&[], &[]], BoardWeighting::Equal).is_none());
+        assert!(best_joint_guess(&[], &[&solved], BoardWeighting::Equal).is_none());
+
+        // Guessing a board's last candidate solves it, leaving nothing
+        let (guess, score) =
+            best_joint_guess(&wordbank, &[&[], &solved], BoardWeighting::NearSolved).unwrap();
+        assert_eq!((guess.as_str(), score), ("SLATE", 0.0));
+        assert_eq!(expected_remaining("CRANE", &solved), 1.0);
+    }
+
+    #[test]
+    fn test_rank_guesses_sorted() {
+        let wordbank = vec![
+            "AAAAA".to_string(),
+            "CRANE".to_string(),
+            "TRAIN".to_string(),
+        ];
+        let candidates = vec!["CRANE".to_string(), "TRAIN".to_string()];
+        let ranked = rank_guesses(&wordbank, &candidates);
+        assert_eq!(ranked.len(), 3);
+        assert!(ranked.windows(2).all(|w| w[0].1 <= w[1].1));
+        assert_eq!(ranked.last().unwrap().0, "AAAAA");
+    }
+
+    #[test]
+    fn test_lookahead_pool_size_not_worse_than_greedy() {
+        let wordbank: Vec<String> = ["BATCH", "CATCH", "HATCH", "LATCH", "MATCH", "CLAMP"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let candidates = wordbank[..5].to_vec();
+        let greedy = expected_pool_size("CLAMP", &candidates);
+        let lookahead = lookahead_pool_size("CLAMP", &wordbank, &candidates);
+        assert!(lookahead <= greedy);
+        assert!(lookahead >= 1.0);
+    }
+
+    #[test]
+    fn test_ties_go_to_the_first_word_in_the_wordbank() {
+        let mut wordbank: Vec<String> = ["BATCH", "CATCH", "HATCH", "LATCH"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        // Every word only tells apart itself, so all four tie
+        for _ in 0..2 {
+            let (guess, _, _) = best_information_guess(&wordbank, &wordbank).unwrap();
+            assert_eq!(guess, &wordbank[0]);
+            assert_eq!(rank_guesses(&wordbank, &wordbank)[0].0, &wordbank[0]);
+            let arena = WordArena::new(&wordbank).unwrap();
+            let scorer = PoolScorer::new(&arena, &arena.ids_of(&wordbank));
+            assert_eq!(arena.get(scorer.best_guess().unwrap().0), wordbank[0]);
+            wordbank.reverse();
+        }
+    }
+
+    #[test]
+    fn test_lookahead_pool_size_is_reproducible() {
+        let wordbank: Vec<String> = [
+            "BATCH", "CATCH", "HATCH", "LATCH", "MATCH", "PATCH", "CLAMP", "BLIMP", "CHOMP",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+        let candidates = wordbank[..6].to_vec();
+        let first = lookahead_pool_size("CLAMP", &wordbank, &candidates);
+        for _ in 0..20 {
+            let again = lookahead_pool_size("CLAMP", &wordbank, &candidates);
+            assert_eq!(again.to_bits(), first.to_bits());
+        }
+    }
+
+    #[test]
+    fn test_best_lookahead_guess_returns_wordbank_word() {
+        let wordbank: Vec<String> = ["BATCH", "CATCH", "HATCH", "LATCH", "MATCH", "CLAMP"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let candidates = wordbank[..5].to_vec();
+        let (guess, score, is_candidate) = best_lookahead_guess(&wordbank, &candidates).unwrap();
+        assert!(wordbank.contains(guess));
+        assert_eq!(score, expected_pool_size(guess, &candidates));
+        assert_eq!(is_candidate, candidates.contains(guess));
+    }
+
+    #[test]
+    fn test_pattern_code_distinct() {
+        let candidates = ["CRANE", "BRAIN", "NACRE", "SLATE", "CRATE"];
+        let codes: std::collections::HashSet<u8> = candidates
+            .iter()
+            .map(|c| pattern_code(&get_feedback("CRANE", c)))
+            .collect();
+        assert_eq!(codes.len(), candidates.len());
+    }
+
+    #[test]
+    fn test_feedback_code_matches_get_feedback() {
+        let words = [
+            "CRANE", "EERIE", "ELEGY", "SKILL", "SLATE", "ROBOT", "WORLD", "NACRE",
+        ];
+        for guess in words {
+            for solution in words {
+                assert_eq!(
+                    feedback_code(guess.as_bytes(), solution.as_bytes()),
+                    pattern_code(&get_feedback(guess, solution)),
+                    "{guess} vs {solution}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_pool_scorer_matches_expected_pool_size() {
+        let wordbank: Vec<String> = [
+            "CRANE", "SLATE", "TRACE", "GRACE", "PLACE", "BRACE", "STARE",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+        let arena = WordArena::new(&wordbank).unwrap();
+        let mut scorer = PoolScorer::new(&arena, &arena.ids().collect::<Vec<_>>());
+        let mut candidates = wordbank.clone();
+
+        for removed in ["SLATE", "PLACE", "STARE"] {
+            assert!(scorer.remove(arena.id(removed).unwrap()));
+            candidates.retain(|c| c != removed);
+
+            assert_eq!(scorer.candidate_count(), candidates.len());
+            for (id, guess) in arena.ids().zip(&wordbank) {
+                let expected = expected_pool_size(guess, &candidates);
+                assert!((scorer.expected_pool_size(id) - expected).abs() < 1e-9);
+            }
+            let (best, score, is_candidate) = scorer.best_guess().unwrap();
+            let (expected, expected_score, expected_is_candidate) =
+                best_information_guess(&wordbank, &candidates).unwrap();
+            assert_eq!(arena.get(best), expected);
+            assert_eq!(score, expected_score);
+            assert_eq!(is_candidate, expected_is_candidate);
+        }
+    }
+
+    #[test]
+    fn test_pool_scorer_remove_twice_or_unknown() {
         let wordbank = vec![
+            "CRANE".to_string(),
+            "SLATE".to_string(),
+            "BRAIN".to_string(),
+        ];
+        let arena = WordArena::new(&wordbank).unwrap();
+        // BRAIN is a valid guess but was never a candidate
+        let mut scorer = PoolScorer::new(&arena, &[WordId(0), WordId(1)]);
+        assert!(scorer.remove(WordId(0)));
+        assert!(!scorer.remove(WordId(0)));
+        assert!(!scorer.remove(WordId(2)));
+        assert!(!scorer.is_alive(WordId(0)));
+        assert!(scorer.is_alive(WordId(1)));
+    }
+
+    #[test]
+    fn test_best_information_guess_reports_progress() {
+        let wordbank: Vec<String> = (0..100).map(|i| format!("{:A>5}", i % 26)).collect();
+        let candidates = wordbank[..10].to_vec();
+        let mut reports = Vec::new();
+        let mut sink = |current, total, best: Option<(&str, f64)>| {
+            reports.push((current, total, best.map(|(w, s)| (w.to_string(), s))));
+        };
+        let (guess, score, _) = best_information_guess_with_progress(
+            &wordbank,
+            &candidates,
+            &mut sink,
+            &CancelToken::new(),
+        )
+        .unwrap();
+
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0].0, 64);
+        let (current, total, best) = reports.last().unwrap().clone();
+        assert_eq!((current, total), (100, 100));
+        assert_eq!(best, Some((guess.clone(), score)));
+    }
+
+    #[test]
+    fn test_pool_scorer_reports_progress() {
+        let words: Vec<String> = ["CRANE", "SLATE", "TRACE"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let arena = WordArena::new(&words).unwrap();
+        let scorer = PoolScorer::new(&arena, &arena.ids().collect::<Vec<_>>());
+        let mut last = None;
+        let mut sink = |current, total, best: Option<(&str, f64)>| {
+            last = Some((current, total, best.map(|(w, _)| w.to_string())));
+        };
+        let (id, _, _) = scorer
+            .best_guess_with_progress(Some(&arena), &mut sink, &CancelToken::new())
+            .unwrap();
+
+        assert_eq!(last, Some((3, 3, Some(arena.get(id).to_string()))));
+    }
+
+    #[test]
+    fn test_lookahead_streams_greedy_best_first() {
+        let wordbank: Vec<String> = ["CRANE", "SLATE", "TRACE", "GRACE", "PLACE", "BRACE"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let mut reports = Vec::new();
+        let mut sink = |current, total, best: Option<(&str, f64)>| {
+            reports.push((current, total, best.unwrap().0.to_string()));
+        };
+        let (guess, _, _) = best_lookahead_guess_with_progress(
+            &wordbank,
+            &wordbank,
+            &mut sink,
+            &CancelToken::new(),
+        )
+        .unwrap();
+
+        let (greedy, _, _) = best_information_guess(&wordbank, &wordbank).unwrap();
+        assert_eq!(reports[0], (0, 6, greedy.clone()));
+        assert_eq!(reports.last().unwrap(), &(6, 6, guess.clone()));
+    }
+
+    #[test]
+    fn test_cancel_returns_best_so_far() {
+        let wordbank =
+            WordBank::from_valid((0..200u8).map(|i| format!("{:A>5}", char::from(b'A' + i % 26))));
+        let cancel = CancelToken::new();
+        let mut reports = 0;
+        let mut sink = |_, _, _: Option<(&str, f64)>| {
+            reports += 1;
+            cancel.cancel();
+        };
+        let (guess, score, _) =
+            best_information_guess_with_progress(&wordbank, &wordbank, &mut sink, &cancel).unwrap();
+
+        // Stopped at the first report, so only the first 64 words were scored
+        assert_eq!(reports, 1);
+        let (expected, expected_score, _) =
+            best_information_guess(&wordbank[..64], &wordbank).unwrap();
+        assert_eq!((guess, score), (expected, expected_score));
+
+        let cancelled = CancelToken::new();
+        cancelled.cancel();
+        let words = compute_scored_starting_words_with_progress(
+            &wordbank,
+            DEFAULT_STARTING_WORDS,
+            &mut NoProgress,
+            &cancelled,
+        );
+        assert_eq!(words.len(), 1);
+        assert_eq!(words[0].0, wordbank[0]);
+    }
+
+    #[test]
+    fn test_compute_best_starting_words_returns_five() {
+        let wordbank = WordBank::new(vec![
             "CRANE".to_string(),
             "SLATE".to_string(),
             "RAISE".to_string(),
@@ -525,7 +2607,8 @@ mod tests {
             "ARISE".to_string(),
             "ATONE".to_string(),
             "IRATE".to_string(),
-        ];
+        ])
+        .unwrap();
         let starting_words = compute_best_starting_words(&wordbank);
 
         assert_eq!(starting_words.len(), 5);
@@ -535,10 +2618,70 @@ mod tests {
 
     #[test]
     fn test_compute_best_starting_words_with_small_wordbank() {
-        let wordbank = vec!["CRANE".to_string(), "SLATE".to_string()];
+        let wordbank = WordBank::new(vec!["CRANE".to_string(), "SLATE".to_string()]).unwrap();
         let starting_words = compute_best_starting_words(&wordbank);
 
         // Should return at most 5, but only 2 available
         assert_eq!(starting_words.len(), 2);
     }
+
+    #[test]
+    fn test_resume_scored_starting_words_matches_fresh_run() {
+        let wordbank = WordBank::from_valid(
+            ('A'..='Z').flat_map(|a| ('A'..='L').map(move |b| format!("{a}{b}ATE"))),
+        );
+        let fresh = compute_scored_starting_words(&wordbank, 5);
+
+        // Stop at the first report and keep what the checkpoint was given
+        let cancel = CancelToken::new();
+        let mut sink = |_: usize, _: usize, _: Option<(&str, f64)>| cancel.cancel();
+        let mut saved = Vec::new();
+        resume_scored_starting_words(
+            &wordbank,
+            5,
+            Vec::new(),
+            &mut |scored| saved = scored.to_vec(),
+            &mut sink,
+            &cancel,
+        );
+        assert_eq!(saved.len(), REPORT_INTERVAL);
+
+        let mut checkpoints = 0;
+        let resumed = resume_scored_starting_words(
+            &wordbank,
+            5,
+            saved,
+            &mut |_| checkpoints += 1,
+            &mut NoProgress,
+            &CancelToken::new(),
+        );
+        assert_eq!(resumed, fresh);
+        // 312 words, so one checkpoint at 256
+        assert_eq!(checkpoints, 1);
+
+        // Scores from another wordbank are thrown away
+        let foreign = vec![("ZZZZZ".to_string(), 0.0)];
+        let rescored = resume_scored_starting_words(
+            &wordbank,
+            5,
+            foreign,
+            &mut |_| {},
+            &mut NoProgress,
+            &CancelToken::new(),
+        );
+        assert_eq!(rescored, fresh);
+    }
+
+    #[test]
+    fn test_compute_scored_starting_words_count() {
+        let wordbank = WordBank::from_valid([
+            "CRANE", "SLATE", "RAISE", "STARE", "ARISE", "ATONE", "IRATE",
+        ]);
+        let scored = compute_scored_starting_words(&wordbank, 7);
+
+        assert_eq!(scored.len(), 7);
+        assert!(scored.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+        assert_eq!(scored[0].1, expected_pool_size(&scored[0].0, &wordbank));
+        assert_eq!(compute_scored_starting_words(&wordbank, 1)[0], scored[0]);
+    }
 }
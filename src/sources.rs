@@ -0,0 +1,221 @@
+//! Where wordbank words come from.
+//!
+//! Each [`WordSource`] turns some location into a list of valid uppercase words. New kinds of
+//! source only need an implementation here and a case in [`source_for`].
+
+use crate::wordbank::{LoadedWords, embedded_wordbank, load_checked_wordbank_from_file};
+#[cfg(feature = "http")]
+use crate::wordbank::{parse_wordbank, wordbank_warnings};
+use crate::words::WordBank;
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// A place to load wordbank words from
+pub trait WordSource {
+    /// Where the words come from, for messages
+    fn describe(&self) -> String;
+
+    /// Loads the words, keeping only valid 5-letter words, uppercased.
+    ///
+    /// # Errors
+    /// Returns an error if the source cannot be read.
+    fn words(&self) -> io::Result<WordBank>;
+
+    /// Loads the words along with warnings about any it had to skip, such as words of another
+    /// length or with letters outside A-Z. Sources that can't tell just load the words.
+    ///
+    /// # Errors
+    /// Returns an error if the source cannot be read.
+    fn load(&self) -> io::Result<LoadedWords> {
+        self.words().map(|words| LoadedWords {
+            words,
+            warnings: Vec::new(),
+        })
+    }
+}
+
+/// The wordbank compiled into the binary
+pub struct EmbeddedSource;
+
+impl WordSource for EmbeddedSource {
+    fn describe(&self) -> String {
+        "embedded wordbank".to_string()
+    }
+
+    fn words(&self) -> io::Result<WordBank> {
+        Ok(embedded_wordbank())
+    }
+}
+
+/// A newline-delimited word file
+pub struct FileSource {
+    pub path: PathBuf,
+}
+
+impl WordSource for FileSource {
+    fn describe(&self) -> String {
+        self.path.display().to_string()
+    }
+
+    fn words(&self) -> io::Result<WordBank> {
+        self.load().map(|loaded| loaded.words)
+    }
+
+    fn load(&self) -> io::Result<LoadedWords> {
+        load_checked_wordbank_from_file(&self.path)
+    }
+}
+
+/// Every file in a directory, read in name order with duplicate words dropped
+pub struct DirectorySource {
+    pub path: PathBuf,
+}
+
+impl WordSource for DirectorySource {
+    fn describe(&self) -> String {
+        format!("{}/", self.path.display())
+    }
+
+    fn words(&self) -> io::Result<WordBank> {
+        self.load().map(|loaded| loaded.words)
+    }
+
+    fn load(&self) -> io::Result<LoadedWords> {
+        let mut files = Vec::new();
+        for entry in fs::read_dir(&self.path)? {
+            let path = entry?.path();
+            if path.is_file() {
+                files.push(path);
+            }
+        }
+        files.sort();
+
+        let mut seen = HashSet::new();
+        let mut loaded = LoadedWords::default();
+        for file in files {
+            let LoadedWords { words, warnings } = load_checked_wordbank_from_file(&file)?;
+            let name = file.file_name().unwrap_or_default().to_string_lossy();
+            for warning in warnings {
+                loaded.warnings.push(format!("{name}: {warning}"));
+            }
+            for word in words {
+                if seen.insert(word.clone()) {
+                    loaded.words.push(word);
+                }
+            }
+        }
+        Ok(loaded)
+    }
+}
+
+/// A word list downloaded over http(s). Needs the `http` feature.
+pub struct UrlSource {
+    pub url: String,
+}
+
+impl WordSource for UrlSource {
+    fn describe(&self) -> String {
+        self.url.clone()
+    }
+
+    #[cfg(feature = "http")]
+    fn words(&self) -> io::Result<WordBank> {
+        self.load().map(|loaded| loaded.words)
+    }
+
+    #[cfg(feature = "http")]
+    fn load(&self) -> io::Result<LoadedWords> {
+        let body = ureq::get(&self.url)
+            .call()
+            .map_err(io::Error::other)?
+            .body_mut()
+            .read_to_string()
+            .map_err(io::Error::other)?;
+        let (_, words) =
+            parse_wordbank(&body).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        Ok(LoadedWords {
+            words,
+            warnings: wordbank_warnings(&body),
+        })
+    }
+
+    #[cfg(not(feature = "http"))]
+    fn words(&self) -> io::Result<WordBank> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "loading from a URL needs the `http` feature (cargo build --features http)",
+        ))
+    }
+}
+
+/// Picks a source for a `--input` value: a URL, a directory, or otherwise a file.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::sources::source_for;
+///
+/// assert_eq!(source_for("https://example.com/words.txt").describe(), "https://example.com/words.txt");
+/// assert_eq!(source_for("words.txt").describe(), "words.txt");
+/// ```
+#[must_use]
+pub fn source_for(spec: &str) -> Box<dyn WordSource> {
+    if spec.starts_with("http://") || spec.starts_with("https://") {
+        Box::new(UrlSource {
+            url: spec.to_string(),
+        })
+    } else {
+        let path = PathBuf::from(spec);
+        if path.is_dir() {
+            Box::new(DirectorySource { path })
+        } else {
+            Box::new(FileSource { path })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_embedded_source() {
+        let words = EmbeddedSource.words().unwrap();
+        assert_eq!(words, embedded_wordbank());
+    }
+
+    #[test]
+    fn test_directory_source_merges_files() {
+        let dir = std::env::temp_dir().join("test_wordbank_dir");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("a.txt"), "crane\nslate\n").unwrap();
+        fs::write(dir.join("b.txt"), "slate\nraise\ntoolong\n").unwrap();
+
+        let source = source_for(dir.to_str().unwrap());
+        assert!(source.describe().ends_with('/'));
+        assert_eq!(source.words().unwrap(), vec!["CRANE", "SLATE", "RAISE"]);
+        let warnings = source.load().unwrap().warnings;
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].starts_with("b.txt: skipped 1 of 3 words"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_file_source_missing_file() {
+        let source = source_for("nonexistent_wordbank_source.txt");
+        assert_eq!(source.describe(), "nonexistent_wordbank_source.txt");
+        assert!(source.words().is_err());
+    }
+
+    #[cfg(not(feature = "http"))]
+    #[test]
+    fn test_url_source_needs_http_feature() {
+        let err = source_for("https://example.com/words.txt")
+            .words()
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::Unsupported);
+    }
+}
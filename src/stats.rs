@@ -0,0 +1,396 @@
+//! Finished games and achievements persisted between runs.
+//!
+//! Stats live in `~/.wordle_stats`, one `game` or `achievement` line per entry. Lines that
+//! don't parse are skipped so a damaged file only loses those entries.
+
+use crate::play::MAX_GUESSES;
+use chrono::NaiveDate;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Wins in a row needed for [`Achievement::WinStreak`]
+pub const STREAK_GOAL: usize = 10;
+
+/// Games needed for [`Achievement::Veteran`]
+pub const VETERAN_GAMES: usize = 100;
+
+const VOWELS: [char; 5] = ['A', 'E', 'I', 'O', 'U'];
+
+/// One finished game
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GameRecord {
+    pub date: NaiveDate,
+    /// Every guess in order, ending with the answer
+    pub guesses: Vec<String>,
+}
+
+impl GameRecord {
+    /// Whether the answer was found within the usual six guesses
+    #[must_use]
+    pub fn won(&self) -> bool {
+        !self.guesses.is_empty() && self.guesses.len() <= MAX_GUESSES
+    }
+}
+
+/// A milestone unlocked once and kept forever
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Achievement {
+    FirstWin,
+    /// Solved on the very first guess
+    HoleInOne,
+    TwoGuessSolve,
+    /// [`STREAK_GOAL`] wins in a row
+    WinStreak,
+    /// Won after opening with a word that has no A, E, I, O or U
+    VowelFreeOpener,
+    /// [`VETERAN_GAMES`] games finished
+    Veteran,
+}
+
+impl Achievement {
+    pub const ALL: [Self; 6] = [
+        Self::FirstWin,
+        Self::HoleInOne,
+        Self::TwoGuessSolve,
+        Self::WinStreak,
+        Self::VowelFreeOpener,
+        Self::Veteran,
+    ];
+
+    /// Name used in the stats file
+    #[must_use]
+    pub const fn key(self) -> &'static str {
+        match self {
+            Self::FirstWin => "first-win",
+            Self::HoleInOne => "hole-in-one",
+            Self::TwoGuessSolve => "two-guess-solve",
+            Self::WinStreak => "win-streak",
+            Self::VowelFreeOpener => "vowel-free-opener",
+            Self::Veteran => "veteran",
+        }
+    }
+
+    #[must_use]
+    pub const fn title(self) -> &'static str {
+        match self {
+            Self::FirstWin => "First Win",
+            Self::HoleInOne => "Hole in One",
+            Self::TwoGuessSolve => "Sharp Shooter",
+            Self::WinStreak => "On a Roll",
+            Self::VowelFreeOpener => "Consonant Crusher",
+            Self::Veteran => "Veteran",
+        }
+    }
+
+    #[must_use]
+    pub const fn description(self) -> &'static str {
+        match self {
+            Self::FirstWin => "win a game",
+            Self::HoleInOne => "solve on the first guess",
+            Self::TwoGuessSolve => "solve in two guesses",
+            Self::WinStreak => "win 10 games in a row",
+            Self::VowelFreeOpener => "win after opening without a vowel",
+            Self::Veteran => "finish 100 games",
+        }
+    }
+
+    /// Whether the stats, with `game` as the latest game, earn this achievement
+    fn earned(self, stats: &Stats, game: &GameRecord) -> bool {
+        match self {
+            Self::FirstWin => game.won(),
+            Self::HoleInOne => game.guesses.len() == 1,
+            Self::TwoGuessSolve => game.guesses.len() == 2,
+            Self::WinStreak => stats.win_streak() >= STREAK_GOAL,
+            Self::VowelFreeOpener => {
+                game.won()
+                    && game
+                        .guesses
+                        .first()
+                        .is_some_and(|opener| !opener.contains(VOWELS))
+            }
+            Self::Veteran => stats.games.len() >= VETERAN_GAMES,
+        }
+    }
+}
+
+impl FromStr for Achievement {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|achievement| achievement.key() == s)
+            .ok_or_else(|| format!("unknown achievement '{s}'"))
+    }
+}
+
+impl fmt::Display for Achievement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.title(), self.description())
+    }
+}
+
+/// Days in a row with at least one game played
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DailyStreak {
+    /// Run of days ending today, or yesterday if today hasn't been played yet
+    pub current: usize,
+    /// Longest run ever
+    pub max: usize,
+    pub played_today: bool,
+}
+
+impl fmt::Display for DailyStreak {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.current == 0 {
+            write!(f, "No streak (best {})", self.max)
+        } else if self.played_today {
+            write!(f, "{}-day streak (best {})", self.current, self.max)
+        } else {
+            write!(
+                f,
+                "{}-day streak (best {}) - play today to keep it",
+                self.current, self.max
+            )
+        }
+    }
+}
+
+/// Saved game history and unlocked achievements
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub games: Vec<GameRecord>,
+    /// Achievements in the order they were unlocked, with the date of unlocking
+    pub achievements: Vec<(Achievement, NaiveDate)>,
+}
+
+impl Stats {
+    /// Parses `game DATE GUESS,GUESS,...` and `achievement KEY DATE` lines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wordle_solver::stats::{Achievement, Stats};
+    ///
+    /// let stats = Stats::parse("game 2026-01-02 CRANE,SLATE\nachievement first-win 2026-01-02");
+    /// assert_eq!(stats.games[0].guesses, ["CRANE", "SLATE"]);
+    /// assert!(stats.has(Achievement::FirstWin));
+    /// ```
+    #[must_use]
+    pub fn parse(data: &str) -> Self {
+        let mut stats = Self::default();
+        for line in data.lines() {
+            let mut fields = line.split_whitespace();
+            match (fields.next(), fields.next(), fields.next()) {
+                (Some("game"), Some(date), Some(guesses)) => {
+                    if let Ok(date) = date.parse() {
+                        stats.games.push(GameRecord {
+                            date,
+                            guesses: guesses.split(',').map(str::to_string).collect(),
+                        });
+                    }
+                }
+                (Some("achievement"), Some(key), Some(date)) => {
+                    if let (Ok(achievement), Ok(date)) = (key.parse(), date.parse()) {
+                        stats.achievements.push((achievement, date));
+                    }
+                }
+                _ => {}
+            }
+        }
+        stats
+    }
+
+    /// Reads the stats at `path`, starting fresh if it is missing or unreadable
+    #[must_use]
+    pub fn load(path: &Path) -> Self {
+        fs::read_to_string(path)
+            .map(|data| Self::parse(&data))
+            .unwrap_or_default()
+    }
+
+    /// # Errors
+    /// Returns an error if the file cannot be written.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    #[must_use]
+    pub fn has(&self, achievement: Achievement) -> bool {
+        self.achievements.iter().any(|&(a, _)| a == achievement)
+    }
+
+    /// Wins in a row counting back from the latest game
+    #[must_use]
+    pub fn win_streak(&self) -> usize {
+        self.games.iter().rev().take_while(|g| g.won()).count()
+    }
+
+    /// Current and longest runs of consecutive days with a game, as of `today`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use wordle_solver::stats::Stats;
+    ///
+    /// let stats = Stats::parse("game 2026-01-01 CRANE\ngame 2026-01-02 SLATE");
+    /// let streak = stats.daily_streak(NaiveDate::from_ymd_opt(2026, 1, 3).unwrap());
+    /// assert_eq!((streak.current, streak.max, streak.played_today), (2, 2, false));
+    /// ```
+    #[must_use]
+    pub fn daily_streak(&self, today: NaiveDate) -> DailyStreak {
+        let days: BTreeSet<NaiveDate> = self.games.iter().map(|g| g.date).collect();
+        let mut max = 0;
+        let mut run = 0;
+        let mut previous: Option<NaiveDate> = None;
+        for &day in &days {
+            run = if previous.and_then(|p| p.succ_opt()) == Some(day) {
+                run + 1
+            } else {
+                1
+            };
+            max = usize::max(max, run);
+            previous = Some(day);
+        }
+        let played_today = days.contains(&today);
+        let yesterday = today.pred_opt();
+        // The streak is still alive until a whole day goes by without a game
+        let current = match previous {
+            Some(last) if last == today || Some(last) == yesterday => run,
+            _ => 0,
+        };
+        DailyStreak {
+            current,
+            max,
+            played_today,
+        }
+    }
+
+    /// Adds a finished game and returns the achievements it unlocked
+    pub fn record(&mut self, game: GameRecord) -> Vec<Achievement> {
+        let date = game.date;
+        self.games.push(game);
+        let game = &self.games[self.games.len() - 1];
+        let unlocked: Vec<Achievement> = Achievement::ALL
+            .into_iter()
+            .filter(|&a| !self.has(a) && a.earned(self, game))
+            .collect();
+        self.achievements
+            .extend(unlocked.iter().map(|&achievement| (achievement, date)));
+        unlocked
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for game in &self.games {
+            writeln!(f, "game {} {}", game.date, game.guesses.join(","))?;
+        }
+        for (achievement, date) in &self.achievements {
+            writeln!(f, "achievement {} {date}", achievement.key())?;
+        }
+        Ok(())
+    }
+}
+
+#[must_use]
+pub fn get_wordle_stats_path() -> Option<PathBuf> {
+    dirs::home_dir().map(|mut path| {
+        path.push(".wordle_stats");
+        path
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn game(guesses: &[&str]) -> GameRecord {
+        GameRecord {
+            date: NaiveDate::from_ymd_opt(2026, 3, 14).unwrap(),
+            guesses: guesses.iter().map(|g| g.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_record_unlocks_each_achievement_once() {
+        let mut stats = Stats::default();
+        assert_eq!(
+            stats.record(game(&["CRANE", "SLATE"])),
+            [Achievement::FirstWin, Achievement::TwoGuessSolve]
+        );
+        assert!(stats.record(game(&["CRANE", "SLATE"])).is_empty());
+        assert_eq!(
+            stats.record(game(&["CRWTH", "SLATE", "PLATE"])),
+            [Achievement::VowelFreeOpener]
+        );
+    }
+
+    #[test]
+    fn test_loss_breaks_win_streak() {
+        let mut stats = Stats::default();
+        for _ in 0..STREAK_GOAL - 1 {
+            stats.record(game(&["CRANE", "SLATE", "PLATE"]));
+        }
+        stats.record(game(&["A"; MAX_GUESSES + 1]));
+        assert_eq!(stats.win_streak(), 0);
+
+        for _ in 0..STREAK_GOAL - 1 {
+            stats.record(game(&["CRANE", "SLATE", "PLATE"]));
+        }
+        assert!(!stats.has(Achievement::WinStreak));
+        assert_eq!(
+            stats.record(game(&["CRANE", "SLATE", "PLATE"])),
+            [Achievement::WinStreak]
+        );
+    }
+
+    #[test]
+    fn test_daily_streak() {
+        let day = |d| NaiveDate::from_ymd_opt(2026, 3, d).unwrap();
+        let mut stats = Stats::default();
+        assert_eq!(stats.daily_streak(day(1)), DailyStreak::default());
+
+        for d in [1, 2, 3, 5, 6, 6] {
+            stats.record(GameRecord {
+                date: day(d),
+                guesses: vec!["CRANE".to_string()],
+            });
+        }
+        let streak = stats.daily_streak(day(6));
+        assert_eq!((streak.current, streak.max), (2, 3));
+        assert!(streak.played_today);
+        assert_eq!(streak.to_string(), "2-day streak (best 3)");
+
+        let streak = stats.daily_streak(day(7));
+        assert_eq!(streak.current, 2);
+        assert!(!streak.played_today);
+
+        // Missing a whole day ends it
+        assert_eq!(stats.daily_streak(day(8)).to_string(), "No streak (best 3)");
+    }
+
+    #[test]
+    fn test_parse_skips_bad_lines() {
+        let data =
+            "game yesterday CRANE\nachievement juggler 2026-01-01\ngame 2026-01-01\nnonsense";
+        assert_eq!(Stats::parse(data), Stats::default());
+    }
+
+    #[test]
+    fn test_save_then_load_roundtrip() {
+        let file_path = std::env::temp_dir().join("test_wordle_stats.txt");
+        let mut stats = Stats::default();
+        stats.record(game(&["SLATE"]));
+
+        stats.save(&file_path).unwrap();
+        assert_eq!(Stats::load(&file_path), stats);
+
+        std::fs::remove_file(&file_path).unwrap();
+    }
+}
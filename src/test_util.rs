@@ -0,0 +1,18 @@
+//! Helpers shared by the unit tests.
+
+use crate::words::{Word, WordBank};
+
+/// Owned copies of `list`, for building wordbanks and candidate lists
+pub fn words(list: &[&str]) -> Vec<String> {
+    list.iter().map(|s| s.to_string()).collect()
+}
+
+/// A wordbank of `list`, which must hold only valid words
+pub fn bank(list: &[&str]) -> WordBank {
+    WordBank::new(words(list)).expect("test words are valid")
+}
+
+/// `text` as a word, which must be valid
+pub fn word(text: &str) -> Word {
+    Word::new(text).expect("test words are valid")
+}
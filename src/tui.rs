@@ -5,38 +5,84 @@
 //! # Architecture
 //! - `TuiInterface`: Core UI component handling rendering and input
 //! - `TuiWrapper`: Wrapper that integrates with game loop
+//! - `PlayTui`: Two-board play modes against a hidden word (versus, race), run by [`run_play`]
+//! - `SimulationDashboard`: Live progress of a batch of simulated games, one row per strategy
 //!
 //! # State Machine
 //! The UI follows these state transitions:
 //! - `EnteringGuess` → `MarkingFeedback` → `ConfirmingFeedback` → `WaitingForNext` → back to `EnteringGuess`
+//! - `EnteringGuess` → `ConfirmingGuess` → `MarkingFeedback` (or back) when a guess is dominated
 //! - Terminal states: `Computing`, `GameOver`
 
-use crate::game_state::{GameInterface, Recommendation, StartingWordsInfo, UserAction};
-use crate::solver::Feedback;
-use crate::{debug_log, info_log};
+use crate::cli::{
+    avoid_message, correction_message, dominated_warning, format_duration, memory_message,
+    parse_correction, parse_feedback, parse_rounds, parse_tag, parse_why_not, restrict_message,
+    splitter_message, tagging_message, trap_message,
+};
+use crate::clipboard;
+use crate::cluster::{Splitter, parse_pattern};
+use crate::config::{CandidateSort, Config, Cue, KeyboardLayout, get_wordle_config_path};
+use crate::elimination::WhyNot;
+use crate::export::{DEFAULT_EXPORT_PATH, write_candidates};
+use crate::game::{Game, get_wordle_save_path};
+use crate::game_state::{
+    CandidateDiff, Correction, GameDisplay, GameEvent, GameInterface, GameObserver, GameResult,
+    GuessWarning, HINT_LEVELS, Hint, InputResult, Recommendation, StartingWordsInfo, Tagging,
+    UserAction,
+};
+use crate::i18n::Message;
+use crate::information::{GuessInformation, information_chart};
+use crate::log_buffer::LogBuffer;
+use crate::noise::SimulationProgress;
+use crate::odds::WinOdds;
+use crate::play::{
+    Board, GuessTimer, KeyboardState, Knowledge, LetterHint, PlayMode, TimeoutAction, pick_answer,
+};
+use crate::progress::CancelToken;
+use crate::puzzle::PuzzleInfo;
+use crate::recording::{Recorder, Replay};
+use crate::rng::Rng;
+use crate::share::share_text;
+use crate::solver::{Feedback, SolverConfig, expected_pool_size, letter_counts};
+use crate::stats::Achievement;
+use crate::wordbank::WordbankStats;
+use crate::words::{WORD_LENGTH, Word, WordBank};
 use crossterm::{
     cursor,
-    event::{self, Event, KeyCode, KeyEvent},
+    event::{self, DisableBracketedPaste, EnableBracketedPaste, Event, KeyCode, KeyEvent},
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
 use ratatui::{
     Frame, Terminal,
-    backend::CrosstermBackend,
-    layout::{Constraint, Direction, Layout, Rect},
+    backend::{Backend, ClearType, CrosstermBackend, TestBackend, WindowSize},
+    buffer::{Buffer, Cell},
+    layout::{Constraint, Direction, Layout, Position, Rect, Size},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Wrap},
+    widgets::{Block, Borders, Gauge, Paragraph, Wrap},
 };
-use std::io;
+use std::collections::VecDeque;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+use tracing::{debug, info};
 
 const MAX_GUESSES: usize = 6;
-const WORD_LENGTH: usize = 5;
 const MAX_CANDIDATES_DISPLAY: usize = 10;
+/// Pools larger than this aren't scored for the candidate pane, since scoring is quadratic
+const CANDIDATE_SCORE_LIMIT: usize = 500;
+/// Most rows per page of the candidate diff view; fewer when the terminal is short
+const DIFF_PAGE_SIZE: usize = 10;
 const EVENT_POLL_TIMEOUT_MS: u64 = 100;
 const COMPUTING_POLL_TIMEOUT_MS: u64 = 10;
 const ROW_SPACING: u16 = 2;
 const ASCII_CONTROL_CHAR_THRESHOLD: u32 = 32;
+/// Smallest terminal, in columns and rows, that fits the whole layout without clipping
+pub const MIN_TERMINAL_SIZE: (u16, u16) = (80, 31);
 
 // Style constants for consistent UI
 const HEADER_STYLE: Style = Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD);
@@ -45,6 +91,252 @@ const SUCCESS_STYLE: Style = Style::new().fg(Color::Green).add_modifier(Modifier
 const INFO_STYLE: Style = Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD);
 const MESSAGE_STYLE: Style = Style::new().fg(Color::Cyan);
 
+/// Exit status used when the user interrupts with Ctrl+C (128 + SIGINT)
+pub const INTERRUPT_EXIT_CODE: i32 = 130;
+
+/// Set while the TUI owns the terminal (raw mode and alternate screen)
+static TERMINAL_ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// The game in progress as `--state` rounds and as a save for `--resume`, kept up to date by
+/// [`InterruptSaver`]
+static GAME_IN_PROGRESS: Mutex<Option<(String, String)>> = Mutex::new(None);
+
+/// Leave raw mode and the alternate screen and show the cursor again.
+///
+/// Does nothing unless the TUI is currently active, so it is safe to call from a signal
+/// handler or more than once.
+pub fn restore_terminal() {
+    if TERMINAL_ACTIVE.swap(false, Ordering::SeqCst) {
+        let _ = disable_raw_mode();
+        let _ = execute!(
+            io::stdout(),
+            DisableBracketedPaste,
+            LeaveAlternateScreen,
+            cursor::Show
+        );
+    }
+}
+
+/// Chain a panic hook that restores the terminal before the panic message is printed.
+///
+/// Without it a panic inside the TUI leaves raw mode on and the message lost on the
+/// alternate screen. Cleanup only touches an atomic flag, so it can't be blocked by a
+/// poisoned lock.
+pub fn install_panic_hook() {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        restore_terminal();
+        previous(info);
+    }));
+}
+
+/// Restore the terminal and exit after a Ctrl+C, saving the game in progress to resume
+pub fn exit_on_interrupt() -> ! {
+    restore_terminal();
+    println!();
+    println!("Interrupted. Exiting the solver.");
+    let in_progress = GAME_IN_PROGRESS.lock().ok().and_then(|game| game.clone());
+    if let Some((state, saved)) = in_progress {
+        match get_wordle_save_path().filter(|path| std::fs::write(path, saved).is_ok()) {
+            Some(path) => println!(
+                "Saved the game in progress; pick it up again with --resume {}",
+                path.display()
+            ),
+            None => println!("To pick up this game where you left off, run with --state {state}"),
+        }
+    }
+    std::process::exit(INTERRUPT_EXIT_CODE);
+}
+
+/// Rounds in the `--state` form, e.g. `CRANE:XYGXX,SLATE:GYXXX`
+fn state_argument(rounds: &[(String, Vec<Feedback>)]) -> String {
+    rounds
+        .iter()
+        .map(|(guess, feedback)| {
+            let feedback: String = feedback.iter().map(|fb| fb.as_char()).collect();
+            format!("{guess}:{feedback}")
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Keeps track of the game being played, so [`exit_on_interrupt`] can save it to resume
+pub struct InterruptSaver {
+    wordbank: WordBank,
+    game: Game,
+}
+
+impl InterruptSaver {
+    /// Tracks the games played over `wordbank`
+    #[must_use]
+    pub fn new(wordbank: &WordBank) -> Self {
+        Self {
+            wordbank: wordbank.clone(),
+            game: Game::new(wordbank, SolverConfig::default()),
+        }
+    }
+}
+
+impl GameObserver for InterruptSaver {
+    fn on_round_complete(&mut self, round: usize, diff: &CandidateDiff) {
+        // A new game, or a correction reporting the game again from its first round
+        if round == 1 {
+            self.game = Game::new(&self.wordbank, SolverConfig::default());
+        }
+        self.game
+            .guess(&Word::from_checked(diff.guess.clone()), &diff.feedback);
+        if let Ok(mut in_progress) = GAME_IN_PROGRESS.lock() {
+            *in_progress = Some((state_argument(self.game.history()), self.game.save()));
+        }
+    }
+
+    fn on_game_end(&mut self, _result: &GameResult) {
+        if let Ok(mut in_progress) = GAME_IN_PROGRESS.lock() {
+            *in_progress = None;
+        }
+    }
+}
+
+/// Enter raw mode and the alternate screen and set up a terminal to draw on
+fn enter_terminal() -> io::Result<Terminal<CrosstermBackend<io::Stdout>>> {
+    Terminal::new(CrosstermBackend::new(take_over_terminal()?))
+}
+
+/// Enter raw mode and the alternate screen, returning the stdout to draw on
+fn take_over_terminal() -> io::Result<io::Stdout> {
+    enable_raw_mode()?;
+    TERMINAL_ACTIVE.store(true, Ordering::SeqCst);
+    info!("Raw mode enabled");
+    let mut stdout = io::stdout();
+    execute!(
+        stdout,
+        EnterAlternateScreen,
+        EnableBracketedPaste,
+        cursor::Hide
+    )?;
+    info!("Terminal setup complete: alternate screen, mouse capture, cursor hidden");
+    Ok(stdout)
+}
+
+/// Where the main screen is drawn: the real terminal, or an off-screen buffer for scripted
+/// runs such as snapshot tests
+enum Screen {
+    Terminal(CrosstermBackend<io::Stdout>),
+    Offscreen(TestBackend),
+}
+
+impl Backend for Screen {
+    fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        match self {
+            Self::Terminal(backend) => backend.draw(content),
+            Self::Offscreen(backend) => backend.draw(content),
+        }
+    }
+
+    fn append_lines(&mut self, n: u16) -> io::Result<()> {
+        match self {
+            Self::Terminal(backend) => backend.append_lines(n),
+            Self::Offscreen(backend) => backend.append_lines(n),
+        }
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        match self {
+            Self::Terminal(backend) => backend.hide_cursor(),
+            Self::Offscreen(backend) => backend.hide_cursor(),
+        }
+    }
+
+    fn show_cursor(&mut self) -> io::Result<()> {
+        match self {
+            Self::Terminal(backend) => backend.show_cursor(),
+            Self::Offscreen(backend) => backend.show_cursor(),
+        }
+    }
+
+    fn get_cursor_position(&mut self) -> io::Result<Position> {
+        match self {
+            Self::Terminal(backend) => backend.get_cursor_position(),
+            Self::Offscreen(backend) => backend.get_cursor_position(),
+        }
+    }
+
+    fn set_cursor_position<P: Into<Position>>(&mut self, position: P) -> io::Result<()> {
+        match self {
+            Self::Terminal(backend) => backend.set_cursor_position(position),
+            Self::Offscreen(backend) => backend.set_cursor_position(position),
+        }
+    }
+
+    fn clear(&mut self) -> io::Result<()> {
+        match self {
+            Self::Terminal(backend) => backend.clear(),
+            Self::Offscreen(backend) => backend.clear(),
+        }
+    }
+
+    fn clear_region(&mut self, clear_type: ClearType) -> io::Result<()> {
+        match self {
+            Self::Terminal(backend) => backend.clear_region(clear_type),
+            Self::Offscreen(backend) => backend.clear_region(clear_type),
+        }
+    }
+
+    fn size(&self) -> io::Result<Size> {
+        match self {
+            Self::Terminal(backend) => backend.size(),
+            Self::Offscreen(backend) => backend.size(),
+        }
+    }
+
+    fn window_size(&mut self) -> io::Result<WindowSize> {
+        match self {
+            Self::Terminal(backend) => backend.window_size(),
+            Self::Offscreen(backend) => backend.window_size(),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Self::Terminal(backend) => Backend::flush(backend),
+            Self::Offscreen(backend) => backend.flush(),
+        }
+    }
+}
+
+/// The text of `buffer`, one line per row with trailing spaces trimmed, for comparing
+/// what was drawn against a snapshot. A wide character is followed by the blank cell it
+/// covers.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::buffer::Buffer;
+/// use wordle_solver::tui::buffer_text;
+///
+/// let buffer = Buffer::with_lines(["WORDLE  ", "        "]);
+/// assert_eq!(buffer_text(&buffer), "WORDLE\n\n");
+/// ```
+#[must_use]
+pub fn buffer_text(buffer: &Buffer) -> String {
+    let width = usize::from(buffer.area.width.max(1));
+    let mut text = String::new();
+    for row in buffer.content.chunks(width) {
+        let line: String = row.iter().map(Cell::symbol).collect();
+        text.push_str(line.trim_end());
+        text.push('\n');
+    }
+    text
+}
+
+/// Raw mode turns Ctrl+C into a key event instead of SIGINT
+fn is_interrupt(key: &KeyEvent) -> bool {
+    key.code == KeyCode::Char('c') && key.modifiers.contains(event::KeyModifiers::CONTROL)
+}
+
 #[derive(Clone, Copy, PartialEq, Debug)]
 enum LetterState {
     Empty,
@@ -56,26 +348,36 @@ enum LetterState {
 
 #[derive(Debug)]
 struct GuessRow {
-    letters: [char; 5],
-    states: [LetterState; 5],
+    letters: Vec<char>,
+    states: Vec<LetterState>,
 }
 
 impl GuessRow {
-    fn new() -> Self {
-        Self {
-            letters: [' '; WORD_LENGTH],
-            states: [LetterState::Empty; WORD_LENGTH],
-        }
+    /// A row of tiles as long as `guess`, entered but not yet marked
+    fn from_guess(guess: &str) -> Self {
+        let letters: Vec<char> = guess.chars().collect();
+        let states = vec![LetterState::Entered; letters.len()];
+        Self { letters, states }
     }
 
-    fn from_guess(guess: &str) -> Self {
-        let mut row = Self::new();
-        for (i, ch) in guess.chars().enumerate().take(WORD_LENGTH) {
-            row.letters[i] = ch;
-            row.states[i] = LetterState::Entered;
+    fn from_feedback(guess: &str, feedback: &[Feedback]) -> Self {
+        let mut row = Self::from_guess(guess);
+        for (state, fb) in row.states.iter_mut().zip(feedback) {
+            *state = LetterState::from_feedback(*fb);
         }
         row
     }
+
+    /// The row's feedback once every letter has been marked
+    fn feedback(&self) -> Option<Vec<Feedback>> {
+        self.states
+            .iter()
+            .map(|state| match state {
+                LetterState::Empty | LetterState::Entered => None,
+                marked => Some(marked.to_feedback()),
+            })
+            .collect()
+    }
 }
 
 impl LetterState {
@@ -88,6 +390,40 @@ impl LetterState {
         }
     }
 
+    const fn from_feedback(feedback: Feedback) -> Self {
+        match feedback {
+            Feedback::Match => Self::Match,
+            Feedback::PartialMatch => Self::PartialMatch,
+            Feedback::NoMatch => Self::NoMatch,
+        }
+    }
+
+    /// Mark drawn beside the letter so the state reads without colour
+    const fn glyph(self) -> char {
+        match self {
+            Self::Empty | Self::Entered => ' ',
+            Self::Match => '✓',
+            Self::PartialMatch => '•',
+            Self::NoMatch => '✗',
+        }
+    }
+
+    /// A tile's text: the letter, followed by the state's glyph if `glyphs` is set
+    fn tile(self, letter: char, glyphs: bool) -> String {
+        let mark = if glyphs { self.glyph() } else { ' ' };
+        format!(" {letter}{mark}")
+    }
+
+    /// How a screen reader should hear this state
+    const fn spoken(self) -> &'static str {
+        match self {
+            Self::Empty | Self::Entered => "unmarked",
+            Self::Match => "green",
+            Self::PartialMatch => "yellow",
+            Self::NoMatch => "gray",
+        }
+    }
+
     fn to_feedback(self) -> Feedback {
         match self {
             Self::Match => Feedback::Match,
@@ -100,6 +436,8 @@ impl LetterState {
 #[derive(Debug)]
 enum TuiState {
     EnteringGuess,
+    /// Guess was flagged as dominated - waiting for the user to override or re-enter it
+    ConfirmingGuess,
     MarkingFeedback {
         marking_index: usize,
     },
@@ -110,85 +448,311 @@ enum TuiState {
     GameOver,
 }
 
+/// A remaining candidate with the numbers used to sort and annotate it
+struct CandidateEntry {
+    word: String,
+    /// Expected pool size if guessed, unless the pool is too large to score
+    score: Option<f64>,
+    /// Sum over the word's distinct letters of how many candidates contain that letter
+    frequency: usize,
+}
+
+impl CandidateEntry {
+    fn from_candidates(candidates: &[String]) -> Vec<Self> {
+        let counts = letter_counts(candidates);
+        let scored = candidates.len() <= CANDIDATE_SCORE_LIMIT;
+        candidates
+            .iter()
+            .map(|word| {
+                let mut letters: Vec<u8> = word.bytes().filter(u8::is_ascii_uppercase).collect();
+                letters.sort_unstable();
+                letters.dedup();
+                Self {
+                    word: word.clone(),
+                    score: scored.then(|| expected_pool_size(word, candidates)),
+                    frequency: letters.iter().map(|&b| counts[usize::from(b - b'A')]).sum(),
+                }
+            })
+            .collect()
+    }
+
+    fn sort(entries: &mut [Self], sort: CandidateSort) {
+        match sort {
+            CandidateSort::Alphabetical => entries.sort_by(|a, b| a.word.cmp(&b.word)),
+            // Unscored entries keep alphabetical order after any scored ones
+            CandidateSort::Score => entries.sort_by(|a, b| match (a.score, b.score) {
+                (Some(x), Some(y)) => x.total_cmp(&y).then_with(|| a.word.cmp(&b.word)),
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (None, None) => a.word.cmp(&b.word),
+            }),
+            CandidateSort::Frequency => entries.sort_by(|a, b| {
+                b.frequency
+                    .cmp(&a.frequency)
+                    .then_with(|| a.word.cmp(&b.word))
+            }),
+        }
+    }
+
+    fn annotated(&self, pool_size: usize) -> String {
+        #[allow(clippy::cast_precision_loss)] // pool sizes are far below 2^52
+        let probability = 100.0 / pool_size as f64;
+        let score = self
+            .score
+            .map_or_else(|| "-".to_string(), |score| format!("{score:.2}"));
+        format!("{}  {probability:>5.1}%  score {score}", self.word)
+    }
+}
+
+/// The row being typed into, drawn below the guesses
+#[derive(Clone, Copy)]
+struct InputRow<'a> {
+    typed: &'a str,
+    length: usize,
+    /// Suggested guess, shown dimmed in the tiles not yet typed
+    ghost: Option<&'a str>,
+}
+
 /// Context for rendering the UI - groups related parameters to avoid too many function arguments.
 struct RenderContext<'a> {
     guesses: &'a [GuessRow],
     current_input: &'a str,
+    show_current_input: bool,
+    ghost: Option<&'a str>,
     state: &'a TuiState,
-    candidates_display: &'a [String],
+    candidates_display: &'a [CandidateEntry],
+    config: &'a Config,
+    diff_view: Option<(&'a CandidateDiff, usize)>,
+    stats_view: Option<&'a WordbankStats>,
+    log_view: Option<&'a LogBuffer>,
+    grid_view: Option<&'a Knowledge>,
     recommendation: Option<&'a Recommendation>,
-    starting_words: &'a [String],
+    /// Chances of solving in time by following the recommendations, if worked out this round
+    win_odds: &'a str,
+    /// Chart of the bits each guess gained, once the game is solved
+    information: &'a [String],
+    starting_words: &'a [(String, f64)],
+    starting_pick: Option<usize>,
     message: &'a str,
     error_message: &'a str,
+    timing: &'a str,
+    memory: &'a str,
     status: &'a str,
+    progress: Option<&'a Progress>,
+    /// Describe the board in words instead of drawing tiles
+    accessible: bool,
+    /// Mark tiles with glyphs as well as colours
+    tile_glyphs: bool,
+}
+
+/// A long computation in flight, shown as a gauge in place of the status line
+struct Progress {
+    percent: u16,
+    label: String,
+    /// Provisional recommendation, shown until the final one arrives
+    best: Option<(String, f64)>,
 }
 
 /// Main TUI interface component.
 ///
 /// Manages terminal rendering, input handling, and game state display.
 pub struct TuiInterface {
-    terminal: Terminal<CrosstermBackend<io::Stdout>>,
+    terminal: Terminal<Screen>,
+    /// Events to read instead of the terminal's when drawing off-screen; running out of them
+    /// closes the input
+    scripted: Option<VecDeque<Event>>,
+    /// Recorded session whose input is played back before the keyboard's
+    replay: Option<Replay>,
+    /// Where this session's input and states are being recorded, if anywhere
+    recorder: Option<Recorder>,
     guesses: Vec<GuessRow>,
     current_input: String,
     state: TuiState,
-    candidates_display: Vec<String>,
+    candidates_display: Vec<CandidateEntry>,
+    /// Saved preferences, written back to `config_path` when changed
+    config: Config,
+    config_path: Option<PathBuf>,
+    candidate_diff: Option<CandidateDiff>,
+    /// Page of the candidate diff being shown, or `None` when the view is closed
+    diff_page: Option<usize>,
+    /// Rows per diff page that fit in the information panel at the last draw
+    diff_page_size: usize,
+    wordbank_stats: Option<WordbankStats>,
+    show_stats: bool,
+    /// Recent trace lines for the F12 log panel
+    log_buffer: Option<LogBuffer>,
+    show_log: bool,
+    /// Show the F9 grid of what's known about each position
+    show_grid: bool,
     recommendation: Option<Recommendation>,
-    starting_words: Vec<String>,
+    /// Chances of solving in time by following the recommendations, for this round
+    win_odds: String,
+    /// Chart of the bits each guess gained, shown once the game is solved
+    information: Vec<String>,
+    /// Best first, each with its expected pool size
+    starting_words: Vec<(String, f64)>,
+    /// Starting word currently picked with the arrow keys, if any
+    starting_pick: Option<usize>,
     message: String,
     error_message: String,
+    /// Most recent `--time` measurement, empty when timing output is off
+    timing: String,
+    show_timing: bool,
+    /// Most recent `--low-memory` report, empty otherwise
+    memory: String,
+    /// Describe the board in words instead of drawing tiles
+    accessible: bool,
+    /// Mark tiles with glyphs as well as colours
+    tile_glyphs: bool,
     status: String,
+    progress: Option<Progress>,
+    /// Token for the computation in flight, cancelled by ESC while progress is shown
+    cancel: CancelToken,
+    /// When the player last typed or pasted, for idle timeouts
+    last_input: Instant,
 }
 
 impl TuiInterface {
     pub fn new() -> Result<Self, io::Error> {
-        info_log!("TuiInterface::new() - Initializing TUI");
-        enable_raw_mode()?;
-        info_log!("Raw mode enabled");
-        let mut stdout = io::stdout();
-        execute!(stdout, EnterAlternateScreen, cursor::Hide)?;
-        info_log!("Terminal setup complete: alternate screen, mouse capture, cursor hidden");
-        let backend = CrosstermBackend::new(stdout);
-        let terminal = Terminal::new(backend)?;
-        info_log!("Terminal backend created");
+        info!("TuiInterface::new() - Initializing TUI");
+        let terminal = Terminal::new(Screen::Terminal(CrosstermBackend::new(
+            take_over_terminal()?
+        )))?;
+        info!("Terminal backend created");
+        let config_path = get_wordle_config_path();
+        let config = config_path.as_deref().map(Config::load).unwrap_or_default();
+        Ok(Self::with_terminal(terminal, None, config, config_path))
+    }
 
-        Ok(Self {
+    /// A TUI drawing on an off-screen `width` by `height` buffer and reading `events` instead
+    /// of the terminal. Saved preferences are neither loaded nor written.
+    pub fn offscreen(
+        width: u16,
+        height: u16,
+        events: impl IntoIterator<Item = Event>,
+    ) -> Result<Self, io::Error> {
+        let terminal = Terminal::new(Screen::Offscreen(TestBackend::new(width, height)))?;
+        Ok(Self::with_terminal(
+            terminal,
+            Some(events.into_iter().collect()),
+            Config::default(),
+            None,
+        ))
+    }
+
+    fn with_terminal(
+        terminal: Terminal<Screen>,
+        scripted: Option<VecDeque<Event>>,
+        config: Config,
+        config_path: Option<PathBuf>,
+    ) -> Self {
+        Self {
             terminal,
+            scripted,
+            replay: None,
+            recorder: None,
             guesses: Vec::new(),
             current_input: String::new(),
             state: TuiState::EnteringGuess,
             candidates_display: Vec::new(),
+            config,
+            config_path,
+            candidate_diff: None,
+            diff_page: None,
+            diff_page_size: DIFF_PAGE_SIZE,
+            wordbank_stats: None,
+            show_stats: false,
+            log_buffer: None,
+            show_log: false,
+            show_grid: false,
             recommendation: None,
+            win_odds: String::new(),
+            information: Vec::new(),
             starting_words: Vec::new(),
+            starting_pick: None,
             message: String::new(),
             error_message: String::new(),
-            status: "Ready to start".to_string(),
-        })
+            timing: String::new(),
+            show_timing: false,
+            memory: String::new(),
+            accessible: false,
+            tile_glyphs: false,
+            status: Message::StatusReady.text().to_string(),
+            progress: None,
+            cancel: CancelToken::new(),
+            last_input: Instant::now(),
+        }
     }
 
     pub fn cleanup(&mut self) -> Result<(), io::Error> {
+        let Screen::Terminal(backend) = self.terminal.backend_mut() else {
+            return Ok(());
+        };
+        if !TERMINAL_ACTIVE.swap(false, Ordering::SeqCst) {
+            return Ok(());
+        }
         disable_raw_mode()?;
-        execute!(
-            self.terminal.backend_mut(),
-            LeaveAlternateScreen,
-            cursor::Show
-        )?;
+        execute!(backend, LeaveAlternateScreen, cursor::Show)?;
         Ok(())
     }
 
+    /// What was last drawn off-screen, or `None` on the real terminal
+    #[must_use]
+    pub fn offscreen_buffer(&self) -> Option<&Buffer> {
+        match self.terminal.backend() {
+            Screen::Terminal(_) => None,
+            Screen::Offscreen(backend) => Some(backend.buffer()),
+        }
+    }
+
+    /// The next input event, waiting up to `timeout` for the terminal's
+    fn next_event(&mut self, timeout: Duration) -> io::Result<Option<Event>> {
+        let event = match (&mut self.scripted, &mut self.replay) {
+            (Some(events), _) => Some(events.pop_front().ok_or_else(|| {
+                io::Error::new(io::ErrorKind::UnexpectedEof, "scripted input ran out")
+            })?),
+            (None, Some(replay)) => replay.next_event(timeout)?,
+            (None, None) if event::poll(timeout)? => Some(event::read()?),
+            (None, None) => None,
+        };
+        if let (Some(recorder), Some(event)) = (&mut self.recorder, &event) {
+            recorder.input(event);
+        }
+        Ok(event)
+    }
+
     /// Draw the current UI state to the terminal.
     ///
     /// Returns an error if rendering fails.
     fn draw(&mut self) -> Result<(), io::Error> {
+        self.fit_to_terminal()?;
+        let knowledge = self.show_grid.then(|| self.knowledge());
+        let ghost = self.ghost().map(str::to_string);
         let ctx = RenderContext {
             guesses: &self.guesses,
             current_input: &self.current_input,
+            show_current_input: self.should_show_current_input(),
+            ghost: ghost.as_deref(),
             state: &self.state,
             candidates_display: &self.candidates_display,
+            config: &self.config,
+            diff_view: self.candidate_diff.as_ref().zip(self.diff_page),
+            stats_view: self.wordbank_stats.as_ref().filter(|_| self.show_stats),
+            log_view: self.log_buffer.as_ref().filter(|_| self.show_log),
+            grid_view: knowledge.as_ref(),
             recommendation: self.recommendation.as_ref(),
+            win_odds: &self.win_odds,
+            information: &self.information,
             starting_words: &self.starting_words,
+            starting_pick: self.starting_pick,
             message: &self.message,
             error_message: &self.error_message,
+            timing: &self.timing,
+            memory: &self.memory,
             status: &self.status,
+            progress: self.progress.as_ref(),
+            accessible: self.accessible,
+            tile_glyphs: self.tile_glyphs,
         };
 
         self.terminal.draw(|f| {
@@ -197,21 +761,68 @@ impl TuiInterface {
         Ok(())
     }
 
+    /// Resize the viewport to the terminal and re-clamp the diff page to what now fits
+    fn fit_to_terminal(&mut self) -> Result<(), io::Error> {
+        self.terminal.autoresize()?;
+        let area = self.terminal.get_frame().area();
+        self.diff_page_size = Self::diff_page_size(Self::layout(area)[2]);
+        if let (Some(diff), Some(page)) = (&self.candidate_diff, self.diff_page) {
+            self.diff_page = Some(page.min(diff.page_count(self.diff_page_size) - 1));
+        }
+        Ok(())
+    }
+
+    /// Diff rows that fit in the information panel below its borders and two header lines
+    fn diff_page_size(info_area: Rect) -> usize {
+        usize::from(info_area.height.saturating_sub(4)).clamp(1, DIFF_PAGE_SIZE)
+    }
+
+    /// What the marked rows and the candidates shown say about each position
+    fn knowledge(&self) -> Knowledge {
+        let history: Vec<(String, Vec<Feedback>)> = self
+            .guesses
+            .iter()
+            .filter_map(|row| Some((row.letters.iter().collect(), row.feedback()?)))
+            .collect();
+        let candidates: Vec<String> = self
+            .candidates_display
+            .iter()
+            .map(|entry| entry.word.clone())
+            .collect();
+        Knowledge::new(&history, &candidates)
+    }
+
     /// Helper method to check if current input should be displayed
     fn should_show_current_input(&self) -> bool {
         matches!(self.state, TuiState::EnteringGuess) && self.guesses.len() < MAX_GUESSES
     }
 
+    /// The guess TAB plays: the recommendation, or before one the best starting word
+    fn suggestion(&self) -> Option<&str> {
+        self.recommendation
+            .as_ref()
+            .map(|rec| rec.guess.as_str())
+            .or_else(|| self.starting_words.first().map(|(word, _)| word.as_str()))
+    }
+
+    /// The suggestion, while what's typed so far agrees with it; typing anything else
+    /// clears it
+    fn ghost(&self) -> Option<&str> {
+        self.suggestion().filter(|guess| {
+            guess.len() == WORD_LENGTH && guess.starts_with(self.current_input.as_str())
+        })
+    }
+
     /// Log and handle draw errors appropriately
     fn draw_or_log(&mut self) {
         if let Err(e) = self.draw() {
-            debug_log!("Draw error: {}", e);
+            debug!("Draw error: {}", e);
         }
     }
 
-    /// Render the complete UI layout using the provided context.
-    fn render_static(f: &mut Frame, ctx: &RenderContext) {
-        let chunks = Layout::default()
+    /// Split the screen into title, board, info panel, status, and instructions
+    fn layout(area: Rect) -> Rc<[Rect]> {
+        Layout::default()
             .direction(Direction::Vertical)
             .constraints([
                 Constraint::Length(3),  // Title
@@ -220,20 +831,54 @@ impl TuiInterface {
                 Constraint::Length(3),  // Status line
                 Constraint::Length(3),  // Instructions
             ])
-            .split(f.area());
+            .split(area)
+    }
+
+    /// Render the complete UI layout using the provided context.
+    fn render_static(f: &mut Frame, ctx: &RenderContext) {
+        let chunks = Self::layout(f.area());
 
         Self::render_title(f, chunks[0]);
-        Self::render_board(f, chunks[1], ctx.guesses, ctx.current_input, ctx.state);
-        Self::render_info(
-            f,
-            chunks[2],
-            ctx.candidates_display,
-            ctx.recommendation,
-            ctx.starting_words,
-            ctx.message,
-            ctx.error_message,
-        );
-        Self::render_status(f, chunks[3], ctx.status);
+        if ctx.accessible {
+            Self::render_board_text(
+                f,
+                chunks[1],
+                ctx.guesses,
+                ctx.current_input,
+                ctx.show_current_input,
+                ctx.ghost,
+            );
+        } else {
+            Self::render_board(
+                f,
+                chunks[1],
+                Message::TuiGuesses.text(),
+                ctx.guesses,
+                ctx.show_current_input.then_some(InputRow {
+                    typed: ctx.current_input,
+                    length: WORD_LENGTH,
+                    ghost: ctx.ghost,
+                }),
+                ctx.state,
+                ctx.tile_glyphs,
+            );
+        }
+        if let Some(log) = ctx.log_view {
+            Self::render_log(f, chunks[2], log);
+        } else if let Some(stats) = ctx.stats_view {
+            Self::render_stats(f, chunks[2], stats);
+        } else if let Some(knowledge) = ctx.grid_view {
+            Self::render_grid(f, chunks[2], knowledge);
+        } else if let Some((diff, page)) = ctx.diff_view {
+            Self::render_diff(f, chunks[2], diff, page, Self::diff_page_size(chunks[2]));
+        } else {
+            Self::render_info(f, chunks[2], ctx);
+        }
+        if let Some(progress) = ctx.progress {
+            Self::render_progress(f, chunks[3], progress);
+        } else {
+            Self::render_status(f, chunks[3], ctx.status);
+        }
         Self::render_instructions(f, chunks[4], ctx.state);
     }
 
@@ -247,12 +892,14 @@ impl TuiInterface {
     fn render_board(
         f: &mut Frame,
         area: Rect,
+        title: &str,
         guesses: &[GuessRow],
-        current_input: &str,
+        current_input: Option<InputRow>,
         state: &TuiState,
+        glyphs: bool,
     ) {
         let block = Block::default()
-            .title("Guesses")
+            .title(title)
             .borders(Borders::ALL)
             .style(Style::default());
 
@@ -262,10 +909,7 @@ impl TuiInterface {
         // Calculate how many rows can fit in the available space
         let available_rows = (inner.height / ROW_SPACING) as usize;
 
-        // Determine if we need to show current input
-        let showing_current_input =
-            matches!(state, TuiState::EnteringGuess) && guesses.len() < MAX_GUESSES;
-        let rows_needed = if showing_current_input {
+        let rows_needed = if current_input.is_some() {
             guesses.len() + 1
         } else {
             guesses.len()
@@ -284,11 +928,12 @@ impl TuiInterface {
                 inner,
                 state,
                 guesses.len() - skip_count,
+                glyphs,
             );
         }
 
         // Render current input if entering a guess
-        if showing_current_input {
+        if let Some(current_input) = current_input {
             let display_row = if rows_needed > available_rows {
                 available_rows - 1
             } else {
@@ -298,6 +943,50 @@ impl TuiInterface {
         }
     }
 
+    /// The board as one line of words per row, for screen readers
+    fn render_board_text(
+        f: &mut Frame,
+        area: Rect,
+        guesses: &[GuessRow],
+        current_input: &str,
+        showing_current_input: bool,
+        ghost: Option<&str>,
+    ) {
+        let mut lines: Vec<Line> = guesses
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let letters: Vec<String> = row
+                    .letters
+                    .iter()
+                    .zip(&row.states)
+                    .map(|(letter, state)| format!("{letter} {}", state.spoken()))
+                    .collect();
+                Line::from(format!("Row {}: {}", i + 1, letters.join(", ")))
+            })
+            .collect();
+        if showing_current_input {
+            let mut typed = if current_input.is_empty() {
+                "nothing typed yet".to_string()
+            } else {
+                format!("typing {current_input}")
+            };
+            if let Some(ghost) = ghost {
+                typed.push_str(&format!(", suggested {ghost}"));
+            }
+            lines.push(Line::from(format!("Row {}: {typed}", guesses.len() + 1)));
+        }
+        // Keep the latest rows in view when they don't all fit
+        let visible = usize::from(area.height.saturating_sub(2));
+        let skip = lines.len().saturating_sub(visible);
+        let paragraph = Paragraph::new(lines.split_off(skip)).block(
+            Block::default()
+                .title(Message::TuiGuesses.text())
+                .borders(Borders::ALL),
+        );
+        f.render_widget(paragraph, area);
+    }
+
     #[allow(clippy::cast_possible_truncation)]
     fn render_guess_row(
         f: &mut Frame,
@@ -306,6 +995,7 @@ impl TuiInterface {
         area: Rect,
         state: &TuiState,
         guesses_len: usize,
+        glyphs: bool,
     ) {
         let y = area.y + (row_index as u16 * ROW_SPACING);
         if y >= area.y + area.height {
@@ -313,12 +1003,11 @@ impl TuiInterface {
         }
 
         let mut spans = vec![Span::raw("  ")];
-        for i in 0..WORD_LENGTH {
-            let (bg_color, fg_color) = guess.states[i].colors();
-            let letter = guess.letters[i];
+        for (&letter, state) in guess.letters.iter().zip(&guess.states) {
+            let (bg_color, fg_color) = state.colors();
 
             spans.push(Span::styled(
-                format!(" {letter} "),
+                state.tile(letter, glyphs),
                 Style::default().fg(fg_color).bg(bg_color),
             ));
             spans.push(Span::raw(" "));
@@ -338,6 +1027,10 @@ impl TuiInterface {
     }
 
     fn render_line(f: &mut Frame, area: Rect, y: u16, spans: Vec<Span>) {
+        // Rows past the bottom of a shrunken board would draw outside the buffer
+        if y >= area.bottom() {
+            return;
+        }
         let line = Line::from(spans);
         let paragraph = Paragraph::new(line);
         f.render_widget(
@@ -352,18 +1045,29 @@ impl TuiInterface {
     }
 
     #[allow(clippy::cast_possible_truncation)]
-    fn render_current_input(f: &mut Frame, row_index: usize, area: Rect, current_input: &str) {
+    fn render_current_input(f: &mut Frame, row_index: usize, area: Rect, input: InputRow) {
         let y = area.y + (row_index as u16 * ROW_SPACING);
         if y >= area.y + area.height {
             return;
         }
 
         let mut spans = vec![Span::raw("  ")];
-        for i in 0..WORD_LENGTH {
-            let letter = current_input.chars().nth(i).unwrap_or(' ');
+        let typed: Vec<char> = input.typed.chars().collect();
+        let ghost: Vec<char> = input.ghost.unwrap_or_default().chars().collect();
+        for i in 0..input.length {
+            let (letter, style) = match (typed.get(i), ghost.get(i)) {
+                (Some(&letter), _) => (letter, Style::default().fg(Color::White)),
+                (None, Some(&letter)) => (
+                    letter,
+                    Style::default()
+                        .fg(Color::Gray)
+                        .add_modifier(Modifier::DIM | Modifier::ITALIC),
+                ),
+                (None, None) => (' ', Style::default()),
+            };
             spans.push(Span::styled(
                 format!(" {letter} "),
-                Style::default().fg(Color::White).bg(Color::DarkGray),
+                style.bg(Color::DarkGray),
             ));
             spans.push(Span::raw(" "));
         }
@@ -371,60 +1075,119 @@ impl TuiInterface {
         Self::render_line(f, area, y, spans);
     }
 
-    fn render_info(
-        f: &mut Frame,
-        area: Rect,
-        candidates_display: &[String],
-        recommendation: Option<&Recommendation>,
-        starting_words: &[String],
-        message: &str,
-        error_message: &str,
-    ) {
+    fn render_info(f: &mut Frame, area: Rect, ctx: &RenderContext) {
+        let RenderContext {
+            candidates_display,
+            config,
+            recommendation,
+            win_odds,
+            information,
+            starting_words,
+            starting_pick,
+            message,
+            error_message,
+            timing,
+            memory,
+            progress,
+            ..
+        } = *ctx;
         let mut lines = Vec::new();
 
         // Starting words
         if !starting_words.is_empty() {
             lines.push(Line::from(vec![Span::styled(
-                "Suggested Starting Words:",
+                Message::TuiStartingWords.text(),
                 HEADER_STYLE,
             )]));
-            for (i, word) in starting_words.iter().take(3).enumerate() {
+            for (i, (word, score)) in starting_words.iter().enumerate() {
                 let num = i + 1;
-                lines.push(Line::from(format!("  {num}. {word}")));
+                if starting_pick == Some(i) {
+                    lines.push(Line::from(vec![Span::styled(
+                        format!("> {num}. {word} ({score:.2})"),
+                        SUCCESS_STYLE,
+                    )]));
+                } else {
+                    lines.push(Line::from(format!("  {num}. {word} ({score:.2})")));
+                }
             }
             lines.push(Line::from(""));
         }
 
-        // Recommendation
-        if let Some(rec) = recommendation {
+        // Recommendation, or the best guess so far while one is being computed
+        if let Some((guess, score)) = progress.and_then(|p| p.best.as_ref()) {
+            lines.push(Line::from(vec![Span::styled(
+                Message::TuiBestSoFar.fill(&[("guess", guess), ("score", &format!("{score:.2}"))]),
+                INFO_STYLE,
+            )]));
+            lines.push(Line::from(""));
+        } else if let Some(rec) = recommendation {
             let category = if rec.is_candidate {
-                "solution candidate"
+                Message::GameSolutionCandidate
             } else {
-                "information-gathering"
+                Message::GameInformationGathering
+            };
+            let score = match rec.confidence_interval {
+                Some((lower, upper)) => {
+                    format!("~{:.2}, 95% CI {lower:.2}-{upper:.2}", rec.score)
+                }
+                None => format!("{:.2}", rec.score),
             };
             lines.push(Line::from(vec![Span::styled(
-                format!(
-                    "Recommended: {} (score: {:.2}) [{}]",
-                    rec.guess, rec.score, category
-                ),
+                Message::TuiRecommended.fill(&[
+                    ("guess", &rec.guess),
+                    ("score", &score),
+                    ("category", &category.text()),
+                ]),
                 SUCCESS_STYLE,
             )]));
+            if !win_odds.is_empty() {
+                lines.push(Line::from(vec![Span::styled(win_odds, INFO_STYLE)]));
+            }
+            lines.push(Line::from(""));
+        }
+
+        // Information chart of the solved game
+        if let Some((title, bars)) = information.split_first() {
+            lines.push(Line::from(vec![Span::styled(title.as_str(), HEADER_STYLE)]));
+            for bar in bars {
+                lines.push(Line::from(bar.as_str()));
+            }
             lines.push(Line::from(""));
         }
 
-        // Candidates
+        // Candidates, trimmed so the messages below still fit after the terminal shrinks
         if !candidates_display.is_empty() {
+            let trailing = [message, error_message, timing, memory]
+                .iter()
+                .filter(|s| !s.is_empty())
+                .count();
+            let room = usize::from(area.height.saturating_sub(2))
+                .saturating_sub(lines.len() + trailing + 3);
+            let shown = candidates_display
+                .len()
+                .min(MAX_CANDIDATES_DISPLAY)
+                .min(room);
+
             lines.push(Line::from(vec![Span::styled(
-                format!("Possible candidates ({}):", candidates_display.len()),
+                Message::TuiCandidates.fill(&[
+                    ("count", &candidates_display.len()),
+                    ("sort", &config.candidate_sort),
+                ]),
                 INFO_STYLE,
             )]));
-            for word in candidates_display.iter().take(MAX_CANDIDATES_DISPLAY) {
-                lines.push(Line::from(format!("  {word}")));
+            for entry in candidates_display.iter().take(shown) {
+                let text = if config.annotate_candidates {
+                    entry.annotated(candidates_display.len())
+                } else {
+                    entry.word.clone()
+                };
+                lines.push(Line::from(format!("  {text}")));
             }
-            if candidates_display.len() > MAX_CANDIDATES_DISPLAY {
+            if candidates_display.len() > shown {
                 lines.push(Line::from(format!(
-                    "  ... and {} more",
-                    candidates_display.len() - MAX_CANDIDATES_DISPLAY
+                    "  {}",
+                    Message::TuiMoreCandidates
+                        .fill(&[("count", &(candidates_display.len() - shown))])
                 )));
             }
             lines.push(Line::from(""));
@@ -440,47 +1203,362 @@ impl TuiInterface {
             lines.push(Line::from(vec![Span::styled(error_message, ERROR_STYLE)]));
         }
 
+        for diagnostic in [timing, memory] {
+            if !diagnostic.is_empty() {
+                lines.push(Line::from(vec![Span::styled(
+                    diagnostic,
+                    Style::default().fg(Color::DarkGray),
+                )]));
+            }
+        }
+
         let paragraph = Paragraph::new(lines)
-            .block(Block::default().title("Information").borders(Borders::ALL))
+            .block(
+                Block::default()
+                    .title(Message::TuiInformation.text())
+                    .borders(Borders::ALL),
+            )
             .wrap(Wrap { trim: true });
         f.render_widget(paragraph, area);
     }
 
-    fn render_instructions(f: &mut Frame, area: Rect, state: &TuiState) {
-        let text = match state {
-            TuiState::EnteringGuess => "Type your 5-letter guess | ENTER: Submit | ESC: Quit",
-            TuiState::MarkingFeedback { .. } => {
-                "G: Green (correct) | Y: Yellow (wrong position) | X: Gray (not in word) | BACKSPACE: Go back"
-            }
-            TuiState::ConfirmingFeedback => "ENTER: Confirm feedback | BACKSPACE: Go back and edit",
-            TuiState::Computing => "Computing optimal next guess...",
-            TuiState::WaitingForNext => "Press any key to continue | ESC: Quit",
-            TuiState::GameOver => "N: New Game | ESC: Quit",
-        };
+    fn render_diff(f: &mut Frame, area: Rect, diff: &CandidateDiff, page: usize, page_size: usize) {
+        let (eliminated, survived) = diff.page(page, page_size);
+        let feedback: String = diff.feedback.iter().map(|fb| fb.as_char()).collect();
 
-        let paragraph = Paragraph::new(text)
-            .style(Style::default().fg(Color::Gray))
-            .block(Block::default().borders(Borders::ALL));
+        let mut lines = vec![
+            Line::from(vec![Span::styled(
+                format!(
+                    "{} = {}: eliminated {}, survived {}",
+                    diff.guess,
+                    feedback,
+                    diff.eliminated.len(),
+                    diff.survived.len()
+                ),
+                HEADER_STYLE,
+            )]),
+            Line::from(vec![
+                Span::styled(format!("  {:<12}", "Eliminated"), ERROR_STYLE),
+                Span::styled("Survived", INFO_STYLE),
+            ]),
+        ];
+        for i in 0..eliminated.len().max(survived.len()) {
+            let left = eliminated.get(i).map_or("", String::as_str);
+            let right = survived.get(i).map_or("", String::as_str);
+            lines.push(Line::from(format!("  {left:<12}{right}")));
+        }
+
+        let title = format!(
+            "Candidate Diff (page {}/{})",
+            page + 1,
+            diff.page_count(page_size)
+        );
+        let paragraph =
+            Paragraph::new(lines).block(Block::default().title(title).borders(Borders::ALL));
         f.render_widget(paragraph, area);
     }
 
-    fn render_status(f: &mut Frame, area: Rect, status: &str) {
-        let status_text = if status.is_empty() { "Ready" } else { status };
-        let paragraph = Paragraph::new(status_text)
-            .style(HEADER_STYLE)
-            .block(Block::default().borders(Borders::ALL).title("Status"));
+    fn render_stats(f: &mut Frame, area: Rect, stats: &WordbankStats) {
+        let lines: Vec<Line> = stats.report().into_iter().map(Line::from).collect();
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .title(Message::TuiStatsTitle.text())
+                .borders(Borders::ALL),
+        );
         f.render_widget(paragraph, area);
     }
 
-    fn handle_input(&mut self) -> Result<Option<UserAction>, io::Error> {
-        // For Computing state, use non-blocking poll to avoid hanging
-        if matches!(self.state, TuiState::Computing) {
-            debug_log!("handle_input() - In Computing state, using non-blocking poll");
-            // Check if there's an event available without blocking
-            if event::poll(std::time::Duration::from_millis(COMPUTING_POLL_TIMEOUT_MS))?
-                && let Event::Key(_) = event::read()?
-            {
-                debug_log!("handle_input() - Ignoring key during Computing state");
+    /// One column per position: the confirmed letter, letters ruled out there, and the most
+    /// common letters there among the candidates, as many as fit in `area`
+    fn render_grid(f: &mut Frame, area: Rect, knowledge: &Knowledge) {
+        const LABEL_WIDTH: usize = 12;
+        const COLUMN_WIDTH: usize = 10;
+        let row = |label: &str, cells: Vec<String>| -> String {
+            let mut line = format!("{label:<LABEL_WIDTH$}");
+            for cell in cells {
+                line.push_str(&format!("{cell:<COLUMN_WIDTH$}"));
+            }
+            line
+        };
+        let positions = &knowledge.positions;
+
+        let mut lines = vec![
+            Line::from(vec![Span::styled(
+                row(
+                    Message::TuiGridPosition.text(),
+                    (1..=positions.len()).map(|i| i.to_string()).collect(),
+                ),
+                HEADER_STYLE,
+            )]),
+            Line::from(vec![Span::styled(
+                row(
+                    Message::TuiGridConfirmed.text(),
+                    positions
+                        .iter()
+                        .map(|p| p.confirmed.map_or_else(|| "-".to_string(), String::from))
+                        .collect(),
+                ),
+                SUCCESS_STYLE,
+            )]),
+            Line::from(vec![Span::styled(
+                row(
+                    Message::TuiGridRuledOut.text(),
+                    positions
+                        .iter()
+                        .map(|p| p.ruled_out.iter().take(COLUMN_WIDTH - 1).collect())
+                        .collect(),
+                ),
+                ERROR_STYLE,
+            )]),
+            Line::from(""),
+            Line::from(vec![Span::styled(
+                Message::TuiGridCandidates.fill(&[("count", &knowledge.candidates)]),
+                INFO_STYLE,
+            )]),
+        ];
+        let room = usize::from(area.height.saturating_sub(2)).saturating_sub(lines.len());
+        let depth = positions
+            .iter()
+            .map(|p| p.distribution.len())
+            .max()
+            .unwrap_or(0)
+            .min(room);
+        #[allow(clippy::cast_precision_loss)] // candidate counts are far below 2^52
+        let percent = |count: usize| count as f64 * 100.0 / knowledge.candidates.max(1) as f64;
+        for rank in 0..depth {
+            let cells = positions
+                .iter()
+                .map(|p| {
+                    p.distribution
+                        .get(rank)
+                        .map_or_else(String::new, |&(letter, count)| {
+                            format!("{letter} {:.0}%", percent(count))
+                        })
+                })
+                .collect();
+            lines.push(Line::from(row("", cells)));
+        }
+
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .title(Message::TuiGridTitle.text())
+                .borders(Borders::ALL),
+        );
+        f.render_widget(paragraph, area);
+    }
+
+    /// The most recent trace lines that fit in `area`
+    fn render_log(f: &mut Frame, area: Rect, log: &LogBuffer) {
+        let lines: Vec<Line> = log
+            .tail(usize::from(area.height.saturating_sub(2)))
+            .into_iter()
+            .map(Line::from)
+            .collect();
+        let paragraph = Paragraph::new(lines)
+            .style(Style::default().fg(Color::Gray))
+            .block(
+                Block::default()
+                    .title(Message::TuiLogTitle.text())
+                    .borders(Borders::ALL),
+            );
+        f.render_widget(paragraph, area);
+    }
+
+    fn render_instructions(f: &mut Frame, area: Rect, state: &TuiState) {
+        let text = match state {
+            TuiState::EnteringGuess => Message::KeysGuess,
+            TuiState::ConfirmingGuess => Message::KeysConfirmGuess,
+            TuiState::MarkingFeedback { .. } => Message::KeysFeedback,
+            TuiState::ConfirmingFeedback => Message::KeysConfirmFeedback,
+            TuiState::Computing => Message::StatusComputing,
+            TuiState::WaitingForNext => Message::KeysWaiting,
+            TuiState::GameOver => Message::KeysGameOver,
+        }
+        .fill(&[("length", &WORD_LENGTH)]);
+
+        let paragraph = Paragraph::new(text)
+            .style(Style::default().fg(Color::Gray))
+            .block(Block::default().borders(Borders::ALL));
+        f.render_widget(paragraph, area);
+    }
+
+    fn render_status(f: &mut Frame, area: Rect, status: &str) {
+        let status_text = if status.is_empty() {
+            Message::StatusReady.text()
+        } else {
+            status
+        };
+        let paragraph = Paragraph::new(status_text).style(HEADER_STYLE).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(Message::TuiStatus.text()),
+        );
+        f.render_widget(paragraph, area);
+    }
+
+    fn render_progress(f: &mut Frame, area: Rect, progress: &Progress) {
+        let gauge = Gauge::default()
+            .gauge_style(INFO_STYLE)
+            .percent(progress.percent)
+            .label(progress.label.as_str())
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(Message::TuiStatus.text()),
+            );
+        f.render_widget(gauge, area);
+    }
+
+    /// Waits for a guess or command, giving up after `idle` without a keypress if given
+    fn guess_input(&mut self, idle: Option<Duration>) -> InputResult<UserAction> {
+        self.last_input = Instant::now();
+        info!("read_guess() - Starting guess input loop");
+        // Without automatic recommendations nothing ends the Computing state after feedback
+        if matches!(self.state, TuiState::Computing) {
+            self.transition_to_entering_guess();
+            self.status = Message::StatusNextGuess.text().to_string();
+        }
+        loop {
+            // Draw the current state
+            if self.draw().is_err() {
+                info!("read_guess() - Draw failed, returning Exit");
+                return InputResult::Ready(UserAction::Exit);
+            }
+
+            // Handle input - this will block until an event is available
+            match self.handle_input() {
+                Ok(Some(action)) => {
+                    info!("read_guess() - Action received: {:?}", action);
+                    return InputResult::Ready(action);
+                }
+                Ok(None) => {
+                    // No action yet, continue the loop (character was added or ignored)
+                    if idle.is_some_and(|idle| self.last_input.elapsed() >= idle) {
+                        info!("read_guess() - Idle timeout");
+                        return InputResult::Timeout;
+                    }
+                }
+                Err(_e) => {
+                    info!("read_guess() - Error handling input, returning Exit");
+                    return InputResult::Ready(UserAction::Exit);
+                }
+            }
+        }
+    }
+
+    /// Waits for feedback to be marked, giving up after `idle` without a keypress if given
+    fn feedback_input(&mut self, idle: Option<Duration>) -> InputResult<Vec<Feedback>> {
+        self.last_input = Instant::now();
+        // Transition to marking state
+        self.set_state(TuiState::MarkingFeedback { marking_index: 0 });
+        self.error_message.clear();
+        self.status = Message::StatusMarkFeedback.text().to_string();
+
+        // Draw once before entering loop to show the updated state
+        if self.draw().is_err() {
+            debug!("read_feedback() - Initial draw failed");
+            return InputResult::Closed;
+        }
+
+        loop {
+            // Update status if we're in confirming state
+            if matches!(self.state, TuiState::ConfirmingFeedback) {
+                self.status = Message::StatusConfirmFeedback.text().to_string();
+            }
+
+            // Use handle_input which now properly handles state-based input
+            match self.handle_input() {
+                Ok(Some(action)) => {
+                    // Handle exit during feedback marking
+                    match action {
+                        UserAction::Exit | UserAction::NewGame => {
+                            // Return dummy feedback to allow the action to be processed
+                            return InputResult::Ready(vec![Feedback::NoMatch; WORD_LENGTH]);
+                        }
+                        UserAction::Guess(_)
+                        | UserAction::Hint
+                        | UserAction::Rounds(_)
+                        | UserAction::Probe(_)
+                        | UserAction::Avoid(_)
+                        | UserAction::Restrict(_)
+                        | UserAction::Correct { .. }
+                        | UserAction::WhyNot(_)
+                        | UserAction::Split(_)
+                        | UserAction::Tag { .. } => {}
+                    }
+                }
+                Ok(None) => {
+                    // Check if we've finished marking and confirmed
+                    if matches!(self.state, TuiState::WaitingForNext) {
+                        self.status = Message::StatusFeedbackRecorded.text().to_string();
+                        self.draw_or_log();
+                        return self.get_feedback_from_last_guess().into();
+                    }
+                    if idle.is_some_and(|idle| self.last_input.elapsed() >= idle) {
+                        info!("read_feedback() - Idle timeout");
+                        return InputResult::Timeout;
+                    }
+                }
+                Err(e) => {
+                    debug!("read_feedback() - Input error: {}", e);
+                    return InputResult::Closed;
+                }
+            }
+
+            // Redraw after each input
+            if self.draw().is_err() {
+                debug!("read_feedback() - Draw failed in loop");
+                return InputResult::Closed;
+            }
+        }
+    }
+
+    /// Read and handle one input event, sounding the invalid-input cue for any new error
+    fn handle_input(&mut self) -> Result<Option<UserAction>, io::Error> {
+        let error_before = self.error_message.clone();
+        let action = self.read_input();
+        if !self.error_message.is_empty() && self.error_message != error_before {
+            self.cue(Cue::Invalid);
+        }
+        action
+    }
+
+    /// Ring the terminal bell and run the cue command if `cue` is enabled in the config
+    fn cue(&self, cue: Cue) {
+        if !self.config.cues.contains(&cue) {
+            return;
+        }
+        let mut stdout = io::stdout();
+        let _ = write!(stdout, "\x07");
+        let _ = stdout.flush();
+        if let Some(command) = &self.config.cue_command {
+            // Fire and forget, so a slow sound player never holds up the game
+            let spawned = std::process::Command::new(command)
+                .arg(cue.to_string())
+                .stdin(std::process::Stdio::null())
+                .stdout(std::process::Stdio::null())
+                .stderr(std::process::Stdio::null())
+                .spawn();
+            if let Err(e) = spawned {
+                info!("cue() - Couldn't run cue command '{}': {}", command, e);
+            }
+        }
+    }
+
+    fn read_input(&mut self) -> Result<Option<UserAction>, io::Error> {
+        // For Computing state, use non-blocking poll to avoid hanging
+        if matches!(self.state, TuiState::Computing) {
+            debug!("handle_input() - In Computing state, using non-blocking poll");
+            // Check if there's an event available without blocking. Scripted events are kept
+            // for once the computation is done.
+            if self.scripted.is_none()
+                && let Some(Event::Key(key)) =
+                    self.next_event(Duration::from_millis(COMPUTING_POLL_TIMEOUT_MS))?
+            {
+                if is_interrupt(&key) {
+                    exit_on_interrupt();
+                }
+                debug!("handle_input() - Ignoring key during Computing state");
                 // Ignore any input during computing
             }
             return Ok(None);
@@ -489,44 +1567,49 @@ impl TuiInterface {
         // For all other states, use blocking read to ensure we only get one event
 
         // Poll with a timeout to check if events are available
-        let poll_result = event::poll(std::time::Duration::from_millis(EVENT_POLL_TIMEOUT_MS))?;
-
-        if !poll_result {
+        let Some(event) = self.next_event(Duration::from_millis(EVENT_POLL_TIMEOUT_MS))? else {
             // No event available, return None to continue the loop
             return Ok(None);
+        };
+        debug!("handle_input() - Event received: {:?}", event);
+        if matches!(event, Event::Key(_) | Event::Paste(_)) {
+            self.last_input = Instant::now();
         }
 
-        let event = event::read()?;
-        debug_log!("handle_input() - Event received: {:?}", event);
-
         // Filter out non-key events (mouse, focus, etc.)
         match event {
             Event::Mouse(_) => {
-                debug_log!("handle_input() - Ignoring mouse event");
+                debug!("handle_input() - Ignoring mouse event");
                 Ok(None)
             }
             Event::FocusGained | Event::FocusLost => {
-                debug_log!("handle_input() - Ignoring focus event");
+                debug!("handle_input() - Ignoring focus event");
                 Ok(None)
             }
-            Event::Paste(_) => {
-                debug_log!("handle_input() - Ignoring paste event");
-                Ok(None)
+            Event::Paste(text) => {
+                info!("handle_input() - Pasted {} bytes", text.len());
+                Ok(self.handle_paste(&text))
             }
             Event::Resize(_, _) => {
-                debug_log!("handle_input() - Ignoring resize event");
+                debug!("handle_input() - Terminal resized, redrawing");
+                self.draw()?;
                 Ok(None)
             }
             Event::Key(key) => {
                 // Only process Press events, ignore Release and Repeat to avoid double input
                 if key.kind != event::KeyEventKind::Press {
-                    debug_log!(
+                    debug!(
                         "handle_input() - Ignoring non-Press key event: {:?}",
                         key.kind
                     );
                     return Ok(None);
                 }
 
+                if is_interrupt(&key) {
+                    info!("handle_input() - Ctrl+C pressed, exiting");
+                    exit_on_interrupt();
+                }
+
                 // Filter out invalid characters that come from terminal focus events (alt-tab)
                 // These show up as replacement characters (�), control characters, or other garbage
                 if let KeyCode::Char(c) = key.code {
@@ -538,7 +1621,7 @@ impl TuiInterface {
                             && c != '\n'
                             && c != '\r'
                     {
-                        debug_log!(
+                        debug!(
                             "handle_input() - Ignoring invalid character from escape sequence: {:?}",
                             c
                         );
@@ -546,30 +1629,54 @@ impl TuiInterface {
                     }
                 }
 
-                debug_log!(
+                debug!(
                     "handle_input() - Key event received: code={:?}, modifiers={:?}",
-                    key.code,
-                    key.modifiers
+                    key.code, key.modifiers
                 );
+                if key.code == KeyCode::Char('v')
+                    && key.modifiers.contains(event::KeyModifiers::CONTROL)
+                {
+                    return Ok(match clipboard::paste() {
+                        Ok(text) => self.handle_paste(&text),
+                        Err(e) => {
+                            self.error_message = format!("Couldn't paste: {e}");
+                            None
+                        }
+                    });
+                }
+                if self.handle_log_panel_input(key)
+                    || self.handle_stats_view_input(key)
+                    || self.handle_grid_view_input(key)
+                    || self.handle_clipboard_input(key)
+                    || self.handle_diff_view_input(key)
+                    || self.handle_candidate_pane_input(key)
+                    || self.handle_export_input(key)
+                {
+                    return Ok(None);
+                }
                 match &self.state {
                     TuiState::EnteringGuess => {
-                        debug_log!("handle_input() - Processing in EnteringGuess state");
+                        debug!("handle_input() - Processing in EnteringGuess state");
                         return Ok(self.handle_guess_input(key));
                     }
+                    TuiState::ConfirmingGuess => {
+                        debug!("handle_input() - Processing in ConfirmingGuess state");
+                        self.handle_confirming_guess_input(key);
+                    }
                     TuiState::MarkingFeedback { .. } => {
-                        debug_log!("handle_input() - Processing in MarkingFeedback state");
+                        debug!("handle_input() - Processing in MarkingFeedback state");
                         self.handle_feedback_input(key);
                     }
                     TuiState::ConfirmingFeedback => {
-                        debug_log!("handle_input() - Processing in ConfirmingFeedback state");
+                        debug!("handle_input() - Processing in ConfirmingFeedback state");
                         self.handle_confirming_feedback_input(key);
                     }
                     TuiState::WaitingForNext => {
-                        debug_log!("handle_input() - Processing in WaitingForNext state");
+                        debug!("handle_input() - Processing in WaitingForNext state");
                         return Ok(self.handle_waiting_input(key));
                     }
                     TuiState::GameOver => {
-                        debug_log!("handle_input() - Processing in GameOver state");
+                        debug!("handle_input() - Processing in GameOver state");
                         return Ok(Self::handle_game_over_input(key));
                     }
                     TuiState::Computing => {}
@@ -579,27 +1686,255 @@ impl TuiInterface {
         }
     }
 
+    /// Use pasted text as a guess, rounds, a correction, a tag, a why-not query or a split
+    /// pattern while entering a guess, or as the pattern while marking feedback. Once the game
+    /// is over only a correction, a tag or a why-not query is taken.
+    fn handle_paste(&mut self, text: &str) -> Option<UserAction> {
+        let text = text.trim().to_uppercase();
+        if matches!(self.state, TuiState::EnteringGuess | TuiState::GameOver)
+            && let Some((round, feedback)) = parse_correction(&text)
+        {
+            return Some(UserAction::Correct { round, feedback });
+        }
+        if matches!(self.state, TuiState::EnteringGuess | TuiState::GameOver)
+            && let Some(word) = parse_why_not(&text)
+        {
+            return Some(UserAction::WhyNot(word));
+        }
+        if matches!(self.state, TuiState::EnteringGuess | TuiState::GameOver)
+            && let Some((round, forced)) = parse_tag(&text)
+        {
+            return Some(UserAction::Tag { round, forced });
+        }
+        match self.state {
+            TuiState::EnteringGuess => {
+                if let Some(pattern) = text.strip_prefix("SPLIT ").and_then(parse_pattern) {
+                    return Some(UserAction::Split(pattern));
+                }
+                if let Some(rounds) = parse_rounds(&text) {
+                    self.starting_pick = None;
+                    self.current_input.clear();
+                    return Some(UserAction::Rounds(rounds));
+                }
+                if text.len() == WORD_LENGTH && text.chars().all(|c| c.is_ascii_alphabetic()) {
+                    self.starting_pick = None;
+                    self.current_input = text;
+                } else {
+                    self.error_message = format!(
+                        "Paste a {}-letter guess or rounds like CRANE=XYGXX",
+                        WORD_LENGTH
+                    );
+                }
+            }
+            TuiState::MarkingFeedback { .. } => {
+                match (parse_feedback(&text), self.guesses.last_mut()) {
+                    (Some(feedback), Some(row)) if feedback.len() == row.states.len() => {
+                        for (state, fb) in row.states.iter_mut().zip(feedback) {
+                            *state = LetterState::from_feedback(fb);
+                        }
+                        self.set_state(TuiState::ConfirmingFeedback);
+                    }
+                    _ => {
+                        self.set_feedback_error("Paste feedback as GYXXG, 21002, or share squares")
+                    }
+                }
+            }
+            _ => {
+                debug!("handle_paste() - Ignoring paste in {:?}", self.state);
+            }
+        }
+        None
+    }
+
+    /// Handle keys that copy to the clipboard. Returns true if the key was consumed.
+    fn handle_clipboard_input(&mut self, key: KeyEvent) -> bool {
+        let (text, what) = match key.code {
+            KeyCode::F(6) => {
+                let Some(guess) = self
+                    .recommendation
+                    .as_ref()
+                    .map(|rec| rec.guess.to_string())
+                    .or_else(|| self.starting_words.first().map(|(word, _)| word.clone()))
+                else {
+                    self.error_message = Message::ErrorNoRecommendation.text().to_string();
+                    return true;
+                };
+                (guess, "recommendation")
+            }
+            KeyCode::F(7) => {
+                let rows: Vec<Vec<Feedback>> =
+                    self.guesses.iter().filter_map(GuessRow::feedback).collect();
+                if rows.is_empty() {
+                    self.error_message = "No rounds to share yet!".to_string();
+                    return true;
+                }
+                let puzzle = PuzzleInfo::for_date(chrono::Local::now().date_naive());
+                (share_text(puzzle.map(|p| p.number), &rows), "share grid")
+            }
+            _ => return false,
+        };
+        match clipboard::copy(&text) {
+            Ok(()) => self.message = format!("Copied the {what} to the clipboard"),
+            Err(e) => self.error_message = format!("Couldn't copy: {e}"),
+        }
+        true
+    }
+
+    /// Handle keys for the log panel. Returns true if the key was consumed.
+    fn handle_log_panel_input(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::F(12) if self.log_buffer.is_some() => {
+                self.show_log = !self.show_log;
+                true
+            }
+            KeyCode::Esc if self.show_log => {
+                self.show_log = false;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Handle keys for the wordbank stats screen. Returns true if the key was consumed.
+    fn handle_stats_view_input(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::F(5) if self.wordbank_stats.is_some() => {
+                self.show_stats = !self.show_stats;
+                true
+            }
+            KeyCode::Esc if self.show_stats => {
+                self.show_stats = false;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Handle keys for the letters-by-position grid. Returns true if the key was consumed.
+    fn handle_grid_view_input(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::F(9) => {
+                self.show_grid = !self.show_grid;
+                true
+            }
+            KeyCode::Esc if self.show_grid => {
+                self.show_grid = false;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Handle keys for the candidate diff view. Returns true if the key was consumed.
+    fn handle_diff_view_input(&mut self, key: KeyEvent) -> bool {
+        let pages = self
+            .candidate_diff
+            .as_ref()
+            .map_or(1, |diff| diff.page_count(self.diff_page_size));
+
+        match (key.code, self.diff_page) {
+            (KeyCode::F(2), None) => {
+                if self.candidate_diff.is_some() {
+                    self.diff_page = Some(0);
+                } else {
+                    self.error_message = Message::ErrorNoDiff.text().to_string();
+                }
+                true
+            }
+            (KeyCode::F(2) | KeyCode::Esc, Some(_)) => {
+                self.diff_page = None;
+                true
+            }
+            (KeyCode::PageDown | KeyCode::Down, Some(page)) => {
+                self.diff_page = Some((page + 1).min(pages - 1));
+                true
+            }
+            (KeyCode::PageUp | KeyCode::Up, Some(page)) => {
+                self.diff_page = Some(page.saturating_sub(1));
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Handle keys that change how the candidate pane is shown. Returns true if the key was
+    /// consumed.
+    fn handle_candidate_pane_input(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::F(3) => {
+                self.config.candidate_sort = self.config.candidate_sort.next();
+                CandidateEntry::sort(&mut self.candidates_display, self.config.candidate_sort);
+            }
+            KeyCode::F(4) => {
+                self.config.annotate_candidates = !self.config.annotate_candidates;
+            }
+            _ => return false,
+        }
+        self.save_config();
+        true
+    }
+
+    /// Handle the key that writes the candidates to a file. Returns true if the key was
+    /// consumed.
+    fn handle_export_input(&mut self, key: KeyEvent) -> bool {
+        if key.code != KeyCode::F(8) {
+            return false;
+        }
+        if self.candidates_display.is_empty() {
+            self.error_message = Message::ErrorNothingToExport.text().to_string();
+            return true;
+        }
+        let words: Vec<String> = self
+            .candidates_display
+            .iter()
+            .map(|entry| entry.word.clone())
+            .collect();
+        match write_candidates(Path::new(DEFAULT_EXPORT_PATH), &words) {
+            Ok(()) => {
+                self.message = Message::GameExported
+                    .fill(&[("count", &words.len()), ("path", &DEFAULT_EXPORT_PATH)]);
+            }
+            Err(e) => self.error_message = Message::ErrorExport.fill(&[("error", &e)]),
+        }
+        true
+    }
+
+    fn save_config(&mut self) {
+        if let Some(path) = &self.config_path
+            && let Err(e) = self.config.save(path)
+        {
+            self.error_message = format!("Couldn't save preferences: {e}");
+        }
+    }
+
     fn handle_guess_input(&mut self, key: KeyEvent) -> Option<UserAction> {
         self.error_message.clear();
-        debug_log!(
+        debug!(
             "handle_guess_input() - Processing key: {:?}, current_input: '{}'",
-            key.code,
-            self.current_input
+            key.code, self.current_input
         );
 
+        if let Some(word) = self.pick_starting_word(key.code) {
+            self.current_input = word;
+            return None;
+        }
+
         match key.code {
-            KeyCode::Char(c) if c.is_ascii_alphabetic() && self.current_input.len() < 5 => {
+            KeyCode::Char(c)
+                if c.is_ascii_alphabetic() && self.current_input.len() < WORD_LENGTH =>
+            {
+                self.starting_pick = None;
                 // Ignore characters with Alt, Control, or other modifiers (Shift is ok for uppercase)
                 let has_alt = key.modifiers.contains(event::KeyModifiers::ALT);
                 let has_ctrl = key.modifiers.contains(event::KeyModifiers::CONTROL);
                 if has_alt || has_ctrl {
-                    debug_log!(
+                    debug!(
                         "handle_guess_input() - Ignoring character with modifier: {:?}",
                         key.modifiers
                     );
                 } else {
                     self.current_input.push(c.to_ascii_uppercase());
-                    info_log!(
+                    info!(
                         "handle_guess_input() - Added '{}' to input, now: '{}'",
                         c.to_ascii_uppercase(),
                         self.current_input
@@ -607,52 +1942,108 @@ impl TuiInterface {
                 }
             }
             KeyCode::Backspace if !self.current_input.is_empty() => {
+                self.starting_pick = None;
                 self.current_input.pop();
-                info_log!(
+                info!(
                     "handle_guess_input() - Removed character, now: '{}'",
                     self.current_input
                 );
             }
-            KeyCode::Enter if self.current_input.len() == 5 => {
+            KeyCode::Enter if self.current_input.len() == WORD_LENGTH => {
+                self.starting_pick = None;
                 let guess = self.current_input.clone();
                 self.current_input.clear();
-                info_log!(
+                info!(
                     "handle_guess_input() - Enter pressed with valid guess: '{}', returning Guess action",
                     guess
                 );
                 return Some(UserAction::Guess(guess));
             }
+            KeyCode::Tab => {
+                if let Some(guess) = self.suggestion().map(str::to_string) {
+                    info!("handle_guess_input() - TAB pressed, using '{}'", guess);
+                    self.current_input.clear();
+                    self.starting_pick = None;
+                    return Some(UserAction::Guess(guess));
+                }
+                self.error_message = Message::ErrorNoRecommendation.text().to_string();
+            }
             KeyCode::Enter => {
-                self.error_message = "Guess must be exactly 5 letters!".to_string();
-                info_log!(
+                self.error_message = Message::ErrorGuessLength.fill(&[("length", &WORD_LENGTH)]);
+                info!(
                     "handle_guess_input() - Enter pressed but input length is {}, showing error",
                     self.current_input.len()
                 );
             }
             KeyCode::Esc => {
-                info_log!("handle_guess_input() - ESC pressed, returning Exit");
+                info!("handle_guess_input() - ESC pressed, returning Exit");
                 return Some(UserAction::Exit);
             }
+            KeyCode::Char('?') => {
+                info!("handle_guess_input() - ? pressed, returning Hint");
+                return Some(UserAction::Hint);
+            }
             KeyCode::Char(c) if !c.is_ascii_alphabetic() => {
                 // Explicitly reject non-alphabetic characters
-                self.error_message = format!("Only letters are allowed! ('{c}' is not a letter)");
-                debug_log!(
+                self.error_message = Message::ErrorNotALetter.fill(&[("char", &c)]);
+                debug!(
                     "handle_guess_input() - Rejecting non-alphabetic character: '{}'",
                     c
                 );
             }
             _ => {
-                debug_log!("handle_guess_input() - Ignoring key: {:?}", key.code);
+                debug!("handle_guess_input() - Ignoring key: {:?}", key.code);
             }
         }
         None
     }
 
+    /// Move through the suggested starting words with Up/Down before the first guess.
+    /// Returns the newly picked word to fill in as the guess.
+    fn pick_starting_word(&mut self, code: KeyCode) -> Option<String> {
+        let count = self.starting_words.len();
+        if count == 0 || !self.guesses.is_empty() {
+            return None;
+        }
+        let pick = match (code, self.starting_pick) {
+            (KeyCode::Down, None) => 0,
+            (KeyCode::Down, Some(i)) => (i + 1) % count,
+            (KeyCode::Up, None) => count - 1,
+            (KeyCode::Up, Some(i)) => (i + count - 1) % count,
+            _ => return None,
+        };
+        self.starting_pick = Some(pick);
+        Some(self.starting_words[pick].0.clone())
+    }
+
+    fn handle_confirming_guess_input(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Enter | KeyCode::Char('y' | 'Y') => {
+                self.set_state(TuiState::MarkingFeedback { marking_index: 0 });
+                info!("handle_confirming_guess_input() - Dominated guess accepted");
+            }
+            KeyCode::Esc | KeyCode::Backspace | KeyCode::Char('n' | 'N') => {
+                // Put the guess back into the input row so it can be edited
+                if let Some(row) = self.guesses.pop() {
+                    self.current_input = row.letters.iter().collect();
+                }
+                self.set_state(TuiState::EnteringGuess);
+                info!("handle_confirming_guess_input() - Dominated guess rejected");
+            }
+            _ => {
+                debug!(
+                    "handle_confirming_guess_input() - Ignoring key: {:?}",
+                    key.code
+                );
+            }
+        }
+    }
+
     fn handle_feedback_input(&mut self, key: KeyEvent) -> Option<UserAction> {
         if let TuiState::MarkingFeedback { marking_index } = self.state {
             // Ignore inputs with Alt or Control modifiers to prevent alt-tab issues
             if Self::has_modifier_keys(&key) {
-                debug_log!(
+                debug!(
                     "handle_feedback_input() - Ignoring input with modifier: {:?}",
                     key.modifiers
                 );
@@ -663,7 +2054,7 @@ impl TuiInterface {
 
             match key.code {
                 KeyCode::Esc => {
-                    info_log!("handle_feedback_input() - ESC pressed, returning Exit");
+                    info!("handle_feedback_input() - ESC pressed, returning Exit");
                     return Some(UserAction::Exit);
                 }
                 KeyCode::Char('g' | 'G') => {
@@ -681,23 +2072,20 @@ impl TuiInterface {
                 KeyCode::Backspace if marking_index > 0 => {
                     // Reset the state of the previous letter before going back
                     last_guess.states[marking_index - 1] = LetterState::Entered;
-                    self.state = TuiState::MarkingFeedback {
+                    self.set_state(TuiState::MarkingFeedback {
                         marking_index: marking_index - 1,
-                    };
+                    });
                 }
                 KeyCode::Char(c) if c.is_ascii_alphabetic() => {
-                    self.set_feedback_error(&format!(
-                        "Invalid feedback! Use G (green), Y (yellow), or X (gray). ('{}' is not valid)",
-                        c.to_ascii_uppercase()
-                    ));
+                    self.set_feedback_error(
+                        &Message::ErrorFeedbackLetter.fill(&[("char", &c.to_ascii_uppercase())]),
+                    );
                 }
                 KeyCode::Char(c) => {
-                    self.set_feedback_error(&format!(
-                        "Only letters G, Y, or X are allowed! ('{c}' is not valid)"
-                    ));
+                    self.set_feedback_error(&Message::ErrorNotFeedback.fill(&[("char", &c)]));
                 }
                 _ => {
-                    debug_log!(
+                    debug!(
                         "handle_feedback_input() - Ignoring non-character key: {:?}",
                         key.code
                     );
@@ -710,30 +2098,31 @@ impl TuiInterface {
     fn handle_confirming_feedback_input(&mut self, key: KeyEvent) -> Option<UserAction> {
         match key.code {
             KeyCode::Esc => {
-                info_log!("handle_confirming_feedback_input() - ESC pressed, returning Exit");
+                info!("handle_confirming_feedback_input() - ESC pressed, returning Exit");
                 Some(UserAction::Exit)
             }
             KeyCode::Enter => {
+                self.cue(Cue::Feedback);
                 // Confirm the feedback and proceed
-                self.state = TuiState::WaitingForNext;
-                info_log!("handle_confirming_feedback_input() - Feedback confirmed");
+                self.set_state(TuiState::WaitingForNext);
+                info!("handle_confirming_feedback_input() - Feedback confirmed");
                 None
             }
             KeyCode::Backspace => {
                 // Go back to editing the last letter
-                if let Some(last_guess) = self.guesses.last_mut() {
-                    last_guess.states[WORD_LENGTH - 1] = LetterState::Entered;
-                    self.state = TuiState::MarkingFeedback {
-                        marking_index: WORD_LENGTH - 1,
-                    };
-                    info_log!(
-                        "handle_confirming_feedback_input() - Going back to edit last letter"
-                    );
+                if let Some(last_guess) = self.guesses.last_mut()
+                    && let Some(last) = last_guess.states.len().checked_sub(1)
+                {
+                    last_guess.states[last] = LetterState::Entered;
+                    self.set_state(TuiState::MarkingFeedback {
+                        marking_index: last,
+                    });
+                    info!("handle_confirming_feedback_input() - Going back to edit last letter");
                 }
                 None
             }
             _ => {
-                debug_log!(
+                debug!(
                     "handle_confirming_feedback_input() - Ignoring key: {:?}",
                     key.code
                 );
@@ -748,25 +2137,29 @@ impl TuiInterface {
     }
 
     fn advance_feedback_marking(&mut self, current_index: usize) {
-        if current_index < WORD_LENGTH - 1 {
-            self.state = TuiState::MarkingFeedback {
+        let row_length = self
+            .guesses
+            .last()
+            .map_or(WORD_LENGTH, |row| row.states.len());
+        if current_index + 1 < row_length {
+            self.set_state(TuiState::MarkingFeedback {
                 marking_index: current_index + 1,
-            };
+            });
         } else {
-            self.state = TuiState::ConfirmingFeedback;
+            self.set_state(TuiState::ConfirmingFeedback);
         }
     }
 
     fn set_feedback_error(&mut self, message: &str) {
         self.error_message = message.to_string();
-        debug_log!("handle_feedback_input() - {}", message);
+        debug!("handle_feedback_input() - {}", message);
     }
 
     fn handle_waiting_input(&mut self, key: KeyEvent) -> Option<UserAction> {
         if key.code == KeyCode::Esc {
             Some(UserAction::Exit)
         } else {
-            self.state = TuiState::EnteringGuess;
+            self.set_state(TuiState::EnteringGuess);
             None
         }
     }
@@ -790,156 +2183,344 @@ impl TuiInterface {
         Some(feedback)
     }
 
+    /// Moves to `state`, tracing changes between states but not moves within one
+    fn set_state(&mut self, state: TuiState) {
+        if std::mem::discriminant(&self.state) != std::mem::discriminant(&state) {
+            debug!(from = ?self.state, to = ?state, "TUI state transition");
+        }
+        if let Some(recorder) = &mut self.recorder {
+            recorder.state(&format!("{state:?}"));
+        }
+        self.state = state;
+    }
+
     /// Transition to the `MarkingFeedback` state
     fn transition_to_marking_feedback(&mut self, guess: &str) {
-        self.state = TuiState::MarkingFeedback { marking_index: 0 };
-        self.status = format!("Guess entered: {guess} - Now mark feedback");
+        self.set_state(TuiState::MarkingFeedback { marking_index: 0 });
+        self.status = Message::StatusGuessEntered.fill(&[("guess", &guess)]);
     }
 
     /// Transition to the `EnteringGuess` state
     fn transition_to_entering_guess(&mut self) {
-        self.state = TuiState::EnteringGuess;
+        self.set_state(TuiState::EnteringGuess);
     }
 
     /// Transition to the `GameOver` state
     fn transition_to_game_over(&mut self) {
-        self.state = TuiState::GameOver;
+        self.set_state(TuiState::GameOver);
     }
 }
 
 impl GameInterface for TuiInterface {
-    fn display_starting_words(&mut self, info: &StartingWordsInfo) {
-        self.starting_words.clone_from(&info.words);
-        if !info.words.is_empty() {
-            self.message = format!("Suggested starting word: {}", info.words[0]);
-        }
-        self.status = "Ready - Enter your first 5-letter guess".to_string();
-        self.draw_or_log();
+    fn handle_event(&mut self, event: GameEvent<'_>) {
+        event.dispatch(self);
     }
 
     fn read_guess(&mut self) -> Option<UserAction> {
-        info_log!("read_guess() - Starting guess input loop");
+        match self.guess_input(None) {
+            InputResult::Ready(action) => Some(action),
+            InputResult::Closed => Some(UserAction::Exit),
+            InputResult::Invalid | InputResult::Timeout => None,
+        }
+    }
+
+    fn read_guess_within(&mut self, idle: Duration) -> InputResult<UserAction> {
+        self.guess_input(Some(idle))
+    }
+
+    fn confirm_dominated_guess(&mut self, warning: &GuessWarning) -> bool {
+        self.set_state(TuiState::ConfirmingGuess);
+        self.error_message = dominated_warning(warning);
+        self.status = Message::StatusUseAnyway.fill(&[("guess", &warning.guess)]);
+
         loop {
-            // Draw the current state
             if self.draw().is_err() {
-                info_log!("read_guess() - Draw failed, returning Exit");
-                return Some(UserAction::Exit);
+                return true;
             }
-
-            // Handle input - this will block until an event is available
-            match self.handle_input() {
-                Ok(Some(action)) => {
-                    info_log!("read_guess() - Action received: {:?}", action);
-                    return Some(action);
-                }
-                Ok(None) => {
-                    // No action yet, continue the loop (character was added or ignored)
+            if self.handle_input().is_err() {
+                return true;
+            }
+            match self.state {
+                TuiState::ConfirmingGuess => {}
+                TuiState::EnteringGuess => {
+                    self.error_message.clear();
+                    self.status = Message::StatusChooseAnother.text().to_string();
+                    return false;
                 }
-                Err(_e) => {
-                    info_log!("read_guess() - Error handling input, returning Exit");
-                    return Some(UserAction::Exit);
+                _ => {
+                    self.error_message.clear();
+                    return true;
                 }
             }
         }
     }
 
-    fn read_feedback(&mut self) -> Option<Vec<Feedback>> {
-        // Transition to marking state
-        self.state = TuiState::MarkingFeedback { marking_index: 0 };
-        self.error_message.clear();
-        self.status = "Mark each letter: G (green), Y (yellow), or X (gray)".to_string();
-
-        // Draw once before entering loop to show the updated state
-        if self.draw().is_err() {
-            debug_log!("read_feedback() - Initial draw failed");
-            return None;
-        }
+    fn read_feedback(&mut self) -> InputResult<Vec<Feedback>> {
+        self.feedback_input(None)
+    }
 
-        loop {
-            // Update status if we're in confirming state
-            if matches!(self.state, TuiState::ConfirmingFeedback) {
-                self.status = "Press ENTER to confirm feedback".to_string();
-            }
+    fn read_feedback_within(&mut self, idle: Duration) -> InputResult<Vec<Feedback>> {
+        self.feedback_input(Some(idle))
+    }
 
-            // Use handle_input which now properly handles state-based input
-            match self.handle_input() {
-                Ok(Some(action)) => {
-                    // Handle exit during feedback marking
-                    match action {
-                        UserAction::Exit | UserAction::NewGame => {
-                            // Return dummy feedback to allow the action to be processed
-                            return Some(vec![Feedback::NoMatch; 5]);
-                        }
-                        UserAction::Guess(_) => {}
-                    }
-                }
-                Ok(None) => {
-                    // Check if we've finished marking and confirmed
-                    if matches!(self.state, TuiState::WaitingForNext) {
-                        self.status = "Feedback recorded".to_string();
-                        self.draw_or_log();
-                        return self.get_feedback_from_last_guess();
-                    }
-                }
-                Err(e) => {
-                    debug_log!("read_feedback() - Input error: {}", e);
-                    return None;
-                }
-            }
+    fn cancel_token(&mut self) -> CancelToken {
+        self.cancel = CancelToken::new();
+        self.cancel.clone()
+    }
+}
 
-            // Redraw after each input
-            if self.draw().is_err() {
-                debug_log!("read_feedback() - Draw failed in loop");
-                return None;
-            }
+impl GameDisplay for TuiInterface {
+    fn display_starting_words(&mut self, info: &StartingWordsInfo) {
+        self.progress = None;
+        self.starting_words.clone_from(&info.words);
+        if !info.words.is_empty() {
+            self.message = Message::TuiStartingWord.fill(&[("word", &info.words[0].0)]);
         }
+        self.status = Message::StatusFirstGuess.fill(&[("length", &WORD_LENGTH)]);
+        self.draw_or_log();
     }
 
     fn display_candidates(&mut self, candidates: &[String]) {
-        self.candidates_display = candidates.to_vec();
+        // Worked out again after the recommendation, if the pool is small enough
+        self.win_odds.clear();
+        self.candidates_display = CandidateEntry::from_candidates(candidates);
+        CandidateEntry::sort(&mut self.candidates_display, self.config.candidate_sort);
         // If we're in WaitingForNext state, transition out of it
         // This happens after feedback is entered
         if matches!(self.state, TuiState::WaitingForNext) {
-            self.state = TuiState::Computing;
+            self.set_state(TuiState::Computing);
         }
         self.status = format!("Filtering candidates... {} remaining", candidates.len());
         self.draw_or_log();
     }
 
+    fn display_rounds(&mut self, rounds: &[(String, Vec<Feedback>)]) {
+        for (guess, feedback) in rounds {
+            self.guesses.push(GuessRow::from_feedback(guess, feedback));
+        }
+        self.draw_or_log();
+    }
+
+    fn display_candidate_diff(&mut self, diff: &CandidateDiff) {
+        self.candidate_diff = Some(diff.clone());
+        self.diff_page = None;
+    }
+
     fn display_recommendation(&mut self, recommendation: &Recommendation) {
         self.recommendation = Some(recommendation.clone());
+        self.progress = None;
         self.transition_to_entering_guess();
-        self.status = format!("Recommendation ready: {}", recommendation.guess);
+        self.status = if recommendation.truncated {
+            Message::StatusTruncated
+        } else {
+            Message::StatusRecommendationReady
+        }
+        .fill(&[("guess", &recommendation.guess)]);
         // Clear starting words once we have a recommendation from gameplay
         self.starting_words.clear();
+        self.starting_pick = None;
+        self.draw_or_log();
+    }
+
+    fn display_win_odds(&mut self, odds: &WinOdds) {
+        self.win_odds = odds.summary();
+        self.draw_or_log();
+    }
+
+    fn display_hint(&mut self, hint: &Hint) {
+        let level = hint.level();
+        let levels = HINT_LEVELS;
+        self.message = match hint {
+            Hint::ProbeLetter(letter) => Message::HintProbeLetter.fill(&[
+                ("level", &level),
+                ("levels", &levels),
+                ("letter", letter),
+            ]),
+            Hint::FirstLetter(letter) => Message::HintFirstLetter.fill(&[
+                ("level", &level),
+                ("levels", &levels),
+                ("letter", letter),
+            ]),
+            Hint::Full(recommendation) => {
+                self.recommendation = Some(recommendation.clone());
+                self.progress = None;
+                Message::HintFull.fill(&[
+                    ("level", &level),
+                    ("levels", &levels),
+                    ("guess", &recommendation.guess),
+                ])
+            }
+        };
+        self.status = Message::StatusHintShown.text().to_string();
+        self.draw_or_log();
+    }
+
+    fn display_probe(&mut self, letters: &[char], guesses: &[(String, f64)]) {
+        let letters: String = letters.iter().collect();
+        self.message = if guesses.is_empty() {
+            Message::GameProbeNone.fill(&[("letters", &letters)])
+        } else {
+            let words: Vec<&str> = guesses.iter().map(|(word, _)| word.as_str()).collect();
+            format!(
+                "{} {}",
+                Message::GameProbe.fill(&[("letters", &letters)]),
+                words.join(", ")
+            )
+        };
+        self.draw_or_log();
+    }
+
+    fn display_avoid(&mut self, letters: &[char]) {
+        self.message = avoid_message(letters);
+        self.draw_or_log();
+    }
+
+    fn display_restrict(&mut self, listed: usize, remaining: usize) {
+        self.message = restrict_message(listed, remaining);
+        self.draw_or_log();
+    }
+
+    fn display_tagging(&mut self, tagging: Tagging<'_>) {
+        if matches!(tagging, Tagging::Applied { .. }) {
+            self.message = tagging_message(tagging);
+            self.error_message.clear();
+        } else {
+            self.error_message = tagging_message(tagging);
+        }
+        self.draw_or_log();
+    }
+
+    fn display_correction(&mut self, correction: Correction<'_>) {
+        if let Correction::Applied { round, feedback } = correction {
+            if let Some(row) = self.guesses.get_mut(round - 1) {
+                for (state, &fb) in row.states.iter_mut().zip(feedback) {
+                    *state = LetterState::from_feedback(fb);
+                }
+            }
+            self.message = correction_message(correction);
+            self.error_message.clear();
+            // The replay decides whether the game is over again
+            if matches!(self.state, TuiState::GameOver) {
+                self.transition_to_entering_guess();
+            }
+        } else {
+            self.error_message = correction_message(correction);
+        }
+        self.draw_or_log();
+    }
+
+    fn display_splitter(&mut self, splitter: &Splitter) {
+        self.message = splitter_message(splitter);
+        self.draw_or_log();
+    }
+
+    fn display_trap(&mut self, splitter: &Splitter, guesses_left: usize) {
+        self.message = trap_message(splitter, guesses_left);
+        self.draw_or_log();
+    }
+
+    fn display_why_not(&mut self, word: &str, explanation: &WhyNot) {
+        self.message = explanation.describe(word);
         self.draw_or_log();
     }
 
     fn display_computing_message(&mut self) {
         // Just update the message, don't block or change to Computing state
         // The Computing state doesn't accept input which causes hangs
-        self.message = "Computing optimal guess...".to_string();
-        self.status = "Computing optimal next guess...".to_string();
+        self.message = Message::GameComputing.text().to_string();
+        self.status = Message::StatusComputing.text().to_string();
+        self.draw_or_log();
+    }
+
+    fn display_timing(&mut self, label: &str, elapsed: Duration) {
+        if self.show_timing {
+            self.timing = format!("[time] {label}: {}", format_duration(elapsed));
+            self.draw_or_log();
+        }
+    }
+
+    fn display_memory_usage(&mut self, total: usize, scorer: usize) {
+        self.memory = memory_message(total, scorer);
+        self.draw_or_log();
+    }
+
+    fn display_progress(&mut self, current: usize, total: usize, best: Option<(&str, f64)>) {
+        // The game loop is busy computing, so check for ESC and Ctrl+C here
+        while self.scripted.is_none()
+            && let Ok(Some(event)) = self.next_event(Duration::ZERO)
+        {
+            if let Event::Key(key) = event {
+                if is_interrupt(&key) {
+                    exit_on_interrupt();
+                }
+                if key.code == KeyCode::Esc {
+                    info!("display_progress() - ESC pressed, cancelling computation");
+                    self.cancel.cancel();
+                }
+            }
+        }
+
+        #[allow(clippy::cast_possible_truncation)] // at most 100
+        let percent = (current * 100).checked_div(total).unwrap_or(100) as u16;
+        let best = best.map(|(word, score)| (word.to_string(), score));
+        // Redraw only when the gauge moves or the provisional guess changes
+        if self
+            .progress
+            .as_ref()
+            .is_some_and(|p| p.percent == percent && p.best == best)
+        {
+            return;
+        }
+        let hint = if self.cancel.is_cancelled() {
+            "stopping"
+        } else {
+            "ESC to stop"
+        };
+        let label = format!("{percent}% - {hint}");
+        self.progress = Some(Progress {
+            percent,
+            label,
+            best,
+        });
         self.draw_or_log();
     }
 
     fn display_no_candidates_message(&mut self) {
         self.transition_to_game_over();
-        self.message = "No candidates remain. Check your inputs.".to_string();
-        self.status = "Error: No valid candidates found".to_string();
+        self.message = Message::ErrorNoCandidates.text().to_string();
+        self.status = Message::StatusNoCandidates.text().to_string();
         self.draw_or_log();
     }
 
     fn display_solution_found(&mut self, solution: &str) {
+        self.cue(Cue::Solve);
         self.transition_to_game_over();
-        self.message = format!("✓ Solution found: {solution}");
-        self.status = format!("Game Over - Solution: {solution}");
+        self.message = format!(
+            "✓ {}",
+            Message::GameSolutionFound.fill(&[("solution", &solution)])
+        );
+        self.status = Message::StatusGameOver.fill(&[("solution", &solution)]);
+        self.draw_or_log();
+    }
+
+    fn display_achievements(&mut self, unlocked: &[Achievement]) {
+        let titles: Vec<&str> = unlocked.iter().map(|a| a.title()).collect();
+        self.message = format!(
+            "{} | {}",
+            self.message,
+            Message::GameAchievement.fill(&[("achievement", &titles.join(", "))])
+        );
+        self.draw_or_log();
+    }
+
+    fn display_information(&mut self, info: &[GuessInformation]) {
+        self.information = information_chart(info);
         self.draw_or_log();
     }
 
     fn display_exit_message(&mut self) {
-        self.message = "Exiting...".to_string();
-        self.status = "Exiting application...".to_string();
+        self.message = Message::GameExiting.text().to_string();
+        self.status = Message::StatusExiting.text().to_string();
         self.draw_or_log();
     }
 
@@ -947,10 +2528,15 @@ impl GameInterface for TuiInterface {
         self.guesses.clear();
         self.current_input.clear();
         self.candidates_display.clear();
+        self.candidate_diff = None;
+        self.diff_page = None;
         self.recommendation = None;
+        self.win_odds.clear();
+        self.information.clear();
+        self.starting_pick = None;
         self.transition_to_entering_guess();
-        self.message = format!("New game started. Loaded {word_count} words.");
-        self.status = "New game - Enter your first guess".to_string();
+        self.message = Message::GameNewGame.fill(&[("count", &word_count)]);
+        self.status = Message::StatusNewGame.text().to_string();
         self.error_message.clear();
         self.draw_or_log();
     }
@@ -980,74 +2566,827 @@ impl TuiWrapper {
             interface: TuiInterface::new()?,
         })
     }
-}
 
-impl GameInterface for TuiWrapper {
-    fn display_starting_words(&mut self, info: &StartingWordsInfo) {
-        info_log!(
-            "TuiWrapper::display_starting_words() - {} words",
-            info.words.len()
-        );
-        self.interface.display_starting_words(info);
+    /// A TUI that draws off-screen and plays `events` instead of reading the terminal, for
+    /// scripted games whose screens can be checked with [`offscreen_buffer`](Self::offscreen_buffer).
+    /// The game ends when the events run out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossterm::event::Event;
+    /// use wordle_solver::tui::{TuiWrapper, buffer_text};
+    ///
+    /// let tui = TuiWrapper::offscreen(80, 31, [Event::Paste("CRANE".to_string())]).unwrap();
+    /// // Nothing is drawn until the game starts
+    /// assert_eq!(buffer_text(tui.offscreen_buffer().unwrap()).trim(), "");
+    /// ```
+    pub fn offscreen(
+        width: u16,
+        height: u16,
+        events: impl IntoIterator<Item = Event>,
+    ) -> Result<Self, io::Error> {
+        Ok(Self {
+            interface: TuiInterface::offscreen(width, height, events)?,
+        })
     }
 
-    fn read_guess(&mut self) -> Option<UserAction> {
-        info_log!("TuiWrapper::read_guess() - Called");
+    /// What was last drawn off-screen, or `None` on the real terminal
+    #[must_use]
+    pub fn offscreen_buffer(&self) -> Option<&Buffer> {
+        self.interface.offscreen_buffer()
+    }
+
+    /// Enable or disable showing `[time]` measurements in the information panel
+    #[must_use]
+    pub const fn with_timing(mut self, show_timing: bool) -> Self {
+        self.interface.show_timing = show_timing;
+        self
+    }
+
+    /// Describe the board in words instead of drawing tiles, for screen readers
+    #[must_use]
+    pub const fn with_accessible(mut self, accessible: bool) -> Self {
+        self.interface.accessible = accessible;
+        self
+    }
+
+    /// Mark tiles with ✓, • and ✗ as well as colours, so they read without colour vision
+    #[must_use]
+    pub const fn with_tile_glyphs(mut self, tile_glyphs: bool) -> Self {
+        self.interface.tile_glyphs = tile_glyphs;
+        self
+    }
+
+    /// Statistics to show on the F5 wordbank stats screen
+    #[must_use]
+    pub fn with_wordbank_stats(mut self, stats: WordbankStats) -> Self {
+        self.interface.wordbank_stats = Some(stats);
+        self
+    }
+
+    /// Record the session's input and states with `recorder`, timed from now
+    #[must_use]
+    pub fn with_recorder(mut self, mut recorder: Recorder) -> Self {
+        recorder.start();
+        recorder.state(&format!("{:?}", self.interface.state));
+        self.interface.recorder = Some(recorder);
+        self
+    }
+
+    /// Play back a recorded session's input, timed from now, before reading the keyboard
+    #[must_use]
+    pub fn with_replay(mut self, mut replay: Replay) -> Self {
+        replay.start();
+        self.interface.replay = Some(replay);
+        self
+    }
+
+    /// Trace lines to show on the F12 log panel
+    #[must_use]
+    pub fn with_log_buffer(mut self, buffer: LogBuffer) -> Self {
+        self.interface.log_buffer = Some(buffer);
+        self
+    }
+
+    fn wait_for_guess(&mut self) {
         self.interface.status = "Waiting for guess...".to_string();
         self.interface.draw_or_log();
+    }
 
-        let action = self.interface.read_guess();
-        info_log!("TuiWrapper::read_guess() - Received action: {:?}", action);
-
-        // Record the guess for display
-        if let Some(UserAction::Guess(ref guess)) = action {
-            info_log!("TuiWrapper::read_guess() - Recording guess: '{}'", guess);
+    /// Puts a guess on the board before its feedback is asked for
+    fn show_guess(&mut self, action: &UserAction) {
+        if let UserAction::Guess(guess) = action {
+            info!("TuiWrapper::read_guess() - Recording guess: '{}'", guess);
             self.interface.record_guess(guess);
             // Transition to MarkingFeedback state immediately to prevent showing next empty row
             self.interface.transition_to_marking_feedback(guess);
             // Redraw to show the guess before asking for feedback
             // Note: draw() is synchronous and blocks until rendering is complete
             self.interface.draw_or_log();
-            info_log!("TuiWrapper::read_guess() - Guess recorded and displayed");
+            info!("TuiWrapper::read_guess() - Guess recorded and displayed");
+        }
+    }
+}
+
+impl GameInterface for TuiWrapper {
+    fn handle_event(&mut self, event: GameEvent<'_>) {
+        self.interface.handle_event(event);
+    }
+
+    fn read_guess(&mut self) -> Option<UserAction> {
+        info!("TuiWrapper::read_guess() - Called");
+        self.wait_for_guess();
+        let action = self.interface.read_guess();
+        info!("TuiWrapper::read_guess() - Received action: {:?}", action);
+        if let Some(action) = &action {
+            self.show_guess(action);
         }
         action
     }
 
-    fn read_feedback(&mut self) -> Option<Vec<Feedback>> {
-        info_log!("TuiWrapper::read_feedback() - Called");
+    fn read_guess_within(&mut self, idle: Duration) -> InputResult<UserAction> {
+        self.wait_for_guess();
+        let result = self.interface.read_guess_within(idle);
+        info!("TuiWrapper::read_guess_within() - Result: {:?}", result);
+        if let InputResult::Ready(action) = &result {
+            self.show_guess(action);
+        }
+        result
+    }
+
+    fn confirm_dominated_guess(&mut self, warning: &GuessWarning) -> bool {
+        info!(
+            "TuiWrapper::confirm_dominated_guess() - {} dominated by {}",
+            warning.guess, warning.better_guess
+        );
+        self.interface.confirm_dominated_guess(warning)
+    }
+
+    fn read_feedback(&mut self) -> InputResult<Vec<Feedback>> {
+        info!("TuiWrapper::read_feedback() - Called");
         let result = self.interface.read_feedback();
-        info_log!(
+        info!(
             "TuiWrapper::read_feedback() - Feedback received: {:?}",
             result
         );
         result
     }
 
-    fn display_candidates(&mut self, candidates: &[String]) {
-        self.interface.display_candidates(candidates);
+    fn read_feedback_within(&mut self, idle: Duration) -> InputResult<Vec<Feedback>> {
+        self.interface.read_feedback_within(idle)
     }
 
-    fn display_recommendation(&mut self, recommendation: &Recommendation) {
-        self.interface.display_recommendation(recommendation);
+    fn cancel_token(&mut self) -> CancelToken {
+        self.interface.cancel_token()
     }
+}
 
-    fn display_computing_message(&mut self) {
-        self.interface.display_computing_message();
+/// Play mode screen: two boards side by side against the same hidden word, with a keyboard
+/// showing what the typed guesses have revealed
+pub struct PlayTui<'a, G: PlayMode> {
+    terminal: Terminal<CrosstermBackend<io::Stdout>>,
+    wordbank: &'a [String],
+    rng: Rng,
+    game: G,
+    input: String,
+    message: String,
+    timer: Option<GuessTimer>,
+    /// Layout of the on-screen keyboard, from the saved config
+    layout: KeyboardLayout,
+    /// Mark tiles and keys with glyphs as well as colours
+    tile_glyphs: bool,
+}
+
+/// Run `game` until the player quits, drawing a new hidden word from `rng` for each rematch.
+/// With a `timer`, each guess has to be made before it runs out. `tile_glyphs` marks tiles and
+/// keys with glyphs so they read without colour.
+///
+/// # Errors
+/// Returns an error if the terminal can't be set up or drawn to.
+pub fn run_play<G: PlayMode>(
+    wordbank: &[String],
+    rng: Rng,
+    game: G,
+    timer: Option<GuessTimer>,
+    tile_glyphs: bool,
+) -> io::Result<()> {
+    let mut play = PlayTui::new(wordbank, rng, game, timer, tile_glyphs)?;
+    let result = play.run();
+    restore_terminal();
+    result
+}
+
+impl<'a, G: PlayMode> PlayTui<'a, G> {
+    fn new(
+        wordbank: &'a [String],
+        rng: Rng,
+        game: G,
+        timer: Option<GuessTimer>,
+        tile_glyphs: bool,
+    ) -> io::Result<Self> {
+        info!("PlayTui::new() - {} words", wordbank.len());
+        Ok(Self {
+            timer,
+            terminal: enter_terminal()?,
+            wordbank,
+            rng,
+            game,
+            input: String::new(),
+            message: String::new(),
+            layout: get_wordle_config_path()
+                .map(|path| Config::load(&path).keyboard_layout)
+                .unwrap_or_default(),
+            tile_glyphs,
+        })
     }
 
-    fn display_no_candidates_message(&mut self) {
-        self.interface.display_no_candidates_message();
+    fn run(&mut self) -> io::Result<()> {
+        self.restart_timer();
+        loop {
+            self.draw()?;
+            if self.game.outcome().is_none()
+                && self
+                    .timer
+                    .as_ref()
+                    .is_some_and(|t| t.expired(Instant::now()))
+            {
+                self.time_out();
+            }
+            if !event::poll(Duration::from_millis(EVENT_POLL_TIMEOUT_MS))? {
+                continue;
+            }
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            if is_interrupt(&key) {
+                exit_on_interrupt();
+            }
+            if key.code == KeyCode::Esc {
+                return Ok(());
+            }
+            if self.game.outcome().is_some() {
+                if matches!(key.code, KeyCode::Char('n' | 'N')) {
+                    self.new_game();
+                }
+                continue;
+            }
+            self.handle_guess_key(key.code);
+        }
     }
 
-    fn display_solution_found(&mut self, solution: &str) {
-        self.interface.display_solution_found(solution);
+    fn new_game(&mut self) {
+        self.game.restart(pick_answer(self.wordbank, &mut self.rng));
+        self.input.clear();
+        self.message.clear();
+        self.restart_timer();
     }
 
-    fn display_exit_message(&mut self) {
-        self.interface.display_exit_message();
+    fn restart_timer(&mut self) {
+        if let Some(timer) = &mut self.timer {
+            timer.restart(Instant::now());
+        }
     }
 
-    fn display_new_game_message(&mut self, word_count: usize) {
-        self.interface.display_new_game_message(word_count);
+    /// The guess timer ran out: forfeit or play the recommendation for whoever is on turn
+    fn time_out(&mut self) {
+        let Some(timer) = &self.timer else {
+            return;
+        };
+        let turn = self.game.turn();
+        let name = self.game.player_name(turn);
+        let recommendation = match timer.action {
+            TimeoutAction::Forfeit => None,
+            TimeoutAction::Recommend => {
+                self.game.boards()[turn].next_guess(self.wordbank, &timer.opener)
+            }
+        };
+        info!("PlayTui::time_out() - {} ran out of time", name);
+        self.input.clear();
+        self.message = match recommendation {
+            Some(guess) if self.game.submit(&guess, self.wordbank).is_ok() => {
+                format!("Time's up! Played {guess} for {name}")
+            }
+            _ => {
+                self.game.forfeit_turn(self.wordbank);
+                format!("Time's up! {name} forfeits")
+            }
+        };
+        self.restart_timer();
+    }
+
+    fn handle_guess_key(&mut self, code: KeyCode) {
+        match code {
+            KeyCode::Char(c) if c.is_ascii_alphabetic() && self.input.len() < WORD_LENGTH => {
+                self.input.push(c.to_ascii_uppercase());
+            }
+            KeyCode::Backspace => {
+                self.input.pop();
+            }
+            KeyCode::Enter if self.input.len() == WORD_LENGTH => {
+                let guess = std::mem::take(&mut self.input);
+                match self.game.submit(&guess, self.wordbank) {
+                    Ok(()) => {
+                        self.message.clear();
+                        self.restart_timer();
+                    }
+                    Err(e) => {
+                        self.message = e;
+                        self.input = guess;
+                    }
+                }
+            }
+            KeyCode::Enter => {
+                self.message = Message::ErrorGuessLength.fill(&[("length", &WORD_LENGTH)]);
+            }
+            _ => {}
+        }
+    }
+
+    fn status(&self) -> String {
+        if let Some(summary) = self.game.summary() {
+            return summary;
+        }
+        let status = if self.message.is_empty() {
+            format!("{}: enter a guess", self.game.player_name(self.game.turn()))
+        } else {
+            self.message.clone()
+        };
+        match &self.timer {
+            Some(timer) => {
+                let left = timer.remaining(Instant::now()).as_secs_f64().ceil();
+                format!("{status} | {left:.0}s left")
+            }
+            None => status,
+        }
+    }
+
+    fn draw(&mut self) -> io::Result<()> {
+        let status = self.status();
+        let game = &self.game;
+        let input = &self.input;
+        let layout = self.layout;
+        let tile_glyphs = self.tile_glyphs;
+        self.terminal.draw(|f| {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Length(3),  // Title
+                    Constraint::Length(14), // Boards
+                    Constraint::Length(5),  // Keyboard
+                    Constraint::Min(0),
+                    Constraint::Length(3), // Status line
+                    Constraint::Length(3), // Instructions
+                ])
+                .split(f.area());
+            let boards = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .split(chunks[1]);
+
+            TuiInterface::render_title(f, chunks[0]);
+            let playing = game.outcome().is_none();
+            for (player, board) in game.boards().iter().enumerate() {
+                let on_turn = playing && player == game.turn();
+                let name = game.player_name(player);
+                Self::render_player_board(
+                    f,
+                    boards[player],
+                    &name,
+                    board,
+                    on_turn,
+                    input,
+                    tile_glyphs,
+                );
+            }
+            Self::render_keyboard(f, chunks[2], game.keyboard(), layout, tile_glyphs);
+            TuiInterface::render_status(f, chunks[4], &status);
+            let instructions = if playing {
+                format!("Type a {WORD_LENGTH}-letter guess | ENTER: Submit | ESC: Quit")
+            } else {
+                "N: New Game | ESC: Quit".to_string()
+            };
+            let paragraph = Paragraph::new(instructions)
+                .style(Style::default().fg(Color::Gray))
+                .block(Block::default().borders(Borders::ALL));
+            f.render_widget(paragraph, chunks[5]);
+        })?;
+        Ok(())
+    }
+
+    fn render_player_board(
+        f: &mut Frame,
+        area: Rect,
+        name: &str,
+        board: &Board,
+        on_turn: bool,
+        input: &str,
+        glyphs: bool,
+    ) {
+        let rows: Vec<GuessRow> = board
+            .guesses
+            .iter()
+            .map(|(guess, feedback)| GuessRow::from_feedback(guess, feedback))
+            .collect();
+        let title = if board.is_solved() {
+            format!("{name} - solved in {}", board.guesses.len())
+        } else if board.forfeited {
+            format!("{name} - forfeited")
+        } else if on_turn {
+            format!("{name} - your turn")
+        } else {
+            name.to_string()
+        };
+        TuiInterface::render_board(
+            f,
+            area,
+            &title,
+            &rows,
+            on_turn.then_some(InputRow {
+                typed: input,
+                length: WORD_LENGTH,
+                ghost: None,
+            }),
+            &TuiState::EnteringGuess,
+            glyphs,
+        );
+    }
+
+    fn render_keyboard(
+        f: &mut Frame,
+        area: Rect,
+        keyboard: &KeyboardState,
+        layout: KeyboardLayout,
+        glyphs: bool,
+    ) {
+        let lines: Vec<Line> = layout
+            .rows()
+            .iter()
+            .map(|(indent, row)| {
+                let mut spans = vec![Span::raw(" ".repeat(indent * 2))];
+                for letter in row.chars() {
+                    let (bg, fg, state) = match keyboard.hint(letter) {
+                        LetterHint::Unknown => (Color::DarkGray, Color::White, LetterState::Empty),
+                        LetterHint::Absent => (Color::Black, Color::DarkGray, LetterState::NoMatch),
+                        LetterHint::Present => {
+                            (Color::Yellow, Color::Black, LetterState::PartialMatch)
+                        }
+                        LetterHint::Correct => (Color::Green, Color::Black, LetterState::Match),
+                    };
+                    spans.push(Span::styled(
+                        state.tile(letter, glyphs),
+                        Style::default().fg(fg).bg(bg),
+                    ));
+                    spans.push(Span::raw(" "));
+                }
+                Line::from(spans)
+            })
+            .collect();
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(Message::TuiKeyboard.text()),
+        );
+        f.render_widget(paragraph, area);
+    }
+}
+
+impl<G: PlayMode> Drop for PlayTui<'_, G> {
+    fn drop(&mut self) {
+        restore_terminal();
+    }
+}
+
+/// Least time between redraws of a [`SimulationDashboard`], so fast games aren't slowed down
+/// by drawing
+const DASHBOARD_INTERVAL: Duration = Duration::from_millis(50);
+
+/// A live view of simulated games: a progress bar per strategy, with its running average and
+/// the answer that has gone worst so far. The terminal is restored when it's dropped.
+pub struct SimulationDashboard {
+    terminal: Terminal<CrosstermBackend<io::Stdout>>,
+    last_draw: Option<Instant>,
+}
+
+impl SimulationDashboard {
+    /// Takes over the terminal to draw on.
+    ///
+    /// # Errors
+    /// Returns an error if the terminal can't be set up.
+    pub fn new() -> io::Result<Self> {
+        Ok(Self {
+            terminal: enter_terminal()?,
+            last_draw: None,
+        })
+    }
+
+    /// Redraws `rows` under `title`, unless it was drawn too recently and `force` isn't set.
+    /// Exits on Ctrl+C, since raw mode keeps it from interrupting the simulation.
+    ///
+    /// # Errors
+    /// Returns an error if the terminal can't be drawn to.
+    pub fn draw(
+        &mut self,
+        title: &str,
+        rows: &[SimulationProgress],
+        force: bool,
+    ) -> io::Result<()> {
+        while event::poll(Duration::ZERO)? {
+            if let Event::Key(key) = event::read()?
+                && is_interrupt(&key)
+            {
+                exit_on_interrupt();
+            }
+        }
+        if !force
+            && self
+                .last_draw
+                .is_some_and(|last| last.elapsed() < DASHBOARD_INTERVAL)
+        {
+            return Ok(());
+        }
+        self.last_draw = Some(Instant::now());
+        self.terminal.draw(|f| {
+            let mut constraints = vec![Constraint::Length(1)];
+            constraints.extend(rows.iter().map(|_| Constraint::Length(4)));
+            constraints.push(Constraint::Min(0));
+            let areas = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints(constraints)
+                .split(f.area());
+            f.render_widget(Paragraph::new(title).style(HEADER_STYLE), areas[0]);
+            for (row, &area) in rows.iter().zip(&areas[1..]) {
+                Self::render_row(f, area, row);
+            }
+        })?;
+        Ok(())
+    }
+
+    fn render_row(f: &mut Frame, area: Rect, row: &SimulationProgress) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(row.label.as_str());
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+        let [bar, stats] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Length(1)])
+            .areas(inner);
+        #[allow(clippy::cast_precision_loss)] // a handful of games
+        let ratio = if row.total == 0 {
+            0.0
+        } else {
+            row.played as f64 / row.total as f64
+        };
+        let gauge = Gauge::default()
+            .gauge_style(INFO_STYLE)
+            .ratio(ratio.min(1.0))
+            .label(format!("{}/{}", row.played, row.total));
+        f.render_widget(gauge, bar);
+        let average = row
+            .average()
+            .map_or_else(|| "-".to_string(), |average| format!("{average:.2}"));
+        let worst = match &row.worst {
+            Some((answer, Some(guesses))) => format!("{answer} ({guesses} guesses)"),
+            Some((answer, None)) => format!("{answer} (not solved)"),
+            None => "-".to_string(),
+        };
+        let line = format!(
+            "solved {}/{}   average {average}   worst {worst}",
+            row.solved, row.played
+        );
+        f.render_widget(Paragraph::new(line), stats);
+    }
+}
+
+impl Drop for SimulationDashboard {
+    fn drop(&mut self) {
+        restore_terminal();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::get_feedback;
+    use crate::wordbank::sample_wordbank;
+
+    fn offscreen() -> TuiInterface {
+        TuiInterface::offscreen(80, 40, []).unwrap()
+    }
+
+    /// Presses each key in turn and returns the action the last one gave
+    fn press(
+        tui: &mut TuiInterface,
+        codes: impl IntoIterator<Item = KeyCode>,
+    ) -> Option<UserAction> {
+        let mut action = None;
+        for code in codes {
+            tui.scripted
+                .as_mut()
+                .unwrap()
+                .push_back(Event::Key(KeyEvent::from(code)));
+            action = tui.handle_input().unwrap();
+        }
+        action
+    }
+
+    /// Presses the keys typing `text`
+    fn type_text(tui: &mut TuiInterface, text: &str) -> Option<UserAction> {
+        press(tui, text.chars().map(KeyCode::Char))
+    }
+
+    fn screen(tui: &mut TuiInterface) -> String {
+        tui.draw().unwrap();
+        buffer_text(tui.offscreen_buffer().unwrap())
+    }
+
+    /// Puts `guess` on the board and starts marking it, as the game loop does once it's played
+    fn play_guess(tui: &mut TuiInterface, guess: &str) {
+        tui.record_guess(guess);
+        tui.transition_to_marking_feedback(guess);
+    }
+
+    #[test]
+    fn test_entering_a_guess() {
+        let mut tui = offscreen();
+        assert!(type_text(&mut tui, "crx").is_none());
+        assert!(press(&mut tui, [KeyCode::Backspace]).is_none());
+        assert_eq!(tui.current_input, "CR");
+        assert!(screen(&mut tui).contains(" C   R "));
+
+        // Too short to submit, and digits aren't letters
+        assert!(press(&mut tui, [KeyCode::Enter]).is_none());
+        assert_eq!(
+            tui.error_message,
+            Message::ErrorGuessLength.fill(&[("length", &5)])
+        );
+        type_text(&mut tui, "1");
+        assert!(screen(&mut tui).contains("('1' is not a letter)"));
+
+        assert!(type_text(&mut tui, "ane").is_none());
+        let action = press(&mut tui, [KeyCode::Enter]);
+        assert!(matches!(action, Some(UserAction::Guess(guess)) if guess == "CRANE"));
+        assert!(tui.current_input.is_empty());
+        assert!(matches!(tui.state, TuiState::EnteringGuess));
+    }
+
+    #[test]
+    fn test_marking_feedback_transitions() {
+        let mut tui = offscreen();
+        tui.tile_glyphs = true;
+        play_guess(&mut tui, "CRANE");
+        assert!(matches!(
+            tui.state,
+            TuiState::MarkingFeedback { marking_index: 0 }
+        ));
+
+        type_text(&mut tui, "x");
+        assert!(matches!(
+            tui.state,
+            TuiState::MarkingFeedback { marking_index: 1 }
+        ));
+        press(&mut tui, [KeyCode::Backspace]);
+        assert!(matches!(
+            tui.state,
+            TuiState::MarkingFeedback { marking_index: 0 }
+        ));
+
+        // A letter that isn't a colour is refused without moving on
+        type_text(&mut tui, "q");
+        assert!(matches!(
+            tui.state,
+            TuiState::MarkingFeedback { marking_index: 0 }
+        ));
+        assert!(!tui.error_message.is_empty());
+
+        type_text(&mut tui, "xxyxg");
+        assert!(matches!(tui.state, TuiState::ConfirmingFeedback));
+        assert!(screen(&mut tui).contains("C✗  R✗  A•  N✗  E✓"));
+
+        // Backspace reopens the last letter, Enter confirms
+        press(&mut tui, [KeyCode::Backspace]);
+        assert!(matches!(
+            tui.state,
+            TuiState::MarkingFeedback { marking_index: 4 }
+        ));
+        type_text(&mut tui, "y");
+        assert!(matches!(tui.state, TuiState::ConfirmingFeedback));
+        press(&mut tui, [KeyCode::Enter]);
+        assert!(matches!(tui.state, TuiState::WaitingForNext));
+        assert_eq!(tui.get_feedback_from_last_guess(), parse_feedback("XXYXY"));
+    }
+
+    #[test]
+    fn test_suggestion_shows_as_ghost_letters() {
+        let mut tui = offscreen();
+        assert_eq!(tui.ghost(), None);
+        tui.starting_words = vec![("CRANE".to_string(), 1.0)];
+        assert!(screen(&mut tui).contains(" C   R   A   N   E "));
+
+        // Typing along keeps the rest of the suggestion, typing over it clears it
+        type_text(&mut tui, "cr");
+        assert_eq!(tui.ghost(), Some("CRANE"));
+        type_text(&mut tui, "o");
+        assert_eq!(tui.ghost(), None);
+        assert!(!screen(&mut tui).contains(" N   E "));
+
+        press(&mut tui, [KeyCode::Backspace]);
+        let action = press(&mut tui, [KeyCode::Tab]);
+        assert!(matches!(action, Some(UserAction::Guess(guess)) if guess == "CRANE"));
+    }
+
+    #[test]
+    fn test_guess_length_follows_word_length() {
+        let mut tui = offscreen();
+        assert!(screen(&mut tui).contains(&format!("Type your {WORD_LENGTH}-letter guess")));
+
+        // Four letters don't make a guess, and a sixth isn't taken
+        type_text(&mut tui, "cran");
+        assert!(press(&mut tui, [KeyCode::Enter]).is_none());
+        assert_eq!(
+            tui.error_message,
+            Message::ErrorGuessLength.fill(&[("length", &WORD_LENGTH)])
+        );
+        type_text(&mut tui, "es");
+        assert_eq!(tui.current_input, "CRANE");
+        let action = press(&mut tui, [KeyCode::Enter]);
+        assert!(matches!(action, Some(UserAction::Guess(guess)) if guess == "CRANE"));
+
+        // Marking runs over every letter before asking to confirm
+        play_guess(&mut tui, "CRANE");
+        type_text(&mut tui, "xxyx");
+        assert!(matches!(
+            tui.state,
+            TuiState::MarkingFeedback { marking_index: 4 }
+        ));
+        type_text(&mut tui, "g");
+        assert!(matches!(tui.state, TuiState::ConfirmingFeedback));
+        press(&mut tui, [KeyCode::Backspace]);
+        assert!(matches!(
+            tui.state,
+            TuiState::MarkingFeedback { marking_index: 4 }
+        ));
+    }
+
+    #[test]
+    fn test_interrupt_saver_keeps_game_in_progress() {
+        let wordbank = sample_wordbank();
+        let answer = wordbank[0].clone();
+        let round = |guess: &str| CandidateDiff {
+            guess: guess.to_string(),
+            feedback: get_feedback(guess, &answer),
+            eliminated: Vec::new(),
+            survived: Vec::new(),
+        };
+        let in_progress = || GAME_IN_PROGRESS.lock().unwrap().clone();
+        let mut saver = InterruptSaver::new(&wordbank);
+        saver.on_round_complete(1, &round("CRANE"));
+        saver.on_round_complete(2, &round("SLATE"));
+        let (state, saved) = in_progress().unwrap();
+        assert_eq!(parse_rounds(&state).map(|rounds| rounds.len()), Some(2));
+        let resumed = Game::resume(&wordbank, SolverConfig::default(), &saved).unwrap();
+        assert_eq!(resumed.history().len(), 2);
+
+        // A correction reports the game again from its first round
+        saver.on_round_complete(1, &round("CRANE"));
+        assert_eq!(
+            parse_rounds(&in_progress().unwrap().0).map(|r| r.len()),
+            Some(1)
+        );
+
+        let result = GameResult {
+            outcome: crate::game::Outcome::InProgress,
+            rounds: 1,
+            candidates: Vec::new(),
+            history: Vec::new(),
+            aborted: false,
+        };
+        saver.on_game_end(&result);
+        assert!(in_progress().is_none());
+    }
+
+    #[test]
+    fn test_read_feedback_plays_scripted_keys() {
+        let keys = "xxyxg".chars().map(KeyCode::Char).chain([KeyCode::Enter]);
+        let mut tui =
+            TuiInterface::offscreen(80, 40, keys.map(|code| Event::Key(KeyEvent::from(code))))
+                .unwrap();
+        play_guess(&mut tui, "CRANE");
+        assert_eq!(
+            tui.read_feedback(),
+            InputResult::Ready(parse_feedback("XXYXG").unwrap())
+        );
+        assert!(screen(&mut tui).contains(Message::StatusFeedbackRecorded.text()));
+
+        // Running out of keys closes the input
+        play_guess(&mut tui, "SLATE");
+        assert_eq!(tui.read_feedback(), InputResult::Closed);
+        assert!(matches!(tui.read_guess(), Some(UserAction::Exit)));
+    }
+
+    #[test]
+    fn test_escape_and_game_over_keys() {
+        let mut tui = offscreen();
+        assert!(matches!(
+            press(&mut tui, [KeyCode::Esc]),
+            Some(UserAction::Exit)
+        ));
+
+        play_guess(&mut tui, "CRANE");
+        type_text(&mut tui, "ggggg");
+        press(&mut tui, [KeyCode::Enter]);
+        assert!(press(&mut tui, [KeyCode::Char(' ')]).is_none());
+        assert!(matches!(tui.state, TuiState::EnteringGuess));
+
+        tui.set_state(TuiState::GameOver);
+        assert!(matches!(
+            type_text(&mut tui, "n"),
+            Some(UserAction::NewGame)
+        ));
+        assert!(matches!(
+            press(&mut tui, [KeyCode::Esc]),
+            Some(UserAction::Exit)
+        ));
     }
 }
@@ -1,31 +1,317 @@
+use crate::exit;
+use crate::solver::Strategy;
+use crate::sources::{EmbeddedSource, WordSource, source_for};
+use crate::words::{Word, WordBank};
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs::{File, OpenOptions};
 use std::io::{self, BufRead, BufReader, Write};
 use std::path::{Path, PathBuf};
 
-pub const EMBEDDED_WORDBANK: &str = include_str!("resources/wordbank.txt");
+/// The default wordbank, packed with [`encode_wordbank`] from `resources/wordbank.txt`
+pub const EMBEDDED_WORDBANK: &[u8] = include_bytes!("resources/wordbank.wbk");
 
-fn is_valid_word(word: &str) -> bool {
-    word.len() == 5 && word.chars().all(|c| c.is_ascii_alphabetic())
+/// A few dozen everyday words from the default wordbank, small enough that examples and
+/// doctests can play whole games in moments
+pub const SAMPLE_WORDBANK: &str = include_str!("resources/sample_wordbank.txt");
+
+/// Magic bytes at the start of a packed `.wbk` wordbank
+pub const PACKED_MAGIC: &[u8; 4] = b"WBK1";
+
+/// Bits per packed letter (A-Z fit in 5)
+const BITS_PER_LETTER: usize = 5;
+
+/// Word length the solver supports
+const SUPPORTED_LENGTH: usize = 5;
+
+/// What a wordbank file's words are for
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WordRole {
+    /// Words that can be the answer
+    Answers,
+    /// Words that are only allowed as guesses
+    Guesses,
+    /// Both (default)
+    #[default]
+    Both,
+}
+
+/// Settings a wordbank file declares about itself in leading `#key: value` lines, e.g.
+///
+/// ```text
+/// #role: answers
+/// #length: 5
+/// #lang: en
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WordbankHeader {
+    pub role: WordRole,
+    pub length: usize,
+    pub lang: Option<String>,
+}
+
+impl Default for WordbankHeader {
+    fn default() -> Self {
+        Self {
+            role: WordRole::default(),
+            length: SUPPORTED_LENGTH,
+            lang: None,
+        }
+    }
+}
+
+impl WordbankHeader {
+    /// Reads the `#key: value` lines at the top of `data`, stopping at the first word.
+    ///
+    /// Other comments and unknown keys are ignored.
+    ///
+    /// # Errors
+    /// Returns an error if `role` or `length` has a value that can't be understood.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wordle_solver::wordbank::{WordRole, WordbankHeader};
+    ///
+    /// let header = WordbankHeader::parse("#role: answers\n#lang: de\nkranz").unwrap();
+    /// assert_eq!(header.role, WordRole::Answers);
+    /// assert_eq!(header.lang.as_deref(), Some("de"));
+    /// assert_eq!(header.length, 5);
+    /// ```
+    pub fn parse(data: &str) -> Result<Self, String> {
+        let mut header = Self::default();
+        for line in data.lines().map(str::trim) {
+            if line.is_empty() {
+                continue;
+            }
+            let Some(comment) = line.strip_prefix('#') else {
+                break;
+            };
+            let Some((key, value)) = comment.split_once(':') else {
+                continue;
+            };
+            let value = value.trim();
+            match key.trim().to_ascii_lowercase().as_str() {
+                "role" => {
+                    header.role = match value.to_ascii_lowercase().as_str() {
+                        "answers" => WordRole::Answers,
+                        "guesses" => WordRole::Guesses,
+                        "both" => WordRole::Both,
+                        _ => return Err(format!("unknown role '{value}'")),
+                    };
+                }
+                "length" => {
+                    header.length = value
+                        .parse()
+                        .map_err(|_| format!("invalid length '{value}'"))?;
+                }
+                "lang" => header.lang = Some(value.to_string()),
+                _ => {}
+            }
+        }
+        Ok(header)
+    }
+}
+
+/// Parses a wordbank and its header, rejecting files declared for a word length the solver
+/// doesn't support.
+///
+/// # Errors
+/// Returns an error if the header is invalid or declares a length other than 5.
+pub fn parse_wordbank(data: &str) -> Result<(WordbankHeader, WordBank), String> {
+    let header = WordbankHeader::parse(data)?;
+    if header.length != SUPPORTED_LENGTH {
+        return Err(format!(
+            "wordbank declares {}-letter words, but only {SUPPORTED_LENGTH}-letter words are supported",
+            header.length
+        ));
+    }
+    Ok((header, load_wordbank_from_str(data)))
+}
+
+/// Unpacks the embedded wordbank.
+///
+/// # Panics
+/// Panics if the embedded data is corrupt, which the test suite rules out
+#[must_use]
+pub fn embedded_wordbank() -> WordBank {
+    decode_wordbank(EMBEDDED_WORDBANK).expect("embedded wordbank is a valid packed wordbank")
+}
+
+/// Loads [`SAMPLE_WORDBANK`].
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::game::{Game, Outcome};
+/// use wordle_solver::solver::{SolverConfig, get_feedback};
+/// use wordle_solver::wordbank::sample_wordbank;
+///
+/// let wordbank = sample_wordbank();
+/// let config = SolverConfig { use_cache: false, ..SolverConfig::default() };
+/// let mut game = Game::new(&wordbank, config);
+/// while !game.is_over() {
+///     let guess = game.recommendation().unwrap().guess;
+///     game.guess(&guess, &get_feedback(&guess, "QUOTA"));
+/// }
+/// assert_eq!(game.outcome(), Outcome::Solved("QUOTA".to_string()));
+/// ```
+#[must_use]
+pub fn sample_wordbank() -> WordBank {
+    load_wordbank_from_str(SAMPLE_WORDBANK)
+}
+
+/// Packs 5-letter words at 5 bits per letter: [`PACKED_MAGIC`], the word count as a
+/// little-endian `u32`, then the letters (A = 0) as a big-endian bit stream.
+///
+/// # Errors
+/// Returns an error if a word isn't 5 ASCII letters or there are more than `u32::MAX` words.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::wordbank::{decode_wordbank, encode_wordbank};
+///
+/// let words = vec!["CRANE".to_string(), "SLATE".to_string()];
+/// let packed = encode_wordbank(&words).unwrap();
+/// assert_eq!(packed.len(), 8 + 7); // header + 50 bits
+/// assert_eq!(decode_wordbank(&packed).unwrap(), words);
+/// ```
+pub fn encode_wordbank(words: &[String]) -> Result<Vec<u8>, String> {
+    let count = u32::try_from(words.len()).map_err(|_| "too many words to pack".to_string())?;
+    let mut packed = PACKED_MAGIC.to_vec();
+    packed.extend_from_slice(&count.to_le_bytes());
+
+    let mut buffer: u32 = 0;
+    let mut buffered = 0;
+    for word in words {
+        if !Word::is_valid(word) {
+            return Err(format!("'{word}' is not a 5-letter word"));
+        }
+        for b in word.bytes() {
+            buffer = (buffer << BITS_PER_LETTER) | u32::from(b.to_ascii_uppercase() - b'A');
+            buffered += BITS_PER_LETTER;
+            while buffered >= 8 {
+                buffered -= 8;
+                packed.push((buffer >> buffered) as u8);
+            }
+            buffer &= (1 << buffered) - 1;
+        }
+    }
+    if buffered > 0 {
+        packed.push((buffer << (8 - buffered)) as u8);
+    }
+    Ok(packed)
+}
+
+/// Unpacks words written by [`encode_wordbank`].
+///
+/// # Errors
+/// Returns an error if the data is not a packed wordbank or is truncated or corrupt.
+pub fn decode_wordbank(packed: &[u8]) -> Result<WordBank, String> {
+    let rest = packed
+        .strip_prefix(PACKED_MAGIC)
+        .ok_or_else(|| "not a packed wordbank".to_string())?;
+    let (count, letters) = rest
+        .split_first_chunk::<4>()
+        .ok_or_else(|| "packed wordbank is truncated".to_string())?;
+    let count = u32::from_le_bytes(*count) as usize;
+    let letter_count = count * 5;
+    if letters.len() != (letter_count * BITS_PER_LETTER).div_ceil(8) {
+        return Err("packed wordbank has the wrong length".to_string());
+    }
+
+    let mut words = Vec::with_capacity(count);
+    let mut word = String::with_capacity(5);
+    let mut bytes = letters.iter();
+    let mut buffer: u32 = 0;
+    let mut buffered = 0;
+    for _ in 0..letter_count {
+        while buffered < BITS_PER_LETTER {
+            // The length check above guarantees enough bytes
+            buffer = (buffer << 8) | u32::from(*bytes.next().unwrap_or(&0));
+            buffered += 8;
+        }
+        buffered -= BITS_PER_LETTER;
+        let letter = (buffer >> buffered) as u8 & 0x1f;
+        buffer &= (1 << buffered) - 1;
+        if letter >= 26 {
+            return Err("packed wordbank contains an invalid letter".to_string());
+        }
+        word.push(char::from(b'A' + letter));
+        if word.len() == 5 {
+            words.push(Word::from_checked(std::mem::take(&mut word)));
+        }
+    }
+    Ok(words.into_iter().collect())
+}
+
+/// Loads the wordbank from a file, directory, or URL, or the embedded list if none is given.
+///
+/// Exits the process with [`exit::WORDBANK_ERROR`] if the source can't be read or has no
+/// words to play with.
+#[must_use]
+pub fn load_wordbank(wordbank_path: Option<String>) -> WordBank {
+    let words = load_wordbank_quietly(wordbank_path);
+    println!("Loaded {} words.", words.len());
+    words
 }
 
+/// [`load_wordbank`] without saying how many words were loaded. Warnings and errors are
+/// still printed to stderr.
 #[must_use]
-pub fn load_wordbank(wordbank_path: Option<String>) -> Vec<String> {
-    if let Some(path) = wordbank_path {
-        match load_wordbank_from_file(&path) {
-            Ok(words) => {
-                println!("Loaded {} words.", words.len());
-                words
+pub fn load_wordbank_quietly(wordbank_path: Option<String>) -> WordBank {
+    let source = wordbank_source(wordbank_path);
+    match source.load() {
+        Ok(LoadedWords { words, warnings }) => {
+            for warning in warnings {
+                eprintln!("Warning: {}: {warning}", source.describe());
             }
-            Err(e) => {
-                eprintln!("Failed to load word bank from '{path}': {e}");
-                std::process::exit(1);
+            if let Err(e) = check_wordbank_size(&words) {
+                eprintln!("Failed to load word bank from '{}': {e}", source.describe());
+                std::process::exit(exit::WORDBANK_ERROR);
             }
+            words
         }
-    } else {
-        let words = load_wordbank_from_str(EMBEDDED_WORDBANK);
-        println!("Loaded {} words.", words.len());
-        words
+        Err(e) => {
+            eprintln!("Failed to load word bank from '{}': {e}", source.describe());
+            std::process::exit(exit::WORDBANK_ERROR);
+        }
+    }
+}
+
+/// Fewest words a game can be played with
+pub const MIN_WORDBANK_SIZE: usize = 1;
+
+/// Checks that `words` has enough words to play with.
+///
+/// # Errors
+/// Returns an error if there are fewer than [`MIN_WORDBANK_SIZE`] words.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::wordbank::check_wordbank_size;
+///
+/// assert!(check_wordbank_size(&["CRANE".to_string()]).is_ok());
+/// assert!(check_wordbank_size(&[]).is_err());
+/// ```
+pub fn check_wordbank_size(words: &[String]) -> Result<(), String> {
+    if words.len() < MIN_WORDBANK_SIZE {
+        return Err(format!(
+            "it has {} {SUPPORTED_LENGTH}-letter words, fewer than the {MIN_WORDBANK_SIZE} a game needs",
+            words.len()
+        ));
     }
+    Ok(())
+}
+
+/// Where `--input` says to load the wordbank from, or the embedded one if it wasn't given
+#[must_use]
+pub fn wordbank_source(wordbank_path: Option<String>) -> Box<dyn WordSource> {
+    wordbank_path.map_or_else(
+        || Box::new(EmbeddedSource) as Box<dyn WordSource>,
+        |spec| source_for(&spec),
+    )
 }
 
 /// Loads a wordbank from a string, filtering for valid 5-letter words.
@@ -45,26 +331,103 @@ pub fn load_wordbank(wordbank_path: Option<String>) -> Vec<String> {
 /// assert_eq!(wordbank, vec!["CRANE"]);
 /// ```
 #[must_use]
-pub fn load_wordbank_from_str(data: &str) -> Vec<String> {
-    data.lines()
-        .map(|line| line.trim().to_uppercase())
-        .filter(|word| is_valid_word(word))
-        .collect()
+pub fn load_wordbank_from_str(data: &str) -> WordBank {
+    WordBank::from_valid(data.lines().map(str::trim))
 }
 
-/// # Errors
-/// Returns an error if the file cannot be read or accessed.
-pub fn load_wordbank_from_file<P: AsRef<Path>>(path: P) -> io::Result<Vec<String>> {
-    let file = File::open(path)?;
-    let reader = BufReader::new(file);
-    let mut words = Vec::new();
-    for line in reader.lines() {
-        let word = line?.trim().to_uppercase();
-        if is_valid_word(&word) {
-            words.push(word);
+/// Warnings about the entries of a wordbank's text that a game of 5-letter A-Z words has to
+/// skip: words of another length, and words with letters outside A-Z. Comment lines are
+/// ignored. Empty if every entry is used.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::wordbank::wordbank_warnings;
+///
+/// assert!(wordbank_warnings("#lang: en\ncrane\nslate").is_empty());
+/// assert_eq!(
+///     wordbank_warnings("planet\nstreet\ncrane"),
+///     ["skipped 2 of 3 words that aren't 5 letters long (most are 6)"]
+/// );
+/// ```
+#[must_use]
+pub fn wordbank_warnings(data: &str) -> Vec<String> {
+    let entries: Vec<&str> = data
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect();
+    let mut other_lengths: BTreeMap<usize, usize> = BTreeMap::new();
+    let mut other_letters = Vec::new();
+    for &entry in &entries {
+        let length = entry.chars().count();
+        if length != SUPPORTED_LENGTH {
+            *other_lengths.entry(length).or_default() += 1;
+        } else if !Word::is_valid(entry) {
+            other_letters.push(entry);
         }
     }
-    Ok(words)
+
+    let mut warnings = Vec::new();
+    let wrong_length: usize = other_lengths.values().sum();
+    // Ties go to the shorter length
+    if let Some((&length, _)) = other_lengths
+        .iter()
+        .max_by(|a, b| a.1.cmp(b.1).then_with(|| b.0.cmp(a.0)))
+    {
+        warnings.push(format!(
+            "skipped {wrong_length} of {} words that aren't {SUPPORTED_LENGTH} letters long (most are {length})",
+            entries.len()
+        ));
+    }
+    if let Some(example) = other_letters.first() {
+        warnings.push(format!(
+            "skipped {} words with letters outside A-Z, such as '{example}'",
+            other_letters.len()
+        ));
+    }
+    warnings
+}
+
+/// Words loaded from a source, with warnings about entries that had to be skipped
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LoadedWords {
+    pub words: WordBank,
+    /// See [`wordbank_warnings`]
+    pub warnings: Vec<String>,
+}
+
+/// Loads a wordbank file, honouring its [`WordbankHeader`]. Packed `.wbk` files (see
+/// [`encode_wordbank`]) are recognised by their magic bytes.
+///
+/// # Errors
+/// Returns an error if the file cannot be read or accessed, or its contents are invalid.
+pub fn load_wordbank_from_file<P: AsRef<Path>>(path: P) -> io::Result<WordBank> {
+    load_checked_wordbank_from_file(path).map(|loaded| loaded.words)
+}
+
+/// [`load_wordbank_from_file`], along with warnings about the words it skipped. Packed files
+/// only hold valid words, so never have any.
+///
+/// # Errors
+/// Returns an error if the file cannot be read or accessed, or its contents are invalid.
+pub fn load_checked_wordbank_from_file<P: AsRef<Path>>(path: P) -> io::Result<LoadedWords> {
+    let data = std::fs::read(path)?;
+    let invalid = |e| io::Error::new(io::ErrorKind::InvalidData, e);
+    if data.starts_with(PACKED_MAGIC) {
+        return decode_wordbank(&data)
+            .map(|words| LoadedWords {
+                words,
+                warnings: Vec::new(),
+            })
+            .map_err(invalid);
+    }
+    let data = String::from_utf8(data).map_err(|e| invalid(e.to_string()))?;
+    let (_, words) = parse_wordbank(&data).map_err(invalid)?;
+    Ok(LoadedWords {
+        words,
+        warnings: wordbank_warnings(&data),
+    })
 }
 
 #[must_use]
@@ -75,36 +438,435 @@ pub fn get_wordle_start_path() -> Option<PathBuf> {
     })
 }
 
-pub fn read_starting_words(path: &Path) -> Option<Vec<String>> {
-    if let Ok(file) = File::open(path) {
-        let reader = BufReader::new(file);
-        let words: Vec<String> = reader
-            .lines()
-            .map_while(Result::ok)
-            .map(|w| w.trim().to_uppercase())
-            .filter(|w| is_valid_word(w))
-            .take(5)
-            .collect();
-        if words.len() == 5 {
-            return Some(words);
-        }
-    }
-    None
+/// Reads the first `count` cached starting words with their expected pool sizes, stored one
+/// `WORD SCORE` pair per line. Returns `None` if the cache is missing, holds fewer than `count`
+/// words, or was written before scores were stored.
+#[must_use]
+pub fn read_starting_words(path: &Path, count: usize) -> Option<Vec<(String, f64)>> {
+    let file = File::open(path).ok()?;
+    let words: Vec<(String, f64)> = BufReader::new(file)
+        .lines()
+        .map_while(Result::ok)
+        .filter_map(|line| {
+            let (word, score) = line.trim().split_once(' ')?;
+            let word = Word::new(word)?;
+            Some((word.into_string(), score.trim().parse().ok()?))
+        })
+        .take(count)
+        .collect();
+    (words.len() == count).then_some(words)
 }
 
-pub fn write_starting_words(path: &Path, words: &[String]) {
+/// Caches `words` and their expected pool sizes for [`read_starting_words`]
+pub fn write_starting_words(path: &Path, words: &[(String, f64)]) {
     if let Ok(mut file) = OpenOptions::new()
         .create(true)
         .write(true)
         .truncate(true)
         .open(path)
     {
-        for word in words.iter().take(5) {
-            let _ = writeln!(file, "{word}");
+        for (word, score) in words {
+            let _ = writeln!(file, "{word} {score}");
         }
     }
 }
 
+/// Most wordbanks the opener memo remembers; the least recently computed are forgotten first
+pub const OPENER_MEMO_LIMIT: usize = 32;
+
+/// Where the best opener of each wordbank the starting words cached at `start_path` were
+/// computed for is memoised
+#[must_use]
+pub fn opener_memo_path(start_path: &Path) -> PathBuf {
+    let mut path = start_path.as_os_str().to_owned();
+    path.push(".openers");
+    PathBuf::from(path)
+}
+
+fn opener_key(wordbank: &[String], strategy: Strategy) -> String {
+    format!("{:016x} {strategy}", wordbank_fingerprint(wordbank))
+}
+
+/// Reads the best opener and its expected pool size memoised for `wordbank` and `strategy`,
+/// stored one `FINGERPRINT STRATEGY WORD SCORE` entry per line
+#[must_use]
+pub fn read_opener(path: &Path, wordbank: &[String], strategy: Strategy) -> Option<(String, f64)> {
+    let key = opener_key(wordbank, strategy);
+    std::fs::read_to_string(path)
+        .ok()?
+        .lines()
+        .find_map(|line| {
+            let (word, score) = line
+                .strip_prefix(&key)?
+                .strip_prefix(' ')?
+                .split_once(' ')?;
+            Some((Word::new(word)?.into_string(), score.parse().ok()?))
+        })
+}
+
+/// Memoises `opener` for `wordbank` and `strategy` for [`read_opener`], replacing any earlier
+/// entry and keeping the [`OPENER_MEMO_LIMIT`] most recent
+pub fn write_opener(path: &Path, wordbank: &[String], strategy: Strategy, opener: &(String, f64)) {
+    let key = opener_key(wordbank, strategy);
+    let existing = std::fs::read_to_string(path).unwrap_or_default();
+    let entry = format!("{key} {} {}", opener.0, opener.1);
+    let mut lines: Vec<&str> = existing
+        .lines()
+        .filter(|line| {
+            line.strip_prefix(&key)
+                .is_none_or(|rest| !rest.starts_with(' '))
+        })
+        .collect();
+    lines.push(&entry);
+    let kept = &lines[lines.len().saturating_sub(OPENER_MEMO_LIMIT)..];
+    // The memo only saves time, so a failed write costs a recomputation next time
+    let _ = std::fs::write(path, kept.join("\n") + "\n");
+}
+
+/// The first `count` starting words cached at `start_path`, if they were computed for
+/// `wordbank`: the cache holds one wordbank's words at a time, and its top word must be the
+/// opener memoised for this one. When it holds another wordbank's words, just the memoised
+/// opener is returned. `None` when this wordbank's words have to be computed.
+#[must_use]
+pub fn read_cached_starting_words(
+    start_path: &Path,
+    wordbank: &[String],
+    strategy: Strategy,
+    count: usize,
+) -> Option<Vec<(String, f64)>> {
+    let opener = read_opener(&opener_memo_path(start_path), wordbank, strategy)?;
+    let top = read_starting_words(start_path, 1);
+    if top.is_some_and(|top| top[0].0 == opener.0) {
+        // A cache holding fewer words than asked for is recomputed
+        return read_starting_words(start_path, count);
+    }
+    Some(vec![opener])
+}
+
+/// Caches `words` at `start_path` and memoises the best of them as the opener for
+/// `wordbank` and `strategy`
+pub fn write_cached_starting_words(
+    start_path: &Path,
+    wordbank: &[String],
+    strategy: Strategy,
+    words: &[(String, f64)],
+) {
+    write_starting_words(start_path, words);
+    if let Some(opener) = words.first() {
+        write_opener(&opener_memo_path(start_path), wordbank, strategy, opener);
+    }
+}
+
+/// Where an interrupted computation of the starting words cached at `start_path` keeps the
+/// words it has scored so far
+#[must_use]
+pub fn starting_checkpoint_path(start_path: &Path) -> PathBuf {
+    let mut path = start_path.as_os_str().to_owned();
+    path.push(".partial");
+    PathBuf::from(path)
+}
+
+/// FNV-1a hash of a wordbank's words in order, stable across runs and platforms. Checkpoints
+/// are tagged with it so they are only resumed against the bank they were made for.
+#[must_use]
+pub fn wordbank_fingerprint(wordbank: &[String]) -> u64 {
+    wordbank
+        .iter()
+        .flat_map(|word| word.bytes().chain([b'\n']))
+        .fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        })
+}
+
+/// Reads the words an interrupted starting-word computation over `wordbank` scored, in
+/// wordbank order. Empty if there is no checkpoint or it was made for a different wordbank.
+#[must_use]
+pub fn read_starting_checkpoint(path: &Path, wordbank: &[String]) -> Vec<(String, f64)> {
+    let Ok(file) = File::open(path) else {
+        return Vec::new();
+    };
+    let mut lines = BufReader::new(file).lines().map_while(Result::ok);
+    let header = format!("wordbank {:016x}", wordbank_fingerprint(wordbank));
+    if lines.next().as_deref() != Some(header.as_str()) {
+        return Vec::new();
+    }
+    // A write cut short leaves a torn last line; everything before it is still good
+    lines
+        .map_while(|line| {
+            let (word, score) = line.split_once(' ')?;
+            Some((word.to_string(), score.parse().ok()?))
+        })
+        .collect()
+}
+
+/// Saves the words scored so far by a starting-word computation over `wordbank`, for
+/// [`read_starting_checkpoint`] to resume from
+pub fn write_starting_checkpoint(path: &Path, wordbank: &[String], scored: &[(String, f64)]) {
+    let mut contents = format!("wordbank {:016x}\n", wordbank_fingerprint(wordbank));
+    for (word, score) in scored {
+        contents.push_str(&format!("{word} {score}\n"));
+    }
+    // Progress is a nicety, so a failed write only costs a restart
+    let _ = std::fs::write(path, contents);
+}
+
+const VOWELS: &[u8] = b"AEIOU";
+
+/// Letter statistics over a wordbank
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WordbankStats {
+    pub word_count: usize,
+    /// Occurrences of each letter A-Z across all words
+    pub letter_counts: [usize; 26],
+    /// Occurrences of each letter A-Z at each position
+    pub positional_counts: [[usize; 26]; 5],
+    /// Words that use some letter more than once
+    pub repeated_letter_words: usize,
+}
+
+impl WordbankStats {
+    /// Tallies letters over `words`, which are expected to be 5 uppercase letters each.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wordle_solver::wordbank::WordbankStats;
+    ///
+    /// let stats = WordbankStats::new(&["CRANE".to_string(), "EERIE".to_string()]);
+    /// assert_eq!(stats.repeated_letter_words, 1);
+    /// assert_eq!(stats.vowel_count(), 2 + 4);
+    /// ```
+    #[must_use]
+    pub fn new(words: &[String]) -> Self {
+        let mut stats = Self {
+            word_count: words.len(),
+            letter_counts: [0; 26],
+            positional_counts: [[0; 26]; 5],
+            repeated_letter_words: 0,
+        };
+        for word in words {
+            let mut seen = [false; 26];
+            let mut repeated = false;
+            for (pos, b) in word.bytes().enumerate().take(5) {
+                if !b.is_ascii_uppercase() {
+                    continue;
+                }
+                let letter = usize::from(b - b'A');
+                stats.letter_counts[letter] += 1;
+                stats.positional_counts[pos][letter] += 1;
+                repeated |= seen[letter];
+                seen[letter] = true;
+            }
+            if repeated {
+                stats.repeated_letter_words += 1;
+            }
+        }
+        stats
+    }
+
+    /// Total letters counted
+    #[must_use]
+    pub fn letter_total(&self) -> usize {
+        self.letter_counts.iter().sum()
+    }
+
+    /// Occurrences of A, E, I, O, and U
+    #[must_use]
+    pub fn vowel_count(&self) -> usize {
+        VOWELS
+            .iter()
+            .map(|&v| self.letter_counts[usize::from(v - b'A')])
+            .sum()
+    }
+
+    /// Letters at `position` from most to least common, ties alphabetical
+    #[must_use]
+    pub fn ranked_at(&self, position: usize) -> Vec<(char, usize)> {
+        ranked(&self.positional_counts[position])
+    }
+
+    /// Human-readable report, one line per entry
+    #[allow(clippy::cast_precision_loss)] // don't care about this
+    #[must_use]
+    pub fn report(&self) -> Vec<String> {
+        let percent = |part: usize, whole: usize| {
+            if whole == 0 {
+                0.0
+            } else {
+                100.0 * part as f64 / whole as f64
+            }
+        };
+        let total = self.letter_total();
+        let vowels = self.vowel_count();
+
+        let mut lines = vec![
+            format!("Words: {}", self.word_count),
+            format!(
+                "Vowels (AEIOU): {:.1}% of letters, consonants {:.1}%",
+                percent(vowels, total),
+                percent(total - vowels, total)
+            ),
+            format!(
+                "Words with a repeated letter: {} ({:.1}%)",
+                self.repeated_letter_words,
+                percent(self.repeated_letter_words, self.word_count)
+            ),
+            String::new(),
+            "Letter frequency:".to_string(),
+        ];
+        for row in ranked(&self.letter_counts).chunks(4) {
+            let entries: Vec<String> = row
+                .iter()
+                .map(|&(c, n)| format!("{c} {n:>5} {:>4.1}%", percent(n, total)))
+                .collect();
+            lines.push(format!("  {}", entries.join("   ")));
+        }
+
+        lines.push(String::new());
+        lines.push("By position (most common | least common):".to_string());
+        for position in 0..5 {
+            let ranked = self.ranked_at(position);
+            let list = |entries: &[(char, usize)]| {
+                entries
+                    .iter()
+                    .map(|(c, n)| format!("{c} {n}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            lines.push(format!(
+                "  {}: {} | {}",
+                position + 1,
+                list(&ranked[..3]),
+                list(&ranked[ranked.len() - 3..])
+            ));
+        }
+        lines
+    }
+}
+
+/// The entries of a wordbank file as written, skipping blank lines and `#` comments. Unlike
+/// loading, nothing is uppercased or filtered out. Packed files hold uppercase words only.
+///
+/// # Errors
+/// Returns an error if the file can't be read, or isn't packed or UTF-8 text.
+pub fn read_wordbank_entries<P: AsRef<Path>>(path: P) -> io::Result<Vec<String>> {
+    let data = std::fs::read(path)?;
+    let invalid = |e| io::Error::new(io::ErrorKind::InvalidData, e);
+    if data.starts_with(PACKED_MAGIC) {
+        return decode_wordbank(&data)
+            .map(WordBank::into_vec)
+            .map_err(invalid);
+    }
+    let data = String::from_utf8(data).map_err(|e| invalid(e.to_string()))?;
+    Ok(data
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(String::from)
+        .collect())
+}
+
+/// How one word list differs from an older one, e.g. between releases of the official answer
+/// list. Words are compared ignoring case, so a word that only changed case is reported as
+/// such rather than as removed and added.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::wordbank::WordbankDiff;
+///
+/// let old: Vec<String> = ["crane", "slate", "Quota"].iter().map(|s| s.to_string()).collect();
+/// let new: Vec<String> = ["CRANE", "slate", "trace"].iter().map(|s| s.to_string()).collect();
+/// let diff = WordbankDiff::new(&old, &new);
+/// assert_eq!(diff.added, ["trace"]);
+/// assert_eq!(diff.removed, ["Quota"]);
+/// assert_eq!(diff.recased, [("crane".to_string(), "CRANE".to_string())]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WordbankDiff {
+    /// Words only in the new list, alphabetically
+    pub added: Vec<String>,
+    /// Words only in the old list, alphabetically
+    pub removed: Vec<String>,
+    /// Words in both lists with different capitals, as (old, new) spellings
+    pub recased: Vec<(String, String)>,
+}
+
+impl WordbankDiff {
+    #[must_use]
+    pub fn new(old: &[String], new: &[String]) -> Self {
+        // Keyed by the uppercase word; a list's first spelling of a word wins
+        let by_word = |words: &[String]| {
+            let mut spellings: BTreeMap<String, String> = BTreeMap::new();
+            for word in words {
+                spellings
+                    .entry(word.to_uppercase())
+                    .or_insert_with(|| word.clone());
+            }
+            spellings
+        };
+        let (old, new) = (by_word(old), by_word(new));
+        let keys: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+
+        let mut diff = Self::default();
+        for key in keys {
+            match (old.get(key), new.get(key)) {
+                (Some(before), Some(after)) if before != after => {
+                    diff.recased.push((before.clone(), after.clone()));
+                }
+                (Some(before), None) => diff.removed.push(before.clone()),
+                (None, Some(after)) => diff.added.push(after.clone()),
+                _ => {}
+            }
+        }
+        diff
+    }
+
+    /// Whether the lists hold the same words, spelled the same way
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.recased.is_empty()
+    }
+
+    /// Human-readable report: each kind of change with its count, then one word per line
+    #[must_use]
+    pub fn report(&self) -> Vec<String> {
+        if self.is_empty() {
+            return vec!["No differences.".to_string()];
+        }
+        let mut lines = Vec::new();
+        let mut section = |title: &str, words: Vec<String>| {
+            if words.is_empty() {
+                return;
+            }
+            if !lines.is_empty() {
+                lines.push(String::new());
+            }
+            lines.push(format!("{title} ({}):", words.len()));
+            lines.extend(words.into_iter().map(|word| format!("  {word}")));
+        };
+        section("Added", self.added.clone());
+        section("Removed", self.removed.clone());
+        section(
+            "Changed case",
+            self.recased
+                .iter()
+                .map(|(before, after)| format!("{before} -> {after}"))
+                .collect(),
+        );
+        lines
+    }
+}
+
+fn ranked(counts: &[usize; 26]) -> Vec<(char, usize)> {
+    let mut ranked: Vec<(char, usize)> = (b'A'..=b'Z')
+        .map(char::from)
+        .zip(counts.iter().copied())
+        .collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    ranked
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -214,6 +976,133 @@ mod tests {
         std::fs::remove_file(&file_path).unwrap();
     }
 
+    #[test]
+    fn test_wordbank_warnings_name_what_was_skipped() {
+        // A 6-letter list leaves nothing
+        let warnings = wordbank_warnings("planet\nstreet\nstrand\n");
+        assert_eq!(
+            warnings,
+            ["skipped 3 of 3 words that aren't 5 letters long (most are 6)"]
+        );
+        assert!(check_wordbank_size(&load_wordbank_from_str("planet\nstreet")).is_err());
+
+        let warnings = wordbank_warnings("krähe\nÄpfel\nkranz\nfuß\n");
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(
+            warnings[1],
+            "skipped 2 words with letters outside A-Z, such as 'krähe'"
+        );
+        assert!(wordbank_warnings("").is_empty());
+
+        let file_path = std::env::temp_dir().join("test_wordbank_warnings.txt");
+        std::fs::write(&file_path, "crane\ntoolong\n").unwrap();
+        let loaded = load_checked_wordbank_from_file(&file_path).unwrap();
+        std::fs::remove_file(&file_path).unwrap();
+        assert_eq!(loaded.words, ["CRANE"]);
+        assert_eq!(loaded.warnings.len(), 1);
+    }
+
+    #[test]
+    fn test_wordbank_header_defaults_without_header() {
+        assert_eq!(
+            WordbankHeader::parse("crane\n#role: answers").unwrap(),
+            WordbankHeader::default()
+        );
+    }
+
+    #[test]
+    fn test_wordbank_header_invalid_values() {
+        assert!(WordbankHeader::parse("#role: clues").is_err());
+        assert!(WordbankHeader::parse("#length: five").is_err());
+        // Plain comments and unknown keys are fine
+        assert!(WordbankHeader::parse("# my list\n#source: nyt\ncrane").is_ok());
+    }
+
+    #[test]
+    fn test_parse_wordbank_rejects_other_lengths() {
+        assert!(parse_wordbank("#length: 6\nplanet").is_err());
+
+        let (header, words) = parse_wordbank("#role: guesses\n#length: 5\ncrane").unwrap();
+        assert_eq!(header.role, WordRole::Guesses);
+        assert_eq!(words, vec!["CRANE"]);
+    }
+
+    #[test]
+    fn test_load_wordbank_from_file_with_bad_header() {
+        let file_path = std::env::temp_dir().join("test_wordbank_header.txt");
+        std::fs::write(&file_path, "#length: 6\nplanet\n").unwrap();
+
+        let err = load_wordbank_from_file(&file_path).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        std::fs::remove_file(&file_path).unwrap();
+    }
+
+    #[test]
+    fn test_embedded_wordbank_matches_source_list() {
+        let source = load_wordbank_from_str(include_str!("resources/wordbank.txt"));
+        assert_eq!(embedded_wordbank(), source);
+        // Regenerate with `wordle-solver -i src/resources/wordbank.txt wordbank compile ...`
+        assert_eq!(encode_wordbank(&source).unwrap(), EMBEDDED_WORDBANK);
+    }
+
+    #[test]
+    fn test_sample_wordbank_is_drawn_from_the_default() {
+        let sample = sample_wordbank();
+        assert_eq!(sample.len(), 40);
+        let full = embedded_wordbank();
+        assert!(sample.iter().all(|word| full.contains(word)));
+    }
+
+    #[test]
+    fn test_decode_wordbank_rejects_bad_data() {
+        assert!(decode_wordbank(b"crane\nslate").is_err());
+        assert!(decode_wordbank(b"WBK1").is_err());
+
+        let mut packed = encode_wordbank(&["CRANE".to_string()]).unwrap();
+        packed.pop();
+        assert!(decode_wordbank(&packed).is_err());
+
+        // 31 is not a letter
+        let mut packed = encode_wordbank(&["CRANE".to_string()]).unwrap();
+        packed[8] = 0xff;
+        assert!(decode_wordbank(&packed).is_err());
+    }
+
+    #[test]
+    fn test_encode_wordbank_rejects_invalid_words() {
+        assert!(encode_wordbank(&["CRANES".to_string()]).is_err());
+        assert_eq!(
+            decode_wordbank(&encode_wordbank(&[]).unwrap())
+                .unwrap()
+                .len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_load_wordbank_from_packed_file() {
+        let file_path = std::env::temp_dir().join("test_wordbank_packed.wbk");
+        let words = vec![
+            "CRANE".to_string(),
+            "SLATE".to_string(),
+            "ZESTY".to_string(),
+        ];
+        std::fs::write(&file_path, encode_wordbank(&words).unwrap()).unwrap();
+
+        assert_eq!(load_wordbank_from_file(&file_path).unwrap(), words);
+
+        std::fs::remove_file(&file_path).unwrap();
+    }
+
+    fn scored(words: &[&str]) -> Vec<(String, f64)> {
+        words
+            .iter()
+            .enumerate()
+            .map(|(i, w)| (w.to_string(), 1.5 + i as f64))
+            .collect()
+    }
+
     #[test]
     fn test_read_starting_words_valid() {
         let temp_dir = std::env::temp_dir();
@@ -221,19 +1110,22 @@ mod tests {
 
         {
             let mut file = File::create(&file_path).unwrap();
-            writeln!(file, "crane").unwrap();
-            writeln!(file, "slate").unwrap();
-            writeln!(file, "raise").unwrap();
-            writeln!(file, "stare").unwrap();
-            writeln!(file, "arise").unwrap();
+            writeln!(file, "crane 1.5").unwrap();
+            writeln!(file, "slate 2.5").unwrap();
+            writeln!(file, "raise 3.5").unwrap();
+            writeln!(file, "stare 4.5").unwrap();
+            writeln!(file, "arise 5.5").unwrap();
         }
 
-        let words = read_starting_words(&file_path);
+        let words = read_starting_words(&file_path, 5);
 
         assert!(words.is_some());
         let words = words.unwrap();
         assert_eq!(words.len(), 5);
-        assert_eq!(words, vec!["CRANE", "SLATE", "RAISE", "STARE", "ARISE"]);
+        assert_eq!(
+            words,
+            scored(&["CRANE", "SLATE", "RAISE", "STARE", "ARISE"])
+        );
 
         std::fs::remove_file(&file_path).unwrap();
     }
@@ -245,14 +1137,31 @@ mod tests {
 
         {
             let mut file = File::create(&file_path).unwrap();
-            writeln!(file, "crane").unwrap();
-            writeln!(file, "slate").unwrap();
+            writeln!(file, "crane 1.5").unwrap();
+            writeln!(file, "slate 2.5").unwrap();
         }
 
-        let words = read_starting_words(&file_path);
+        // Should return None if fewer words than asked for
+        assert!(read_starting_words(&file_path, 5).is_none());
+        assert_eq!(read_starting_words(&file_path, 2).unwrap().len(), 2);
 
-        // Should return None if less than 5 words
-        assert!(words.is_none());
+        std::fs::remove_file(&file_path).unwrap();
+    }
+
+    #[test]
+    fn test_read_starting_words_without_scores() {
+        let temp_dir = std::env::temp_dir();
+        let file_path = temp_dir.join("test_wordle_start_unscored.txt");
+
+        {
+            let mut file = File::create(&file_path).unwrap();
+            for word in ["crane", "slate", "raise", "stare", "arise"] {
+                writeln!(file, "{word}").unwrap();
+            }
+        }
+
+        // Caches from before scores were stored are recomputed
+        assert!(read_starting_words(&file_path, 5).is_none());
 
         std::fs::remove_file(&file_path).unwrap();
     }
@@ -260,34 +1169,26 @@ mod tests {
     #[test]
     fn test_read_starting_words_nonexistent() {
         let file_path = PathBuf::from("nonexistent_start_file.txt");
-        let words = read_starting_words(&file_path);
+        let words = read_starting_words(&file_path, 5);
 
         assert!(words.is_none());
     }
 
     #[test]
-    fn test_read_starting_words_takes_only_five() {
+    fn test_read_starting_words_takes_only_count() {
         let temp_dir = std::env::temp_dir();
         let file_path = temp_dir.join("test_wordle_start_long.txt");
 
-        {
-            let mut file = File::create(&file_path).unwrap();
-            writeln!(file, "crane").unwrap();
-            writeln!(file, "slate").unwrap();
-            writeln!(file, "raise").unwrap();
-            writeln!(file, "stare").unwrap();
-            writeln!(file, "arise").unwrap();
-            writeln!(file, "irate").unwrap();
-            writeln!(file, "atone").unwrap();
-        }
+        let words = scored(&[
+            "CRANE", "SLATE", "RAISE", "STARE", "ARISE", "IRATE", "ATONE",
+        ]);
+        write_starting_words(&file_path, &words);
 
-        let words = read_starting_words(&file_path);
+        let read = read_starting_words(&file_path, 5);
 
-        assert!(words.is_some());
-        let words = words.unwrap();
-        assert_eq!(words.len(), 5);
-        // Should only take first 5
-        assert_eq!(words, vec!["CRANE", "SLATE", "RAISE", "STARE", "ARISE"]);
+        assert!(read.is_some());
+        // Should only take the first 5
+        assert_eq!(read.unwrap(), words[..5]);
 
         std::fs::remove_file(&file_path).unwrap();
     }
@@ -297,13 +1198,7 @@ mod tests {
         let temp_dir = std::env::temp_dir();
         let file_path = temp_dir.join("test_write_start.txt");
 
-        let words = vec![
-            "CRANE".to_string(),
-            "SLATE".to_string(),
-            "RAISE".to_string(),
-            "STARE".to_string(),
-            "ARISE".to_string(),
-        ];
+        let words = scored(&["CRANE", "SLATE", "RAISE", "STARE", "ARISE"]);
 
         write_starting_words(&file_path, &words);
 
@@ -312,7 +1207,16 @@ mod tests {
         let lines: Vec<&str> = content.lines().collect();
 
         assert_eq!(lines.len(), 5);
-        assert_eq!(lines, vec!["CRANE", "SLATE", "RAISE", "STARE", "ARISE"]);
+        assert_eq!(
+            lines,
+            vec![
+                "CRANE 1.5",
+                "SLATE 2.5",
+                "RAISE 3.5",
+                "STARE 4.5",
+                "ARISE 5.5"
+            ]
+        );
 
         std::fs::remove_file(&file_path).unwrap();
     }
@@ -322,23 +1226,17 @@ mod tests {
         let temp_dir = std::env::temp_dir();
         let file_path = temp_dir.join("test_write_start_long.txt");
 
-        let words = vec![
-            "CRANE".to_string(),
-            "SLATE".to_string(),
-            "RAISE".to_string(),
-            "STARE".to_string(),
-            "ARISE".to_string(),
-            "IRATE".to_string(),
-            "ATONE".to_string(),
-        ];
+        let words = scored(&[
+            "CRANE", "SLATE", "RAISE", "STARE", "ARISE", "IRATE", "ATONE",
+        ]);
 
         write_starting_words(&file_path, &words);
 
-        // Should only write first 5
+        // Every word is written so a larger count can be read back
         let content = std::fs::read_to_string(&file_path).unwrap();
         let lines: Vec<&str> = content.lines().collect();
 
-        assert_eq!(lines.len(), 5);
+        assert_eq!(lines.len(), 7);
 
         std::fs::remove_file(&file_path).unwrap();
     }
@@ -349,21 +1247,113 @@ mod tests {
         let file_path = temp_dir.join("test_roundtrip.txt");
 
         let original_words = vec![
-            "CRANE".to_string(),
-            "SLATE".to_string(),
-            "RAISE".to_string(),
-            "STARE".to_string(),
-            "ARISE".to_string(),
+            ("CRANE".to_string(), 60.425_137_111_517_37),
+            ("SLATE".to_string(), 71.571_753_607_640_86),
         ];
 
         write_starting_words(&file_path, &original_words);
-        let read_words = read_starting_words(&file_path).unwrap();
+        let read_words = read_starting_words(&file_path, 2).unwrap();
 
         assert_eq!(original_words, read_words);
 
         std::fs::remove_file(&file_path).unwrap();
     }
 
+    #[test]
+    fn test_starting_checkpoint_roundtrip() {
+        let file_path = starting_checkpoint_path(&std::env::temp_dir().join("test_checkpoint"));
+        assert!(file_path.ends_with("test_checkpoint.partial"));
+
+        let wordbank: Vec<String> = ["CRANE", "SLATE", "PLATE"]
+            .iter()
+            .map(|w| w.to_string())
+            .collect();
+        let scored = vec![("CRANE".to_string(), 1.0), ("SLATE".to_string(), 1.667)];
+        write_starting_checkpoint(&file_path, &wordbank, &scored);
+        assert_eq!(read_starting_checkpoint(&file_path, &wordbank), scored);
+
+        // A checkpoint for another wordbank isn't resumed
+        assert!(read_starting_checkpoint(&file_path, &wordbank[..2]).is_empty());
+
+        std::fs::remove_file(&file_path).unwrap();
+        assert!(read_starting_checkpoint(&file_path, &wordbank).is_empty());
+    }
+
+    #[test]
+    fn test_opener_memo_per_wordbank_and_strategy() {
+        let start_path = std::env::temp_dir().join("test_opener_memo_start");
+        let memo = opener_memo_path(&start_path);
+        assert!(memo.ends_with("test_opener_memo_start.openers"));
+        let _ = std::fs::remove_file(&memo);
+
+        let bank: Vec<String> = ["CRANE", "SLATE", "PLATE"]
+            .iter()
+            .map(|w| w.to_string())
+            .collect();
+        let other = &bank[1..];
+        let words = scored(&["SLATE", "CRANE"]);
+        write_cached_starting_words(&start_path, &bank, Strategy::ExpectedSize, &words);
+        assert_eq!(
+            read_cached_starting_words(&start_path, &bank, Strategy::ExpectedSize, 2),
+            Some(words.clone())
+        );
+        // Nothing is known for another wordbank or strategy until it has been computed
+        assert!(
+            read_cached_starting_words(&start_path, other, Strategy::ExpectedSize, 2).is_none()
+        );
+        assert!(read_opener(&memo, &bank, Strategy::CandidatesOnly).is_none());
+
+        // Switching banks overwrites the cache, but the first bank's opener is remembered
+        write_cached_starting_words(
+            &start_path,
+            other,
+            Strategy::ExpectedSize,
+            &scored(&["PLATE"]),
+        );
+        assert_eq!(
+            read_cached_starting_words(&start_path, &bank, Strategy::ExpectedSize, 2),
+            Some(words[..1].to_vec())
+        );
+        write_opener(
+            &memo,
+            &bank,
+            Strategy::ExpectedSize,
+            &("CRANE".to_string(), 2.0),
+        );
+        assert_eq!(
+            read_opener(&memo, &bank, Strategy::ExpectedSize),
+            Some(("CRANE".to_string(), 2.0))
+        );
+        assert_eq!(std::fs::read_to_string(&memo).unwrap().lines().count(), 2);
+
+        std::fs::remove_file(&start_path).unwrap();
+        std::fs::remove_file(&memo).unwrap();
+    }
+
+    #[test]
+    fn test_opener_memo_keeps_the_most_recent() {
+        let memo = std::env::temp_dir().join("test_opener_memo_limit");
+        let _ = std::fs::remove_file(&memo);
+        let banks: Vec<Vec<String>> = (0..=OPENER_MEMO_LIMIT)
+            .map(|i| {
+                vec![
+                    format!("{:A>5}", char::from(b'A' + (i % 26) as u8)),
+                    i.to_string(),
+                ]
+            })
+            .collect();
+        for bank in &banks {
+            write_opener(&memo, bank, Strategy::ExpectedSize, &(bank[0].clone(), 1.0));
+        }
+        assert!(read_opener(&memo, &banks[0], Strategy::ExpectedSize).is_none());
+        assert!(read_opener(&memo, &banks[1], Strategy::ExpectedSize).is_some());
+        assert_eq!(
+            std::fs::read_to_string(&memo).unwrap().lines().count(),
+            OPENER_MEMO_LIMIT
+        );
+        std::fs::remove_file(&memo).unwrap();
+    }
+
     #[test]
     fn test_get_wordle_start_path() {
         let path = get_wordle_start_path();
@@ -377,16 +1367,75 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_wordbank_stats_counts() {
+        let words = load_wordbank_from_str("crane\nslate\neerie");
+        let stats = WordbankStats::new(&words);
+
+        assert_eq!(stats.word_count, 3);
+        assert_eq!(stats.letter_total(), 15);
+        assert_eq!(stats.letter_counts[usize::from(b'E' - b'A')], 5);
+        assert_eq!(stats.repeated_letter_words, 1);
+        // CRANE: A, E; SLATE: A, E; EERIE: E, E, I, E
+        assert_eq!(stats.vowel_count(), 8);
+        assert_eq!(stats.ranked_at(4)[0], ('E', 3));
+        assert_eq!(stats.ranked_at(0)[0], ('C', 1));
+    }
+
+    #[test]
+    fn test_wordbank_stats_report_empty_bank() {
+        let report = WordbankStats::new(&[]).report();
+        assert_eq!(report[0], "Words: 0");
+        assert!(report.iter().any(|line| line.contains("0.0%")));
+    }
+
     #[test]
     fn test_embedded_wordbank_not_empty() {
         assert!(!EMBEDDED_WORDBANK.is_empty());
 
         // Test that embedded wordbank can be loaded
-        let words = load_wordbank_from_str(EMBEDDED_WORDBANK);
-        assert!(words.len() > 0);
+        let words = embedded_wordbank();
+        assert!(!words.is_empty());
 
         // All words should be 5 letters and uppercase
         assert!(words.iter().all(|w| w.len() == 5));
         assert!(words.iter().all(|w| w.chars().all(|c| c.is_uppercase())));
     }
+
+    #[test]
+    fn test_wordbank_diff_of_files() {
+        let dir = std::env::temp_dir();
+        let old = dir.join("test_wordbank_diff_old.txt");
+        let new = dir.join("test_wordbank_diff_new.wbk");
+        std::fs::write(&old, "#role: answers\ncrane\n\nslate\nplanet\n").unwrap();
+        let words: Vec<String> = ["CRANE", "TRACE"].iter().map(|s| s.to_string()).collect();
+        std::fs::write(&new, encode_wordbank(&words).unwrap()).unwrap();
+        let old_entries = read_wordbank_entries(&old).unwrap();
+        let new_entries = read_wordbank_entries(&new).unwrap();
+        std::fs::remove_file(&old).ok();
+        std::fs::remove_file(&new).ok();
+
+        // Entries are kept as written, even those a game would skip
+        assert_eq!(old_entries, ["crane", "slate", "planet"]);
+        let diff = WordbankDiff::new(&old_entries, &new_entries);
+        assert_eq!(
+            diff.report(),
+            [
+                "Added (1):",
+                "  TRACE",
+                "",
+                "Removed (2):",
+                "  planet",
+                "  slate",
+                "",
+                "Changed case (1):",
+                "  crane -> CRANE",
+            ]
+        );
+        assert_eq!(
+            WordbankDiff::new(&old_entries, &old_entries).report(),
+            ["No differences."]
+        );
+        assert!(read_wordbank_entries(dir.join("test_wordbank_diff_missing.txt")).is_err());
+    }
 }
@@ -0,0 +1,578 @@
+//! Validated words and interned word storage.
+//!
+//! [`Word`] and [`WordBank`] hold only words the solver can play, so text is checked once
+//! where it comes in rather than wherever it's used.
+//!
+//! A [`WordArena`] packs every word of a wordbank into one contiguous byte buffer and hands
+//! out [`WordId`] indices, so the solver's hot loops can work on `u16`s and byte slices
+//! instead of thousands of individually allocated `String`s.
+
+use std::fmt;
+use std::ops::Deref;
+
+/// Letters per word in an arena
+pub const WORD_LENGTH: usize = 5;
+
+/// A word the solver can play: [`WORD_LENGTH`] ASCII letters, stored uppercase.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::words::Word;
+///
+/// let word = Word::new("crane").unwrap();
+/// assert_eq!(word.as_str(), "CRANE");
+/// assert!(Word::new("CRAN3").is_none());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Word(String);
+
+impl Word {
+    /// Uppercases `text` if it's a valid word, or returns `None`
+    #[must_use]
+    pub fn new(text: &str) -> Option<Self> {
+        Self::is_valid(text).then(|| Self(text.to_ascii_uppercase()))
+    }
+
+    /// Wraps `text` without checking it, for words built from known-good letters
+    pub(crate) fn from_checked(text: String) -> Self {
+        debug_assert!(Self::is_valid(&text) && text == text.to_ascii_uppercase());
+        Self(text)
+    }
+
+    /// Whether `text` is [`WORD_LENGTH`] ASCII letters, in any case
+    #[must_use]
+    pub fn is_valid(text: &str) -> bool {
+        text.len() == WORD_LENGTH && text.bytes().all(|b| b.is_ascii_alphabetic())
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    #[must_use]
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl Deref for Word {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Word {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl PartialEq<str> for Word {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Word {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<String> for Word {
+    fn eq(&self, other: &String) -> bool {
+        self.0 == *other
+    }
+}
+
+impl From<Word> for String {
+    fn from(word: Word) -> Self {
+        word.0
+    }
+}
+
+/// A wordbank holding only valid [`Word`]s, in order.
+///
+/// It dereferences to `[String]`, so it can be passed anywhere the solver takes a wordbank.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_solver::words::WordBank;
+///
+/// let bank = WordBank::from_valid(["crane", "not a word", "SLATE"]);
+/// assert_eq!(bank.as_slice(), ["CRANE", "SLATE"]);
+/// assert!(WordBank::new(vec!["CRANE".to_string(), "CRAN".to_string()]).is_err());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WordBank(Vec<String>);
+
+impl WordBank {
+    /// Checks and uppercases every word.
+    ///
+    /// # Errors
+    /// Returns an error naming the first word that isn't valid.
+    pub fn new(words: Vec<String>) -> Result<Self, String> {
+        words
+            .into_iter()
+            .map(|word| {
+                Word::new(&word)
+                    .ok_or_else(|| format!("'{word}' is not a {WORD_LENGTH}-letter word"))
+            })
+            .collect()
+    }
+
+    /// Keeps the valid words of `words`, uppercased, and drops the rest
+    #[must_use]
+    pub fn from_valid<I, S>(words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        words
+            .into_iter()
+            .filter_map(|word| Word::new(word.as_ref()))
+            .collect()
+    }
+
+    #[must_use]
+    pub fn as_slice(&self) -> &[String] {
+        &self.0
+    }
+
+    #[must_use]
+    pub fn into_vec(self) -> Vec<String> {
+        self.0
+    }
+
+    pub fn push(&mut self, word: Word) {
+        self.0.push(word.0);
+    }
+}
+
+impl Deref for WordBank {
+    type Target = [String];
+
+    fn deref(&self) -> &[String] {
+        &self.0
+    }
+}
+
+impl<T> PartialEq<Vec<T>> for WordBank
+where
+    String: PartialEq<T>,
+{
+    fn eq(&self, other: &Vec<T>) -> bool {
+        self.0 == *other
+    }
+}
+
+impl<T, const N: usize> PartialEq<[T; N]> for WordBank
+where
+    String: PartialEq<T>,
+{
+    fn eq(&self, other: &[T; N]) -> bool {
+        self.0 == *other
+    }
+}
+
+impl IntoIterator for WordBank {
+    type Item = Word;
+    type IntoIter = std::iter::Map<std::vec::IntoIter<String>, fn(String) -> Word>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter().map(Word)
+    }
+}
+
+impl<'a> IntoIterator for &'a WordBank {
+    type Item = &'a String;
+    type IntoIter = std::slice::Iter<'a, String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl FromIterator<Word> for WordBank {
+    fn from_iter<I: IntoIterator<Item = Word>>(words: I) -> Self {
+        Self(words.into_iter().map(String::from).collect())
+    }
+}
+
+/// Index of a word in a [`WordArena`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct WordId(pub u16);
+
+impl WordId {
+    #[must_use]
+    pub const fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// All words of a wordbank stored back to back in a single buffer
+#[derive(Clone, Debug)]
+pub struct WordArena {
+    buffer: Vec<u8>,
+    /// Ids ordered by word, for lookups by binary search
+    sorted: Vec<WordId>,
+}
+
+impl WordArena {
+    /// Approximate bytes the arena takes
+    #[must_use]
+    pub fn memory_footprint(&self) -> usize {
+        self.buffer.capacity() + self.sorted.capacity() * size_of::<WordId>()
+    }
+
+    /// Interns `words` in order, so `WordId(i)` is `words[i]`.
+    ///
+    /// Returns `None` if any word is not 5 ASCII letters or there are more words than
+    /// a `u16` can index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wordle_solver::words::{WordArena, WordId};
+    ///
+    /// let arena = WordArena::new(&["CRANE".to_string(), "SLATE".to_string()]).unwrap();
+    /// assert_eq!(arena.get(WordId(1)), "SLATE");
+    /// assert_eq!(arena.id("CRANE"), Some(WordId(0)));
+    /// assert_eq!(arena.id("BRAIN"), None);
+    /// ```
+    #[must_use]
+    pub fn new(words: &[String]) -> Option<Self> {
+        if !words.iter().all(|w| Word::is_valid(w)) {
+            return None;
+        }
+        Self::intern(words)
+    }
+
+    /// Interns a [`WordBank`], whose words are already known to be valid. Returns `None` if
+    /// there are more words than a `u16` can index.
+    #[must_use]
+    pub fn from_wordbank(wordbank: &WordBank) -> Option<Self> {
+        Self::intern(wordbank)
+    }
+
+    fn intern(words: &[String]) -> Option<Self> {
+        if words.len() > usize::from(u16::MAX) + 1 {
+            return None;
+        }
+
+        let mut buffer = Vec::with_capacity(words.len() * WORD_LENGTH);
+        for word in words {
+            buffer.extend_from_slice(word.as_bytes());
+        }
+        let mut arena = Self {
+            buffer,
+            sorted: Vec::new(),
+        };
+        let mut sorted: Vec<WordId> = arena.ids().collect();
+        sorted.sort_by(|&a, &b| arena.bytes(a).cmp(arena.bytes(b)));
+        arena.sorted = sorted;
+        Some(arena)
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.buffer.len() / WORD_LENGTH
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// The letters of a word as ASCII bytes
+    #[must_use]
+    pub fn bytes(&self, id: WordId) -> &[u8] {
+        let start = id.index() * WORD_LENGTH;
+        &self.buffer[start..start + WORD_LENGTH]
+    }
+
+    /// The word for an id
+    #[must_use]
+    pub fn get(&self, id: WordId) -> &str {
+        // Only ASCII letters are ever stored, so this can't fail
+        std::str::from_utf8(self.bytes(id)).unwrap_or_default()
+    }
+
+    /// Looks up the id of a word, if it was interned
+    #[must_use]
+    pub fn id(&self, word: &str) -> Option<WordId> {
+        self.sorted
+            .binary_search_by(|&id| self.bytes(id).cmp(word.as_bytes()))
+            .ok()
+            .map(|i| self.sorted[i])
+    }
+
+    /// All ids in wordbank order
+    #[allow(clippy::cast_possible_truncation)] // length is capped at u16::MAX + 1 in new()
+    pub fn ids(&self) -> impl Iterator<Item = WordId> + use<> {
+        (0..self.len()).map(|i| WordId(i as u16))
+    }
+
+    /// Looks up the ids of several words, skipping any that weren't interned
+    #[must_use]
+    pub fn ids_of(&self, words: &[String]) -> Vec<WordId> {
+        words.iter().filter_map(|w| self.id(w)).collect()
+    }
+
+    /// Converts ids back into owned words
+    #[must_use]
+    pub fn to_strings(&self, ids: &[WordId]) -> Vec<String> {
+        ids.iter().map(|&id| self.get(id).to_string()).collect()
+    }
+
+    /// The words of a set, in wordbank order
+    #[must_use]
+    pub fn words(&self, set: &CandidateSet) -> Vec<String> {
+        set.iter().map(|id| self.get(id).to_string()).collect()
+    }
+}
+
+/// Fixed-size bitset of word ids, e.g. the candidates remaining out of a wordbank.
+///
+/// Membership tests and removals are O(1), and intersections and differences work a 64-bit
+/// block at a time.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CandidateSet {
+    blocks: Vec<u64>,
+    capacity: usize,
+}
+
+impl CandidateSet {
+    /// An empty set able to hold ids `0..capacity`
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            blocks: vec![0; capacity.div_ceil(64)],
+            capacity,
+        }
+    }
+
+    /// A set containing every id `0..capacity`
+    #[must_use]
+    pub fn full(capacity: usize) -> Self {
+        let mut set = Self::new(capacity);
+        for (i, block) in set.blocks.iter_mut().enumerate() {
+            let bits = (capacity - i * 64).min(64);
+            *block = if bits == 64 {
+                u64::MAX
+            } else {
+                (1 << bits) - 1
+            };
+        }
+        set
+    }
+
+    /// A set containing the given ids
+    #[must_use]
+    pub fn from_ids(capacity: usize, ids: &[WordId]) -> Self {
+        let mut set = Self::new(capacity);
+        for &id in ids {
+            set.insert(id);
+        }
+        set
+    }
+
+    /// Largest id (exclusive) the set can hold
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Adds an id. Returns false if it was already present.
+    ///
+    /// # Panics
+    /// Panics if `id` is outside the set's capacity
+    pub fn insert(&mut self, id: WordId) -> bool {
+        assert!(id.index() < self.capacity, "word id out of range");
+        let (block, bit) = (id.index() / 64, id.index() % 64);
+        let was_set = self.blocks[block] & (1 << bit) != 0;
+        self.blocks[block] |= 1 << bit;
+        !was_set
+    }
+
+    /// Removes an id. Returns false if it wasn't present.
+    pub fn remove(&mut self, id: WordId) -> bool {
+        let present = self.contains(id);
+        if present {
+            self.blocks[id.index() / 64] &= !(1 << (id.index() % 64));
+        }
+        present
+    }
+
+    #[must_use]
+    pub fn contains(&self, id: WordId) -> bool {
+        self.blocks
+            .get(id.index() / 64)
+            .is_some_and(|block| block & (1 << (id.index() % 64)) != 0)
+    }
+
+    /// Number of ids in the set
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.blocks.iter().map(|b| b.count_ones() as usize).sum()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.blocks.iter().all(|&b| b == 0)
+    }
+
+    /// Ids in the set, in ascending order
+    #[allow(clippy::cast_possible_truncation)] // ids always fit in u16
+    pub fn iter(&self) -> impl Iterator<Item = WordId> + '_ {
+        self.blocks.iter().enumerate().flat_map(|(i, &block)| {
+            (0..64)
+                .filter(move |bit| block & (1 << bit) != 0)
+                .map(move |bit| WordId((i * 64 + bit) as u16))
+        })
+    }
+
+    /// Ids in both sets
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.zip_blocks(other, |a, b| a & b)
+    }
+
+    /// Ids in this set but not in `other`
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        self.zip_blocks(other, |a, b| a & !b)
+    }
+
+    fn zip_blocks(&self, other: &Self, op: impl Fn(u64, u64) -> u64) -> Self {
+        let blocks = self
+            .blocks
+            .iter()
+            .enumerate()
+            .map(|(i, &block)| op(block, other.blocks.get(i).copied().unwrap_or(0)))
+            .collect();
+        Self {
+            blocks,
+            capacity: self.capacity,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::words;
+
+    #[test]
+    fn test_is_valid_word() {
+        assert!(Word::is_valid("CRANE"));
+        assert!(Word::is_valid("crane"));
+        assert!(Word::is_valid("AbCdE"));
+        assert!(!Word::is_valid("CRAN")); // Too short
+        assert!(!Word::is_valid("CRANES")); // Too long
+        assert!(!Word::is_valid("CRAN3")); // Contains digit
+        assert!(!Word::is_valid("CRAN ")); // Contains space
+        assert!(!Word::is_valid("")); // Empty
+    }
+
+    #[test]
+    fn test_wordbank_keeps_only_valid_words() {
+        let bank = WordBank::new(words(&["crane", "Slate"])).unwrap();
+        assert_eq!(bank.as_slice(), ["CRANE", "SLATE"]);
+        assert_eq!(
+            WordBank::new(words(&["CRANE", "ÉCLAT"])),
+            Err("'ÉCLAT' is not a 5-letter word".to_string())
+        );
+
+        let mut bank = WordBank::from_valid(["trace", "", "CRAN3"]);
+        bank.push(Word::new("brain").unwrap());
+        assert_eq!(bank.into_vec(), ["TRACE", "BRAIN"]);
+
+        let bank: WordBank = ["SLATE", "CRANE"]
+            .into_iter()
+            .filter_map(Word::new)
+            .collect();
+        let arena = WordArena::from_wordbank(&bank).unwrap();
+        assert_eq!(arena.id("CRANE"), Some(WordId(1)));
+        assert_eq!(bank, ["SLATE", "CRANE"]);
+        assert_eq!(bank.into_iter().next(), Word::new("slate"));
+    }
+
+    #[test]
+    fn test_arena_round_trip() {
+        let list = words(&["SLATE", "CRANE", "TRACE"]);
+        let arena = WordArena::new(&list).unwrap();
+
+        assert_eq!(arena.len(), 3);
+        assert!(!arena.is_empty());
+        let ids: Vec<WordId> = arena.ids().collect();
+        assert_eq!(arena.to_strings(&ids), list);
+        for (i, word) in list.iter().enumerate() {
+            assert_eq!(arena.id(word), Some(WordId(i as u16)));
+        }
+    }
+
+    #[test]
+    fn test_arena_rejects_invalid_words() {
+        assert!(WordArena::new(&words(&["CRANE", "TOOLONG"])).is_none());
+        assert!(WordArena::new(&words(&["CRAN3"])).is_none());
+    }
+
+    #[test]
+    fn test_arena_empty() {
+        let arena = WordArena::new(&[]).unwrap();
+        assert!(arena.is_empty());
+        assert_eq!(arena.id("CRANE"), None);
+    }
+
+    #[test]
+    fn test_candidate_set_membership() {
+        let mut set = CandidateSet::new(130);
+        assert!(set.is_empty());
+        assert!(set.insert(WordId(0)));
+        assert!(set.insert(WordId(129)));
+        assert!(!set.insert(WordId(129)));
+
+        assert!(set.contains(WordId(129)));
+        assert!(!set.contains(WordId(64)));
+        assert!(!set.contains(WordId(1000)));
+        assert_eq!(set.len(), 2);
+
+        assert!(set.remove(WordId(0)));
+        assert!(!set.remove(WordId(0)));
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![WordId(129)]);
+    }
+
+    #[test]
+    fn test_candidate_set_full() {
+        for capacity in [0, 1, 63, 64, 65, 200] {
+            let set = CandidateSet::full(capacity);
+            assert_eq!(set.len(), capacity);
+            assert_eq!(set.iter().count(), capacity);
+        }
+    }
+
+    #[test]
+    fn test_candidate_set_operations() {
+        let a = CandidateSet::from_ids(100, &[WordId(1), WordId(2), WordId(70)]);
+        let b = CandidateSet::from_ids(100, &[WordId(2), WordId(99)]);
+
+        let ids = |set: CandidateSet| set.iter().map(|id| id.0).collect::<Vec<_>>();
+        assert_eq!(ids(a.intersection(&b)), vec![2]);
+        assert_eq!(ids(a.difference(&b)), vec![1, 70]);
+    }
+
+    #[test]
+    fn test_ids_of_skips_unknown() {
+        let arena = WordArena::new(&words(&["CRANE", "SLATE"])).unwrap();
+        assert_eq!(
+            arena.ids_of(&words(&["SLATE", "BRAIN", "CRANE"])),
+            vec![WordId(1), WordId(0)]
+        );
+    }
+}
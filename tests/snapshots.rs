@@ -0,0 +1,148 @@
+// Golden tests for what the front ends show during scripted games
+// Each test compares the CLI's output or the TUI's screen with a file under tests/snapshots.
+// After a deliberate change to the output, rerun with UPDATE_SNAPSHOTS=1 to rewrite them and
+// review the diff like any other change.
+
+#![cfg(feature = "tui")]
+
+use crossterm::event::{Event, KeyCode, KeyEvent};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use wordle_solver::game_state::game_loop_with_config;
+use wordle_solver::load_wordbank_from_file;
+use wordle_solver::solver::SolverConfig;
+use wordle_solver::tui::{TuiWrapper, buffer_text};
+use wordle_solver::wordbank::sample_wordbank;
+
+/// Tall enough for the information panel to list a few candidates
+const SCREEN: (u16, u16) = (80, 40);
+
+fn snapshot_path(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("snapshots")
+        .join(format!("{name}.txt"))
+}
+
+/// Compares `actual` with the snapshot called `name`, or rewrites the snapshot when
+/// `UPDATE_SNAPSHOTS` is set. On a mismatch the new output is written next to the snapshot
+/// with a `.new` extension, to diff against it.
+fn assert_snapshot(name: &str, actual: &str) {
+    let path = snapshot_path(name);
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, actual).unwrap();
+        return;
+    }
+    let expected = std::fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "no snapshot at {}; run with UPDATE_SNAPSHOTS=1 to write it",
+            path.display()
+        )
+    });
+    if expected != actual {
+        let new = path.with_extension("txt.new");
+        std::fs::write(&new, actual).unwrap();
+        let line = expected
+            .lines()
+            .zip(actual.lines())
+            .position(|(expected, actual)| expected != actual)
+            .unwrap_or_else(|| expected.lines().count().min(actual.lines().count()));
+        panic!(
+            "{name} differs from its snapshot at line {}; the new output is in {}",
+            line + 1,
+            new.display()
+        );
+    }
+}
+
+/// Runs the binary's CLI on the sample wordbank with `input` typed in, and returns what it
+/// printed
+fn run_cli(name: &str, args: &[&str], input: &str) -> String {
+    // A home of its own, so saved stats and preferences neither leak in nor get written
+    let home = std::env::temp_dir().join(format!("wordle-snapshot-{}-{name}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&home);
+    std::fs::create_dir_all(&home).unwrap();
+    let wordbank = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/resources/sample_wordbank.txt");
+    let mut child = Command::new(env!("CARGO_BIN_EXE_wordle-solver"))
+        .args(["--ui", "cli", "--no-cache", "--lang", "en", "-i"])
+        .arg(&wordbank)
+        .args(args)
+        .env("HOME", &home)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    let _ = std::fs::remove_dir_all(&home);
+    String::from_utf8(output.stdout).unwrap()
+}
+
+/// Key presses typing `text`, with a newline pressing Enter
+fn keys(text: &str) -> Vec<Event> {
+    text.chars()
+        .map(|c| match c {
+            '\n' => KeyCode::Enter,
+            c => KeyCode::Char(c),
+        })
+        .map(|code| Event::Key(KeyEvent::from(code)))
+        .collect()
+}
+
+/// Plays `events` in the TUI on the sample wordbank and returns the last screen drawn
+fn run_tui(events: Vec<Event>, tile_glyphs: bool) -> String {
+    let wordbank = sample_wordbank();
+    let config = SolverConfig {
+        use_cache: false,
+        ..SolverConfig::default()
+    };
+    let mut interface = TuiWrapper::offscreen(SCREEN.0, SCREEN.1, events)
+        .unwrap()
+        .with_tile_glyphs(tile_glyphs);
+    game_loop_with_config(&wordbank, &mut interface, &config);
+    buffer_text(interface.offscreen_buffer().unwrap())
+}
+
+#[test]
+fn test_cli_solved_game() {
+    let output = run_cli("solved", &[], "CRANE\nXXXXG\nhint\nQUOTE\nGGGGG\nexit\n");
+    assert_snapshot("cli_solved_game", &output);
+}
+
+#[test]
+fn test_cli_accessible_game() {
+    let output = run_cli(
+        "accessible",
+        &["--accessible"],
+        "CRANE\nXXGXG\nwhy-not SLATE\nexit\n",
+    );
+    assert_snapshot("cli_accessible_game", &output);
+}
+
+#[test]
+fn test_tui_mid_game() {
+    // Guess CRANE, mark it, and stop while the recommendation is shown
+    let events = keys("CRANE\nxxxxg\n");
+    assert_snapshot("tui_mid_game", &run_tui(events, false));
+}
+
+#[test]
+fn test_tui_solved_game_with_glyphs() {
+    let events = keys("CRANE\nxxxxg\n \nQUOTE\nggggg\n");
+    assert_snapshot("tui_solved_game_with_glyphs", &run_tui(events, true));
+}
+
+#[test]
+fn test_sample_wordbank_file_matches_embedded_sample() {
+    // The CLI snapshots load the file, the TUI ones the embedded copy
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/resources/sample_wordbank.txt");
+    assert_eq!(load_wordbank_from_file(path).unwrap(), sample_wordbank());
+}